@@ -1,11 +1,24 @@
 use std::env::{self, VarError};
-use std::path::PathBuf;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Local, LocalResult, NaiveDateTime, TimeZone, Utc};
-use clap::{Parser, Subcommand};
-use lz_db::{BookmarkSearch, Connection, DateInput, ExistingBookmark, ReadOnly, Transaction};
+use clap::{Parser, Subcommand, ValueEnum};
+use lz_db::{
+    restore_backup, BookmarkOrder, BookmarkSearch, BookmarkSearchDateParams,
+    BookmarkSearchDatetimeField, BookmarkSearchDatetimeOrientation, Connection, DateInput,
+    DuplicateBehavior, ExistingBookmark, ExistingTag, FsckReport, FullTextQuery, LinkStatus,
+    ReadOnly, SchemaStatus, TagTreeNode, Timezone, Transaction, TransactionMode,
+    UrlCanonicalization,
+};
+use lz_web::archive::local::{LocalArchiveArgs, LocalArchiver};
+use lz_web::archive::{archive_bookmark, Wayback};
+use lz_web::discovery::{discover, record_discussions, sites, DiscoveryArgs, DiscussionSite};
+use lz_web::http::{Fetcher, FetcherArgs, Metadata};
+use lz_web::link_check::{check_all_links, fix_redirects, RedirectFix};
 use sentry::types::Dsn;
 use std::collections::HashSet;
 use url::Url;
@@ -22,6 +35,119 @@ struct Cli {
     /// Path to the database to use
     #[clap(long, global = true, default_value = "db.sqlite")]
     db: PathBuf,
+
+    /// Don't bring the database schema up to date before using it
+    #[clap(long, global = true)]
+    no_migrate: bool,
+
+    #[clap(flatten)]
+    url_canonicalization: UrlCanonicalizationArgs,
+}
+
+impl Cli {
+    /// Open the database, with the URL canonicalization rules given
+    /// on the command line, applying pending migrations unless asked
+    /// not to.
+    async fn connect(&self) -> Result<Connection> {
+        let conn = if self.no_migrate {
+            Connection::from_path_unmigrated(&self.db).await?
+        } else {
+            Connection::from_path(&self.db).await?
+        };
+        Ok(conn.with_url_canonicalization(self.url_canonicalization.clone().into()))
+    }
+}
+
+/// Rules for telling whether two links point to the same page; see
+/// [`UrlCanonicalization`].
+#[derive(clap::Args, Clone, Debug)]
+struct UrlCanonicalizationArgs {
+    /// Tell `http://` and `https://` links apart
+    #[clap(long = "url-keep-scheme", action = clap::ArgAction::SetFalse, global = true)]
+    ignore_scheme: bool,
+
+    /// Tell links apart by a leading `www.` in their host name
+    #[clap(long = "url-keep-www", action = clap::ArgAction::SetFalse, global = true)]
+    ignore_www: bool,
+
+    /// Tell links apart by a trailing slash in their path
+    #[clap(long = "url-keep-trailing-slash", action = clap::ArgAction::SetFalse, global = true)]
+    ignore_trailing_slash: bool,
+
+    /// Ignore fragments (the part after `#`) when telling links apart
+    #[clap(long = "url-ignore-fragment", global = true)]
+    ignore_fragment: bool,
+
+    /// Query parameters that don't change which page a link points
+    /// to. A trailing `*` matches all parameters starting with what
+    /// comes before it.
+    #[clap(
+        long = "url-ignored-params",
+        value_delimiter = ',',
+        default_values_t = UrlCanonicalization::default().ignored_params,
+        global = true
+    )]
+    ignored_params: Vec<String>,
+}
+
+impl From<UrlCanonicalizationArgs> for UrlCanonicalization {
+    fn from(args: UrlCanonicalizationArgs) -> Self {
+        Self {
+            ignore_scheme: args.ignore_scheme,
+            ignore_www: args.ignore_www,
+            ignore_trailing_slash: args.ignore_trailing_slash,
+            ignore_fragment: args.ignore_fragment,
+            ignored_params: args.ignored_params,
+        }
+    }
+}
+
+/// The orders that `lz list` can list bookmarks in; see [`BookmarkOrder`].
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+enum ListOrder {
+    /// Newest bookmarks first
+    #[default]
+    Created,
+    /// Most recently modified bookmarks first
+    Modified,
+    /// Most recently accessed bookmarks first
+    Accessed,
+    /// Alphabetically by title
+    Title,
+    /// Best matches of the `--search` words first
+    Relevance,
+}
+
+impl From<ListOrder> for BookmarkOrder {
+    fn from(order: ListOrder) -> Self {
+        match order {
+            ListOrder::Created => BookmarkOrder::Created,
+            ListOrder::Modified => BookmarkOrder::Modified,
+            ListOrder::Accessed => BookmarkOrder::Accessed,
+            ListOrder::Title => BookmarkOrder::Title,
+            ListOrder::Relevance => BookmarkOrder::Relevance,
+        }
+    }
+}
+
+/// What an import does with bookmarks for URLs that are already
+/// bookmarked; see [`DuplicateBehavior`].
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+enum OnDuplicate {
+    /// Replace the existing bookmark with the imported one
+    Overwrite,
+    /// Keep the existing bookmark and ignore the imported one
+    #[default]
+    Skip,
+}
+
+impl From<OnDuplicate> for DuplicateBehavior {
+    fn from(on_duplicate: OnDuplicate) -> Self {
+        match on_duplicate {
+            OnDuplicate::Overwrite => DuplicateBehavior::Overwrite,
+            OnDuplicate::Skip => DuplicateBehavior::Skip,
+        }
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -31,6 +157,90 @@ struct TuiArgs {
     user: String,
 }
 
+#[derive(Parser, Debug)]
+struct TagFilterArgs {
+    /// Tag (or tags as a comma-delineated list) for the link; note
+    /// that listed bookmarks must be tagged with all tags given.
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    tagged: Option<Vec<String>>,
+    /// Tag (or tags as a comma-delineated list); listed bookmarks
+    /// must be tagged with at least one of the tags given.
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    any_tag: Option<Vec<String>>,
+    /// Tag (or tags as a comma-delineated list); listed bookmarks
+    /// must not be tagged with any of the tags given.
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    not_tagged: Option<Vec<String>>,
+    /// Make the tags given above also match the tags below them in
+    /// the hierarchy, e.g. `lang` also matches `lang/rust`.
+    #[arg(long)]
+    with_subtags: bool,
+}
+
+/// Dates can be days like `2024-01-31`, timestamps like
+/// `2024-01-31T12:00:00Z`, or relative to now, like `7d` or
+/// `last-month`.
+#[derive(Parser, Debug)]
+struct DateFilterArgs {
+    /// Created on or after a date
+    #[arg(long)]
+    created_after: Option<DateInput>,
+    /// Created on or before a date
+    #[arg(long)]
+    created_before: Option<DateInput>,
+    /// Created on a date
+    #[arg(long)]
+    created_on: Option<DateInput>,
+    /// Last modified on or after a date
+    #[arg(long)]
+    modified_after: Option<DateInput>,
+    /// Last modified on or before a date
+    #[arg(long)]
+    modified_before: Option<DateInput>,
+    /// Last modified on a date
+    #[arg(long)]
+    modified_on: Option<DateInput>,
+    /// Last accessed on or after a date
+    #[arg(long)]
+    accessed_after: Option<DateInput>,
+    /// Last accessed on or before a date
+    #[arg(long)]
+    accessed_before: Option<DateInput>,
+    /// Last accessed on a date
+    #[arg(long)]
+    accessed_on: Option<DateInput>,
+    /// The timezone that days are counted in, like `Europe/Berlin`;
+    /// defaults to the user's timezone setting, then to the system's
+    /// timezone.
+    #[arg(long)]
+    timezone: Option<Timezone>,
+}
+
+impl DateFilterArgs {
+    fn filters(&self) -> Vec<BookmarkSearch> {
+        use BookmarkSearchDatetimeField::*;
+        use BookmarkSearchDatetimeOrientation::*;
+        [
+            (&self.created_after, Created, After),
+            (&self.created_before, Created, Before),
+            (&self.created_on, Created, On),
+            (&self.modified_after, Modified, After),
+            (&self.modified_before, Modified, Before),
+            (&self.modified_on, Modified, On),
+            (&self.accessed_after, Accessed, After),
+            (&self.accessed_before, Accessed, Before),
+            (&self.accessed_on, Accessed, On),
+        ]
+        .into_iter()
+        .filter_map(|(date, field, orientation)| {
+            Some(BookmarkSearch::ByDate {
+                date: BookmarkSearchDateParams::new(field, orientation, date.clone()?),
+            })
+        })
+        .collect()
+    }
+}
+
 /// A date timestamp specified as 0:00:00 local time.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 struct LocalDatestamp(DateTime<Utc>);
@@ -77,12 +287,148 @@ struct CliAddArgs {
     /// Optional context for the association
     #[arg(long)]
     associated_context: Option<String>,
+
+    #[clap(flatten)]
+    fetcher: FetcherArgs,
+
+    #[clap(flatten)]
+    archive: LocalArchiveArgs,
 }
 
 #[derive(Subcommand, Debug)]
 enum ImportCommands {
     /// Import from linkding (https://github.com/sissbruecker/linkding)
     Linkding(lz_import_linkding::Args),
+
+    /// Import from a Netscape bookmark file (bookmarks.html), as
+    /// exported by browsers, Pinboard and linkding
+    Netscape(lz_netscape::ImportArgs),
+
+    /// Import from a Pinboard (https://pinboard.in) JSON export
+    Pinboard(lz_import_pinboard::Args),
+}
+
+#[derive(Subcommand, Debug)]
+enum ExportCommands {
+    /// Export to a Netscape bookmark file (bookmarks.html), which
+    /// browsers, Pinboard and linkding can import
+    Netscape(lz_netscape::ExportArgs),
+}
+
+#[derive(Subcommand, Debug)]
+enum DbCommands {
+    /// Apply pending migrations to the database, creating it if it
+    /// doesn't exist
+    Migrate,
+
+    /// Show the migrations that are applied to the database, and those
+    /// that are pending
+    Status,
+
+    /// Back up the database; this is safe while `lz web` is running
+    Backup {
+        /// File to write the backup to, or a directory to write a
+        /// timestamped backup into
+        dest: PathBuf,
+        /// Keep only this many of the most recent backups in the
+        /// destination directory
+        #[arg(long)]
+        keep: Option<NonZeroUsize>,
+    },
+
+    /// Check the database for problems, and for URLs and tags that
+    /// nothing uses anymore
+    Fsck {
+        /// Remove the URLs and tags that nothing uses
+        #[arg(long, action)]
+        remove_orphans: bool,
+    },
+
+    /// Replace the database with a backup; nothing else may be using
+    /// the database meanwhile
+    Restore {
+        /// The backup to restore
+        backup: PathBuf,
+        /// Replace the database without asking
+        #[arg(long, action)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum TagsCommands {
+    /// List the user's tags, with how many bookmarks have each
+    #[clap(alias = "ls")]
+    List {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+    },
+
+    /// Show the hierarchy of the user's tags (like `lang/rust/async`),
+    /// with how many bookmarks have each
+    Tree {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+    },
+
+    /// Rename one of the user's tags; if they have a tag with the
+    /// new name, the bookmarks get that tag instead
+    Rename {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// The tag to rename
+        tag: String,
+        /// Its new name
+        new_name: String,
+    },
+
+    /// Replace a tag with another one on all the user's bookmarks
+    Merge {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// The tag to replace
+        from: String,
+        /// The tag to replace it with
+        into: String,
+    },
+
+    /// Delete one of the user's tags, removing it from all bookmarks
+    #[clap(alias = "rm")]
+    Remove {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// The tag to remove
+        tag: String,
+    },
+
+    /// Make a name an alias of a tag: Tagging bookmarks with the alias
+    /// tags them with the tag, and searching for it finds the tag
+    Alias {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// The alias, e.g. `js`
+        alias: String,
+        /// The tag it stands for, e.g. `javascript`
+        tag: String,
+        /// Also replace a tag named like the alias with the tag on
+        /// all bookmarks
+        #[arg(long)]
+        retro_apply: bool,
+    },
+
+    /// List the user's tag aliases
+    Aliases {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+    },
+
+    /// Remove a tag alias
+    Unalias {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// The alias to remove
+        alias: String,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -110,16 +456,23 @@ enum Commands {
         #[clap(flatten)]
         common_args: TuiArgs,
 
-        /// Created on or after a date; accepts a YYYY-MM-DD string
-        #[arg(long)]
-        created_after: Option<String>,
-        /// Created before a date; accepts a YYYY-MM-DD string
+        #[clap(flatten)]
+        date_args: DateFilterArgs,
+        #[clap(flatten)]
+        tag_args: TagFilterArgs,
+        /// Words that must occur in the bookmark's title, description,
+        /// notes, URL or archived page; a word ending in `*` matches as
+        /// a prefix.
         #[arg(long)]
-        created_before: Option<String>,
-        /// Tag (or tags as a comma-delineated list) for the link; note
-        /// that listed bookmarks must be tagged with all tags given.
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tagged: Option<Vec<String>>,
+        search: Option<String>,
+        /// A search query like `tag:rust -tag:old "exact phrase"
+        /// site:github.com before:2024-01-01 unread:true`; see the
+        /// other options for what each kind of term means.
+        #[arg(long, short, allow_hyphen_values = true)]
+        query: Option<String>,
+        /// The order to list bookmarks in
+        #[arg(long, value_enum, default_value_t)]
+        order: ListOrder,
     },
 
     /// Add or remove tags from existing bookmarks
@@ -136,13 +489,148 @@ enum Commands {
         delete: bool,
     },
 
+    /// Find a bookmark's snapshot in the Wayback Machine and
+    /// associate it with the bookmark
+    Snapshot {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// Bookmark to find a snapshot for
+        link: String,
+        /// Ask the Wayback Machine to take a snapshot, if it has none
+        #[arg(long, action)]
+        request: bool,
+        #[clap(flatten)]
+        fetcher: FetcherArgs,
+    },
+
+    /// Keep local copies of bookmarked pages, so they stay readable
+    /// and searchable when the pages change or disappear
+    Archive {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// Bookmark whose page to archive
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        link: Option<String>,
+        /// Archive the pages of all the user's bookmarks that weren't
+        /// archived yet
+        #[arg(long, action)]
+        all: bool,
+        /// With --all, archive pages again even if they were archived
+        /// before
+        #[arg(long, action, requires = "all")]
+        refresh: bool,
+        /// Directory to keep local copies of pages in. Defaults to an
+        /// `archive` directory next to the database.
+        #[arg(long)]
+        archive_dir: Option<PathBuf>,
+        #[clap(flatten)]
+        fetcher: FetcherArgs,
+    },
+
+    /// Merge a bookmark into another one: The kept bookmark gets the
+    /// other's tags, notes and associated links, and the other one is
+    /// deleted
+    Merge {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// The bookmark to keep
+        keep: String,
+        /// The bookmark to merge into it
+        absorb: String,
+    },
+
+    /// Find bookmarks whose URLs point to the same page, and merge
+    /// them into the oldest one
+    Dedupe {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// Merge all duplicates without asking
+        #[arg(long, action, conflicts_with = "dry_run")]
+        yes: bool,
+        /// Only list the duplicates
+        #[arg(long, action)]
+        dry_run: bool,
+    },
+
+    /// Check whether all bookmarked links still work, and record
+    /// which ones are gone or have moved
+    CheckLinks {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// Afterwards, update the user's bookmarks whose links
+        /// permanently redirect to point to the new location
+        #[arg(long, action)]
+        fix_redirects: bool,
+        /// Milliseconds to wait between checking two links
+        #[arg(long, default_value = "1000")]
+        delay: u64,
+        #[clap(flatten)]
+        fetcher: FetcherArgs,
+    },
+
+    /// Search discussion sites (Hacker News, Reddit, Lobsters) for
+    /// discussions of bookmarks, and associate them with the bookmarks
+    Discover {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// Bookmark to find discussions of
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        link: Option<String>,
+        /// Find discussions of all the user's bookmarks
+        #[arg(long, action)]
+        all: bool,
+        /// Keep running, searching again for all bookmarks every this
+        /// many seconds
+        #[arg(long, requires = "all")]
+        every: Option<u64>,
+        /// Milliseconds to wait between bookmarks, to go easy on the
+        /// discussion sites
+        #[arg(long, default_value = "1000")]
+        delay: u64,
+        #[clap(flatten)]
+        discovery: DiscoveryArgs,
+        #[clap(flatten)]
+        fetcher: FetcherArgs,
+    },
+
     /// Run the lz web server
     #[clap(alias = "serve")]
     Web(lz_web::Args),
 
+    /// Manage the user's tags
+    #[clap(subcommand)]
+    Tags(TagsCommands),
+
+    /// Show or change the user's settings
+    Settings {
+        #[clap(flatten)]
+        common_args: TuiArgs,
+        /// Count days in date searches in this timezone, like
+        /// `Europe/Berlin`
+        #[arg(long)]
+        timezone: Option<Timezone>,
+        /// Count days in date searches in the system's timezone again
+        #[arg(long, conflicts_with = "timezone")]
+        clear_timezone: bool,
+    },
+
+    /// Maintain the database
+    #[clap(subcommand)]
+    Db(DbCommands),
+
     /// Import bookmarks from another system
+    Import {
+        /// What to do when encountering a bookmark under the same URL
+        #[clap(long, default_value_t, value_enum, global = true)]
+        on_duplicate: OnDuplicate,
+
+        #[command(subcommand)]
+        source: ImportCommands,
+    },
+
+    /// Export bookmarks to other bookmark managers
     #[clap(subcommand)]
-    Import(ImportCommands),
+    Export(ExportCommands),
 
     /// Writes the contents of the openapi.json file to stdout
     #[clap(subcommand, alias = "generate-openapi-spec")]
@@ -176,23 +664,31 @@ async fn main() -> Result<()> {
             common_args,
             add_args,
         } => {
-            let conn = Connection::from_path(&cli.db).await?;
+            let conn = cli.connect().await?;
             let mut txn = conn.begin_for_user(&common_args.user).await?;
-            add_cmd(&mut txn, add_args).await?;
+            add_cmd(&mut txn, add_args, &cli.db).await?;
             txn.commit().await?;
         }
         Commands::List {
             common_args,
-            created_after,
-            created_before,
-            tagged,
+            date_args,
+            tag_args,
+            search,
+            query,
+            order,
         } => {
-            let conn = Connection::from_path(&cli.db).await?;
+            let mut filters = list_filters(date_args, tag_args, search, query)?;
+            let conn = cli.connect().await?;
             let txn = conn.begin_ro_for_user(&common_args.user).await?;
-            list_cmd(txn, created_after, created_before, tagged).await?;
+            if let Some(tz) = date_args.timezone.or(txn.user().timezone) {
+                for filter in &mut filters {
+                    filter.default_timezone(tz);
+                }
+            }
+            list_cmd(txn, &filters, (*order).into()).await?;
         }
         Commands::Remove { common_args, link } => {
-            let conn = Connection::from_path(&cli.db).await?;
+            let conn = cli.connect().await?;
             let mut txn = conn.begin_for_user(&common_args.user).await?;
             remove_cmd(&mut txn, link).await?;
             txn.commit().await?;
@@ -203,70 +699,363 @@ async fn main() -> Result<()> {
             delete,
             tag,
         } => {
-            let conn = Connection::from_path(&cli.db).await?;
+            let conn = cli.connect().await?;
             let mut txn = conn.begin_for_user(&common_args.user).await?;
             tag_cmd(&mut txn, link, tag, delete).await?;
             txn.commit().await?;
         }
+        Commands::Snapshot {
+            common_args,
+            link,
+            request,
+            fetcher,
+        } => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            snapshot_cmd(&mut txn, link, *request, fetcher).await?;
+            txn.commit().await?;
+        }
+        Commands::Archive {
+            common_args,
+            link,
+            all: _,
+            refresh,
+            archive_dir,
+            fetcher,
+        } => {
+            let conn = cli.connect().await?;
+            let store = LocalArchiveArgs {
+                archive_pages: true,
+                archive_dir: archive_dir.clone(),
+            }
+            .store(Some(&cli.db));
+            let archiver = LocalArchiver::new(Fetcher::new(fetcher.clone())?, store);
+            archive_cmd(
+                &conn,
+                &common_args.user,
+                &archiver,
+                link.as_deref(),
+                *refresh,
+            )
+            .await?;
+        }
+        Commands::Merge {
+            common_args,
+            keep,
+            absorb,
+        } => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            merge_cmd(&mut txn, keep, absorb).await?;
+            txn.commit().await?;
+        }
+        Commands::Dedupe {
+            common_args,
+            yes,
+            dry_run,
+        } => {
+            let conn = cli.connect().await?;
+            dedupe_cmd(&conn, &common_args.user, *yes, *dry_run).await?;
+        }
+        Commands::CheckLinks {
+            common_args,
+            fix_redirects,
+            delay,
+            fetcher,
+        } => {
+            let conn = cli.connect().await?;
+            let fetcher = Fetcher::new(fetcher.clone())?;
+            check_links_cmd(&conn, &fetcher, Duration::from_millis(*delay)).await?;
+            if *fix_redirects {
+                let mut txn = conn.begin_for_user(&common_args.user).await?;
+                fix_redirects_cmd(&mut txn).await?;
+                txn.commit().await?;
+            }
+        }
+        Commands::Discover {
+            common_args,
+            link,
+            all: _,
+            every,
+            delay,
+            discovery,
+            fetcher,
+        } => {
+            let conn = cli.connect().await?;
+            let fetcher = Fetcher::new(fetcher.clone())?;
+            let sites = sites(&fetcher, discovery);
+            match link {
+                Some(link) => {
+                    let mut txn = conn.begin_for_user(&common_args.user).await?;
+                    discover_cmd(&mut txn, &sites, link).await?;
+                    txn.commit().await?;
+                }
+                None => loop {
+                    discover_all_cmd(
+                        &conn,
+                        &common_args.user,
+                        &sites,
+                        Duration::from_millis(*delay),
+                    )
+                    .await?;
+                    let Some(every) = every else { break };
+                    tokio::time::sleep(Duration::from_secs(*every)).await;
+                },
+            }
+        }
         Commands::Web(args) => {
-            let conn = Connection::from_path(&cli.db).await?;
+            let conn = cli.connect().await?;
             lz_web::run(conn, args).await?;
         }
-        Commands::Import(ImportCommands::Linkding(args)) => {
-            let conn = Connection::from_path(&cli.db).await?;
-            lz_import_linkding::run(conn, args).await?;
+        Commands::Import {
+            on_duplicate,
+            source: ImportCommands::Linkding(args),
+        } => {
+            let conn = cli.connect().await?;
+            lz_import_linkding::run(conn, args, (*on_duplicate).into()).await?;
+        }
+        Commands::Import {
+            on_duplicate,
+            source: ImportCommands::Netscape(args),
+        } => {
+            let conn = cli.connect().await?;
+            lz_netscape::run_import(conn, args, (*on_duplicate).into()).await?;
+        }
+        Commands::Import {
+            on_duplicate,
+            source: ImportCommands::Pinboard(args),
+        } => {
+            let conn = cli.connect().await?;
+            lz_import_pinboard::run(conn, args, (*on_duplicate).into()).await?;
+        }
+        Commands::Tags(TagsCommands::List { common_args }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_ro_for_user(&common_args.user).await?;
+            for tag in txn.list_tags_with_counts().await? {
+                println!("{}\t{}", tag.count, tag.tag.name);
+            }
+        }
+        Commands::Tags(TagsCommands::Tree { common_args }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_ro_for_user(&common_args.user).await?;
+            print_tag_tree(&txn.tag_tree().await?, 0);
+        }
+        Commands::Tags(TagsCommands::Rename {
+            common_args,
+            tag,
+            new_name,
+        }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            let existing = user_tag_named(&mut txn, tag).await?;
+            let renamed = txn.rename_tag(existing.id, new_name).await?;
+            txn.commit().await?;
+            println!("Renamed {} to {}", existing.name, renamed.name);
+        }
+        Commands::Tags(TagsCommands::Merge {
+            common_args,
+            from,
+            into,
+        }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            let from = user_tag_named(&mut txn, from).await?;
+            let into = txn
+                .ensure_tags([into])
+                .await?
+                .pop()
+                .expect("ensure_tags returns the tag");
+            let count = txn.merge_tags(from.id, into.id).await?;
+            txn.commit().await?;
+            println!(
+                "Replaced {} with {} on {} bookmarks",
+                from.name, into.name, count
+            );
+        }
+        Commands::Tags(TagsCommands::Remove { common_args, tag }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            let tag = user_tag_named(&mut txn, tag).await?;
+            let count = txn.delete_tag(tag.id).await?;
+            txn.commit().await?;
+            println!("Removed {} from {} bookmarks", tag.name, count);
+        }
+        Commands::Tags(TagsCommands::Alias {
+            common_args,
+            alias,
+            tag,
+            retro_apply,
+        }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            let tag = user_tag_named(&mut txn, tag).await?;
+            let alias = txn.add_tag_alias(alias, tag.id, *retro_apply).await?;
+            txn.commit().await?;
+            println!("{} is now an alias of {}", alias.name, alias.tag_name);
+        }
+        Commands::Tags(TagsCommands::Aliases { common_args }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_ro_for_user(&common_args.user).await?;
+            for alias in txn.list_tag_aliases().await? {
+                println!("{}\t{}", alias.name, alias.tag_name);
+            }
+        }
+        Commands::Tags(TagsCommands::Unalias { common_args, alias }) => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            let removed = match txn.remove_tag_alias(alias).await {
+                Err(sqlx::Error::RowNotFound) => Err(anyhow!("no alias named {}", alias)),
+                res => Ok(res?),
+            }?;
+            txn.commit().await?;
+            println!("Removed alias {} of {}", removed.name, removed.tag_name);
+        }
+        Commands::Settings {
+            common_args,
+            timezone,
+            clear_timezone,
+        } => {
+            let conn = cli.connect().await?;
+            let mut txn = conn.begin_for_user(&common_args.user).await?;
+            if timezone.is_some() || *clear_timezone {
+                txn.set_user_timezone(*timezone).await?;
+            }
+            let timezone = txn.user().timezone;
+            txn.commit().await?;
+            match timezone {
+                Some(tz) => println!("timezone\t{tz}"),
+                None => println!("timezone\t(system)"),
+            }
+        }
+        Commands::Db(DbCommands::Migrate) => {
+            if !cli.db.exists() {
+                let conn = Connection::from_path(&cli.db).await?;
+                let applied = conn.schema_status().await?.migrations.len();
+                println!("Created {} with {} migrations", cli.db.display(), applied);
+            } else {
+                let conn = Connection::from_path_unmigrated(&cli.db).await?;
+                let pending = conn.schema_status().await?.pending().count();
+                conn.migrate().await?;
+                println!("Applied {} migrations", pending);
+            }
+        }
+        Commands::Db(DbCommands::Status) => {
+            let conn = Connection::from_path_unmigrated(&cli.db).await?;
+            print_schema_status(&conn.schema_status().await?);
+        }
+        Commands::Db(DbCommands::Backup { dest, keep }) => {
+            let conn = Connection::from_path_unmigrated(&cli.db).await?;
+            if dest.is_dir() || keep.is_some() {
+                let path = conn.backup_to_directory(dest, *keep).await?;
+                println!("Backed up to {}", path.display());
+            } else {
+                conn.backup_into(dest).await?;
+                println!("Backed up to {}", dest.display());
+            }
+        }
+        Commands::Db(DbCommands::Fsck { remove_orphans }) => {
+            let conn = Connection::from_path_unmigrated(&cli.db).await?;
+            let report = conn.fsck(*remove_orphans).await?;
+            print_fsck_report(&report);
+            if !report.is_consistent() {
+                anyhow::bail!("the database has problems");
+            }
+        }
+        Commands::Db(DbCommands::Restore { backup, yes }) => {
+            if cli.db.exists()
+                && !(*yes || confirm(&format!("Replace {} with the backup?", cli.db.display()))?)
+            {
+                return Ok(());
+            }
+            let status = restore_backup(backup, &cli.db).await?;
+            println!("Restored {} to {}", backup.display(), cli.db.display());
+            let pending = status.pending().count();
+            if pending > 0 && !cli.no_migrate {
+                cli.connect().await?;
+                println!("Applied {} migrations", pending);
+            }
+        }
+        Commands::Export(ExportCommands::Netscape(args)) => {
+            let conn = cli.connect().await?;
+            lz_netscape::run_export(conn, args).await?;
         }
         Commands::GenerateOpenApiSpec(args) => lz_web::export_openapi::run(args)?,
     }
     Ok(())
 }
 
-async fn list_cmd(
-    mut txn: Transaction<ReadOnly>,
-    created_after: &Option<String>,
-    created_before: &Option<String>,
-    tagged: &Option<Vec<String>>,
-) -> Result<()> {
-    let mut last_seen = None;
-    let page_size = 1000;
-
-    // All datetimes currently use the sqlite3 `localtime` options; for purposes of
-    // dates, we'll eventually want to allow a config option setting a default timezone.
-    let mut filters: Vec<BookmarkSearch> = vec![];
-    if let Some(created_before_str) = created_before {
-        let dt = created_before_str.parse::<DateInput>()?;
-        filters.push(lz_db::created_before_from_datetime(dt));
-    };
-    if let Some(created_after_str) = created_after {
-        let dt = created_after_str.parse::<DateInput>()?;
-        filters.push(lz_db::created_after_from_datetime(dt))
+fn list_filters(
+    date_args: &DateFilterArgs,
+    tag_args: &TagFilterArgs,
+    search: &Option<String>,
+    query: &Option<String>,
+) -> Result<Vec<BookmarkSearch>> {
+    let mut filters = date_args.filters();
+    let tag_criterion = |namestring: &String| {
+        let tag = lz_db::TagName(namestring.clone());
+        if tag_args.with_subtags {
+            BookmarkSearch::TagPrefix { tag }
+        } else {
+            BookmarkSearch::TagByName { tag }
+        }
     };
-    if let Some(tag_strings) = tagged {
+    if let Some(tag_strings) = &tag_args.tagged {
+        filters.extend(tag_strings.iter().map(tag_criterion));
+    }
+    if let Some(tag_strings) = &tag_args.any_tag {
+        let of = tag_strings.iter().map(tag_criterion).collect();
+        filters.push(BookmarkSearch::Any { of });
+    }
+    if let Some(tag_strings) = &tag_args.not_tagged {
         for namestring in tag_strings.iter() {
-            let tag = lz_db::TagName(namestring.clone());
-            filters.push(BookmarkSearch::TagByName { tag });
+            filters.push(BookmarkSearch::Not {
+                criterion: Box::new(tag_criterion(namestring)),
+            });
         }
     }
+    if let Some(text) = search {
+        filters.push(BookmarkSearch::FullText {
+            query: FullTextQuery(text.clone()),
+        });
+    }
+    if let Some(query) = query {
+        filters.extend(lz_db::parse_query(query)?);
+    }
+    Ok(filters)
+}
+
+async fn list_cmd(
+    mut txn: Transaction<ReadOnly>,
+    filters: &[BookmarkSearch],
+    order: BookmarkOrder,
+) -> Result<()> {
+    let mut cursor = None;
+    let page_size = 1000;
     loop {
-        let bookmarks = txn
-            .list_bookmarks_matching(&filters, page_size, last_seen)
+        let mut bookmarks = txn
+            .list_bookmarks_in_order(filters, order, page_size, cursor.as_ref())
             .await?;
-        for (elt, bm) in bookmarks.iter().enumerate() {
-            if elt == usize::from(page_size) {
-                last_seen = Some(bm.id);
-                break;
-            }
-            println!("{}: <{}>", bm.title, bm.url);
+        // The extra bookmark starts the next page:
+        let next = if bookmarks.len() > usize::from(page_size) {
+            bookmarks.pop()
+        } else {
+            None
+        };
+        for listed in &bookmarks {
+            println!("{}: <{}>", listed.bookmark.title, listed.bookmark.url);
         }
-        if bookmarks.len() < usize::from(page_size) + 1 {
-            return Ok(());
+        match next {
+            Some(next) => cursor = Some(next.cursor),
+            None => return Ok(()),
         }
     }
 }
 
-async fn add_cmd(txn: &mut Transaction, args: &CliAddArgs) -> Result<()> {
-    let bookmark = add_link(
+async fn add_cmd(txn: &mut Transaction, args: &CliAddArgs, db_path: &Path) -> Result<()> {
+    let fetcher = Fetcher::new(args.fetcher.clone())?;
+    let (bookmark, metadata) = add_link(
         txn,
+        &fetcher,
         args.link.to_string(),
         args.backdate.as_ref(),
         args.description.as_deref(),
@@ -291,21 +1080,43 @@ async fn add_cmd(txn: &mut Transaction, args: &CliAddArgs) -> Result<()> {
         txn.associate_bookmark_link(&bookmark.id, &url_id, args.associated_context.as_deref())
             .await?;
     }
+    for (context, link) in metadata.associated_links(&bookmark.url) {
+        let url_id = txn.ensure_url(link).await?;
+        match txn
+            .associate_bookmark_link(&bookmark.id, &url_id, Some(context))
+            .await
+        {
+            // The link was already associated (e.g. when re-adding with --force):
+            Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {}
+            res => res?,
+        }
+    }
+    if args.archive.archive_pages {
+        let archiver = LocalArchiver::new(fetcher, args.archive.store(Some(db_path)));
+        let url_id = txn.ensure_url(&bookmark.url).await?;
+        // The bookmark is useful without the archive, too:
+        match archiver.archive_page(url_id, &bookmark.url).await {
+            Ok(archive) => txn.set_archive(&archive).await?,
+            Err(e) => eprintln!("Could not archive <{}>: {}", args.link, e),
+        }
+    }
     println!("Added bookmark for <{}>", args.link);
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn add_link(
     txn: &mut Transaction,
+    fetcher: &Fetcher,
     link: String,
     backdate: Option<&LocalDatestamp>,
     description: Option<&str>,
     force: bool,
     notes: Option<&str>,
     title: Option<&str>,
-) -> Result<ExistingBookmark> {
+) -> Result<(ExistingBookmark, Metadata)> {
     let url = Url::parse(&link).with_context(|| format!("invalid url {:?}", link))?;
-    let mut bookmark = lz_web::http::lookup_bookmark_from_web(&url).await?;
+    let (mut bookmark, metadata) = lz_web::http::lookup_bookmark_from_web(fetcher, &url).await?;
     if let Some(user_title) = title {
         bookmark.title = user_title.to_string();
     }
@@ -317,7 +1128,7 @@ async fn add_link(
     }
     bookmark.notes = notes.map(|n| n.to_string());
     match txn.add_bookmark(bookmark.clone()).await {
-        Ok(v) => Ok(v),
+        Ok(v) => Ok((v, metadata)),
         Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
             if force {
                 // Known to be valid or we would have errored out on the URL parse
@@ -329,7 +1140,7 @@ async fn add_link(
                 existing_bookmark.website_description = bookmark.website_description;
                 existing_bookmark.website_title = bookmark.website_title;
                 match txn.update_bookmark(&existing_bookmark).await {
-                    Ok(_) => Ok(existing_bookmark),
+                    Ok(_) => Ok((existing_bookmark, metadata)),
                     Err(err) => Err(err.into()),
                 }
             } else {
@@ -359,6 +1170,341 @@ async fn remove_cmd(txn: &mut Transaction, link: &String) -> Result<()> {
     Ok(())
 }
 
+async fn snapshot_cmd(
+    txn: &mut Transaction,
+    link: &str,
+    request: bool,
+    fetcher: &FetcherArgs,
+) -> Result<()> {
+    let url = Url::parse(link).with_context(|| format!("invalid url {:?}", link))?;
+    let Some(bookmark) = txn.find_bookmark_with_url(&url).await? else {
+        return Err(anyhow!("<{}> is not bookmarked", link));
+    };
+    let wayback = Wayback::new(Fetcher::new(fetcher.clone())?);
+    match archive_bookmark(&wayback, txn, &bookmark, request).await? {
+        Some(snapshot) => println!("Snapshot of <{}>: <{}>", link, snapshot),
+        None => println!("No snapshot of <{}> found", link),
+    }
+    Ok(())
+}
+
+async fn discover_cmd(
+    txn: &mut Transaction,
+    sites: &[Box<dyn DiscussionSite>],
+    link: &str,
+) -> Result<()> {
+    let url = Url::parse(link).with_context(|| format!("invalid url {:?}", link))?;
+    let Some(bookmark) = txn.find_bookmark_with_url(&url).await? else {
+        return Err(anyhow!("<{}> is not bookmarked", link));
+    };
+    let found = discover(sites, &bookmark.url).await;
+    for (site, error) in &found.failures {
+        eprintln!("Could not search {}: {}", site, error);
+    }
+    record_discussions(txn, bookmark.id, &found.discussions).await?;
+    if found.discussions.is_empty() {
+        println!("No discussions of <{}> found", link);
+    }
+    for discussion in &found.discussions {
+        println!(
+            "Discussion of <{}> on {}: <{}>",
+            link, discussion.context, discussion.url
+        );
+    }
+    Ok(())
+}
+
+/// All of the user's bookmarks, newest first.
+async fn all_bookmarks(conn: &Connection, user: &str) -> Result<Vec<ExistingBookmark>> {
+    let mut txn = conn.begin_ro_for_user(user).await?;
+    let criteria = [BookmarkSearch::User { id: txn.user().id }];
+    let page_size = 1000;
+    let mut last_seen = None;
+    let mut bookmarks = vec![];
+    loop {
+        let mut page = txn
+            .list_bookmarks_matching(&criteria, page_size, last_seen)
+            .await?;
+        let next = if page.len() > usize::from(page_size) {
+            page.pop().map(|bm| bm.id)
+        } else {
+            None
+        };
+        bookmarks.extend(page);
+        match next {
+            Some(next) => last_seen = Some(next),
+            None => return Ok(bookmarks),
+        }
+    }
+}
+
+/// Keep local copies of one bookmark's page, or of all the user's
+/// bookmarks' pages. Like with discovery, the database isn't kept
+/// locked while pages are being retrieved.
+async fn archive_cmd(
+    conn: &Connection,
+    user: &str,
+    archiver: &LocalArchiver,
+    link: Option<&str>,
+    refresh: bool,
+) -> Result<()> {
+    let bookmarks = match link {
+        Some(link) => {
+            let url = Url::parse(link).with_context(|| format!("invalid url {:?}", link))?;
+            let mut txn = conn.begin_ro_for_user(user).await?;
+            let Some(bookmark) = txn.find_bookmark_with_url(&url).await? else {
+                return Err(anyhow!("<{}> is not bookmarked", link));
+            };
+            vec![bookmark]
+        }
+        None => all_bookmarks(conn, user).await?,
+    };
+    let (mut archived, mut failed) = (0, 0);
+    for bookmark in &bookmarks {
+        let url_id = {
+            let mut txn = conn.begin_ro_for_user(user).await?;
+            let Some(url_id) = txn.url_id_for_link(&bookmark.url).await? else {
+                continue;
+            };
+            if link.is_none() && !refresh && txn.archive_for_url(url_id).await?.is_some() {
+                continue;
+            }
+            url_id
+        };
+        match archiver.archive_page(url_id, &bookmark.url).await {
+            Ok(archive) => {
+                let mut txn = conn.begin_for_user(user).await?;
+                txn.set_archive(&archive).await?;
+                txn.commit().await?;
+                archived += 1;
+                println!("Archived <{}>", bookmark.url);
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!("Could not archive <{}>: {}", bookmark.url, e);
+            }
+        }
+    }
+    if link.is_none() {
+        println!(
+            "Archived {} pages, {} could not be archived",
+            archived, failed
+        );
+    } else if failed > 0 {
+        return Err(anyhow!("<{}> could not be archived", bookmarks[0].url));
+    }
+    Ok(())
+}
+
+async fn merge_cmd(txn: &mut Transaction, keep: &str, absorb: &str) -> Result<()> {
+    let mut bookmarks = vec![];
+    for link in [keep, absorb] {
+        let url = Url::parse(link).with_context(|| format!("invalid url {:?}", link))?;
+        let bookmark = txn
+            .find_bookmark_with_url(&url)
+            .await?
+            .ok_or_else(|| anyhow!("<{}> not found", link))?;
+        bookmarks.push(bookmark.id);
+    }
+    txn.merge_bookmarks(bookmarks[0], bookmarks[1]).await?;
+    println!("Merged <{}> into <{}>", absorb, keep);
+    Ok(())
+}
+
+/// Find the user's duplicate bookmarks, and merge each group of them
+/// into its oldest bookmark (if the user agrees).
+async fn dedupe_cmd(conn: &Connection, user: &str, yes: bool, dry_run: bool) -> Result<()> {
+    // The rules may have changed since URLs were stored:
+    let mut txn = conn.begin_for_user(user).await?;
+    txn.refresh_canonical_urls().await?;
+    let groups = txn.duplicate_bookmarks().await?;
+    txn.commit().await?;
+
+    let mut merged = 0;
+    for group in &groups {
+        let (keep, absorb) = group.split_first().expect("duplicates come in groups");
+        println!("<{}> {}", keep.url, keep.title);
+        for bookmark in absorb {
+            println!("  duplicate <{}> {}", bookmark.url, bookmark.title);
+        }
+        if dry_run || !(yes || confirm("Merge the duplicates into the first bookmark?")?) {
+            continue;
+        }
+        // Don't keep the database locked while waiting for an answer:
+        let mut txn = conn.begin_for_user(user).await?;
+        for bookmark in absorb {
+            txn.merge_bookmarks(keep.id, bookmark.id).await?;
+        }
+        txn.commit().await?;
+        merged += absorb.len();
+    }
+    println!(
+        "Found {} groups of duplicates, merged {} bookmarks",
+        groups.len(),
+        merged
+    );
+    Ok(())
+}
+
+/// Ask a yes/no question on the terminal.
+fn confirm(question: &str) -> Result<bool> {
+    eprint!("{} [y/N] ", question);
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// Check all stored links, and report the ones that don't work.
+async fn check_links_cmd(conn: &Connection, fetcher: &Fetcher, delay: Duration) -> Result<()> {
+    let report = check_all_links(conn, fetcher, delay).await?;
+    for (link, check) in &report.problems {
+        match (&check.redirected_to, &check.error) {
+            (Some(to), _) if check.status == LinkStatus::Redirected => {
+                println!("{}\t<{}> -> <{}>", check.status, link, to)
+            }
+            (_, Some(error)) => println!("{}\t<{}>: {}", check.status, link, error),
+            _ => println!("{}\t<{}>", check.status, link),
+        }
+    }
+    println!(
+        "Checked {} links, {} had problems",
+        report.checked,
+        report.problems.len()
+    );
+    Ok(())
+}
+
+/// Point the user's bookmarks whose links moved to the new location.
+async fn fix_redirects_cmd(txn: &mut Transaction) -> Result<()> {
+    for fix in fix_redirects(txn).await? {
+        match fix {
+            RedirectFix::Updated { from, to } => println!("Moved <{}> to <{}>", from, to),
+            RedirectFix::AlreadyBookmarked { from, to } => {
+                eprintln!("Not moving <{}>: <{}> is already bookmarked", from, to)
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Search for discussions of all the user's bookmarks. Each bookmark's
+/// discussions get recorded in their own transaction, so the database
+/// isn't kept locked while waiting for the discussion sites.
+async fn discover_all_cmd(
+    conn: &Connection,
+    user: &str,
+    sites: &[Box<dyn DiscussionSite>],
+    delay: Duration,
+) -> Result<()> {
+    let bookmarks = all_bookmarks(conn, user).await?;
+    let mut recorded = 0;
+    for (i, bookmark) in bookmarks.iter().enumerate() {
+        if i > 0 {
+            tokio::time::sleep(delay).await;
+        }
+        let found = discover(sites, &bookmark.url).await;
+        for (site, error) in &found.failures {
+            eprintln!(
+                "Could not search {} for <{}>: {}",
+                site, bookmark.url, error
+            );
+        }
+        if found.discussions.is_empty() {
+            continue;
+        }
+        let mut txn = conn.begin_for_user(user).await?;
+        recorded += record_discussions(&mut txn, bookmark.id, &found.discussions).await?;
+        txn.commit().await?;
+    }
+    println!(
+        "Found {} new discussions of {} bookmarks",
+        recorded,
+        bookmarks.len()
+    );
+    Ok(())
+}
+
+/// Print each problem that `lz db fsck` found on a line of its own,
+/// followed by the orphans it found (or removed).
+fn print_fsck_report(report: &FsckReport) {
+    for error in &report.integrity_errors {
+        println!("integrity: {}", error);
+    }
+    for violation in &report.foreign_key_violations {
+        println!(
+            "foreign key: {} row {} refers to a missing {}",
+            violation.table,
+            violation.rowid.map_or("?".to_string(), |id| id.to_string()),
+            violation.parent
+        );
+    }
+    for invalid in &report.invalid_import_properties {
+        println!(
+            "import properties: bookmark {}: {}",
+            invalid.bookmark_id, invalid.error
+        );
+    }
+    for slug in &report.mismatched_slugs {
+        println!(
+            "slug: {} {:?} has slug {:?}, expected {:?}",
+            slug.kind, slug.name, slug.slug, slug.expected
+        );
+    }
+    let orphans = if report.removed_orphans {
+        "removed orphaned"
+    } else {
+        "orphaned"
+    };
+    for url in &report.orphaned_urls {
+        println!("{} url: {}", orphans, url.link);
+    }
+    for tag in &report.orphaned_tags {
+        println!("{} tag: {} (of {})", orphans, tag.name, tag.user_name);
+    }
+}
+
+fn print_schema_status(status: &SchemaStatus) {
+    for migration in &status.migrations {
+        let applied = match migration.applied_at {
+            Some(at) => at.to_rfc3339(),
+            None => "pending".to_string(),
+        };
+        println!(
+            "{}\t{}\t{}",
+            migration.version, applied, migration.description
+        );
+    }
+    for migration in &status.unknown {
+        println!("{}\tunknown\t{}", migration.version, migration.description);
+    }
+    if !status.unknown.is_empty() {
+        println!("The database was migrated by a newer version of lz.");
+    }
+}
+
+/// Print tag tree nodes, indenting them by their depth in the tree.
+fn print_tag_tree(nodes: &[TagTreeNode], depth: usize) {
+    for node in nodes {
+        let indent = "  ".repeat(depth);
+        match node.tag_id {
+            Some(_) => println!("{indent}{}\t{}", node.name, node.count),
+            None => println!("{indent}{}", node.name),
+        }
+        print_tag_tree(&node.children, depth + 1);
+    }
+}
+
+/// Find the user's tag with a name.
+async fn user_tag_named<M: TransactionMode>(
+    txn: &mut Transaction<M>,
+    name: &str,
+) -> Result<ExistingTag> {
+    txn.get_tags_with_names([name])
+        .await?
+        .pop()
+        .ok_or_else(|| anyhow!("no tag named {}", name))
+}
+
 async fn tag_cmd(
     txn: &mut Transaction,
     link: &String,
@@ -0,0 +1,61 @@
+-- Full-text search index over bookmarks.
+--
+-- The index's rowid is the bookmark_id of the indexed bookmark. It is
+-- kept up to date by the triggers below, so nothing in the
+-- application code needs to write to it.
+
+CREATE VIRTUAL TABLE "bookmarks_fts" USING fts5(
+  "title",
+  "description",
+  "website_title",
+  "website_description",
+  "notes",
+  "url",
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER "bookmarks_fts_after_insert" AFTER INSERT ON "bookmarks" BEGIN
+  INSERT INTO "bookmarks_fts" (
+    "rowid", "title", "description", "website_title", "website_description", "notes", "url"
+  ) VALUES (
+    new."bookmark_id",
+    new."title",
+    new."description",
+    new."website_title",
+    new."website_description",
+    new."notes",
+    (SELECT "link" FROM "urls" WHERE "url_id" = new."url_id")
+  );
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_update" AFTER UPDATE ON "bookmarks" BEGIN
+  DELETE FROM "bookmarks_fts" WHERE "rowid" = old."bookmark_id";
+  INSERT INTO "bookmarks_fts" (
+    "rowid", "title", "description", "website_title", "website_description", "notes", "url"
+  ) VALUES (
+    new."bookmark_id",
+    new."title",
+    new."description",
+    new."website_title",
+    new."website_description",
+    new."notes",
+    (SELECT "link" FROM "urls" WHERE "url_id" = new."url_id")
+  );
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_delete" AFTER DELETE ON "bookmarks" BEGIN
+  DELETE FROM "bookmarks_fts" WHERE "rowid" = old."bookmark_id";
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_url_update" AFTER UPDATE OF "link" ON "urls" BEGIN
+  UPDATE "bookmarks_fts" SET "url" = new."link"
+  WHERE "rowid" IN (SELECT "bookmark_id" FROM "bookmarks" WHERE "url_id" = new."url_id");
+END;
+
+-- Index all the bookmarks that existed before this migration:
+INSERT INTO "bookmarks_fts" (
+  "rowid", "title", "description", "website_title", "website_description", "notes", "url"
+)
+SELECT
+  "bookmark_id", "title", "description", "website_title", "website_description", "notes", "link"
+FROM "bookmarks" JOIN "urls" USING ("url_id");
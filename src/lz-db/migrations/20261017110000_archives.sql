@@ -0,0 +1,99 @@
+-- Local copies of bookmarked pages.
+--
+-- The archived HTML and the readable text extracted from it live in
+-- content-addressed files next to the database, named by the SHA-256
+-- digests recorded here. The text is kept in the table too, so that
+-- the full-text index can cover it.
+
+CREATE TABLE "archives" (
+  "url_id" INTEGER NOT NULL PRIMARY KEY,
+  "archived_at" TEXT NOT NULL,
+  "retrieved_from" TEXT NOT NULL,
+  "html_sha256" TEXT NOT NULL,
+  "text_sha256" TEXT NOT NULL,
+  "text" TEXT NOT NULL,
+
+  FOREIGN KEY ("url_id") REFERENCES "urls"("url_id")
+) STRICT;
+
+-- FTS5 tables can't gain columns, so the full-text index gets rebuilt
+-- with a column for the archived text:
+DROP TRIGGER "bookmarks_fts_after_insert";
+DROP TRIGGER "bookmarks_fts_after_update";
+DROP TRIGGER "bookmarks_fts_after_delete";
+DROP TRIGGER "bookmarks_fts_after_url_update";
+DROP TABLE "bookmarks_fts";
+
+CREATE VIRTUAL TABLE "bookmarks_fts" USING fts5(
+  "title",
+  "description",
+  "website_title",
+  "website_description",
+  "notes",
+  "url",
+  "archived_text",
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER "bookmarks_fts_after_insert" AFTER INSERT ON "bookmarks" BEGIN
+  INSERT INTO "bookmarks_fts" (
+    "rowid", "title", "description", "website_title", "website_description", "notes", "url", "archived_text"
+  ) VALUES (
+    new."bookmark_id",
+    new."title",
+    new."description",
+    new."website_title",
+    new."website_description",
+    new."notes",
+    (SELECT "link" FROM "urls" WHERE "url_id" = new."url_id"),
+    (SELECT "text" FROM "archives" WHERE "url_id" = new."url_id")
+  );
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_update" AFTER UPDATE ON "bookmarks" BEGIN
+  DELETE FROM "bookmarks_fts" WHERE "rowid" = old."bookmark_id";
+  INSERT INTO "bookmarks_fts" (
+    "rowid", "title", "description", "website_title", "website_description", "notes", "url", "archived_text"
+  ) VALUES (
+    new."bookmark_id",
+    new."title",
+    new."description",
+    new."website_title",
+    new."website_description",
+    new."notes",
+    (SELECT "link" FROM "urls" WHERE "url_id" = new."url_id"),
+    (SELECT "text" FROM "archives" WHERE "url_id" = new."url_id")
+  );
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_delete" AFTER DELETE ON "bookmarks" BEGIN
+  DELETE FROM "bookmarks_fts" WHERE "rowid" = old."bookmark_id";
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_url_update" AFTER UPDATE OF "link" ON "urls" BEGIN
+  UPDATE "bookmarks_fts" SET "url" = new."link"
+  WHERE "rowid" IN (SELECT "bookmark_id" FROM "bookmarks" WHERE "url_id" = new."url_id");
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_archive_insert" AFTER INSERT ON "archives" BEGIN
+  UPDATE "bookmarks_fts" SET "archived_text" = new."text"
+  WHERE "rowid" IN (SELECT "bookmark_id" FROM "bookmarks" WHERE "url_id" = new."url_id");
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_archive_update" AFTER UPDATE OF "text" ON "archives" BEGIN
+  UPDATE "bookmarks_fts" SET "archived_text" = new."text"
+  WHERE "rowid" IN (SELECT "bookmark_id" FROM "bookmarks" WHERE "url_id" = new."url_id");
+END;
+
+CREATE TRIGGER "bookmarks_fts_after_archive_delete" AFTER DELETE ON "archives" BEGIN
+  UPDATE "bookmarks_fts" SET "archived_text" = NULL
+  WHERE "rowid" IN (SELECT "bookmark_id" FROM "bookmarks" WHERE "url_id" = old."url_id");
+END;
+
+-- Re-index all the bookmarks (there are no archives yet):
+INSERT INTO "bookmarks_fts" (
+  "rowid", "title", "description", "website_title", "website_description", "notes", "url"
+)
+SELECT
+  "bookmark_id", "title", "description", "website_title", "website_description", "notes", "link"
+FROM "bookmarks" JOIN "urls" USING ("url_id");
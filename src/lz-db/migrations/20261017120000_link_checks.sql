@@ -0,0 +1,16 @@
+-- The results of checking whether stored URLs still work.
+--
+-- Each URL has at most one row, holding the result of its latest check.
+
+CREATE TABLE "link_checks" (
+  "url_id" INTEGER NOT NULL PRIMARY KEY,
+  "checked_at" TEXT NOT NULL,
+  "status" TEXT NOT NULL,
+  "http_status" INTEGER,
+  "redirected_to" TEXT,
+  "error" TEXT,
+
+  FOREIGN KEY ("url_id") REFERENCES "urls"("url_id")
+) STRICT;
+
+CREATE INDEX "link_checks_by_status" ON "link_checks" ("status");
@@ -0,0 +1,11 @@
+-- Canonical forms of stored URLs, for telling which links point to
+-- the same page.
+--
+-- The canonical form depends on rules configured in the application,
+-- so it can't be computed here: Existing URLs get theirs when they're
+-- next stored, or when `lz dedupe` refreshes all of them. Until then,
+-- lookups still find them by their exact link.
+
+ALTER TABLE "urls" ADD COLUMN "canonical" TEXT;
+
+CREATE INDEX "urls_by_canonical" ON "urls" ("canonical");
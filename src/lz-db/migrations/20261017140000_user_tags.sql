@@ -0,0 +1,49 @@
+-- Tags belong to users, so that nobody sees (or renames) anyone
+-- else's vocabulary.
+--
+-- Each tag that several users' bookmarks have gets split into one tag
+-- per user. Tags that no bookmark has belong to nobody and are
+-- dropped.
+
+CREATE TABLE "user_tags" (
+  "tag_id" INTEGER NOT NULL PRIMARY KEY,
+  "user_id" INTEGER NOT NULL,
+  "created_at" TEXT NOT NULL,
+  "name" TEXT NOT NULL,
+  "slug" TEXT NOT NULL,
+
+  UNIQUE ("user_id", "name"),
+  UNIQUE ("user_id", "slug"),
+  FOREIGN KEY ("user_id") REFERENCES "users"("user_id"),
+  CHECK("name" NOT LIKE '% %' AND length("name") >= 1)
+) STRICT;
+
+INSERT INTO "user_tags" ("user_id", "created_at", "name", "slug")
+SELECT DISTINCT "bookmarks"."user_id", "tags"."created_at", "tags"."name", "tags"."slug"
+FROM "tags"
+  JOIN "bookmark_tags" USING ("tag_id")
+  JOIN "bookmarks" USING ("bookmark_id")
+ORDER BY "tags"."tag_id", "bookmarks"."user_id";
+
+CREATE TABLE "user_bookmark_tags" (
+  "bookmark_id" INTEGER NOT NULL,
+  "tag_id" INTEGER NOT NULL,
+
+  PRIMARY KEY ("bookmark_id","tag_id"),
+  FOREIGN KEY("bookmark_id") REFERENCES "bookmarks"("bookmark_id") ON DELETE CASCADE,
+  FOREIGN KEY("tag_id") REFERENCES "user_tags"("tag_id")
+) STRICT;
+
+INSERT INTO "user_bookmark_tags" ("bookmark_id", "tag_id")
+SELECT "bookmarks"."bookmark_id", "user_tags"."tag_id"
+FROM "bookmark_tags"
+  JOIN "tags" USING ("tag_id")
+  JOIN "bookmarks" USING ("bookmark_id")
+  JOIN "user_tags" ON "user_tags"."user_id" = "bookmarks"."user_id" AND "user_tags"."slug" = "tags"."slug";
+
+DROP TABLE "bookmark_tags";
+DROP TABLE "tags";
+ALTER TABLE "user_tags" RENAME TO "tags";
+ALTER TABLE "user_bookmark_tags" RENAME TO "bookmark_tags";
+
+CREATE INDEX "bookmark_tags_by_tag" ON "bookmark_tags" ("tag_id");
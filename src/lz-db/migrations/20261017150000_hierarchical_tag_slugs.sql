@@ -0,0 +1,13 @@
+-- Slugs of hierarchical tags (like `lang/rust/async`) keep the `/`
+-- between the levels of the hierarchy, instead of turning it into `-`.
+--
+-- Slugs are computed in the application, so this only fixes the tags
+-- whose names are already their own slug. Any others get their slug
+-- fixed when they are next used by name.
+
+UPDATE "tags" SET "slug" = "name"
+WHERE "name" GLOB '*/*'
+  AND "name" NOT GLOB '*[^a-z0-9:/-]*'
+  AND "name" NOT GLOB '[/:-]*'
+  AND "name" NOT GLOB '*[/:-]'
+  AND "name" NOT GLOB '*[/:-][/:-]*';
@@ -0,0 +1,20 @@
+-- Alternative names for a user's tags, like `js` for `javascript`.
+--
+-- Looking up a tag by a name whose slug is an alias finds the tag that
+-- the alias stands for.
+
+CREATE TABLE "tag_aliases" (
+  "alias_id" INTEGER NOT NULL PRIMARY KEY,
+  "user_id" INTEGER NOT NULL,
+  "tag_id" INTEGER NOT NULL,
+  "created_at" TEXT NOT NULL,
+  "name" TEXT NOT NULL,
+  "slug" TEXT NOT NULL,
+
+  UNIQUE ("user_id", "slug"),
+  FOREIGN KEY ("user_id") REFERENCES "users"("user_id"),
+  FOREIGN KEY ("tag_id") REFERENCES "tags"("tag_id") ON DELETE CASCADE,
+  CHECK("name" NOT LIKE '% %' AND length("name") >= 1)
+) STRICT;
+
+CREATE INDEX "tag_aliases_by_tag" ON "tag_aliases" ("tag_id");
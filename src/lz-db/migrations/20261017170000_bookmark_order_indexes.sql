@@ -0,0 +1,18 @@
+-- Indexes for listing a user's bookmarks in each of the orders that
+-- lz offers, so listings can seek to the position of a cursor.
+--
+-- The expressions must match those in `BookmarkOrder::key_expression`
+-- for SQLite to use the indexes. Ordering by relevance uses the
+-- full-text index instead.
+
+CREATE INDEX "bookmarks_by_created" ON "bookmarks" ("user_id", "created_at", "bookmark_id");
+
+CREATE INDEX "bookmarks_by_modified" ON "bookmarks" (
+  "user_id", COALESCE("modified_at", "created_at"), "bookmark_id"
+);
+
+CREATE INDEX "bookmarks_by_accessed" ON "bookmarks" (
+  "user_id", COALESCE("accessed_at", ''), "bookmark_id"
+);
+
+CREATE INDEX "bookmarks_by_title" ON "bookmarks" ("user_id", "title" COLLATE NOCASE, "bookmark_id");
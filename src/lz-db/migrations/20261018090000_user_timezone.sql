@@ -0,0 +1,3 @@
+-- The timezone that a user's date searches count days in, like
+-- `Europe/Berlin`. Without one, the server's timezone is used.
+ALTER TABLE "users" ADD COLUMN "timezone" TEXT;
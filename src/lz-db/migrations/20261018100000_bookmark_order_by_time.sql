@@ -0,0 +1,17 @@
+-- Sort bookmarks by modification and access times as times, not as
+-- text: `modified_at` is written by SQLite's `datetime()`, while
+-- `created_at` and `accessed_at` come in RFC 3339, and the two
+-- formats don't compare correctly as strings.
+--
+-- The expressions must match those in `BookmarkOrder::key_expression`
+-- for SQLite to use the indexes.
+
+DROP INDEX "bookmarks_by_modified";
+CREATE INDEX "bookmarks_by_modified" ON "bookmarks" (
+  "user_id", unixepoch(COALESCE("modified_at", "created_at"), 'subsec'), "bookmark_id"
+);
+
+DROP INDEX "bookmarks_by_accessed";
+CREATE INDEX "bookmarks_by_accessed" ON "bookmarks" (
+  "user_id", COALESCE(unixepoch("accessed_at", 'subsec'), -1e308), "bookmark_id"
+);
@@ -0,0 +1,270 @@
+//! # Backing up and restoring the database
+//!
+//! Copying the database file while lz is running isn't safe: With the
+//! write-ahead log, recent changes live in a separate file, and a copy
+//! can catch the database file mid-checkpoint. Backups here are made
+//! with `VACUUM INTO`, which writes a consistent snapshot of the
+//! database to a new file, and doesn't block writers.
+
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+
+use crate::{Connection, SchemaError, SchemaStatus};
+
+/// The file names of backups in a backup directory start with this.
+const BACKUP_PREFIX: &str = "lz-";
+
+/// The file names of backups in a backup directory end with this.
+const BACKUP_SUFFIX: &str = ".sqlite";
+
+/// An error backing up or restoring the database.
+#[derive(thiserror::Error, Debug)]
+pub enum BackupError {
+    /// The backup file exists already.
+    #[error("{path} exists already")]
+    Exists { path: PathBuf },
+
+    /// The file to restore doesn't hold an lz database.
+    #[error("{path} is not an lz database")]
+    NotAnLzDatabase { path: PathBuf },
+
+    /// The backup's schema can't be used by this version of lz.
+    #[error("can not restore {path}")]
+    Schema { path: PathBuf, source: SchemaError },
+
+    /// Reading or writing the files failed.
+    #[error("file system error")]
+    Io(#[from] std::io::Error),
+
+    /// Any error raised by sqlx.
+    #[error("sql datastore error")]
+    Sqlx(#[from] sqlx::Error),
+}
+
+/// # Backups
+impl Connection {
+    /// Write a consistent copy of the database to a new file at
+    /// `dest`.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn backup_into(&self, dest: &Path) -> Result<(), BackupError> {
+        if dest.exists() {
+            return Err(BackupError::Exists {
+                path: dest.to_owned(),
+            });
+        }
+        let dest = dest.to_str().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{} is not valid UTF-8", dest.display()),
+            )
+        })?;
+        sqlx::query("VACUUM INTO ?")
+            .bind(dest)
+            .execute(&self.rw)
+            .await?;
+        Ok(())
+    }
+
+    /// Back up the database into a new, timestamped file in the
+    /// directory `dir`, and return its path.
+    ///
+    /// If `keep` is given, only that many of the most recent backups
+    /// in `dir` are kept; older ones are deleted. The new backup is
+    /// always kept.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn backup_to_directory(
+        &self,
+        dir: &Path,
+        keep: Option<NonZeroUsize>,
+    ) -> Result<PathBuf, BackupError> {
+        fs::create_dir_all(dir)?;
+        let dest = dir.join(format!(
+            "{BACKUP_PREFIX}{}{BACKUP_SUFFIX}",
+            Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+        ));
+        self.backup_into(&dest).await?;
+        if let Some(keep) = keep {
+            for old in list_backups(dir)?.iter().rev().skip(keep.get()) {
+                tracing::info!(path = %old.display(), "removing old backup");
+                fs::remove_file(old)?;
+            }
+        }
+        Ok(dest)
+    }
+}
+
+/// List the backups in `dir` that [`Connection::backup_to_directory`]
+/// made, oldest first.
+pub fn list_backups(dir: &Path) -> Result<Vec<PathBuf>, std::io::Error> {
+    let mut backups = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else {
+            continue;
+        };
+        if name.starts_with(BACKUP_PREFIX)
+            && name.ends_with(BACKUP_SUFFIX)
+            && entry.file_type()?.is_file()
+        {
+            backups.push(entry.path());
+        }
+    }
+    // The timestamps in the names sort chronologically:
+    backups.sort();
+    Ok(backups)
+}
+
+/// Replace the database at `dest` with the backup at `backup`, and
+/// return the schema status of the restored database.
+///
+/// Backups made by newer versions of lz are refused; ones made by
+/// older versions can be brought up to date with
+/// [`Connection::migrate`]. Nothing may have the database at `dest`
+/// open while it is restored.
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN))]
+pub async fn restore_backup(backup: &Path, dest: &Path) -> Result<SchemaStatus, BackupError> {
+    // The backup is opened read-only, so it stays as it was:
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(SqliteConnectOptions::new().filename(backup).read_only(true))
+        .await?;
+    let source = Connection::from_pool(pool);
+    let not_lz = || BackupError::NotAnLzDatabase {
+        path: backup.to_owned(),
+    };
+    let status = source.schema_status().await.map_err(|_| not_lz())?;
+    if status.migrations.iter().all(|m| m.applied_at.is_none()) && status.unknown.is_empty() {
+        return Err(not_lz());
+    }
+    status
+        .check_not_newer()
+        .map_err(|source| BackupError::Schema {
+            path: backup.to_owned(),
+            source,
+        })?;
+
+    // Copy the backup next to the database first, so the database is
+    // only replaced once the copy is complete:
+    let mut restoring = dest.as_os_str().to_owned();
+    restoring.push(".restoring");
+    let restoring = PathBuf::from(restoring);
+    if restoring.exists() {
+        fs::remove_file(&restoring)?;
+    }
+    source.backup_into(&restoring).await?;
+    source.rw.close().await;
+
+    // A leftover write-ahead log would get applied to the restored
+    // database, so it has to go:
+    for suffix in ["-wal", "-shm"] {
+        let mut path = dest.as_os_str().to_owned();
+        path.push(suffix);
+        match fs::remove_file(PathBuf::from(path)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {}
+        }
+    }
+    fs::rename(&restoring, dest)?;
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use testresult::TestResult;
+    use url::Url;
+
+    use super::*;
+    use crate::{Bookmark, NoId};
+
+    // Backups of in-memory databases end up in memory too, so these
+    // tests use database files.
+
+    #[tokio::test]
+    async fn backup_and_restore() -> TestResult {
+        let dir = tempfile::tempdir()?;
+        let conn = Connection::from_path(&dir.path().join("db.sqlite")).await?;
+        let mut txn = conn.begin_for_user("tester").await?;
+        txn.add_bookmark(Bookmark {
+            id: NoId,
+            user_id: NoId,
+            created_at: Default::default(),
+            modified_at: None,
+            accessed_at: None,
+            url: Url::parse("https://example.com/")?,
+            title: "Example".to_string(),
+            description: None,
+            website_title: None,
+            website_description: None,
+            notes: None,
+            import_properties: None,
+            shared: false,
+            unread: false,
+        })
+        .await?;
+        txn.commit().await?;
+
+        let backups = dir.path().join("backups");
+        let mut made = vec![];
+        for _ in 0..3 {
+            made.push(
+                conn.backup_to_directory(&backups, NonZeroUsize::new(2))
+                    .await?,
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        }
+        assert_eq!(list_backups(&backups)?, made[1..]);
+        assert!(matches!(
+            conn.backup_into(&made[2]).await,
+            Err(BackupError::Exists { .. })
+        ));
+
+        let dest = dir.path().join("restored.sqlite");
+        let status = restore_backup(&made[2], &dest).await?;
+        assert_eq!(status.pending().count(), 0);
+        let restored = Connection::from_path(&dest).await?;
+        let mut txn = restored.begin_for_user("tester").await?;
+        let bookmark = txn
+            .find_bookmark_with_url(&Url::parse("https://example.com/")?)
+            .await?;
+        assert_eq!(bookmark.map(|bm| bm.title), Some("Example".to_string()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refuse_restoring_other_databases() -> TestResult {
+        let dir = tempfile::tempdir()?;
+        let conn = Connection::from_path(&dir.path().join("db.sqlite")).await?;
+        sqlx::query(
+            r#"
+              INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+              VALUES (99991231000000, 'from the future', true, x'00', 0)
+            "#,
+        )
+        .execute(&conn.rw)
+        .await?;
+        let backup = dir.path().join("backup.sqlite");
+        conn.backup_into(&backup).await?;
+
+        let dest = dir.path().join("restored.sqlite");
+        assert!(matches!(
+            restore_backup(&backup, &dest).await,
+            Err(BackupError::Schema {
+                source: SchemaError::TooNew { .. },
+                ..
+            })
+        ));
+        assert!(!dest.exists());
+
+        fs::write(&backup, "not a database")?;
+        assert!(matches!(
+            restore_backup(&backup, &dest).await,
+            Err(BackupError::NotAnLzDatabase { .. })
+        ));
+        Ok(())
+    }
+}
@@ -1,27 +1,62 @@
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
 use thiserror::Error;
 
+use crate::{SchemaError, UrlCanonicalization};
+
 /// A connection to an sqlite DB holding our bookmark data.
 pub struct Connection {
     pub(crate) rw: sqlx::sqlite::SqlitePool,
     pub(crate) ro: Option<sqlx::sqlite::SqlitePool>,
+    path: Option<PathBuf>,
+    pub(crate) url_canonicalization: Arc<UrlCanonicalization>,
 }
 
 /// Error establishing sqlite connection pools to a database at a given path.
 #[derive(Error, Debug)]
-#[error("could not open database file {path}")]
-pub struct ConnectionFromPathFailed {
-    path: PathBuf,
-    source: sqlx::Error,
+pub enum ConnectionFromPathFailed {
+    /// The database file could not be opened.
+    #[error("could not open database file {path}")]
+    Open { path: PathBuf, source: sqlx::Error },
+
+    /// The database's schema could not be brought up to date.
+    #[error("could not migrate database file {path}")]
+    Migrate { path: PathBuf, source: SchemaError },
 }
 
 impl Connection {
-    /// Create a database connection to a file on disk.
+    /// Create a database connection to a file on disk, creating the
+    /// file if it doesn't exist and applying any pending
+    /// [migrations][crate::MIGRATOR] to it.
     pub async fn from_path(path: &Path) -> Result<Self, ConnectionFromPathFailed> {
-        let options = SqliteConnectOptions::new()
+        let connection = Self::open(path, true).await?;
+        connection
+            .migrate()
+            .await
+            .map_err(|source| ConnectionFromPathFailed::Migrate {
+                path: path.to_owned(),
+                source,
+            })?;
+        connection.with_read_only_pool(path).await
+    }
+
+    /// Create a database connection to an existing file on disk,
+    /// without touching its schema.
+    ///
+    /// Use [`Connection::schema_status`] to find out whether the
+    /// database is usable, and [`Connection::migrate`] to make it so.
+    pub async fn from_path_unmigrated(path: &Path) -> Result<Self, ConnectionFromPathFailed> {
+        Self::open(path, false)
+            .await?
+            .with_read_only_pool(path)
+            .await
+    }
+
+    fn options(path: &Path) -> SqliteConnectOptions {
+        SqliteConnectOptions::new()
             .filename(path)
             // Options from https://kerkour.com/sqlite-for-servers:
             .journal_mode(SqliteJournalMode::Wal)
@@ -32,30 +67,56 @@ impl Connection {
             .pragma("temp_store", "memory")
             // Some settings that just seem like a good idea:
             .shared_cache(true)
-            .optimize_on_close(true, None);
+            .optimize_on_close(true, None)
+    }
 
+    /// Open the read-write pool to the database at `path`.
+    async fn open(path: &Path, create: bool) -> Result<Self, ConnectionFromPathFailed> {
         let rw = SqlitePoolOptions::new()
             .max_connections(1)
-            .connect_with(options.clone())
+            .connect_with(Self::options(path).create_if_missing(create))
             .await
-            .map_err(|source| ConnectionFromPathFailed {
+            .map_err(|source| ConnectionFromPathFailed::Open {
                 path: path.to_owned(),
                 source,
             })?;
-        let ro = Some(
+        Ok(Connection {
+            rw,
+            ro: None,
+            path: Some(path.to_owned()),
+            url_canonicalization: Default::default(),
+        })
+    }
+
+    /// Add the read-only pool to a connection made by [`Connection::open`].
+    ///
+    /// This has to happen after the database file exists, since
+    /// read-only connections can't create it.
+    async fn with_read_only_pool(mut self, path: &Path) -> Result<Self, ConnectionFromPathFailed> {
+        self.ro = Some(
             SqlitePoolOptions::new()
-                .connect_with(options.read_only(true))
+                .connect_with(Self::options(path).read_only(true))
                 .await
-                .map_err(|source| ConnectionFromPathFailed {
+                .map_err(|source| ConnectionFromPathFailed::Open {
                     path: path.to_owned(),
                     source,
                 })?,
         );
-        Ok(Connection { rw, ro })
+        Ok(self)
     }
 
     /// Create a database connection from an open SqlitePool.
     pub fn from_pool(rw: sqlx::sqlite::SqlitePool) -> Self {
-        Self { rw, ro: None }
+        Self {
+            rw,
+            ro: None,
+            path: None,
+            url_canonicalization: Default::default(),
+        }
+    }
+
+    /// The path of the database file, if the connection was made to one.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
     }
 }
@@ -0,0 +1,360 @@
+//! # Checking the database for problems
+//!
+//! [`Connection::fsck`] looks for data that nothing uses anymore and
+//! for data that lz can't make sense of, and runs SQLite's own
+//! consistency checks.
+//!
+//! Some data becomes unused in the normal course of things: Deleting
+//! a bookmark leaves its URL stored, previewing a link stores its URL
+//! without bookmarking it, and tags stay around when they're removed
+//! from their last bookmark. Such orphans can be removed.
+
+use sqlx::prelude::*;
+
+use crate::{
+    normalize_tag, BookmarkId, Connection, ImportProperties, SchemaError, StoredUrlId, TagId,
+};
+
+/// A stored URL that no bookmark, association or archive refers to.
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct OrphanedUrl {
+    #[sqlx(rename = "url_id")]
+    pub id: StoredUrlId,
+    pub link: String,
+}
+
+/// A tag that no bookmark has and that no alias stands for.
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct OrphanedTag {
+    #[sqlx(rename = "tag_id")]
+    pub id: TagId,
+    pub name: String,
+    /// The name of the user whose tag it is.
+    pub user_name: String,
+}
+
+/// A bookmark whose imported properties can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidImportProperties {
+    pub bookmark_id: BookmarkId,
+    /// Why the properties couldn't be parsed.
+    pub error: String,
+}
+
+/// A tag or alias whose slug isn't its normalized name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MismatchedSlug {
+    /// Whether this is a `tag` or an `alias`.
+    pub kind: &'static str,
+    pub name: String,
+    pub slug: String,
+    /// What the slug should be, according to [`normalize_tag`].
+    pub expected: String,
+}
+
+/// A row whose foreign key points nowhere, as reported by
+/// `PRAGMA foreign_key_check`.
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct ForeignKeyViolation {
+    pub table: String,
+    pub rowid: Option<i64>,
+    pub parent: String,
+}
+
+/// The problems that [`Connection::fsck`] found.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FsckReport {
+    /// Problems that `PRAGMA integrity_check` found.
+    pub integrity_errors: Vec<String>,
+    pub foreign_key_violations: Vec<ForeignKeyViolation>,
+    pub invalid_import_properties: Vec<InvalidImportProperties>,
+    pub mismatched_slugs: Vec<MismatchedSlug>,
+    pub orphaned_urls: Vec<OrphanedUrl>,
+    pub orphaned_tags: Vec<OrphanedTag>,
+
+    /// Whether the orphaned URLs and tags were removed.
+    pub removed_orphans: bool,
+}
+
+impl FsckReport {
+    /// Whether the database is free of problems, other than orphans.
+    pub fn is_consistent(&self) -> bool {
+        self.integrity_errors.is_empty()
+            && self.foreign_key_violations.is_empty()
+            && self.invalid_import_properties.is_empty()
+            && self.mismatched_slugs.is_empty()
+    }
+}
+
+/// # Checking the database
+impl Connection {
+    /// Check the whole database (for all users) for problems and
+    /// orphaned data, and return what was found. If `remove_orphans`
+    /// is set, the orphaned URLs and tags are removed too.
+    ///
+    /// Only databases whose schema is
+    /// [current](crate::SchemaStatus::check_current) can be checked.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn fsck(&self, remove_orphans: bool) -> Result<FsckReport, SchemaError> {
+        self.schema_status().await?.check_current()?;
+        let mut txn = self.rw.begin().await?;
+        let mut report = FsckReport::default();
+
+        let integrity: Vec<(String,)> = sqlx::query_as("PRAGMA integrity_check")
+            .fetch_all(&mut *txn)
+            .await?;
+        report.integrity_errors = integrity
+            .into_iter()
+            .map(|(message,)| message)
+            .filter(|message| message != "ok")
+            .collect();
+        report.foreign_key_violations = sqlx::query_as(r#"PRAGMA foreign_key_check"#)
+            .fetch_all(&mut *txn)
+            .await?;
+
+        let import_properties: Vec<(BookmarkId, String)> = sqlx::query_as(
+            r#"
+              SELECT bookmark_id, import_properties FROM bookmarks
+              WHERE import_properties IS NOT NULL
+              ORDER BY bookmark_id
+            "#,
+        )
+        .fetch_all(&mut *txn)
+        .await?;
+        for (bookmark_id, json) in import_properties {
+            if let Err(error) = serde_json::from_str::<ImportProperties>(&json) {
+                report
+                    .invalid_import_properties
+                    .push(InvalidImportProperties {
+                        bookmark_id,
+                        error: error.to_string(),
+                    });
+            }
+        }
+
+        let slugs: Vec<(String, String, String)> = sqlx::query_as(
+            r#"
+              SELECT 'tag', name, slug FROM tags
+              UNION ALL
+              SELECT 'alias', name, slug FROM tag_aliases
+              ORDER BY 1 DESC, 2
+            "#,
+        )
+        .fetch_all(&mut *txn)
+        .await?;
+        for (kind, name, slug) in slugs {
+            let expected = normalize_tag(&name);
+            if slug != expected {
+                report.mismatched_slugs.push(MismatchedSlug {
+                    kind: if kind == "tag" { "tag" } else { "alias" },
+                    name,
+                    slug,
+                    expected,
+                });
+            }
+        }
+
+        report.orphaned_urls = sqlx::query_as(
+            r#"
+              SELECT url_id, link FROM urls
+              WHERE NOT EXISTS (SELECT 1 FROM bookmarks WHERE bookmarks.url_id = urls.url_id)
+                AND NOT EXISTS (SELECT 1 FROM bookmark_associations WHERE bookmark_associations.url_id = urls.url_id)
+                AND NOT EXISTS (SELECT 1 FROM archives WHERE archives.url_id = urls.url_id)
+              ORDER BY url_id
+            "#,
+        )
+        .fetch_all(&mut *txn)
+        .await?;
+        report.orphaned_tags = sqlx::query_as(
+            r#"
+              SELECT tag_id, tags.name, users.name AS user_name
+              FROM tags JOIN users USING (user_id)
+              WHERE NOT EXISTS (SELECT 1 FROM bookmark_tags WHERE bookmark_tags.tag_id = tags.tag_id)
+                AND NOT EXISTS (SELECT 1 FROM tag_aliases WHERE tag_aliases.tag_id = tags.tag_id)
+              ORDER BY users.name, tags.name
+            "#,
+        )
+        .fetch_all(&mut *txn)
+        .await?;
+
+        if remove_orphans {
+            let urls: Vec<StoredUrlId> = report.orphaned_urls.iter().map(|url| url.id).collect();
+            sqlx::query(
+                r#"DELETE FROM link_checks WHERE url_id IN (SELECT value FROM json_each(?))"#,
+            )
+            .bind(sqlx::types::Json(&urls))
+            .execute(&mut *txn)
+            .await?;
+            sqlx::query(r#"DELETE FROM urls WHERE url_id IN (SELECT value FROM json_each(?))"#)
+                .bind(sqlx::types::Json(&urls))
+                .execute(&mut *txn)
+                .await?;
+            let tags: Vec<TagId> = report.orphaned_tags.iter().map(|tag| tag.id).collect();
+            sqlx::query(r#"DELETE FROM tags WHERE tag_id IN (SELECT value FROM json_each(?))"#)
+                .bind(sqlx::types::Json(&tags))
+                .execute(&mut *txn)
+                .await?;
+            report.removed_orphans = true;
+        }
+        txn.commit().await?;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testresult::TestResult;
+    use url::Url;
+
+    use super::*;
+    use crate::{Bookmark, NoId};
+
+    // These use database files: On the shared in-memory test database,
+    // `PRAGMA integrity_check` waits forever for locks that idle
+    // connections hold on to.
+
+    #[tokio::test]
+    async fn finds_and_removes_orphans() -> TestResult {
+        let dir = tempfile::tempdir()?;
+        let conn = Connection::from_path(&dir.path().join("db.sqlite")).await?;
+        let mut txn = conn.begin_for_user("tester").await?;
+        let mut ids = vec![];
+        for link in ["https://example.com/gone", "https://example.com/kept"] {
+            let bookmark = txn
+                .add_bookmark(Bookmark {
+                    id: NoId,
+                    user_id: NoId,
+                    created_at: Default::default(),
+                    modified_at: None,
+                    accessed_at: None,
+                    url: Url::parse(link)?,
+                    title: link.to_string(),
+                    description: None,
+                    website_title: None,
+                    website_description: None,
+                    notes: None,
+                    import_properties: None,
+                    shared: false,
+                    unread: false,
+                })
+                .await?;
+            ids.push(bookmark.id);
+        }
+        let gone = txn.ensure_tags(["gone"]).await?;
+        txn.set_bookmark_tags(ids[0], gone).await?;
+        let kept = txn.ensure_tags(["kept"]).await?;
+        txn.set_bookmark_tags(ids[1], kept).await?;
+        let aliased = txn.ensure_tags(["aliased"]).await?.remove(0);
+        txn.add_tag_alias("alias", aliased.id, false).await?;
+        txn.delete_bookmark(ids[0]).await?;
+        txn.commit().await?;
+
+        let report = conn.fsck(false).await?;
+        assert!(report.is_consistent());
+        assert_eq!(
+            report
+                .orphaned_urls
+                .iter()
+                .map(|url| url.link.as_str())
+                .collect::<Vec<_>>(),
+            vec!["https://example.com/gone"]
+        );
+        assert_eq!(
+            report
+                .orphaned_tags
+                .iter()
+                .map(|tag| (tag.name.as_str(), tag.user_name.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("gone", "tester")]
+        );
+        assert!(!report.removed_orphans);
+
+        assert!(conn.fsck(true).await?.removed_orphans);
+        let report = conn.fsck(false).await?;
+        assert!(report.orphaned_urls.is_empty());
+        assert!(report.orphaned_tags.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checks_only_current_schemas() -> TestResult {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("db.sqlite");
+        // An empty file is a database without any tables:
+        std::fs::File::create(&path)?;
+        let conn = Connection::from_path_unmigrated(&path).await?;
+        assert!(matches!(
+            conn.fsck(false).await,
+            Err(SchemaError::Outdated { versions }) if !versions.is_empty()
+        ));
+
+        conn.migrate().await?;
+        assert!(conn.fsck(false).await?.is_consistent());
+        sqlx::query(
+            r#"
+              INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+              VALUES (99991231000000, 'from the future', true, x'00', 0)
+            "#,
+        )
+        .execute(&conn.rw)
+        .await?;
+        assert!(matches!(
+            conn.fsck(false).await,
+            Err(SchemaError::TooNew { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn finds_unparseable_data() -> TestResult {
+        let dir = tempfile::tempdir()?;
+        let conn = Connection::from_path(&dir.path().join("db.sqlite")).await?;
+        let mut txn = conn.begin_for_user("tester").await?;
+        let bookmark = txn
+            .add_bookmark(Bookmark {
+                id: NoId,
+                user_id: NoId,
+                created_at: Default::default(),
+                modified_at: None,
+                accessed_at: None,
+                url: Url::parse("https://example.com/")?,
+                title: "Example".to_string(),
+                description: None,
+                website_title: None,
+                website_description: None,
+                notes: None,
+                import_properties: None,
+                shared: false,
+                unread: false,
+            })
+            .await?;
+        let tags = txn.ensure_tags(["Rust"]).await?;
+        txn.set_bookmark_tags(bookmark.id, tags).await?;
+        txn.commit().await?;
+        sqlx::query(
+            r#"
+              UPDATE bookmarks SET import_properties = '{"by_system": 1}';
+              UPDATE tags SET slug = 'Rust';
+            "#,
+        )
+        .execute(&conn.rw)
+        .await?;
+
+        let report = conn.fsck(false).await?;
+        assert!(!report.is_consistent());
+        assert_eq!(report.invalid_import_properties.len(), 1);
+        assert_eq!(report.invalid_import_properties[0].bookmark_id, bookmark.id);
+        assert_eq!(
+            report.mismatched_slugs,
+            vec![MismatchedSlug {
+                kind: "tag",
+                name: "Rust".to_string(),
+                slug: "Rust".to_string(),
+                expected: "rust".to_string(),
+            }]
+        );
+        assert!(report.integrity_errors.is_empty());
+        assert!(report.foreign_key_violations.is_empty());
+        Ok(())
+    }
+}
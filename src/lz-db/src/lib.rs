@@ -1,12 +1,19 @@
 //! Database bindings and models for the `lz` bookmark manager
 
-#[cfg(test)]
-pub(crate) static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
+/// The migrations that define the database schema; they are applied
+/// when [`Connection::from_path`] opens a database.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
 
+mod backup;
 mod connection;
+mod fsck;
+mod schema;
 mod transaction;
 
+pub use backup::*;
 pub use connection::*;
+pub use fsck::*;
+pub use schema::*;
 pub use transaction::*;
 
 #[cfg(test)]
@@ -16,6 +23,7 @@ pub use testing::{Context, NonMigratingContext};
 
 #[cfg(test)]
 mod tests {
+    use sqlx::Executor as _;
     use test_context::test_context;
     use testresult::TestResult;
 
@@ -27,4 +35,56 @@ mod tests {
         MIGRATOR.run(ctx.db_pool()).await?;
         Ok(())
     }
+
+    #[test_context(NonMigratingContext)]
+    #[tokio::test]
+    async fn splits_shared_tags_by_user(ctx: &mut NonMigratingContext) -> TestResult {
+        const USER_TAGS: i64 = 20261017140000;
+        let mut conn = ctx.db_pool().acquire().await?;
+        for migration in MIGRATOR.iter().filter(|m| m.version < USER_TAGS) {
+            conn.execute(&*migration.sql).await?;
+        }
+        conn.execute(
+            r#"
+              INSERT INTO users (user_id, name, created_at) VALUES
+                (1, 'one', datetime()), (2, 'two', datetime());
+              INSERT INTO urls (url_id, link) VALUES
+                (1, 'https://example.com/');
+              INSERT INTO bookmarks (bookmark_id, user_id, created_at, url_id, title) VALUES
+                (1, 1, datetime(), 1, 'one'), (2, 2, datetime(), 1, 'two');
+              INSERT INTO tags (tag_id, name, slug, created_at) VALUES
+                (1, 'Shared', 'shared', datetime()),
+                (2, 'mine', 'mine', datetime()),
+                (3, 'unused', 'unused', datetime());
+              INSERT INTO bookmark_tags (bookmark_id, tag_id) VALUES (1, 1), (2, 1), (1, 2);
+            "#,
+        )
+        .await?;
+        for migration in MIGRATOR.iter().filter(|m| m.version == USER_TAGS) {
+            conn.execute(&*migration.sql).await?;
+        }
+
+        let tags: Vec<(i64, String, i64)> = sqlx::query_as(
+            r#"
+              SELECT user_id, name, bookmark_id
+              FROM tags JOIN bookmark_tags USING (tag_id)
+              ORDER BY user_id, name
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+        assert_eq!(
+            tags,
+            vec![
+                (1, "Shared".to_string(), 1),
+                (1, "mine".to_string(), 1),
+                (2, "Shared".to_string(), 2),
+            ]
+        );
+        let (tag_count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM tags")
+            .fetch_one(&mut *conn)
+            .await?;
+        assert_eq!(tag_count, 3);
+        Ok(())
+    }
 }
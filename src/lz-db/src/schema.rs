@@ -0,0 +1,337 @@
+//! # The database schema
+//!
+//! The schema of an lz database is defined by the migrations that are
+//! embedded in [`MIGRATOR`]. [`Connection::from_path`] brings databases
+//! up to date as it opens them; the methods here let callers inspect
+//! and migrate a database explicitly.
+
+use chrono::{DateTime, Utc};
+use sqlx::prelude::*;
+
+use crate::{normalize_tag, Connection, TagId, UserId, MIGRATOR};
+
+/// The version of the migration that changed what slugs of
+/// hierarchical tags look like.
+const HIERARCHICAL_TAG_SLUGS: i64 = 20261017150000;
+
+/// An error checking or migrating the database schema.
+#[derive(thiserror::Error, Debug)]
+pub enum SchemaError {
+    /// The database has migrations applied that this version of lz
+    /// doesn't know about, so it was likely migrated by a newer
+    /// version.
+    #[error("the database schema is newer than this version of lz supports (unknown migrations: {versions:?})")]
+    TooNew { versions: Vec<i64> },
+
+    /// The database has migrations pending, so it lacks tables or
+    /// columns that this version of lz expects.
+    #[error("the database schema is out of date (pending migrations: {versions:?}); run `lz db migrate` first")]
+    Outdated { versions: Vec<i64> },
+
+    /// Applying a migration failed.
+    #[error("could not migrate the database")]
+    Migrate(#[from] sqlx::migrate::MigrateError),
+
+    /// Any error raised by sqlx.
+    #[error("sql datastore error")]
+    Sqlx(#[from] sqlx::Error),
+}
+
+/// A migration, and whether it is applied to the database.
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct MigrationStatus {
+    /// The migration's version, its timestamp.
+    pub version: i64,
+
+    /// What the migration does, from its file name.
+    pub description: String,
+
+    /// When the migration was applied, if it was.
+    #[sqlx(rename = "installed_on")]
+    pub applied_at: Option<DateTime<Utc>>,
+}
+
+/// The state of a database's schema, as returned by
+/// [`Connection::schema_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaStatus {
+    /// The migrations that this version of lz knows about, in order.
+    pub migrations: Vec<MigrationStatus>,
+
+    /// Migrations applied to the database that this version of lz
+    /// doesn't know about.
+    pub unknown: Vec<MigrationStatus>,
+}
+
+impl SchemaStatus {
+    /// The known migrations that are not applied to the database yet.
+    pub fn pending(&self) -> impl Iterator<Item = &MigrationStatus> {
+        self.migrations.iter().filter(|m| m.applied_at.is_none())
+    }
+
+    /// Returns an error if the database has migrations applied that
+    /// this version of lz doesn't know about.
+    pub fn check_not_newer(&self) -> Result<(), SchemaError> {
+        if self.unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError::TooNew {
+                versions: self.unknown.iter().map(|m| m.version).collect(),
+            })
+        }
+    }
+
+    /// Returns an error unless the database has exactly the migrations
+    /// applied that this version of lz knows about.
+    pub fn check_current(&self) -> Result<(), SchemaError> {
+        self.check_not_newer()?;
+        let versions: Vec<i64> = self.pending().map(|m| m.version).collect();
+        if versions.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError::Outdated { versions })
+        }
+    }
+}
+
+/// # Managing the schema
+impl Connection {
+    /// Return which migrations are applied to the database, and which
+    /// are pending.
+    ///
+    /// This does not write to the database, not even to create the
+    /// table that records applied migrations.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn schema_status(&self) -> Result<SchemaStatus, sqlx::Error> {
+        let (has_migrations_table,): (bool,) = sqlx::query_as(
+            r#"SELECT count(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'"#,
+        )
+        .fetch_one(&self.rw)
+        .await?;
+        let mut applied: Vec<MigrationStatus> = if has_migrations_table {
+            sqlx::query_as(
+                r#"
+                  SELECT version, description, installed_on FROM _sqlx_migrations
+                  WHERE success
+                  ORDER BY version
+                "#,
+            )
+            .fetch_all(&self.rw)
+            .await?
+        } else {
+            vec![]
+        };
+        let migrations = MIGRATOR
+            .iter()
+            .filter(|m| m.migration_type.is_up_migration())
+            .map(|m| {
+                let applied_at = applied
+                    .iter()
+                    .find(|a| a.version == m.version)
+                    .and_then(|a| a.applied_at);
+                MigrationStatus {
+                    version: m.version,
+                    description: m.description.to_string(),
+                    applied_at,
+                }
+            })
+            .collect();
+        applied.retain(|a| !MIGRATOR.iter().any(|m| m.version == a.version));
+        Ok(SchemaStatus {
+            migrations,
+            unknown: applied,
+        })
+    }
+
+    /// Apply all pending migrations to the database, and return its
+    /// new status.
+    ///
+    /// Some data can only be brought up to date in Rust, after the
+    /// schema is: When the migration to hierarchical tag slugs gets
+    /// applied, tags whose slug isn't what [`normalize_tag`] makes of
+    /// their name get their slug fixed.
+    ///
+    /// Databases with a schema newer than this version of lz are left
+    /// alone, with a [`SchemaError::TooNew`].
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn migrate(&self) -> Result<SchemaStatus, SchemaError> {
+        let status = self.schema_status().await?;
+        status.check_not_newer()?;
+        MIGRATOR.run(&self.rw).await?;
+        if status
+            .pending()
+            .any(|m| m.version == HIERARCHICAL_TAG_SLUGS)
+        {
+            self.fix_tag_slugs().await?;
+        }
+        Ok(self.schema_status().await?)
+    }
+
+    /// Give every tag the slug that [`normalize_tag`] makes of its
+    /// name, and return how many tags needed fixing.
+    ///
+    /// Slugs used to be computed differently (hierarchical tags got
+    /// their `/` turned into `-`), and an old slug keeps a tag from
+    /// being found by name. If the user already has a tag with the
+    /// right slug (created because the old one couldn't be found),
+    /// the old tag gets merged into it.
+    async fn fix_tag_slugs(&self) -> Result<u64, sqlx::Error> {
+        let mut txn = self.rw.begin().await?;
+        let tags: Vec<(TagId, UserId, String, String)> =
+            sqlx::query_as(r#"SELECT tag_id, user_id, name, slug FROM tags ORDER BY tag_id"#)
+                .fetch_all(&mut *txn)
+                .await?;
+        let mut fixed = 0;
+        for (tag_id, user_id, name, slug) in tags {
+            let expected = normalize_tag(&name);
+            if slug == expected {
+                continue;
+            }
+            let existing: Option<(TagId,)> =
+                sqlx::query_as(r#"SELECT tag_id FROM tags WHERE user_id = ? AND slug = ?"#)
+                    .bind(user_id)
+                    .bind(&expected)
+                    .fetch_optional(&mut *txn)
+                    .await?;
+            if let Some((into,)) = existing {
+                tracing::info!(%name, %slug, %expected, ?into, "merging tag into the one with its slug");
+                sqlx::query(
+                    r#"
+                      INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag_id)
+                      SELECT bookmark_id, ? FROM bookmark_tags WHERE tag_id = ?
+                    "#,
+                )
+                .bind(into)
+                .bind(tag_id)
+                .execute(&mut *txn)
+                .await?;
+                sqlx::query(r#"UPDATE tag_aliases SET tag_id = ? WHERE tag_id = ?"#)
+                    .bind(into)
+                    .bind(tag_id)
+                    .execute(&mut *txn)
+                    .await?;
+                sqlx::query(r#"DELETE FROM bookmark_tags WHERE tag_id = ?"#)
+                    .bind(tag_id)
+                    .execute(&mut *txn)
+                    .await?;
+                sqlx::query(r#"DELETE FROM tags WHERE tag_id = ?"#)
+                    .bind(tag_id)
+                    .execute(&mut *txn)
+                    .await?;
+            } else {
+                tracing::info!(%name, %slug, %expected, "fixing tag slug");
+                sqlx::query(r#"UPDATE tags SET slug = ? WHERE tag_id = ?"#)
+                    .bind(&expected)
+                    .bind(tag_id)
+                    .execute(&mut *txn)
+                    .await?;
+            }
+            fixed += 1;
+        }
+        txn.commit().await?;
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_context::test_context;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{Context, NonMigratingContext};
+
+    #[test_context(NonMigratingContext)]
+    #[tokio::test]
+    async fn migrate_fresh_database(ctx: &mut NonMigratingContext) -> TestResult {
+        let status = ctx.connection().schema_status().await?;
+        assert!(status.migrations.iter().all(|m| m.applied_at.is_none()));
+        assert_eq!(status.pending().count(), status.migrations.len());
+        assert!(status.unknown.is_empty());
+
+        let status = ctx.connection().migrate().await?;
+        assert_eq!(status.pending().count(), 0);
+        assert_eq!(
+            status.migrations.last().map(|m| m.version),
+            MIGRATOR.iter().map(|m| m.version).max()
+        );
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn fix_tag_slugs(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let tags = txn.ensure_tags(["Lang/Rust", "Web/Dev"]).await?;
+        txn.commit().await?;
+        // What the first version of hierarchical slugs left behind:
+        sqlx::query(r#"UPDATE tags SET slug = replace(slug, '/', '-')"#)
+            .execute(ctx.db_pool())
+            .await?;
+        let mut txn = ctx.begin().await?;
+        let duplicate = txn.ensure_tags(["lang/rust"]).await?;
+        assert_ne!(duplicate[0].id, tags[0].id);
+        txn.commit().await?;
+        let slugs = r#"SELECT slug FROM tags ORDER BY slug"#;
+
+        // Migrating an up-to-date database leaves the tags alone:
+        ctx.connection().migrate().await?;
+        assert_eq!(
+            sqlx::query_scalar::<_, String>(slugs)
+                .fetch_all(ctx.db_pool())
+                .await?,
+            vec!["lang-rust", "lang/rust", "web-dev"]
+        );
+
+        // The fix comes with the migration to hierarchical slugs:
+        sqlx::query(r#"DELETE FROM _sqlx_migrations WHERE version = ?"#)
+            .bind(HIERARCHICAL_TAG_SLUGS)
+            .execute(ctx.db_pool())
+            .await?;
+        ctx.connection().migrate().await?;
+        assert_eq!(
+            sqlx::query_scalar::<_, String>(slugs)
+                .fetch_all(ctx.db_pool())
+                .await?,
+            vec!["lang/rust", "web/dev"]
+        );
+        let mut txn = ctx.begin().await?;
+        let mut found = txn.get_tags_with_names(["lang/rust", "web/dev"]).await?;
+        found.sort_by_key(|t| t.slug.clone());
+        assert_eq!(
+            found
+                .iter()
+                .map(|t| (t.id, t.slug.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(duplicate[0].id, "lang/rust"), (tags[1].id, "web/dev")]
+        );
+        assert_eq!(txn.list_tags_with_counts().await?.len(), 2);
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn refuse_newer_schema(ctx: &mut Context) -> TestResult {
+        sqlx::query(
+            r#"
+              INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+              VALUES (99991231000000, 'from the future', true, x'00', 0)
+            "#,
+        )
+        .execute(ctx.db_pool())
+        .await?;
+        let status = ctx.connection().schema_status().await?;
+        assert_eq!(status.pending().count(), 0);
+        assert_eq!(status.unknown.len(), 1);
+        assert_eq!(status.unknown[0].description, "from the future");
+        assert!(matches!(
+            status.check_not_newer(),
+            Err(SchemaError::TooNew { versions }) if versions == vec![99991231000000]
+        ));
+        assert!(matches!(
+            ctx.connection().migrate().await,
+            Err(SchemaError::TooNew { .. })
+        ));
+        Ok(())
+    }
+}
@@ -33,6 +33,11 @@ impl<M: MigrationBehavior> Context<M> {
         self.connection.begin_for_user(name).await
     }
 
+    /// Returns the database connection used in this context
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
     /// Returns the SQLite DB pool used in this context
     pub fn db_pool(&mut self) -> &sqlx::SqlitePool {
         // TODO: Maybe find a way to test the rw/ro duality.
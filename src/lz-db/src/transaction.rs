@@ -2,6 +2,7 @@ use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use std::marker::PhantomData;
+use std::sync::Arc;
 use utoipa::{ToResponse, ToSchema};
 
 use crate::Connection;
@@ -35,6 +36,7 @@ impl TransactionMode for ReadWrite {}
 pub struct Transaction<M: TransactionMode = ReadWrite> {
     txn: sqlx::Transaction<'static, sqlx::sqlite::Sqlite>,
     user: User<UserId>,
+    url_canonicalization: Arc<UrlCanonicalization>,
     marker: PhantomData<M>,
 }
 
@@ -73,6 +75,7 @@ impl Connection {
         Ok(Transaction {
             txn,
             user,
+            url_canonicalization: self.url_canonicalization.clone(),
             marker: PhantomData,
         })
     }
@@ -102,6 +105,7 @@ impl Connection {
         Ok(Transaction {
             txn,
             user,
+            url_canonicalization: self.url_canonicalization.clone(),
             marker: PhantomData,
         })
     }
@@ -201,15 +205,31 @@ pub use user::*;
 mod import_properties;
 pub use import_properties::*;
 
+pub(crate) mod order;
+pub use order::{BookmarkCursor, BookmarkOrder, ListError, ListedBookmark};
+
 pub mod web;
 pub use web::*;
 
 pub(crate) mod criteria;
 pub use criteria::{
-    created_after_from_datetime, created_before_from_datetime, BookmarkSearch,
-    BookmarkSearchCriteria, BookmarkSearchDateParams, BookmarkSearchDatetimeField,
-    BookmarkSearchDatetimeOrientation, DateInput,
+    created_after_from_datetime, created_before_from_datetime, parse_query,
+    push_matching_bookmark_ids, BookmarkSearch, BookmarkSearchCriteria, BookmarkSearchDateParams,
+    BookmarkSearchDatetimeField, BookmarkSearchDatetimeOrientation, DateInput, DateSpan,
+    FullTextQuery, QueryParseError, SiteName, Timezone,
 };
 
 mod url;
 pub use url::*;
+
+mod archive;
+pub use archive::*;
+
+mod link_check;
+pub use link_check::*;
+
+mod merge;
+pub use merge::*;
+
+mod tag_alias;
+pub use tag_alias::*;
@@ -0,0 +1,196 @@
+//! # Local archives of bookmarked pages
+//!
+//! An archive is a copy of the page that a URL pointed to at some
+//! point in time, so that bookmarks stay useful when the page changes
+//! or disappears. Archives belong to the stored URL, not to any one
+//! bookmark: Every user who bookmarked the URL sees the same archive.
+//!
+//! The database only records the archive's digests and its readable
+//! text (which the full-text index covers); the archived files
+//! themselves are stored outside the database.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::*;
+use sqlx::types::Text;
+use url::Url;
+use utoipa::ToSchema;
+
+use crate::{BookmarkId, ReadWrite, StoredUrlId, Transaction, TransactionMode};
+
+/// A local copy of the page that a stored URL points to.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, FromRow, ToSchema)]
+pub struct Archive {
+    /// The archived URL
+    pub url_id: StoredUrlId,
+
+    /// When the page was archived
+    pub archived_at: DateTime<Utc>,
+
+    /// Where the page was retrieved from, after following redirects
+    #[sqlx(try_from = "&'a str")]
+    pub retrieved_from: Url,
+
+    /// SHA-256 digest of the archived HTML, in hex
+    pub html_sha256: String,
+
+    /// SHA-256 digest of the archived text, in hex
+    pub text_sha256: String,
+
+    /// The page's readable text
+    pub text: String,
+}
+
+/// # Reading archives
+impl<M: TransactionMode> Transaction<M> {
+    /// Return the archive of a stored URL, if there is one.
+    #[tracing::instrument(skip(self))]
+    pub async fn archive_for_url(
+        &mut self,
+        url_id: StoredUrlId,
+    ) -> Result<Option<Archive>, sqlx::Error> {
+        sqlx::query_as(r#"SELECT * FROM archives WHERE url_id = ?"#)
+            .bind(url_id)
+            .fetch_optional(&mut *self.txn)
+            .await
+    }
+
+    /// Return the archive of the page that one of the user's
+    /// bookmarks points to, if there is one.
+    #[tracing::instrument(skip(self))]
+    pub async fn archive_for_bookmark(
+        &mut self,
+        bookmark_id: BookmarkId,
+    ) -> Result<Option<Archive>, sqlx::Error> {
+        let user_id = self.user().id;
+        sqlx::query_as(
+            r#"
+              SELECT archives.* FROM archives JOIN bookmarks USING (url_id)
+              WHERE bookmark_id = ? AND user_id = ?
+            "#,
+        )
+        .bind(bookmark_id)
+        .bind(user_id)
+        .fetch_optional(&mut *self.txn)
+        .await
+    }
+}
+
+/// # Recording archives
+impl Transaction<ReadWrite> {
+    /// Record an archive of a stored URL, replacing any earlier one.
+    #[tracing::instrument(skip(self, archive), fields(url_id=%archive.url_id))]
+    pub async fn set_archive(&mut self, archive: &Archive) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+              INSERT INTO archives (
+                url_id, archived_at, retrieved_from, html_sha256, text_sha256, text
+              ) VALUES (?, ?, ?, ?, ?, ?)
+              ON CONFLICT (url_id) DO UPDATE SET
+                archived_at = excluded.archived_at,
+                retrieved_from = excluded.retrieved_from,
+                html_sha256 = excluded.html_sha256,
+                text_sha256 = excluded.text_sha256,
+                text = excluded.text
+            "#,
+        )
+        .bind(archive.url_id)
+        .bind(archive.archived_at)
+        .bind(Text(&archive.retrieved_from))
+        .bind(&archive.html_sha256)
+        .bind(&archive.text_sha256)
+        .bind(&archive.text)
+        .execute(&mut *self.txn)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_context::test_context;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{Bookmark, BookmarkSearch, Context, FullTextQuery, NoId};
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn archived_text_is_searchable(ctx: &mut Context) -> TestResult {
+        let url = Url::parse("https://example.com/rotting")?;
+        let mut txn = ctx.begin().await?;
+        let bookmark = txn
+            .add_bookmark(Bookmark {
+                id: NoId,
+                user_id: NoId,
+                created_at: Default::default(),
+                modified_at: None,
+                accessed_at: None,
+                url: url.clone(),
+                title: "A page that will rot".to_string(),
+                description: None,
+                website_title: None,
+                website_description: None,
+                notes: None,
+                import_properties: None,
+                shared: false,
+                unread: false,
+            })
+            .await?;
+        let search = |text: &str| {
+            vec![BookmarkSearch::FullText {
+                query: FullTextQuery::from(text),
+            }]
+        };
+        assert!(txn
+            .list_bookmarks_matching(&search("linkrot"), 10, None)
+            .await?
+            .is_empty());
+        assert_eq!(txn.archive_for_bookmark(bookmark.id).await?, None);
+
+        let url_id = txn.ensure_url(&url).await?;
+        let mut archive = Archive {
+            url_id,
+            archived_at: Default::default(),
+            retrieved_from: url.clone(),
+            html_sha256: "00".to_string(),
+            text_sha256: "01".to_string(),
+            text: "Everything about linkrot".to_string(),
+        };
+        txn.set_archive(&archive).await?;
+        assert_eq!(
+            txn.archive_for_bookmark(bookmark.id).await?.as_ref(),
+            Some(&archive)
+        );
+        let found = txn
+            .list_bookmarks_matching(&search("linkrot rot"), 10, None)
+            .await?;
+        assert_eq!(
+            found.iter().map(|bm| bm.id).collect::<Vec<_>>(),
+            vec![bookmark.id]
+        );
+        let snippets = txn
+            .full_text_snippets(&FullTextQuery::from("linkrot"), &found)
+            .await?;
+        assert_eq!(
+            snippets.get(&bookmark.id).map(String::as_str),
+            Some("Everything about **linkrot**")
+        );
+
+        // A newer archive replaces the old one, in the index too:
+        archive.text = "Nothing to see here".to_string();
+        txn.set_archive(&archive).await?;
+        assert!(txn
+            .list_bookmarks_matching(&search("linkrot"), 10, None)
+            .await?
+            .is_empty());
+        txn.commit().await?;
+
+        // Other users' bookmarks of the same URL share the archive, but
+        // they can't see archives through bookmarks that aren't theirs:
+        let mut txn = ctx.begin_for_user("someone_else").await?;
+        assert_eq!(txn.archive_for_bookmark(bookmark.id).await?, None);
+        assert_eq!(txn.archive_for_url(url_id).await?, Some(archive));
+        Ok(())
+    }
+}
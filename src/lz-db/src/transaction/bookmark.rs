@@ -4,7 +4,7 @@ use sqlx::query_scalar;
 use url::Url;
 use utoipa::{ToResponse, ToSchema};
 
-use crate::{IdType, NoId, ReadWrite, Transaction, TransactionMode, UserId};
+use crate::{IdType, NoId, ReadWrite, StoredUrlId, Transaction, TransactionMode, UserId};
 
 /// The database ID of a bookmark.
 #[derive(
@@ -149,13 +149,16 @@ impl Bookmark<NoId, NoId> {
 /// # Working with Bookmarks
 impl Transaction<ReadWrite> {
     /// Store a new bookmark in the database.
+    ///
+    /// A user can only have one bookmark of a link, or of links with
+    /// the same canonical form; adding another is a unique violation.
     #[tracing::instrument(skip(self))]
     pub async fn add_bookmark(
         &mut self,
         bm: Bookmark<NoId, NoId>,
     ) -> Result<Bookmark<BookmarkId, UserId>, sqlx::Error> {
         let user_id = self.user().id;
-        let url_id = self.ensure_url(&bm.url).await?;
+        let url_id = self.bookmark_url_id(&bm.url, None).await?;
         let id = query_scalar!(
             r#"
               INSERT INTO bookmarks (
@@ -202,12 +205,15 @@ impl Transaction<ReadWrite> {
     ///
     /// - `accessed_at` and `created_at` - these timestamps can't be
     ///   manually reset.
+    ///
+    /// Like when adding a bookmark, its link must not have the same
+    /// canonical form as another of the user's bookmarks.
     #[tracing::instrument(skip(self))]
     pub async fn update_bookmark<U: IdType<UserId> + std::fmt::Debug>(
         &mut self,
         bm: &Bookmark<BookmarkId, U>,
     ) -> Result<(), sqlx::Error> {
-        let url_id = self.ensure_url(&bm.url).await?;
+        let url_id = self.bookmark_url_id(&bm.url, Some(bm.id)).await?;
         let user_id = self.user().id;
         sqlx::query!(
             r#"
@@ -241,6 +247,37 @@ impl Transaction<ReadWrite> {
         .await
         .map(|_| ())
     }
+
+    /// The stored URL for the user's bookmark `bookmark` (or a new
+    /// bookmark) of `link`.
+    ///
+    /// If another of the user's bookmarks has a link with the same
+    /// canonical form, that is its URL, so storing the bookmark fails
+    /// on the index that keeps users from bookmarking a URL twice.
+    async fn bookmark_url_id(
+        &mut self,
+        link: &Url,
+        bookmark: Option<BookmarkId>,
+    ) -> Result<StoredUrlId, sqlx::Error> {
+        let canonical = self.url_canonicalization().canonicalize(link);
+        let equivalent = sqlx::query_scalar(
+            r#"
+               SELECT url_id FROM bookmarks JOIN urls USING (url_id)
+               WHERE user_id = ? AND bookmark_id IS NOT ? AND (urls.link = ? OR urls.canonical = ?)
+               LIMIT 1;
+            "#,
+        )
+        .bind(self.user().id)
+        .bind(bookmark)
+        .bind(link.to_string())
+        .bind(canonical)
+        .fetch_optional(&mut *self.txn)
+        .await?;
+        match equivalent {
+            Some(url_id) => Ok(url_id),
+            None => self.ensure_url(link).await,
+        }
+    }
 }
 
 /// Reading and finding [`Bookmark`]s
@@ -262,40 +299,101 @@ impl<M: TransactionMode> Transaction<M> {
             .await
     }
 
-    /// Find all users' bookmarks with the given URL.
+    /// Find all users' bookmarks with the given URL, or one with the
+    /// same canonical form.
     #[tracing::instrument(skip(self))]
     pub async fn find_bookmarks_by_url_for_everyone(
         &mut self,
         url: Url,
     ) -> Result<Vec<Bookmark<BookmarkId, UserId>>, sqlx::Error> {
+        let canonical = self.url_canonicalization().canonicalize(&url);
         sqlx::query_as(
             r#"
-               SELECT *, urls.link AS url FROM bookmarks JOIN urls USING (url_id) WHERE url.link = ?;
+               SELECT *, urls.link AS url FROM bookmarks JOIN urls USING (url_id)
+               WHERE urls.link = ? OR urls.canonical = ?;
             "#,
         )
-            .bind(url.to_string())
-            .fetch_all(&mut *self.txn)
-            .await
+        .bind(url.to_string())
+        .bind(canonical)
+        .fetch_all(&mut *self.txn)
+        .await
     }
 
-    /// Find the current user's bookmark with the given URL, if it exists.
+    /// Find the current user's bookmark with the given URL, or one
+    /// with the same canonical form, if it exists.
     #[tracing::instrument(skip(self))]
     pub async fn find_bookmark_with_url(
         &mut self,
         url: &Url,
     ) -> Result<Option<Bookmark<BookmarkId, UserId>>, sqlx::Error> {
+        let canonical = self.url_canonicalization().canonicalize(url);
         sqlx::query_as(
             r#"
-               SELECT *, urls.link AS url FROM bookmarks JOIN urls USING (url_id) WHERE urls.link = ? AND user_id = ?;
+               SELECT *, urls.link AS url FROM bookmarks JOIN urls USING (url_id)
+               WHERE (urls.link = ? OR urls.canonical = ?) AND user_id = ?
+               ORDER BY urls.link = ? DESC, bookmark_id
+               LIMIT 1;
             "#,
         )
-            .bind(url.to_string())
-            .bind(self.user().id)
-            .fetch_optional(&mut *self.txn)
-            .await
+        .bind(url.to_string())
+        .bind(canonical)
+        .bind(self.user().id)
+        .bind(url.to_string())
+        .fetch_optional(&mut *self.txn)
+        .await
+    }
+
+    /// Find groups of the current user's bookmarks whose URLs have the
+    /// same canonical form. The bookmarks in each group are ordered by
+    /// when they were created, oldest first.
+    ///
+    /// This only finds duplicates among URLs whose canonical form is
+    /// up to date, see
+    /// [`refresh_canonical_urls`](Transaction::refresh_canonical_urls).
+    #[tracing::instrument(skip(self))]
+    pub async fn duplicate_bookmarks(
+        &mut self,
+    ) -> Result<Vec<Vec<Bookmark<BookmarkId, UserId>>>, sqlx::Error> {
+        #[derive(FromRow)]
+        struct Duplicate {
+            canonical: String,
+            #[sqlx(flatten)]
+            bookmark: Bookmark<BookmarkId, UserId>,
+        }
+
+        let user_id = self.user().id;
+        let duplicates: Vec<Duplicate> = sqlx::query_as(
+            r#"
+               SELECT *, urls.link AS url FROM bookmarks JOIN urls USING (url_id)
+               WHERE user_id = ? AND canonical IN (
+                 SELECT canonical FROM bookmarks JOIN urls USING (url_id)
+                 WHERE user_id = ? AND canonical IS NOT NULL
+                 GROUP BY canonical HAVING count(*) > 1
+               )
+               ORDER BY canonical, created_at, bookmark_id;
+            "#,
+        )
+        .bind(user_id)
+        .bind(user_id)
+        .fetch_all(&mut *self.txn)
+        .await?;
+        let mut groups: Vec<(String, Vec<_>)> = vec![];
+        for Duplicate {
+            canonical,
+            bookmark,
+        } in duplicates
+        {
+            match groups.last_mut() {
+                Some((last, group)) if *last == canonical => group.push(bookmark),
+                _ => groups.push((canonical, vec![bookmark])),
+            }
+        }
+        Ok(groups.into_iter().map(|(_, group)| group).collect())
     }
 
     /// Delete a user's bookmark.
+    ///
+    /// Returns whether the bookmark existed (and belonged to the user).
     #[tracing::instrument(skip(self))]
     pub async fn delete_bookmark(&mut self, bookmark_id: BookmarkId) -> Result<bool, sqlx::Error> {
         let user_id = self.user().id;
@@ -303,8 +401,7 @@ impl<M: TransactionMode> Transaction<M> {
         // is a foreign key (handled by a foreign key CASCADE in our schema
         // definitions, but for the time being we're not going to delete any tags
         // or URLs; they'll be plausibly interesting for checking historic usage patterns,
-        // moderation, etc. This decision may be revisited, or we may provide a manual
-        // "prune" mechanism to delete these orphaned records.
+        // moderation, etc. `lz db fsck --remove-orphans` deletes them on request.
         sqlx::query!(
             r#"
                DELETE FROM bookmarks WHERE bookmark_id = ? AND user_id = ?;
@@ -313,8 +410,8 @@ impl<M: TransactionMode> Transaction<M> {
             user_id,
         )
         .execute(&mut *self.txn)
-        .await?;
-        Ok(true)
+        .await
+        .map(|result| result.rows_affected() > 0)
     }
 }
 
@@ -355,7 +452,7 @@ mod tests {
         assert_eq!(retrieved, added);
 
         let retrieved_by_url = txn.find_bookmark_with_url(&to_add.url).await?;
-        let retrieved_id = retrieved.id.clone();
+        let retrieved_id = retrieved.id;
         assert_eq!(Some(retrieved), retrieved_by_url);
 
         let second_add = Bookmark {
@@ -375,7 +472,8 @@ mod tests {
             unread: true,
         };
         let _ = txn.add_bookmark(second_add.clone()).await?;
-        txn.delete_bookmark(retrieved_id).await?;
+        assert!(txn.delete_bookmark(retrieved_id).await?);
+        assert!(!txn.delete_bookmark(retrieved_id).await?);
         let retrieved_by_url_1 = txn.find_bookmark_with_url(&to_add.url).await?;
         let remaining_url = Url::parse("https://en.wikipedia.org/wiki/Bookmark")?;
         let retrieved_by_url_2 = txn.find_bookmark_with_url(&remaining_url).await?;
@@ -1,16 +1,17 @@
 //! Search criteria translation in the DB. See trait [`BookmarkSearchCriteria`].
 
-use std::fmt;
-use std::str::FromStr;
-
-use anyhow::{anyhow, Result};
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::query_builder::Separated;
-use sqlx::Sqlite;
+use sqlx::{QueryBuilder, Sqlite};
 use utoipa::ToSchema;
 
-use crate::{IdType, TagId, TagName, UserId};
+use crate::{normalize_tag, IdType, LinkStatus, TagId, TagName, UserId};
+
+mod date;
+pub use date::{DateInput, DateSpan, Timezone};
+
+mod query;
+pub use query::{parse_query, QueryParseError};
 
 /// A trait that allows translating a type internal to lz-db to a set
 /// of database query criteria.
@@ -19,10 +20,8 @@ use crate::{IdType, TagId, TagName, UserId};
 /// [`crate::Transaction::list_bookmarks_matching`] method in two
 /// parts of the query assembly process. Trait implementors only need
 /// to override the method that applies to the criteria you search
-/// for; by default, each method is set to "do nothing" (i.e., not
-/// modify the query and thus not insert anything into the query).
-///
-/// See [`sqlx::QueryBuilder::separated`] for details.
+/// for; by default, each method inserts an expression that doesn't
+/// narrow down the set of bookmarks at all.
 ///
 /// # Limiting the set of bookmark by their relationships
 ///
@@ -37,8 +36,9 @@ use crate::{IdType, TagId, TagName, UserId};
 /// is responsible for putting these queries onto the query
 /// builder.
 ///
-/// The returned expressions must be a SELECT expression (not wrapped
-/// in parens) that returns exactly one column, named `bookmark_id`.
+/// The pushed expression must be a simple SELECT expression (not
+/// wrapped in parens, and not a compound `UNION`/`EXCEPT` select)
+/// that returns exactly one column, named `bookmark_id`.
 ///
 /// # Limiting the set of bookmarks
 ///
@@ -51,35 +51,90 @@ use crate::{IdType, TagId, TagName, UserId};
 /// is responsible for adding any direct criteria expressions to the
 /// query.
 ///
-/// The expressions joined here are surrounded by parens so that
+/// The expressions pushed here get surrounded by parens so that
 /// you don't have to worry about precedence of logic operators.
+///
+/// # Combining criteria
+///
+/// The function [`push_matching_bookmark_ids`] puts both parts
+/// together into a query for the IDs of all bookmarks matching a
+/// set of criteria. That allows criteria like [`BookmarkSearch::Any`]
+/// to combine the results of other criteria with `UNION` and
+/// `EXCEPT`.
 pub trait BookmarkSearchCriteria {
     /// Adds a table to the query builder that the bookmarks query gets joined with.
-    fn bookmarks_join_table<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
-        sep
+    fn bookmarks_join_table(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        qb.push("SELECT bookmark_id FROM bookmarks");
+    }
+
+    /// Inserts the data's criteria into a query WHERE clause.
+    fn where_clause(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        qb.push("1 = 1");
+    }
+}
+
+/// Adds a query to the query builder that returns the `bookmark_id`s
+/// of all bookmarks (of any user) matching all the given criteria.
+pub fn push_matching_bookmark_ids<C: BookmarkSearchCriteria>(
+    qb: &mut QueryBuilder<'_, Sqlite>,
+    criteria: &[C],
+) {
+    qb.push("SELECT bookmark_id FROM bookmarks JOIN (");
+    push_join_tables(qb, criteria);
+    qb.push(") USING (bookmark_id) WHERE ");
+    push_where_clauses(qb, criteria);
+}
+
+/// Adds the `INTERSECT`ion of all the criteria's join tables to the query builder.
+pub(crate) fn push_join_tables<C: BookmarkSearchCriteria>(
+    qb: &mut QueryBuilder<'_, Sqlite>,
+    criteria: &[C],
+) {
+    for criterium in criteria {
+        criterium.bookmarks_join_table(qb);
+        qb.push(" INTERSECT ");
     }
+    // A query for "all" bookmarks to ensure the JOIN works
+    // even if no criteria were given:
+    qb.push("SELECT bookmark_id FROM bookmarks");
+}
 
-    /// Inserts the data's criteria into a query WHERE clause, if applicable.
-    fn where_clause<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
-        sep
+/// Adds the conjunction of all the criteria's WHERE expressions to the query builder.
+pub(crate) fn push_where_clauses<C: BookmarkSearchCriteria>(
+    qb: &mut QueryBuilder<'_, Sqlite>,
+    criteria: &[C],
+) {
+    qb.push("(1 = 1)");
+    for criterium in criteria {
+        qb.push(" AND (");
+        criterium.where_clause(qb);
+        qb.push(")");
     }
 }
 
+/// The timestamp of a bookmark that a date search compares.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum BookmarkSearchDatetimeField {
     Created,
+
+    /// Bookmarks that were never modified don't match.
+    Modified,
+
+    /// Bookmarks that were never accessed don't match.
+    Accessed,
 }
 
+/// How a date search compares a bookmark's timestamp to the date.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum BookmarkSearchDatetimeOrientation {
+    /// On the date or later.
     After,
+
+    /// On the date or earlier.
     Before,
+
+    /// On the date; for a timestamp, that exact moment.
+    On,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
@@ -87,20 +142,35 @@ pub struct BookmarkSearchDateParams {
     date: DateInput,
     field: BookmarkSearchDatetimeField,
     orientation: BookmarkSearchDatetimeOrientation,
-}
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
-pub struct DateInput(String);
+    /// The timezone that days are counted in. Without one, the
+    /// timezone given with the search request, the user's timezone
+    /// setting or the server's timezone is used, in that order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timezone: Option<Timezone>,
+}
 
-impl FromStr for DateInput {
-    type Err = anyhow::Error;
+impl BookmarkSearchDateParams {
+    /// Search for bookmarks whose `field` timestamp is before, after
+    /// or on the `date`, as the `orientation` says.
+    pub fn new(
+        field: BookmarkSearchDatetimeField,
+        orientation: BookmarkSearchDatetimeOrientation,
+        date: DateInput,
+    ) -> Self {
+        BookmarkSearchDateParams {
+            date,
+            field,
+            orientation,
+            timezone: None,
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self> {
-        let naive = NaiveDateTime::parse_from_str(&format!("{} 00:00:00", &s), "%Y-%m-%d %H:%M:%S");
-        if naive.is_ok() {
-            Ok(DateInput(s.to_string()))
-        } else {
-            Err(anyhow!("{} is not a valid YYYY-MM-DD date string", s))
+    /// The span of time that the date stands for, as of `now`.
+    fn span(&self, now: DateTime<Utc>) -> DateSpan {
+        match &self.timezone {
+            Some(tz) => self.date.span(now, &tz.0),
+            None => self.date.span(now, &Local),
         }
     }
 }
@@ -115,10 +185,19 @@ pub enum BookmarkSearch {
     #[serde(rename = "time")]
     ByDate { date: BookmarkSearchDateParams },
 
-    /// Only list bookmarks that are tagged with a tag of the given name.
+    /// Only list bookmarks that are tagged with the tag that the given
+    /// name is an alias of, or else with the tag of that name.
     #[serde(rename = "tag")]
     TagByName { tag: TagName },
 
+    /// Only list bookmarks that are tagged with a tag of the given
+    /// name, or with a tag below it in the hierarchy: `lang/rust` also
+    /// finds bookmarks tagged `lang/rust/async`.
+    TagPrefix {
+        #[serde(rename = "tag_prefix")]
+        tag: TagName,
+    },
+
     /// Only list bookmarks that are tagged with a tag with the given ID.
     TagById {
         #[serde(rename = "tag_id")]
@@ -130,30 +209,156 @@ pub enum BookmarkSearch {
         #[serde(rename = "user_id")]
         id: UserId,
     },
+
+    /// Only list bookmarks whose text (title, descriptions, notes, URL
+    /// or archived page) contains all the words in the query.
+    FullText {
+        #[serde(rename = "fulltext")]
+        query: FullTextQuery,
+    },
+
+    /// Only list bookmarks whose URL points to the given site, or to
+    /// any of its subdomains.
+    Site { site: SiteName },
+
+    /// Only list bookmarks that are (or aren't) marked as unread.
+    Unread { unread: bool },
+
+    /// Only list bookmarks that are (or aren't) shared.
+    Shared { shared: bool },
+
+    /// Only list bookmarks whose URL's latest link check found the
+    /// given status. Bookmarks whose URL wasn't checked yet never match.
+    LinkStatus { link_status: LinkStatus },
+
+    /// Only list bookmarks that match all of the given criteria.
+    ///
+    /// This is how criteria at the top level of a query get combined
+    /// already; use it to group criteria inside [`BookmarkSearch::Any`].
+    All {
+        #[serde(rename = "all")]
+        of: Vec<BookmarkSearch>,
+    },
+
+    /// Only list bookmarks that match at least one of the given criteria.
+    Any {
+        #[serde(rename = "any")]
+        of: Vec<BookmarkSearch>,
+    },
+
+    /// Only list bookmarks that do not match the given criterion.
+    Not {
+        #[serde(rename = "not")]
+        criterion: Box<BookmarkSearch>,
+    },
+}
+
+impl BookmarkSearch {
+    /// Count days in the timezone `tz` in this criterion's date
+    /// searches (and those of the criteria in it) that don't have a
+    /// timezone of their own.
+    pub fn default_timezone(&mut self, tz: Timezone) {
+        match self {
+            BookmarkSearch::ByDate { date } => {
+                date.timezone.get_or_insert(tz);
+            }
+            BookmarkSearch::All { of } | BookmarkSearch::Any { of } => {
+                for criterion in of {
+                    criterion.default_timezone(tz);
+                }
+            }
+            BookmarkSearch::Not { criterion } => criterion.default_timezone(tz),
+            BookmarkSearch::TagByName { .. }
+            | BookmarkSearch::TagPrefix { .. }
+            | BookmarkSearch::TagById { .. }
+            | BookmarkSearch::User { .. }
+            | BookmarkSearch::FullText { .. }
+            | BookmarkSearch::Site { .. }
+            | BookmarkSearch::Unread { .. }
+            | BookmarkSearch::Shared { .. }
+            | BookmarkSearch::LinkStatus { .. } => {}
+        }
+    }
 }
 
 impl BookmarkSearchCriteria for BookmarkSearch {
-    fn bookmarks_join_table<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
+    fn bookmarks_join_table(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
         match self {
-            BookmarkSearch::ByDate { date } => date.bookmarks_join_table(sep),
-            BookmarkSearch::TagByName { tag } => tag.bookmarks_join_table(sep),
-            BookmarkSearch::TagById { id } => id.bookmarks_join_table(sep),
-            BookmarkSearch::User { id } => id.bookmarks_join_table(sep),
+            BookmarkSearch::ByDate { date } => date.bookmarks_join_table(qb),
+            BookmarkSearch::TagByName { tag } => tag.bookmarks_join_table(qb),
+            BookmarkSearch::TagPrefix { tag } => {
+                // Slugs are ASCII, so their length is their byte length:
+                let slug = normalize_tag(tag);
+                let below = format!("{slug}/");
+                qb.push(
+                    r#"SELECT bookmark_id FROM tags JOIN bookmark_tags USING (tag_id) WHERE tags.slug = "#,
+                );
+                qb.push_bind(slug);
+                qb.push(" OR substr(tags.slug, 1, ");
+                qb.push_bind(below.len() as i64);
+                qb.push(") = ");
+                qb.push_bind(below);
+            }
+            BookmarkSearch::TagById { id } => id.bookmarks_join_table(qb),
+            BookmarkSearch::User { id } => id.bookmarks_join_table(qb),
+            BookmarkSearch::FullText { query } => query.bookmarks_join_table(qb),
+            BookmarkSearch::Site { site } => site.bookmarks_join_table(qb),
+            BookmarkSearch::LinkStatus { link_status } => link_status.bookmarks_join_table(qb),
+            BookmarkSearch::Unread { .. } | BookmarkSearch::Shared { .. } => {
+                qb.push("SELECT bookmark_id FROM bookmarks");
+            }
+            BookmarkSearch::All { of } => {
+                qb.push("SELECT bookmark_id FROM (");
+                push_matching_bookmark_ids(qb, of);
+                qb.push(")");
+            }
+            BookmarkSearch::Any { of } => {
+                qb.push("SELECT bookmark_id FROM (");
+                for (i, criterion) in of.iter().enumerate() {
+                    if i > 0 {
+                        qb.push(" UNION ");
+                    }
+                    push_matching_bookmark_ids(qb, std::slice::from_ref(criterion));
+                }
+                if of.is_empty() {
+                    // Matching any of no criteria means matching nothing:
+                    qb.push("SELECT bookmark_id FROM bookmarks WHERE 1 = 0");
+                }
+                qb.push(")");
+            }
+            BookmarkSearch::Not { criterion } => {
+                qb.push("SELECT bookmark_id FROM (SELECT bookmark_id FROM bookmarks EXCEPT ");
+                push_matching_bookmark_ids(qb, std::slice::from_ref(criterion.as_ref()));
+                qb.push(")");
+            }
         }
     }
 
-    fn where_clause<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
+    fn where_clause(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
         match self {
-            BookmarkSearch::ByDate { date } => date.where_clause(sep),
-            BookmarkSearch::TagByName { tag } => tag.where_clause(sep),
-            BookmarkSearch::TagById { id } => id.where_clause(sep),
-            BookmarkSearch::User { id } => id.where_clause(sep),
+            BookmarkSearch::ByDate { date } => date.where_clause(qb),
+            BookmarkSearch::TagByName { tag } => tag.where_clause(qb),
+            BookmarkSearch::TagById { id } => id.where_clause(qb),
+            BookmarkSearch::User { id } => id.where_clause(qb),
+            BookmarkSearch::FullText { query } => query.where_clause(qb),
+            BookmarkSearch::Site { site } => site.where_clause(qb),
+            BookmarkSearch::LinkStatus { link_status } => link_status.where_clause(qb),
+            BookmarkSearch::Unread { unread } => {
+                qb.push("COALESCE(unread, 0) = ");
+                qb.push_bind(*unread);
+            }
+            BookmarkSearch::Shared { shared } => {
+                qb.push("COALESCE(shared, 0) = ");
+                qb.push_bind(*shared);
+            }
+            // Tag hierarchies and combinations of criteria are fully
+            // handled by their join table:
+            BookmarkSearch::TagPrefix { .. }
+            | BookmarkSearch::All { .. }
+            | BookmarkSearch::Any { .. }
+            | BookmarkSearch::Not { .. } => {
+                qb.push("1 = 1");
+            }
         }
     }
 }
@@ -161,87 +366,210 @@ impl BookmarkSearchCriteria for BookmarkSearch {
 /// Constricts a bookmark query to only return bookmarks from before, after, or at a
 /// datetime (with the field and orientation as parameters).
 impl BookmarkSearchCriteria for BookmarkSearchDateParams {
-    fn where_clause<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        mut sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
+    fn where_clause(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
         let field = match self.field {
             BookmarkSearchDatetimeField::Created => "created_at",
+            BookmarkSearchDatetimeField::Modified => "modified_at",
+            BookmarkSearchDatetimeField::Accessed => "accessed_at",
         };
-        let operand = match self.orientation {
-            BookmarkSearchDatetimeOrientation::After => ">=",
-            BookmarkSearchDatetimeOrientation::Before => "<=",
-        };
-        sep.push(format!("DATE({}, 'localtime') {} ", field, operand));
-        sep.push_bind_unseparated(self.date.0.clone());
-        sep
+        // Timestamps are stored in a few different formats, so they
+        // are compared as seconds since the epoch:
+        let seconds = |at: DateTime<Utc>| at.timestamp_millis() as f64 / 1000.0;
+        let span = self.span(Utc::now());
+        let time = format!("unixepoch({field}, 'subsec')");
+        match self.orientation {
+            BookmarkSearchDatetimeOrientation::After => {
+                qb.push(format!("{time} >= "));
+                qb.push_bind(seconds(span.start));
+            }
+            BookmarkSearchDatetimeOrientation::Before => {
+                qb.push(format!("{time} < "));
+                qb.push_bind(seconds(span.end));
+            }
+            BookmarkSearchDatetimeOrientation::On => {
+                qb.push(format!("{time} >= "));
+                qb.push_bind(seconds(span.start));
+                qb.push(format!(" AND {time} < "));
+                qb.push_bind(seconds(span.end));
+            }
+        }
     }
 }
 
-/// Constricts a bookmark query to only return bookmarks that have the given tag name.
+/// Constricts a bookmark query to only return bookmarks that have the
+/// tag that the given name is an alias of, or else the tag with the
+/// given name.
+///
+/// Names resolve as in
+/// [`get_tags_with_names`](crate::Transaction::get_tags_with_names): By their
+/// slug, and separately for each user, whose alias hides their tag of
+/// the same name.
 impl BookmarkSearchCriteria for TagName {
-    fn bookmarks_join_table<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        mut sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
-        sep.push(
-            r#"SELECT bookmark_id FROM tags JOIN bookmark_tags USING (tag_id) WHERE tags.name ="#,
+    fn bookmarks_join_table(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        let slug = normalize_tag(&self.0);
+        qb.push(
+            r#"SELECT bookmark_id FROM bookmark_tags WHERE tag_id IN (SELECT tag_id FROM tag_aliases WHERE slug = "#,
         );
-        sep.push_bind_unseparated(self.0.to_string());
-        sep
-    }
-
-    fn where_clause<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        mut sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
-        sep.push("1 = 1");
-        sep
+        qb.push_bind(slug.clone());
+        qb.push(" UNION SELECT tag_id FROM tags WHERE slug = ");
+        qb.push_bind(slug.clone());
+        qb.push(" AND user_id NOT IN (SELECT user_id FROM tag_aliases WHERE slug = ");
+        qb.push_bind(slug);
+        qb.push("))");
     }
 }
 
 /// Constricts a bookmark query to only return bookmarks having a tag with the given ID.
 impl BookmarkSearchCriteria for TagId {
-    fn bookmarks_join_table<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        mut sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
-        sep.push(r#"SELECT bookmark_id FROM tags WHERE tags.id ="#);
-        sep.push_bind_unseparated(self.id());
-        sep
+    fn bookmarks_join_table(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        qb.push(r#"SELECT bookmark_id FROM bookmark_tags WHERE tag_id ="#);
+        qb.push_bind(self.id());
     }
 }
 
 /// Constricts a bookmark query to only return bookmarks that belong to the given user.
 impl BookmarkSearchCriteria for UserId {
-    fn where_clause<'qb, 'args, Sep: fmt::Display>(
-        &self,
-        mut sep: Separated<'qb, 'args, Sqlite, Sep>,
-    ) -> Separated<'qb, 'args, Sqlite, Sep> {
-        sep.push("user_id = ");
-        sep.push_bind_unseparated(self.id());
-        sep
+    fn where_clause(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        qb.push("user_id = ");
+        qb.push_bind(self.id());
+    }
+}
+
+/// A free-text query, matched against the full-text index of bookmarks.
+///
+/// The query is split into words on whitespace; each word must occur
+/// somewhere in a bookmark for it to match. A word ending in `*`
+/// matches all words starting with it, and words enclosed in double
+/// quotes must occur next to each other, as a phrase.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct FullTextQuery(#[schema(min_length = 1)] pub String);
+
+impl FullTextQuery {
+    /// Returns the query in the syntax that SQLite's FTS5 `MATCH` operator accepts.
+    ///
+    /// Each word and phrase gets quoted, so that punctuation in the
+    /// query (which has special meaning in FTS5) is searched for
+    /// literally. A query without any words results in an empty
+    /// string, which FTS5 does not accept.
+    pub(crate) fn to_fts5(&self) -> String {
+        let quote = |s: &str| format!(r#""{}""#, s.replace('"', r#""""#));
+        let mut terms = vec![];
+        let mut rest = self.0.trim_start();
+        while !rest.is_empty() {
+            let term;
+            if let Some(quoted) = rest.strip_prefix('"') {
+                // A phrase runs until the closing quote, or the end of the query:
+                let (phrase, after) = quoted.split_once('"').unwrap_or((quoted, ""));
+                if !phrase.trim().is_empty() {
+                    terms.push(quote(phrase));
+                }
+                term = after;
+            } else {
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                let word = &rest[..end];
+                terms.push(match word.strip_suffix('*') {
+                    Some(prefix) if !prefix.is_empty() => format!("{}*", quote(prefix)),
+                    _ => quote(word),
+                });
+                term = &rest[end..];
+            }
+            rest = term.trim_start();
+        }
+        terms.join(" ")
+    }
+}
+
+impl From<&str> for FullTextQuery {
+    fn from(value: &str) -> Self {
+        FullTextQuery(value.to_string())
+    }
+}
+
+/// Constricts a bookmark query to only return bookmarks matching the full-text query.
+///
+/// A query without any words matches no bookmarks.
+impl BookmarkSearchCriteria for FullTextQuery {
+    fn bookmarks_join_table(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        let query = self.to_fts5();
+        if query.is_empty() {
+            // FTS5 rejects an empty MATCH expression as a syntax error:
+            qb.push("SELECT bookmark_id FROM bookmarks WHERE 1 = 0");
+            return;
+        }
+        qb.push(r#"SELECT rowid AS bookmark_id FROM bookmarks_fts WHERE bookmarks_fts MATCH "#);
+        qb.push_bind(query);
+    }
+}
+
+/// The host name of a website, as searched for by [`BookmarkSearch::Site`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SiteName(#[schema(min_length = 1)] pub String);
+
+impl From<&str> for SiteName {
+    fn from(value: &str) -> Self {
+        SiteName(value.to_lowercase())
+    }
+}
+
+/// Constricts a bookmark query to only return bookmarks whose URL's
+/// host is the given site, or one of its subdomains.
+impl BookmarkSearchCriteria for SiteName {
+    fn bookmarks_join_table(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        // The URL's authority is everything between the `://` and the
+        // next `/`; the host is that, minus a port number if any:
+        qb.push(
+            r#"SELECT bookmark_id FROM bookmarks JOIN (
+                 SELECT url_id, substr(rest, 1, instr(rest || '/', '/') - 1) AS authority
+                 FROM (SELECT url_id, substr(link, instr(link, '://') + 3) AS rest FROM urls)
+               ) USING (url_id) WHERE authority = "#,
+        );
+        let host = self.0.to_lowercase();
+        let escaped = host
+            .replace('\\', r"\\")
+            .replace('%', r"\%")
+            .replace('_', r"\_");
+        qb.push_bind(host);
+        for pattern in [
+            format!("%.{escaped}"),
+            format!("{escaped}:%"),
+            format!("%.{escaped}:%"),
+        ] {
+            qb.push(r" OR authority LIKE ");
+            qb.push_bind(pattern);
+            qb.push(r" ESCAPE '\'");
+        }
+    }
+}
+
+/// Constricts a bookmark query to only return bookmarks whose URL's
+/// latest check found the given status.
+impl BookmarkSearchCriteria for LinkStatus {
+    fn bookmarks_join_table(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        qb.push(
+            r#"SELECT bookmark_id FROM bookmarks JOIN link_checks USING (url_id) WHERE status = "#,
+        );
+        qb.push_bind(*self);
     }
 }
 
 /// Convenience method to make a ByDate search object, tied to `created_at >=`.
 pub fn created_after_from_datetime(date: DateInput) -> BookmarkSearch {
     BookmarkSearch::ByDate {
-        date: BookmarkSearchDateParams {
+        date: BookmarkSearchDateParams::new(
+            BookmarkSearchDatetimeField::Created,
+            BookmarkSearchDatetimeOrientation::After,
             date,
-            field: BookmarkSearchDatetimeField::Created,
-            orientation: BookmarkSearchDatetimeOrientation::After,
-        },
+        ),
     }
 }
 
 /// Convenience method to make a ByDate search object, tied to `created_at <=`.
 pub fn created_before_from_datetime(date: DateInput) -> BookmarkSearch {
     BookmarkSearch::ByDate {
-        date: BookmarkSearchDateParams {
+        date: BookmarkSearchDateParams::new(
+            BookmarkSearchDatetimeField::Created,
+            BookmarkSearchDatetimeOrientation::Before,
             date,
-            field: BookmarkSearchDatetimeField::Created,
-            orientation: BookmarkSearchDatetimeOrientation::Before,
-        },
+        ),
     }
 }
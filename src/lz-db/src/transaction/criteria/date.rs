@@ -0,0 +1,319 @@
+//! Dates that bookmark timestamps get compared with.
+//!
+//! A [`DateInput`] is a date as a person would write it: a calendar
+//! day, an exact timestamp, or something relative to now, like `7d`
+//! or `last-month`. Only when a search runs does it get resolved into
+//! a [`DateSpan`], in the [`Timezone`] that the search is made in.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use chrono::{
+    DateTime, Datelike as _, Days, FixedOffset, Months, NaiveDate, NaiveTime, TimeDelta, TimeZone,
+    Utc,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use sqlx::Sqlite;
+use utoipa::ToSchema;
+
+/// A date or time to search for bookmarks by.
+///
+/// These forms are accepted:
+///
+/// * `YYYY-MM-DD`, a calendar day.
+/// * An RFC3339 timestamp like `2024-01-31T12:00:00+01:00`, an exact
+///   point in time.
+/// * A number of hours, days, weeks, months or years ago: `12h`,
+///   `7d`, `2w`, `3m`, `1y`.
+/// * `today`, `yesterday`, and `this-` or `last-` followed by `week`
+///   (starting on Monday), `month` or `year`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "String")]
+#[schema(example = "2024-01-31")]
+pub struct DateInput(String);
+
+/// The span of time that a [`DateInput`] stands for: from `start` up
+/// to, but not including, `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateSpan {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spec {
+    Day(NaiveDate),
+    Instant(DateTime<FixedOffset>),
+    Ago(u32, Unit),
+    /// The calendar period that is the given number of periods back
+    /// from the current one.
+    Period(Unit, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Spec {
+    fn parse(input: &str) -> Option<Spec> {
+        let input = input.trim().to_lowercase();
+        if let Ok(day) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+            return Some(Spec::Day(day));
+        }
+        if let Ok(instant) = DateTime::parse_from_rfc3339(&input) {
+            return Some(Spec::Instant(instant));
+        }
+        let period = match input.as_str() {
+            "today" => Some(Spec::Period(Unit::Day, 0)),
+            "yesterday" => Some(Spec::Period(Unit::Day, 1)),
+            "this-week" => Some(Spec::Period(Unit::Week, 0)),
+            "last-week" => Some(Spec::Period(Unit::Week, 1)),
+            "this-month" => Some(Spec::Period(Unit::Month, 0)),
+            "last-month" => Some(Spec::Period(Unit::Month, 1)),
+            "this-year" => Some(Spec::Period(Unit::Year, 0)),
+            "last-year" => Some(Spec::Period(Unit::Year, 1)),
+            _ => None,
+        };
+        if period.is_some() {
+            return period;
+        }
+        let split = input.find(|c: char| !c.is_ascii_digit())?;
+        let (amount, unit) = input.split_at(split);
+        let unit = match unit {
+            "h" => Unit::Hour,
+            "d" => Unit::Day,
+            "w" => Unit::Week,
+            "m" => Unit::Month,
+            "y" => Unit::Year,
+            _ => return None,
+        };
+        Some(Spec::Ago(amount.parse().ok()?, unit))
+    }
+}
+
+impl FromStr for DateInput {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match Spec::parse(s) {
+            Some(_) => Ok(DateInput(s.to_string())),
+            None => Err(anyhow!(
+                "{s} is not a date like 2024-01-31, 2024-01-31T12:00:00Z, 7d or last-month"
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for DateInput {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> anyhow::Result<Self> {
+        value.parse()
+    }
+}
+
+impl DateInput {
+    /// Resolve the date into a span of time, as of `now` and in the
+    /// timezone `tz`.
+    ///
+    /// Days and periods span from the midnight they start at to the
+    /// one they end at. Timestamps and times relative to now span a
+    /// millisecond, the precision that SQLite compares times at.
+    pub fn span<Tz: TimeZone>(&self, now: DateTime<Utc>, tz: &Tz) -> DateSpan {
+        let instant = |start: DateTime<Utc>| DateSpan {
+            start,
+            end: start + TimeDelta::milliseconds(1),
+        };
+        let days = |first: NaiveDate, last: NaiveDate| DateSpan {
+            start: start_of_day(tz, first),
+            end: start_of_day(tz, last.succ_opt().unwrap_or(NaiveDate::MAX)),
+        };
+        let local_now = now.with_timezone(tz);
+        let today = local_now.date_naive();
+        let spec = Spec::parse(&self.0).expect("date inputs are checked when they're made");
+        match spec {
+            Spec::Day(day) => days(day, day),
+            Spec::Instant(at) => instant(at.with_timezone(&Utc)),
+            Spec::Ago(amount, unit) => {
+                let then = match unit {
+                    Unit::Hour => local_now.checked_sub_signed(TimeDelta::hours(amount.into())),
+                    Unit::Day => local_now.checked_sub_days(Days::new(amount.into())),
+                    Unit::Week => local_now.checked_sub_days(Days::new(u64::from(amount) * 7)),
+                    Unit::Month => local_now.checked_sub_months(Months::new(amount)),
+                    Unit::Year => amount
+                        .checked_mul(12)
+                        .and_then(|months| local_now.checked_sub_months(Months::new(months))),
+                };
+                instant(then.map_or(DateTime::<Utc>::MIN_UTC, |then| then.with_timezone(&Utc)))
+            }
+            Spec::Period(unit, back) => {
+                let first = match unit {
+                    Unit::Hour | Unit::Day => today.checked_sub_days(Days::new(back.into())),
+                    Unit::Week => today.checked_sub_days(Days::new(
+                        u64::from(today.weekday().num_days_from_monday()) + u64::from(back) * 7,
+                    )),
+                    Unit::Month => today
+                        .with_day(1)
+                        .and_then(|first| first.checked_sub_months(Months::new(back))),
+                    Unit::Year => NaiveDate::from_ymd_opt(today.year() - back as i32, 1, 1),
+                }
+                .unwrap_or(NaiveDate::MIN);
+                let last = match unit {
+                    Unit::Hour | Unit::Day => Some(first),
+                    Unit::Week => first.checked_add_days(Days::new(6)),
+                    Unit::Month | Unit::Year => first
+                        .checked_add_months(Months::new(if unit == Unit::Month { 1 } else { 12 }))
+                        .and_then(|next| next.pred_opt()),
+                }
+                .unwrap_or(NaiveDate::MAX);
+                days(first, last)
+            }
+        }
+    }
+}
+
+/// The first moment of `day` in the timezone `tz`. In timezones that
+/// skip midnight when daylight saving time starts, that's the first
+/// hour of the day that exists.
+fn start_of_day<Tz: TimeZone>(tz: &Tz, day: NaiveDate) -> DateTime<Utc> {
+    (0..24)
+        .find_map(|hour| {
+            let time = NaiveTime::from_hms_opt(hour, 0, 0)?;
+            tz.from_local_datetime(&day.and_time(time)).earliest()
+        })
+        .map_or(DateTime::<Utc>::MIN_UTC, |start| start.with_timezone(&Utc))
+}
+
+/// A timezone from the IANA timezone database, like `Europe/Berlin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "Europe/Berlin")]
+pub struct Timezone(pub chrono_tz::Tz);
+
+impl FromStr for Timezone {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        s.parse()
+            .map(Timezone)
+            .map_err(|_| anyhow!("{s} is not a timezone like Europe/Berlin or UTC"))
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> anyhow::Result<Self> {
+        value.parse()
+    }
+}
+
+impl From<Timezone> for String {
+    fn from(value: Timezone) -> Self {
+        value.0.name().to_string()
+    }
+}
+
+impl fmt::Display for Timezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.name())
+    }
+}
+
+impl sqlx::Type<Sqlite> for Timezone {
+    fn type_info() -> SqliteTypeInfo {
+        <String as sqlx::Type<Sqlite>>::type_info()
+    }
+}
+
+impl<'q> sqlx::Encode<'q, Sqlite> for Timezone {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> sqlx::encode::IsNull {
+        <String as sqlx::Encode<Sqlite>>::encode(self.0.name().to_string(), buf)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, Sqlite> for Timezone {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(<&str as sqlx::Decode<Sqlite>>::decode(value)?.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Europe::Berlin;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    // A Thursday, shortly after midnight in Berlin but not in UTC:
+    const NOW: &str = "2024-03-27T23:30:00Z";
+
+    #[test_case("2024-03-01", "2024-02-29T23:00:00Z", "2024-03-01T23:00:00Z"; "day")]
+    #[test_case("2024-03-31", "2024-03-30T23:00:00Z", "2024-03-31T22:00:00Z"; "day with dst change")]
+    #[test_case("2024-03-01T12:00:00+01:00", "2024-03-01T11:00:00Z", "2024-03-01T11:00:00.001Z"; "timestamp")]
+    #[test_case("12h", "2024-03-27T11:30:00Z", "2024-03-27T11:30:00.001Z"; "hours ago")]
+    #[test_case("1w", "2024-03-20T23:30:00Z", "2024-03-20T23:30:00.001Z"; "weeks ago")]
+    #[test_case("1m", "2024-02-27T23:30:00Z", "2024-02-27T23:30:00.001Z"; "months ago")]
+    #[test_case("today", "2024-03-27T23:00:00Z", "2024-03-28T23:00:00Z"; "today")]
+    #[test_case("yesterday", "2024-03-26T23:00:00Z", "2024-03-27T23:00:00Z"; "yesterday")]
+    #[test_case("this-week", "2024-03-24T23:00:00Z", "2024-03-31T22:00:00Z"; "this week")]
+    #[test_case("last-month", "2024-01-31T23:00:00Z", "2024-02-29T23:00:00Z"; "last month")]
+    #[test_case("Last-Year", "2022-12-31T23:00:00Z", "2023-12-31T23:00:00Z"; "last year")]
+    fn spans(input: &str, start: &str, end: &str) {
+        let input: DateInput = input.parse().unwrap();
+        assert_eq!(
+            input.span(at(NOW), &Berlin),
+            DateSpan {
+                start: at(start),
+                end: at(end)
+            }
+        );
+    }
+
+    #[test_case("4000000000h"; "hours")]
+    #[test_case("4000000000d"; "days")]
+    #[test_case("4000000000w"; "weeks")]
+    #[test_case("4000000000m"; "months")]
+    #[test_case("4000000000y"; "years")]
+    fn longer_ago_than_representable(input: &str) {
+        let input: DateInput = input.parse().unwrap();
+        assert_eq!(
+            input.span(at(NOW), &Berlin),
+            DateSpan {
+                start: DateTime::<Utc>::MIN_UTC,
+                end: DateTime::<Utc>::MIN_UTC + TimeDelta::milliseconds(1),
+            }
+        );
+    }
+
+    #[test_case("2024-13-01"; "bad day")]
+    #[test_case("7"; "no unit")]
+    #[test_case("7s"; "unknown unit")]
+    #[test_case("next-week"; "unknown period")]
+    fn invalid(input: &str) {
+        assert!(input.parse::<DateInput>().is_err());
+        assert!(serde_json::from_value::<DateInput>(serde_json::json!(input)).is_err());
+    }
+
+    #[test]
+    fn timezone_names() {
+        let tz: Timezone = "Europe/Berlin".parse().unwrap();
+        assert_eq!(tz, Timezone(Berlin));
+        assert_eq!(serde_json::to_value(tz).unwrap(), "Europe/Berlin");
+        assert!("Mars/Olympus_Mons".parse::<Timezone>().is_err());
+    }
+}
@@ -0,0 +1,607 @@
+//! A pinboard-style query language for bookmark searches.
+//!
+//! A query is a whitespace-separated list of terms, all of which a
+//! bookmark has to match:
+//!
+//! * `word` or `"exact phrase"` - full-text search, see [`FullTextQuery`].
+//! * `tag:name` - the bookmark is tagged with `name`; `tag:name/*` also
+//!   finds bookmarks with tags below `name` in the hierarchy, like
+//!   `name/sub`.
+//! * `site:example.com` - the bookmark's URL is on `example.com` or a subdomain.
+//! * `before:YYYY-MM-DD` / `after:YYYY-MM-DD` / `on:YYYY-MM-DD` - the
+//!   bookmark was created on or before/after, or on the given date;
+//!   see [`DateInput`] for the other ways to give a date, like `7d`.
+//!   `modified_before:`, `accessed_on:` and so on search the time the
+//!   bookmark was last modified or accessed.
+//! * `unread:true` / `shared:false` - the bookmark's unread / shared flag.
+//! * `link:not_found` - the latest check of the bookmark's URL found
+//!   the given [`LinkStatus`]; `link:broken` finds links that were
+//!   either not found or unreachable.
+//!
+//! A URL like `https://example.com/x` that isn't quoted is searched
+//! for as text, too.
+//!
+//! Any term can be negated by prefixing it with `-`; terms separated
+//! by `OR` match if either of them does, and parentheses group terms,
+//! e.g. `tag:rust (tag:async OR tag:tokio) -tag:old`.
+
+use std::str::FromStr;
+
+use super::{
+    BookmarkSearch, BookmarkSearchDateParams, BookmarkSearchDatetimeField,
+    BookmarkSearchDatetimeOrientation, DateInput, FullTextQuery, SiteName,
+};
+use crate::{LinkStatus, TagName};
+
+/// An error in a search query, as returned by [`parse_query`].
+///
+/// Each error holds the position (counted in characters from 0) in
+/// the query at which it occurred.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum QueryParseError {
+    /// A double quote was never closed.
+    #[error("unterminated quote starting at position {position}")]
+    UnterminatedQuote { position: usize },
+
+    /// A parenthesis was never closed.
+    #[error("unclosed parenthesis at position {position}")]
+    UnclosedParenthesis { position: usize },
+
+    /// A token showed up where no search term could start.
+    #[error("unexpected `{token}` at position {position}")]
+    Unexpected { token: String, position: usize },
+
+    /// A `-`, `OR` or `(` was not followed by a search term.
+    #[error("expected a search term after `{after}` at position {position}")]
+    MissingTerm { after: String, position: usize },
+
+    /// The key of a `key:value` term is not one we know.
+    #[error("unknown search key `{key}` at position {position} (put the term in double quotes to search for it as text)")]
+    UnknownKey { key: String, position: usize },
+
+    /// The value of a `key:value` term is empty or can not be interpreted.
+    #[error("invalid value {value:?} for `{key}:` at position {position}: {reason}")]
+    InvalidValue {
+        key: String,
+        value: String,
+        reason: String,
+        position: usize,
+    },
+}
+
+impl QueryParseError {
+    /// The position in the query (counted in characters from 0) that the error refers to.
+    pub fn position(&self) -> usize {
+        match self {
+            QueryParseError::UnterminatedQuote { position }
+            | QueryParseError::UnclosedParenthesis { position }
+            | QueryParseError::Unexpected { position, .. }
+            | QueryParseError::MissingTerm { position, .. }
+            | QueryParseError::UnknownKey { position, .. }
+            | QueryParseError::InvalidValue { position, .. } => *position,
+        }
+    }
+}
+
+/// Parses a search query into the criteria that bookmarks must all match.
+///
+/// See the [module documentation][self] for the syntax.
+pub fn parse_query(query: &str) -> Result<Vec<BookmarkSearch>, QueryParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(query)?,
+        next: 0,
+    };
+    let criteria = parser.conjunction()?;
+    if let Some(token) = parser.peek() {
+        // The only token that ends a conjunction early is a `)`:
+        return Err(QueryParseError::Unexpected {
+            token: ")".to_string(),
+            position: token.position,
+        });
+    }
+    Ok(criteria)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Open,
+    Close,
+    Not,
+    Or,
+    Term {
+        key: Option<String>,
+        value: String,
+        quoted: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, QueryParseError> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    // Reads a double-quoted string starting at `start`, returning its
+    // contents and the position after the closing quote:
+    let quoted = |start: usize| -> Result<(String, usize), QueryParseError> {
+        match chars[start + 1..].iter().position(|c| *c == '"') {
+            Some(len) => Ok((
+                chars[start + 1..start + 1 + len].iter().collect(),
+                start + len + 2,
+            )),
+            None => Err(QueryParseError::UnterminatedQuote { position: start }),
+        }
+    };
+    while i < chars.len() {
+        let position = i;
+        let kind = match chars[i] {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => {
+                i += 1;
+                TokenKind::Open
+            }
+            ')' => {
+                i += 1;
+                TokenKind::Close
+            }
+            '-' => {
+                i += 1;
+                TokenKind::Not
+            }
+            '"' => {
+                let (value, end) = quoted(i)?;
+                i = end;
+                TokenKind::Term {
+                    key: None,
+                    value,
+                    quoted: true,
+                }
+            }
+            _ => {
+                let len = chars[i..]
+                    .iter()
+                    .position(|c| c.is_whitespace() || matches!(c, '(' | ')' | '"'))
+                    .unwrap_or(chars.len() - i);
+                let word: String = chars[i..i + len].iter().collect();
+                i += len;
+                match word.split_once(':') {
+                    _ if word == "OR" => TokenKind::Or,
+                    // A key followed by `//` is the scheme of a pasted
+                    // URL, which gets searched for as text:
+                    Some((key, value))
+                        if !key.is_empty()
+                            && key.chars().all(|c| c.is_ascii_alphabetic() || c == '_')
+                            && !value.starts_with("//") =>
+                    {
+                        let (value, quoted) = if value.is_empty() && chars.get(i) == Some(&'"') {
+                            let (value, end) = quoted(i)?;
+                            i = end;
+                            (value, true)
+                        } else {
+                            (value.to_string(), false)
+                        };
+                        TokenKind::Term {
+                            key: Some(key.to_lowercase()),
+                            value,
+                            quoted,
+                        }
+                    }
+                    _ => TokenKind::Term {
+                        key: None,
+                        value: word,
+                        quoted: false,
+                    },
+                }
+            }
+        };
+        tokens.push(Token { kind, position });
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    next: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.next).cloned();
+        self.next += 1;
+        token
+    }
+
+    /// Checks that a search term follows the token at `position`.
+    fn expect_term(&self, after: &str, position: usize) -> Result<(), QueryParseError> {
+        match self.peek().map(|t| &t.kind) {
+            None | Some(TokenKind::Close) | Some(TokenKind::Or) => {
+                Err(QueryParseError::MissingTerm {
+                    after: after.to_string(),
+                    position,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Terms that all have to match, up to the end of the query or of
+    /// the current group.
+    fn conjunction(&mut self) -> Result<Vec<BookmarkSearch>, QueryParseError> {
+        let mut criteria = vec![];
+        let mut fulltext: Option<(usize, String)> = None;
+        while !matches!(self.peek().map(|t| &t.kind), None | Some(TokenKind::Close)) {
+            match self.disjunction()? {
+                // All the words and phrases in a group form one
+                // full-text query, so they get highlighted together:
+                BookmarkSearch::FullText { query } => match &mut fulltext {
+                    Some((_, text)) => {
+                        text.push(' ');
+                        text.push_str(&query.0);
+                    }
+                    None => {
+                        fulltext = Some((criteria.len(), query.0));
+                        criteria.push(BookmarkSearch::All { of: vec![] });
+                    }
+                },
+                criterion => criteria.push(criterion),
+            }
+        }
+        if let Some((index, text)) = fulltext {
+            criteria[index] = BookmarkSearch::FullText {
+                query: FullTextQuery(text),
+            };
+        }
+        Ok(criteria)
+    }
+
+    /// Terms separated by `OR`, of which at least one has to match.
+    fn disjunction(&mut self) -> Result<BookmarkSearch, QueryParseError> {
+        let mut of = vec![self.negation()?];
+        while let Some(Token {
+            kind: TokenKind::Or,
+            position,
+        }) = self.peek().cloned()
+        {
+            self.advance();
+            self.expect_term("OR", position)?;
+            of.push(self.negation()?);
+        }
+        if of.len() == 1 {
+            Ok(of.remove(0))
+        } else {
+            Ok(BookmarkSearch::Any { of })
+        }
+    }
+
+    /// A term, optionally negated with a `-` prefix.
+    fn negation(&mut self) -> Result<BookmarkSearch, QueryParseError> {
+        let Some(token) = self.advance() else {
+            unreachable!("callers check that a term follows");
+        };
+        match token.kind {
+            TokenKind::Not => {
+                self.expect_term("-", token.position)?;
+                Ok(BookmarkSearch::Not {
+                    criterion: Box::new(self.negation()?),
+                })
+            }
+            TokenKind::Open => {
+                self.expect_term("(", token.position)?;
+                let mut of = self.conjunction()?;
+                match self.advance() {
+                    Some(Token {
+                        kind: TokenKind::Close,
+                        ..
+                    }) => {}
+                    _ => {
+                        return Err(QueryParseError::UnclosedParenthesis {
+                            position: token.position,
+                        })
+                    }
+                }
+                if of.len() == 1 {
+                    Ok(of.remove(0))
+                } else {
+                    Ok(BookmarkSearch::All { of })
+                }
+            }
+            TokenKind::Close => Err(QueryParseError::Unexpected {
+                token: ")".to_string(),
+                position: token.position,
+            }),
+            TokenKind::Or => Err(QueryParseError::Unexpected {
+                token: "OR".to_string(),
+                position: token.position,
+            }),
+            TokenKind::Term { key, value, quoted } => term(key, value, quoted, token.position),
+        }
+    }
+}
+
+/// Translates a single `key:value`, word or phrase into a search criterion.
+fn term(
+    key: Option<String>,
+    value: String,
+    quoted: bool,
+    position: usize,
+) -> Result<BookmarkSearch, QueryParseError> {
+    let Some(key) = key else {
+        let query = if quoted {
+            format!(r#""{value}""#)
+        } else {
+            value
+        };
+        return Ok(BookmarkSearch::FullText {
+            query: FullTextQuery(query),
+        });
+    };
+    let invalid = |reason: &str| QueryParseError::InvalidValue {
+        key: key.clone(),
+        value: value.clone(),
+        reason: reason.to_string(),
+        position,
+    };
+    let known = ["tag", "site", "unread", "shared", "link"];
+    let date_search = date_search(&key);
+    if !known.contains(&key.as_str()) && date_search.is_none() {
+        return Err(QueryParseError::UnknownKey { key, position });
+    }
+    if value.trim().is_empty() {
+        return Err(invalid("value must not be empty"));
+    }
+    let flag = || match value.to_lowercase().as_str() {
+        "true" | "yes" => Ok(true),
+        "false" | "no" => Ok(false),
+        _ => Err(invalid("expected `true` or `false`")),
+    };
+    if let Some((field, orientation)) = date_search {
+        let date = DateInput::from_str(&value).map_err(|e| invalid(&e.to_string()))?;
+        return Ok(BookmarkSearch::ByDate {
+            date: BookmarkSearchDateParams::new(field, orientation, date),
+        });
+    }
+    Ok(match key.as_str() {
+        "tag" => match value.strip_suffix("/*") {
+            Some(prefix) if !quoted && !prefix.is_empty() => BookmarkSearch::TagPrefix {
+                tag: TagName(prefix.to_string()),
+            },
+            _ => BookmarkSearch::TagByName {
+                tag: TagName(value.clone()),
+            },
+        },
+        "site" => BookmarkSearch::Site {
+            site: SiteName::from(value.as_str()),
+        },
+        "unread" => BookmarkSearch::Unread { unread: flag()? },
+        "shared" => BookmarkSearch::Shared { shared: flag()? },
+        "link" if value.eq_ignore_ascii_case("broken") => BookmarkSearch::Any {
+            of: LinkStatus::BROKEN
+                .into_iter()
+                .map(|link_status| BookmarkSearch::LinkStatus { link_status })
+                .collect(),
+        },
+        "link" => BookmarkSearch::LinkStatus {
+            link_status: value.to_lowercase().parse().map_err(|_| {
+                invalid(
+                    "expected `ok`, `redirected`, `not_found`, `failed`, `unreachable` or `broken`",
+                )
+            })?,
+        },
+        _ => unreachable!("checked against the known keys above"),
+    })
+}
+
+/// The timestamp and comparison that a date key like `before` or
+/// `modified_after` stands for.
+fn date_search(
+    key: &str,
+) -> Option<(
+    BookmarkSearchDatetimeField,
+    BookmarkSearchDatetimeOrientation,
+)> {
+    let (field, orientation) = match key.split_once('_') {
+        Some(("modified", orientation)) => (BookmarkSearchDatetimeField::Modified, orientation),
+        Some(("accessed", orientation)) => (BookmarkSearchDatetimeField::Accessed, orientation),
+        Some(_) => return None,
+        None => (BookmarkSearchDatetimeField::Created, key),
+    };
+    let orientation = match orientation {
+        "before" => BookmarkSearchDatetimeOrientation::Before,
+        "after" => BookmarkSearchDatetimeOrientation::After,
+        "on" => BookmarkSearchDatetimeOrientation::On,
+        _ => return None,
+    };
+    Some((field, orientation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::created_before_from_datetime;
+    use test_case::test_case;
+
+    fn tag(name: &str) -> BookmarkSearch {
+        BookmarkSearch::TagByName {
+            tag: TagName(name.to_string()),
+        }
+    }
+
+    fn text(query: &str) -> BookmarkSearch {
+        BookmarkSearch::FullText {
+            query: FullTextQuery::from(query),
+        }
+    }
+
+    fn not(criterion: BookmarkSearch) -> BookmarkSearch {
+        BookmarkSearch::Not {
+            criterion: Box::new(criterion),
+        }
+    }
+
+    #[test]
+    fn full_example() {
+        assert_eq!(
+            parse_query(
+                r#"tag:rust -tag:old "exact phrase" site:GitHub.com before:2024-01-01 unread:true"#
+            ),
+            Ok(vec![
+                tag("rust"),
+                not(tag("old")),
+                text(r#""exact phrase""#),
+                BookmarkSearch::Site {
+                    site: SiteName("github.com".to_string())
+                },
+                created_before_from_datetime("2024-01-01".parse().unwrap()),
+                BookmarkSearch::Unread { unread: true },
+            ])
+        );
+    }
+
+    #[test]
+    fn words_are_combined() {
+        assert_eq!(
+            parse_query(r#"hello tag:x "big world" foo*"#),
+            Ok(vec![text(r#"hello "big world" foo*"#), tag("x")])
+        );
+    }
+
+    #[test]
+    fn urls_are_text() {
+        assert_eq!(
+            parse_query("https://example.com/x tag:x -http://example.org"),
+            Ok(vec![
+                text("https://example.com/x"),
+                tag("x"),
+                not(text("http://example.org")),
+            ])
+        );
+    }
+
+    #[test]
+    fn tag_hierarchies() {
+        assert_eq!(
+            parse_query(r#"tag:lang/rust/* tag:lang/rust tag:"lang/*" tag:/*"#),
+            Ok(vec![
+                BookmarkSearch::TagPrefix {
+                    tag: TagName("lang/rust".to_string())
+                },
+                tag("lang/rust"),
+                tag("lang/*"),
+                tag("/*"),
+            ])
+        );
+    }
+
+    #[test]
+    fn grouping() {
+        assert_eq!(
+            parse_query("tag:a (tag:b OR -(tag:c shared:no)) OR tag:d"),
+            Ok(vec![
+                tag("a"),
+                BookmarkSearch::Any {
+                    of: vec![
+                        BookmarkSearch::Any {
+                            of: vec![
+                                tag("b"),
+                                not(BookmarkSearch::All {
+                                    of: vec![tag("c"), BookmarkSearch::Shared { shared: false }]
+                                })
+                            ]
+                        },
+                        tag("d")
+                    ]
+                }
+            ])
+        );
+        assert_eq!(parse_query(r#"tag:"a""#), Ok(vec![tag("a")]));
+        assert_eq!(parse_query(""), Ok(vec![]));
+    }
+
+    #[test_case(r#"foo "bar"#, QueryParseError::UnterminatedQuote { position: 4 }; "unterminated quote")]
+    #[test_case("(tag:a (tag:b)", QueryParseError::UnclosedParenthesis { position: 0 }; "unclosed paren")]
+    #[test_case("tag:a)", QueryParseError::Unexpected { token: ")".to_string(), position: 5 }; "stray paren")]
+    #[test_case("OR tag:a", QueryParseError::Unexpected { token: "OR".to_string(), position: 0 }; "leading or")]
+    #[test_case("tag:a OR", QueryParseError::MissingTerm { after: "OR".to_string(), position: 6 }; "trailing or")]
+    #[test_case("foo -", QueryParseError::MissingTerm { after: "-".to_string(), position: 4 }; "trailing not")]
+    #[test_case("tga:rust", QueryParseError::UnknownKey { key: "tga".to_string(), position: 0 }; "unknown key")]
+    #[test_case("tag:", QueryParseError::InvalidValue { key: "tag".to_string(), value: "".to_string(), reason: "value must not be empty".to_string(), position: 0 }; "empty value")]
+    #[test_case("x unread:maybe", QueryParseError::InvalidValue { key: "unread".to_string(), value: "maybe".to_string(), reason: "expected `true` or `false`".to_string(), position: 2 }; "bad flag")]
+    fn errors(query: &str, error: QueryParseError) {
+        assert_eq!(parse_query(query), Err(error));
+    }
+
+    #[test]
+    fn link_status() {
+        assert_eq!(
+            parse_query("link:redirected link:broken"),
+            Ok(vec![
+                BookmarkSearch::LinkStatus {
+                    link_status: LinkStatus::Redirected
+                },
+                BookmarkSearch::Any {
+                    of: vec![
+                        BookmarkSearch::LinkStatus {
+                            link_status: LinkStatus::NotFound
+                        },
+                        BookmarkSearch::LinkStatus {
+                            link_status: LinkStatus::Unreachable
+                        },
+                    ]
+                },
+            ])
+        );
+        assert!(matches!(
+            parse_query("link:gone"),
+            Err(QueryParseError::InvalidValue { key, .. }) if key == "link"
+        ));
+    }
+
+    #[test]
+    fn dates() {
+        let date = |field, orientation, date: &str| BookmarkSearch::ByDate {
+            date: BookmarkSearchDateParams::new(field, orientation, date.parse().unwrap()),
+        };
+        assert_eq!(
+            parse_query("on:yesterday modified_after:7d accessed_before:2024-01-01T12:00:00Z"),
+            Ok(vec![
+                date(
+                    BookmarkSearchDatetimeField::Created,
+                    BookmarkSearchDatetimeOrientation::On,
+                    "yesterday"
+                ),
+                date(
+                    BookmarkSearchDatetimeField::Modified,
+                    BookmarkSearchDatetimeOrientation::After,
+                    "7d"
+                ),
+                date(
+                    BookmarkSearchDatetimeField::Accessed,
+                    BookmarkSearchDatetimeOrientation::Before,
+                    "2024-01-01T12:00:00Z"
+                ),
+            ])
+        );
+        assert!(matches!(
+            parse_query("created_on:today"),
+            Err(QueryParseError::UnknownKey { key, .. }) if key == "created_on"
+        ));
+    }
+
+    #[test]
+    fn bad_date() {
+        let error = parse_query("after:2024-13-01").unwrap_err();
+        assert_eq!(error.position(), 0);
+        assert!(matches!(error, QueryParseError::InvalidValue { key, .. } if key == "after"));
+    }
+}
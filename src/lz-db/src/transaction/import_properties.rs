@@ -12,6 +12,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, PartialEq, Hash, Eq, Debug, Clone)]
 pub enum ImportableSystem {
     Linkding,
+
+    /// The `bookmarks.html` format that browsers, Pinboard and
+    /// Linkding can all export.
+    Netscape,
+
+    Pinboard,
 }
 
 /// Properties imported from other systems.
@@ -23,3 +29,16 @@ pub enum ImportableSystem {
 pub struct ImportProperties {
     pub by_system: HashMap<ImportableSystem, serde_json::Value>,
 }
+
+/// What to do about duplicate bookmarks.
+///
+/// The user can choose to either overwrite or duplicate each
+/// already-existing bookmark for a URL.
+#[derive(Clone, PartialEq, Copy, Eq, Hash, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "camel_case")]
+pub enum DuplicateBehavior {
+    Overwrite,
+
+    #[default]
+    Skip,
+}
@@ -0,0 +1,213 @@
+//! # Link health
+//!
+//! Pages move and disappear. A [`LinkCheck`] records what happened
+//! the last time that a stored URL was retrieved: Whether it still
+//! works, redirects somewhere else for good, or is gone.
+//!
+//! Stored URLs don't belong to any user, so checks of all URLs get
+//! listed and recorded on the [`Connection`] rather than in a user's
+//! transaction. Users find their broken bookmarks with the
+//! [`BookmarkSearch::LinkStatus`](crate::BookmarkSearch::LinkStatus)
+//! criterion.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::*;
+use sqlx::types::Text;
+use url::Url;
+use utoipa::ToSchema;
+
+use crate::{Connection, StoredUrl, StoredUrlId, Transaction, TransactionMode};
+
+/// What checking a link found.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum LinkStatus {
+    /// The link works.
+    Ok,
+
+    /// The link permanently redirects to another URL.
+    Redirected,
+
+    /// The server says that the page doesn't exist (anymore).
+    NotFound,
+
+    /// The server responded with another error, or refused to be asked.
+    Failed,
+
+    /// The server could not be reached: Its name doesn't resolve, it
+    /// refused the connection, or it didn't respond in time.
+    Unreachable,
+}
+
+impl LinkStatus {
+    /// All the statuses, in order from best to worst.
+    pub const ALL: [LinkStatus; 5] = [
+        LinkStatus::Ok,
+        LinkStatus::Redirected,
+        LinkStatus::NotFound,
+        LinkStatus::Failed,
+        LinkStatus::Unreachable,
+    ];
+
+    /// The statuses of links that are broken for good, most likely.
+    pub const BROKEN: [LinkStatus; 2] = [LinkStatus::NotFound, LinkStatus::Unreachable];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinkStatus::Ok => "ok",
+            LinkStatus::Redirected => "redirected",
+            LinkStatus::NotFound => "not_found",
+            LinkStatus::Failed => "failed",
+            LinkStatus::Unreachable => "unreachable",
+        }
+    }
+}
+
+impl fmt::Display for LinkStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An error parsing a [`LinkStatus`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("unknown link status {0:?}")]
+pub struct UnknownLinkStatus(String);
+
+impl FromStr for LinkStatus {
+    type Err = UnknownLinkStatus;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LinkStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == s)
+            .ok_or_else(|| UnknownLinkStatus(s.to_string()))
+    }
+}
+
+/// The result of checking a stored URL.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, ToSchema)]
+pub struct LinkCheck {
+    /// The checked URL
+    pub url_id: StoredUrlId,
+
+    /// When the URL was checked
+    pub checked_at: DateTime<Utc>,
+
+    /// What the check found
+    pub status: LinkStatus,
+
+    /// The HTTP status of the last response, if there was one
+    pub http_status: Option<u16>,
+
+    /// Where a permanent redirect led, after following all redirects
+    pub redirected_to: Option<Url>,
+
+    /// What went wrong, if the URL couldn't be retrieved
+    pub error: Option<String>,
+}
+
+/// How link checks are stored in the database.
+#[derive(FromRow)]
+struct LinkCheckRow {
+    url_id: StoredUrlId,
+    checked_at: DateTime<Utc>,
+    status: LinkStatus,
+    http_status: Option<u16>,
+    redirected_to: Option<Text<Url>>,
+    error: Option<String>,
+}
+
+impl From<LinkCheckRow> for LinkCheck {
+    fn from(row: LinkCheckRow) -> Self {
+        LinkCheck {
+            url_id: row.url_id,
+            checked_at: row.checked_at,
+            status: row.status,
+            http_status: row.http_status,
+            redirected_to: row.redirected_to.map(|Text(url)| url),
+            error: row.error,
+        }
+    }
+}
+
+/// # Checking all stored URLs
+impl Connection {
+    /// List stored URLs in the order of their IDs, starting after
+    /// `after` (if given).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_urls(
+        &self,
+        after: Option<StoredUrlId>,
+        limit: u16,
+    ) -> Result<Vec<StoredUrl<StoredUrlId>>, sqlx::Error> {
+        let rows: Vec<(StoredUrlId, Text<Url>)> = sqlx::query_as(
+            r#"
+              SELECT url_id, link FROM urls
+              WHERE url_id > COALESCE(?, 0)
+              ORDER BY url_id
+              LIMIT ?
+            "#,
+        )
+        .bind(after)
+        .bind(limit)
+        .fetch_all(self.ro.as_ref().unwrap_or(&self.rw))
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(id, Text(link))| StoredUrl { id, link })
+            .collect())
+    }
+
+    /// Record the result of checking a URL, replacing that of any
+    /// earlier check.
+    #[tracing::instrument(skip(self, check), fields(url_id=%check.url_id, status=%check.status))]
+    pub async fn record_link_check(&self, check: &LinkCheck) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+              INSERT INTO link_checks (
+                url_id, checked_at, status, http_status, redirected_to, error
+              ) VALUES (?, ?, ?, ?, ?, ?)
+              ON CONFLICT (url_id) DO UPDATE SET
+                checked_at = excluded.checked_at,
+                status = excluded.status,
+                http_status = excluded.http_status,
+                redirected_to = excluded.redirected_to,
+                error = excluded.error
+            "#,
+        )
+        .bind(check.url_id)
+        .bind(check.checked_at)
+        .bind(check.status)
+        .bind(check.http_status)
+        .bind(check.redirected_to.as_ref().map(Text))
+        .bind(&check.error)
+        .execute(&self.rw)
+        .await?;
+        Ok(())
+    }
+}
+
+/// # Reading link checks
+impl<M: TransactionMode> Transaction<M> {
+    /// Return the result of the latest check of a stored URL, if it
+    /// was checked.
+    #[tracing::instrument(skip(self))]
+    pub async fn link_check_for_url(
+        &mut self,
+        url_id: StoredUrlId,
+    ) -> Result<Option<LinkCheck>, sqlx::Error> {
+        Ok(
+            sqlx::query_as::<_, LinkCheckRow>(r#"SELECT * FROM link_checks WHERE url_id = ?"#)
+                .bind(url_id)
+                .fetch_optional(&mut *self.txn)
+                .await?
+                .map(LinkCheck::from),
+        )
+    }
+}
@@ -0,0 +1,280 @@
+//! # Merging bookmarks
+//!
+//! When a user ends up with two bookmarks of the same page (through
+//! imports, or links that only differ in details), they can merge one
+//! into the other instead of deleting it and losing what it had.
+
+use sqlx::types::Json;
+use sqlx::Acquire as _;
+
+use crate::{BookmarkId, ExistingBookmark, IdType, ReadWrite, Transaction, MERGED_FROM_CONTEXT};
+
+/// An error that can occur when merging bookmarks.
+#[derive(thiserror::Error, Debug)]
+pub enum MergeError {
+    /// A bookmark can't be merged into itself.
+    #[error("can not merge a bookmark into itself")]
+    SameBookmark,
+
+    /// Any error raised by sqlx. If either bookmark doesn't exist (or
+    /// doesn't belong to the user), this is
+    /// [`RowNotFound`](sqlx::Error::RowNotFound).
+    #[error("sql datastore error")]
+    Sqlx(#[from] sqlx::Error),
+}
+
+/// # Merging bookmarks
+impl Transaction<ReadWrite> {
+    /// Merge the bookmark `absorb` into the bookmark `keep`, and
+    /// delete `absorb`. Both must belong to the user.
+    ///
+    /// The kept bookmark gets:
+    /// - the tags and associated links of both bookmarks, and the
+    ///   absorbed bookmark's URL as an associated link in the
+    ///   [`MERGED_FROM_CONTEXT`] (if it's a different one).
+    /// - both bookmarks' notes, one after the other.
+    /// - the earlier of the two `created_at` times, and the later
+    ///   `accessed_at` time.
+    /// - the import properties of both; where both were imported from
+    ///   the same system, the kept bookmark's properties win.
+    /// - the absorbed bookmark's description and website title and
+    ///   description, if it has them and the kept bookmark doesn't.
+    ///
+    /// Either all of this happens, or (if an error is returned)
+    /// nothing does.
+    #[tracing::instrument(skip(self))]
+    pub async fn merge_bookmarks(
+        &mut self,
+        keep: BookmarkId,
+        absorb: BookmarkId,
+    ) -> Result<ExistingBookmark, MergeError> {
+        if keep == absorb {
+            return Err(MergeError::SameBookmark);
+        }
+        let mut kept = self.get_bookmark_by_id(keep.id()).await?;
+        let absorbed = self.get_bookmark_by_id(absorb.id()).await?;
+
+        let mut txn = self.txn.begin().await?;
+        sqlx::query(
+            r#"
+              INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag_id)
+              SELECT ?, tag_id FROM bookmark_tags WHERE bookmark_id = ?
+            "#,
+        )
+        .bind(keep)
+        .bind(absorb)
+        .execute(&mut *txn)
+        .await?;
+        sqlx::query(
+            r#"
+              INSERT OR IGNORE INTO bookmark_associations (bookmark_id, url_id, context)
+              SELECT ?, url_id, context FROM bookmark_associations
+              WHERE bookmark_id = ?
+              UNION ALL
+              SELECT ?, url_id, ? FROM bookmarks
+              WHERE bookmark_id = ? AND url_id != (SELECT url_id FROM bookmarks WHERE bookmark_id = ?)
+            "#,
+        )
+        .bind(keep)
+        .bind(absorb)
+        .bind(keep)
+        .bind(MERGED_FROM_CONTEXT)
+        .bind(absorb)
+        .bind(keep)
+        .execute(&mut *txn)
+        .await?;
+
+        kept.notes = match (kept.notes.take(), absorbed.notes) {
+            (Some(ours), Some(theirs)) if ours != theirs => Some(format!("{ours}\n\n{theirs}")),
+            (ours, theirs) => ours.or(theirs),
+        };
+        kept.created_at = kept.created_at.min(absorbed.created_at);
+        kept.accessed_at = kept.accessed_at.max(absorbed.accessed_at);
+        kept.description = kept.description.or(absorbed.description);
+        kept.website_title = kept.website_title.or(absorbed.website_title);
+        kept.website_description = kept.website_description.or(absorbed.website_description);
+        kept.import_properties = match (kept.import_properties.take(), absorbed.import_properties) {
+            (Some(Json(mut ours)), Some(Json(theirs))) => {
+                for (system, properties) in theirs.by_system {
+                    ours.by_system.entry(system).or_insert(properties);
+                }
+                Some(Json(ours))
+            }
+            (ours, theirs) => ours.or(theirs),
+        };
+        sqlx::query(
+            r#"
+              UPDATE bookmarks
+              SET
+                modified_at = datetime(),
+                created_at = ?,
+                accessed_at = ?,
+                description = ?,
+                website_title = ?,
+                website_description = ?,
+                notes = ?,
+                import_properties = ?
+              WHERE bookmark_id = ?
+            "#,
+        )
+        .bind(kept.created_at)
+        .bind(kept.accessed_at)
+        .bind(&kept.description)
+        .bind(&kept.website_title)
+        .bind(&kept.website_description)
+        .bind(&kept.notes)
+        .bind(&kept.import_properties)
+        .bind(keep)
+        .execute(&mut *txn)
+        .await?;
+        sqlx::query(r#"DELETE FROM bookmarks WHERE bookmark_id = ?"#)
+            .bind(absorb)
+            .execute(&mut *txn)
+            .await?;
+        txn.commit().await?;
+
+        Ok(self.get_bookmark_by_id(keep.id()).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::{TimeZone as _, Utc};
+    use sqlx::types::Json;
+    use test_context::test_context;
+    use testresult::TestResult;
+    use url::Url;
+
+    use super::*;
+    use crate::{Bookmark, Context, ImportProperties, ImportableSystem, NoId};
+
+    fn bookmark(link: &str, created: i64) -> Bookmark<NoId, NoId> {
+        Bookmark {
+            id: NoId,
+            user_id: NoId,
+            created_at: Utc.timestamp_opt(created, 0).unwrap(),
+            modified_at: None,
+            accessed_at: None,
+            url: Url::parse(link).unwrap(),
+            title: link.to_string(),
+            description: None,
+            website_title: None,
+            website_description: None,
+            notes: None,
+            import_properties: None,
+            shared: true,
+            unread: true,
+        }
+    }
+
+    fn imported(system: ImportableSystem, value: &str) -> Option<Json<ImportProperties>> {
+        Some(Json(ImportProperties {
+            by_system: HashMap::from([(system, serde_json::json!(value))]),
+        }))
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn merges_everything(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let keep = txn
+            .add_bookmark(Bookmark {
+                notes: Some("ours".to_string()),
+                import_properties: imported(ImportableSystem::Pinboard, "ours"),
+                ..bookmark("https://example.com/kept", 2000)
+            })
+            .await?;
+        let absorb = txn
+            .add_bookmark(Bookmark {
+                notes: Some("theirs".to_string()),
+                description: Some("a description".to_string()),
+                import_properties: imported(ImportableSystem::Netscape, "theirs"),
+                ..bookmark("https://example.com/absorbed", 1000)
+            })
+            .await?;
+        let tags = txn.ensure_tags(["shared", "kept", "absorbed"]).await?;
+        txn.set_bookmark_tags(keep.id, [tags[0].id, tags[1].id])
+            .await?;
+        txn.set_bookmark_tags(absorb.id, [tags[0].id, tags[2].id])
+            .await?;
+        let archived = txn
+            .ensure_url(&Url::parse("https://archive.example/absorbed")?)
+            .await?;
+        txn.associate_bookmark_link(&absorb.id, &archived, Some("archive"))
+            .await?;
+
+        let merged = txn.merge_bookmarks(keep.id, absorb.id).await?;
+        assert_eq!(merged.id, keep.id);
+        assert_eq!(merged.url, keep.url);
+        assert_eq!(merged.created_at, absorb.created_at);
+        assert_eq!(merged.notes.as_deref(), Some("ours\n\ntheirs"));
+        assert_eq!(merged.description.as_deref(), Some("a description"));
+        let Json(props) = merged.import_properties.unwrap();
+        assert_eq!(
+            props.by_system,
+            HashMap::from([
+                (ImportableSystem::Pinboard, serde_json::json!("ours")),
+                (ImportableSystem::Netscape, serde_json::json!("theirs")),
+            ])
+        );
+
+        let tag_names: Vec<_> = txn
+            .get_bookmark_tags(keep.id)
+            .await?
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(tag_names, vec!["absorbed", "kept", "shared"]);
+        let mut associations: Vec<_> = txn
+            .associated_links_on_bookmarks([keep.id])
+            .await?
+            .remove(&keep.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|a| (a.link.to_string(), a.context))
+            .collect();
+        associations.sort();
+        assert_eq!(
+            associations,
+            vec![
+                (
+                    "https://archive.example/absorbed".to_string(),
+                    Some("archive".to_string())
+                ),
+                (
+                    "https://example.com/absorbed".to_string(),
+                    Some(MERGED_FROM_CONTEXT.to_string())
+                ),
+            ]
+        );
+        assert!(txn.get_bookmark_by_id(absorb.id.id()).await.is_err());
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn refuses_bad_merges(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let ours = txn
+            .add_bookmark(bookmark("https://example.com/", 0))
+            .await?;
+        assert!(matches!(
+            txn.merge_bookmarks(ours.id, ours.id).await,
+            Err(MergeError::SameBookmark)
+        ));
+        txn.commit().await?;
+
+        let mut txn = ctx.begin_for_user("someone else").await?;
+        let theirs = txn
+            .add_bookmark(bookmark("https://example.com/", 0))
+            .await?;
+        assert!(matches!(
+            txn.merge_bookmarks(theirs.id, ours.id).await,
+            Err(MergeError::Sqlx(sqlx::Error::RowNotFound))
+        ));
+        assert!(txn.get_bookmark_by_id(theirs.id.id()).await.is_ok());
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+//! Orders and cursors for listing bookmarks.
+//!
+//! Listings are paginated with keysets: Each order sorts bookmarks by
+//! a key (like their creation time) and then by their ID, so every
+//! bookmark has a distinct position in the listing. A
+//! [`BookmarkCursor`] records one such position, and the next page
+//! starts at it, no matter how many bookmarks share its key.
+
+use serde::{Deserialize, Serialize};
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{SqliteTypeInfo, SqliteValueRef};
+use sqlx::{QueryBuilder, Sqlite, TypeInfo as _, ValueRef as _};
+use utoipa::ToSchema;
+
+use crate::BookmarkId;
+
+/// The orders that bookmarks can be listed in.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BookmarkOrder {
+    /// Newest bookmarks first.
+    #[default]
+    Created,
+
+    /// Most recently modified bookmarks first; bookmarks that were
+    /// never modified count as modified when they were created.
+    Modified,
+
+    /// Most recently accessed bookmarks first; bookmarks that were
+    /// never accessed come last.
+    Accessed,
+
+    /// Alphabetically by title, ignoring case.
+    Title,
+
+    /// Best matches of the full-text search first. Listings without a
+    /// full-text search are ordered by [`Created`](Self::Created).
+    Relevance,
+}
+
+impl BookmarkOrder {
+    /// The SQL expression that bookmarks are sorted by, before their ID.
+    ///
+    /// Each of these has a supporting index (or, for relevance, the
+    /// full-text index), so the listing query can seek to a cursor.
+    /// Modification and access times are compared as seconds since the
+    /// epoch, since `modified_at` isn't stored in the same format as the
+    /// other timestamps.
+    pub(crate) fn key_expression(&self) -> &'static str {
+        match self {
+            BookmarkOrder::Created => "bookmarks.created_at",
+            BookmarkOrder::Modified => {
+                "unixepoch(COALESCE(bookmarks.modified_at, bookmarks.created_at), 'subsec')"
+            }
+            BookmarkOrder::Accessed => {
+                "COALESCE(unixepoch(bookmarks.accessed_at, 'subsec'), -1e308)"
+            }
+            BookmarkOrder::Title => "bookmarks.title COLLATE NOCASE",
+            BookmarkOrder::Relevance => "relevance.rank",
+        }
+    }
+
+    /// Whether the best bookmarks have the lowest keys.
+    pub(crate) fn ascending(&self) -> bool {
+        matches!(self, BookmarkOrder::Title | BookmarkOrder::Relevance)
+    }
+
+    /// Push the `ORDER BY` clause for this order.
+    pub(crate) fn push_order_by(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        let direction = if self.ascending() { "ASC" } else { "DESC" };
+        qb.push(format!(
+            " ORDER BY {key} {direction}, bookmarks.bookmark_id {direction}",
+            key = self.key_expression()
+        ));
+    }
+}
+
+/// The value of a bookmark's sort key, as SQLite returns it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SortKey {
+    Text(String),
+
+    /// Numbers are kept as text in cursors, since JSON parsing can
+    /// be off by a bit, and then the cursor's own bookmark would
+    /// miss the page that it starts.
+    Number(#[serde(with = "exact_float")] f64),
+}
+
+mod exact_float {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl sqlx::Type<Sqlite> for SortKey {
+    fn type_info() -> SqliteTypeInfo {
+        <String as sqlx::Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(_ty: &SqliteTypeInfo) -> bool {
+        true
+    }
+}
+
+impl<'r> sqlx::Decode<'r, Sqlite> for SortKey {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        if matches!(value.type_info().name(), "REAL" | "INTEGER") {
+            Ok(SortKey::Number(<f64 as sqlx::Decode<Sqlite>>::decode(
+                value,
+            )?))
+        } else {
+            Ok(SortKey::Text(<String as sqlx::Decode<Sqlite>>::decode(
+                value,
+            )?))
+        }
+    }
+}
+
+/// The position of a bookmark in a listing.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct Position {
+    pub(crate) order: BookmarkOrder,
+    pub(crate) key: SortKey,
+    pub(crate) id: BookmarkId,
+}
+
+impl Position {
+    /// Push a condition that holds for the bookmark at this position
+    /// and all that come after it.
+    pub(crate) fn push_from_here(&self, qb: &mut QueryBuilder<'_, Sqlite>) {
+        qb.push(format!(
+            "({}, bookmarks.bookmark_id) {} (",
+            self.order.key_expression(),
+            if self.order.ascending() { ">=" } else { "<=" }
+        ));
+        match &self.key {
+            SortKey::Text(key) => qb.push_bind(key.clone()),
+            SortKey::Number(key) => qb.push_bind(*key),
+        };
+        qb.push(", ");
+        qb.push_bind(self.id);
+        qb.push(")");
+    }
+}
+
+/// A position in a listing of bookmarks.
+///
+/// Cursors are opaque: Each page of a listing comes with the cursor
+/// of the next page's first bookmark, and passing it back lists that
+/// page. A cursor only works with the order that it came from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, ToSchema)]
+#[serde(transparent)]
+pub struct BookmarkCursor(String);
+
+impl BookmarkCursor {
+    pub(crate) fn new(position: &Position) -> Self {
+        let json = serde_json::to_vec(position).expect("positions serialize");
+        BookmarkCursor(hex::encode(json))
+    }
+
+    pub(crate) fn position(&self) -> Result<Position, ListError> {
+        let json = hex::decode(&self.0).map_err(|_| ListError::InvalidCursor)?;
+        serde_json::from_slice(&json).map_err(|_| ListError::InvalidCursor)
+    }
+}
+
+impl From<String> for BookmarkCursor {
+    fn from(value: String) -> Self {
+        BookmarkCursor(value)
+    }
+}
+
+impl AsRef<str> for BookmarkCursor {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A bookmark in a listing, with its position there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListedBookmark {
+    pub bookmark: crate::ExistingBookmark,
+
+    /// Passing this cursor lists bookmarks starting with this one.
+    pub cursor: BookmarkCursor,
+}
+
+/// An error listing bookmarks.
+#[derive(thiserror::Error, Debug)]
+pub enum ListError {
+    /// The cursor wasn't made by lz, or not for the order that was
+    /// asked for.
+    #[error("invalid cursor for this listing")]
+    InvalidCursor,
+
+    /// Any error raised by sqlx.
+    #[error("sql datastore error")]
+    Sqlx(#[from] sqlx::Error),
+}
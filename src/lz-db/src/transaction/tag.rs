@@ -38,6 +38,9 @@ impl IdType<TagId> for TagId {
 
 /// A named tag, possibly assigned to multiple bookmarks.
 ///
+/// Each user has their own tags: Other users' tags, even ones with
+/// the same name, are different tags.
+///
 /// See the section in [Transaction][Transaction#working-with-tags]
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Clone, Hash, Debug, FromRow, ToSchema, ToResponse,
@@ -48,7 +51,6 @@ impl IdType<TagId> for TagId {
 pub struct Tag<ID: IdType<TagId>> {
     /// Database identifier of the tag.
     #[sqlx(rename = "tag_id")]
-    #[serde(skip_deserializing)]
     pub id: ID,
 
     /// Name of the tag.
@@ -96,7 +98,10 @@ impl From<&str> for TagName {
 
 /// # Reading/finding/listing [`Tag`]s
 impl<M: TransactionMode> Transaction<M> {
-    /// Return all existing tags matching the given names.
+    /// Return all of the user's existing tags matching the given names.
+    ///
+    /// A name that is an [alias](crate::TagAlias) matches the tag that
+    /// the alias stands for, instead of any tag with that name.
     ///
     /// If a tag with a given name doesn't exist, it will be missing
     /// in the returned set. The method [`Transaction::ensure_tags`]
@@ -112,6 +117,9 @@ impl<M: TransactionMode> Transaction<M> {
         tags: T,
     ) -> Result<Vec<Tag<TagId>>, sqlx::Error> {
         let slug_iter = tags.into_iter().map(|t| normalize_tag(t));
+        if slug_iter.clone().next().is_none() {
+            return Ok(vec![]);
+        }
         // Hyper-yikes: sqlx with sqlite does not support WHERE..IN
         // query value interpolation
         // (https://github.com/launchbadge/sqlx/blob/main/FAQ.md#how-can-i-do-a-select--where-foo-in--query).
@@ -127,13 +135,24 @@ impl<M: TransactionMode> Transaction<M> {
             .collect::<Vec<&str>>()
             .join(", ");
         let sql = format!(
-            r#"SELECT * FROM tags WHERE slug IN ({})"#,
-            slug_placeholders
+            r#"
+              SELECT tags.* FROM tag_aliases JOIN tags USING (tag_id)
+              WHERE tag_aliases.user_id = ? AND tag_aliases.slug IN ({slug_placeholders})
+              UNION
+              SELECT * FROM tags
+              WHERE user_id = ? AND slug IN ({slug_placeholders})
+                AND slug NOT IN (SELECT slug FROM tag_aliases WHERE user_id = ?)
+            "#
         );
-        let mut existing_query = sqlx::query_as(&sql);
+        let mut existing_query = sqlx::query_as(&sql).bind(self.user().id);
+        for slug in slug_iter.clone() {
+            existing_query = existing_query.bind(slug);
+        }
+        existing_query = existing_query.bind(self.user().id);
         for slug in slug_iter {
             existing_query = existing_query.bind(slug);
         }
+        existing_query = existing_query.bind(self.user().id);
         let existing_tags: Vec<Tag<TagId>> = existing_query.fetch_all(&mut *self.txn).await?;
 
         Ok(existing_tags)
@@ -142,11 +161,15 @@ impl<M: TransactionMode> Transaction<M> {
 
 /// Creating sets of [`Tag`]s.
 impl Transaction<ReadWrite> {
-    /// Ensure all the tags with the given name exist and return them.
+    /// Ensure all the user's tags with the given name exist and return them.
     ///
     /// This method is the ad-hoc-creating mirror to
     /// [`Transaction::get_tags_with_names`]. Use `ensure_tags` to
     /// ensure all the tags with the given name exist in the database.
+    ///
+    /// A tag that was created when it would have gotten a different
+    /// slug (e.g., a hierarchical tag from before `/` was kept in
+    /// slugs) gets its slug updated instead of a duplicate.
     #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
     pub async fn ensure_tags<
         T: std::fmt::Debug + IntoIterator<Item = S, IntoIter = C>,
@@ -158,7 +181,12 @@ impl Transaction<ReadWrite> {
     ) -> Result<Vec<Tag<TagId>>, sqlx::Error> {
         let tag_iter = tags.into_iter().map(|t| t.to_string());
         let existing_tags = self.get_tags_with_names(tag_iter.clone()).await?;
-        let existing_slugs: BTreeSet<_> = existing_tags.iter().map(|t| &t.slug).collect();
+        let aliased_slugs = self.aliased_slugs(tag_iter.clone()).await?;
+        let existing_slugs: BTreeSet<_> = existing_tags
+            .iter()
+            .map(|t| &t.slug)
+            .chain(aliased_slugs.iter())
+            .collect();
         let mut missing_tags = BTreeMap::new();
         for t in tag_iter {
             let slug = normalize_tag(t.clone());
@@ -171,9 +199,12 @@ impl Transaction<ReadWrite> {
         for (slug, tag) in missing_tags {
             let tag = sqlx::query_as(
                 r#"
-                  INSERT INTO tags (name, slug, created_at) VALUES (?, ?, datetime()) RETURNING *;
+                  INSERT INTO tags (user_id, name, slug, created_at) VALUES (?, ?, ?, datetime())
+                  ON CONFLICT (user_id, name) DO UPDATE SET slug = excluded.slug
+                  RETURNING *;
                 "#,
             )
+            .bind(self.user().id)
             .bind(tag)
             .bind(slug)
             .fetch_one(&mut *self.txn)
@@ -188,6 +219,239 @@ impl Transaction<ReadWrite> {
     }
 }
 
+/// A tag, with the number of the user's bookmarks that have it.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, FromRow, ToSchema)]
+pub struct TagCount {
+    #[sqlx(flatten)]
+    pub tag: ExistingTag,
+
+    /// How many of the user's bookmarks are tagged with it.
+    pub count: i64,
+}
+
+/// A level in the hierarchy of the user's tags.
+///
+/// Tags with `/` in their names form a tree: The tag `lang/rust/async`
+/// is below `lang/rust`, which is below `lang`. Levels that no tag is
+/// named for (`lang`, if the user only has `lang/rust`) are in the
+/// tree too, without a tag.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, ToSchema)]
+pub struct TagTreeNode {
+    /// The name of this level, e.g. `async`.
+    pub name: String,
+
+    /// The full name of the tag at this level, e.g. `lang/rust/async`.
+    pub path: String,
+
+    /// The tag named `path`, if the user has one.
+    pub tag_id: Option<TagId>,
+
+    /// How many of the user's bookmarks are tagged with the tag named
+    /// `path` itself.
+    pub count: i64,
+
+    /// The levels below this one, by name.
+    pub children: Vec<TagTreeNode>,
+}
+
+impl TagTreeNode {
+    /// Arrange tags into trees, returning the top-level nodes by name.
+    pub fn build(tags: impl IntoIterator<Item = TagCount>) -> Vec<TagTreeNode> {
+        let mut roots = vec![];
+        for TagCount { tag, count } in tags {
+            let levels: Vec<&str> = tag.name.split('/').filter(|l| !l.is_empty()).collect();
+            Self::insert(&mut roots, "", &levels, tag.id, count);
+        }
+        roots
+    }
+
+    fn insert(
+        nodes: &mut Vec<TagTreeNode>,
+        parent: &str,
+        levels: &[&str],
+        tag_id: TagId,
+        count: i64,
+    ) {
+        let Some((name, below)) = levels.split_first() else {
+            return;
+        };
+        let index = match nodes.binary_search_by(|node| node.name.as_str().cmp(name)) {
+            Ok(index) => index,
+            Err(index) => {
+                let path = if parent.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{parent}/{name}")
+                };
+                nodes.insert(
+                    index,
+                    TagTreeNode {
+                        name: name.to_string(),
+                        path,
+                        tag_id: None,
+                        count: 0,
+                        children: vec![],
+                    },
+                );
+                index
+            }
+        };
+        let node = &mut nodes[index];
+        if below.is_empty() {
+            node.tag_id = Some(tag_id);
+            node.count += count;
+        } else {
+            Self::insert(&mut node.children, &node.path, below, tag_id, count);
+        }
+    }
+}
+
+/// An error that can occur when changing a user's tags.
+#[derive(thiserror::Error, Debug)]
+pub enum TagError {
+    /// Tag names must be non-empty and must not contain whitespace.
+    #[error("invalid tag name {0:?}")]
+    InvalidName(String),
+
+    /// A tag can't be merged into itself.
+    #[error("can not merge a tag into itself")]
+    SameTag,
+
+    /// A tag's own name can't be an alias of it.
+    #[error("{0:?} is the name of the tag itself")]
+    AliasOfItself(String),
+
+    /// Any error raised by sqlx. If the tag isn't one of the user's
+    /// tags, this is [`RowNotFound`](sqlx::Error::RowNotFound).
+    #[error("sql datastore error")]
+    Sqlx(#[from] sqlx::Error),
+}
+
+/// # Listing the user's tags
+impl<M: TransactionMode> Transaction<M> {
+    /// List the user's tags, by name, with the number of bookmarks
+    /// that have each of them (which may be none).
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn list_tags_with_counts(&mut self) -> Result<Vec<TagCount>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+              SELECT tags.*, COUNT(bookmark_tags.bookmark_id) AS count
+              FROM tags LEFT JOIN bookmark_tags USING (tag_id)
+              WHERE tags.user_id = ?
+              GROUP BY tags.tag_id
+              ORDER BY tags.name
+            "#,
+        )
+        .bind(self.user().id)
+        .fetch_all(&mut *self.txn)
+        .await
+    }
+
+    /// Return the hierarchy of the user's tags, as in [`TagTreeNode`].
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn tag_tree(&mut self) -> Result<Vec<TagTreeNode>, sqlx::Error> {
+        Ok(TagTreeNode::build(self.list_tags_with_counts().await?))
+    }
+
+    /// Return one of the user's tags.
+    ///
+    /// Other users' tags are [`RowNotFound`](sqlx::Error::RowNotFound).
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn get_user_tag(&mut self, tag: TagId) -> Result<ExistingTag, sqlx::Error> {
+        sqlx::query_as(r#"SELECT * FROM tags WHERE tag_id = ? AND user_id = ?"#)
+            .bind(tag)
+            .bind(self.user().id)
+            .fetch_one(&mut *self.txn)
+            .await
+    }
+}
+
+/// # Managing the user's tags
+impl Transaction<ReadWrite> {
+    /// Rename one of the user's tags, and return the tag that its
+    /// bookmarks have now.
+    ///
+    /// If the user has a tag with an equivalent name (one with the
+    /// same slug) already, the bookmarks get that tag instead, as with
+    /// [`Transaction::merge_tags`].
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn rename_tag(
+        &mut self,
+        tag: TagId,
+        new_name: &str,
+    ) -> Result<ExistingTag, TagError> {
+        check_tag_name(new_name)?;
+        self.get_user_tag(tag).await?;
+        match self.get_tags_with_names([new_name]).await?.pop() {
+            Some(equivalent) if equivalent.id != tag => {
+                self.merge_tags(tag, equivalent.id).await?;
+                Ok(equivalent)
+            }
+            _ => Ok(sqlx::query_as(
+                r#"UPDATE tags SET name = ?, slug = ? WHERE tag_id = ? RETURNING *"#,
+            )
+            .bind(new_name)
+            .bind(normalize_tag(new_name))
+            .bind(tag)
+            .fetch_one(&mut *self.txn)
+            .await?),
+        }
+    }
+
+    /// Replace the user's tag `from` with their tag `into` on all
+    /// bookmarks, delete `from`, and return how many bookmarks had it.
+    ///
+    /// Aliases of `from` become aliases of `into`.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn merge_tags(&mut self, from: TagId, into: TagId) -> Result<u64, TagError> {
+        if from == into {
+            return Err(TagError::SameTag);
+        }
+        self.get_user_tag(from).await?;
+        self.get_user_tag(into).await?;
+        sqlx::query(
+            r#"
+              INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag_id)
+              SELECT bookmark_id, ? FROM bookmark_tags WHERE tag_id = ?
+            "#,
+        )
+        .bind(into)
+        .bind(from)
+        .execute(&mut *self.txn)
+        .await?;
+        sqlx::query(r#"UPDATE tag_aliases SET tag_id = ? WHERE tag_id = ?"#)
+            .bind(into)
+            .bind(from)
+            .execute(&mut *self.txn)
+            .await?;
+        Ok(self.delete_tag(from).await?)
+    }
+
+    /// Delete one of the user's tags, removing it from all bookmarks,
+    /// and return how many bookmarks had it.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn delete_tag(&mut self, tag: TagId) -> Result<u64, sqlx::Error> {
+        self.get_user_tag(tag).await?;
+        let result = sqlx::query(r#"DELETE FROM bookmark_tags WHERE tag_id = ?"#)
+            .bind(tag)
+            .execute(&mut *self.txn)
+            .await?;
+        sqlx::query(r#"DELETE FROM tags WHERE tag_id = ?"#)
+            .bind(tag)
+            .execute(&mut *self.txn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+/// Check that a name can be used for a tag (or an alias of one).
+pub(crate) fn check_tag_name(name: &str) -> Result<(), TagError> {
+    if name.is_empty() || name.contains(char::is_whitespace) || normalize_tag(name).is_empty() {
+        return Err(TagError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
 struct SlugDeduper;
 
 impl Replacer for SlugDeduper {
@@ -199,20 +463,31 @@ impl Replacer for SlugDeduper {
 
 /// "Slugify" our tags, turning them into 7-bit alphanumeric ASCII (as well
 /// as the colon and hyphen).
+///
+/// Hierarchical tags like `lang/rust/async` get each of their
+/// `/`-separated levels slugified on its own; empty levels are dropped.
 /// ```
 /// use lz_db;
 /// assert_eq!(lz_db::normalize_tag(&"Gödel's Incompleteness Theorem"), "godels-incompleteness-theorem");
 /// assert_eq!(lz_db::normalize_tag(&"Music::C86"), "music:c86");
 /// assert_eq!(lz_db::normalize_tag(&"  Pogo  A  Go Go!"), "pogo-a-go-go");
+/// assert_eq!(lz_db::normalize_tag(&"Lang/Rust//Async Code/"), "lang/rust/async-code");
 /// ```
 pub fn normalize_tag<T: AsRef<str>>(tag: T) -> String {
+    tag.as_ref()
+        .split('/')
+        .map(normalize_tag_level)
+        .filter(|level| !level.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn normalize_tag_level(tag: &str) -> String {
     // TODO: We should return Option<String> instead, allowing us to block degenerate
     // tags such as "foo:-:bar".
     static HYPHENIZE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^a-z0-9:-]").unwrap());
     static DEDUPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(::+|--+)").unwrap());
-    let normal_tag = deunicode(tag.as_ref())
-        .to_lowercase()
-        .replace(['\'', '\"'], "");
+    let normal_tag = deunicode(tag).to_lowercase().replace(['\'', '\"'], "");
     let normal_tag = HYPHENIZE_RE.replace_all(&normal_tag, "-");
     let normal_tag = DEDUPE_RE.replace_all(&normal_tag, SlugDeduper);
     normal_tag.trim_matches('-').trim_matches(':').to_string()
@@ -426,4 +701,210 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn manage_tags(ctx: &mut Context) -> TestResult {
+        async fn tagged(
+            txn: &mut Transaction,
+            link: &str,
+            tags: &[&str],
+        ) -> Result<BookmarkId, sqlx::Error> {
+            let bookmark = txn
+                .add_bookmark(Bookmark {
+                    id: NoId,
+                    user_id: NoId,
+                    created_at: Default::default(),
+                    modified_at: None,
+                    accessed_at: None,
+                    url: Url::parse(link).unwrap(),
+                    title: link.to_string(),
+                    description: None,
+                    website_title: None,
+                    website_description: None,
+                    notes: None,
+                    import_properties: None,
+                    shared: false,
+                    unread: false,
+                })
+                .await?;
+            let tags = txn.ensure_tags(tags).await?;
+            txn.set_bookmark_tags(bookmark.id, tags).await?;
+            Ok(bookmark.id)
+        }
+        fn counts(tags: Vec<TagCount>) -> Vec<(String, i64)> {
+            tags.into_iter().map(|t| (t.tag.name, t.count)).collect()
+        }
+
+        let mut txn = ctx.begin_for_user("someone else").await?;
+        let theirs = tagged(&mut txn, "https://example.com/theirs", &["js"]).await?;
+        txn.commit().await?;
+
+        let mut txn = ctx.begin().await?;
+        let one = tagged(&mut txn, "https://example.com/1", &["js", "javascript"]).await?;
+        tagged(&mut txn, "https://example.com/2", &["js", "Rust"]).await?;
+        assert_eq!(
+            counts(txn.list_tags_with_counts().await?),
+            vec![
+                ("Rust".to_string(), 1),
+                ("javascript".to_string(), 1),
+                ("js".to_string(), 2)
+            ]
+        );
+
+        let tags = txn
+            .get_tags_with_names(["js", "javascript", "rust"])
+            .await?;
+        let id = |name: &str| tags.iter().find(|t| t.name == name).unwrap().id;
+        assert!(matches!(
+            txn.merge_tags(id("js"), id("js")).await,
+            Err(TagError::SameTag)
+        ));
+        assert!(matches!(
+            txn.rename_tag(id("Rust"), "rust lang").await,
+            Err(TagError::InvalidName(_))
+        ));
+        assert_eq!(txn.merge_tags(id("js"), id("javascript")).await?, 2);
+        assert_eq!(txn.rename_tag(id("Rust"), "rust").await?.id, id("Rust"));
+        let renamed = txn.rename_tag(id("javascript"), "ecmascript").await?;
+        assert_eq!(
+            counts(txn.list_tags_with_counts().await?),
+            vec![("ecmascript".to_string(), 2), ("rust".to_string(), 1)]
+        );
+        assert_eq!(txn.delete_tag(renamed.id).await?, 2);
+        assert_eq!(txn.get_bookmark_tags(one).await?, vec![]);
+        assert!(matches!(
+            txn.merge_tags(renamed.id, id("Rust")).await,
+            Err(TagError::Sqlx(sqlx::Error::RowNotFound))
+        ));
+        txn.commit().await?;
+
+        // The other user's bookmarks keep their tags:
+        let mut txn = ctx.begin_for_user("someone else").await?;
+        assert_eq!(
+            txn.get_bookmark_tags(theirs)
+                .await?
+                .into_iter()
+                .map(|t| t.name)
+                .collect::<Vec<_>>(),
+            vec!["js"]
+        );
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn tag_tree(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let bookmark = txn
+            .add_bookmark(Bookmark {
+                id: NoId,
+                user_id: NoId,
+                created_at: Default::default(),
+                modified_at: None,
+                accessed_at: None,
+                url: Url::parse("https://example.com/")?,
+                title: "Example".to_string(),
+                description: None,
+                website_title: None,
+                website_description: None,
+                notes: None,
+                import_properties: None,
+                shared: false,
+                unread: false,
+            })
+            .await?;
+        let tags = txn
+            .ensure_tags(["lang/rust/async", "lang/Python", "misc", "lang/rust"])
+            .await?;
+        assert_eq!(
+            tags.iter()
+                .map(|t| t.slug.as_str())
+                .collect::<BTreeSet<_>>(),
+            BTreeSet::from(["lang/rust/async", "lang/python", "misc", "lang/rust"])
+        );
+        let tagged = txn
+            .get_tags_with_names(["lang/rust/async", "lang/python"])
+            .await?;
+        txn.set_bookmark_tags(bookmark.id, tagged).await?;
+
+        fn shape(nodes: &[TagTreeNode]) -> Vec<(String, bool, i64, usize)> {
+            nodes
+                .iter()
+                .flat_map(|n| {
+                    std::iter::once((
+                        n.path.clone(),
+                        n.tag_id.is_some(),
+                        n.count,
+                        n.children.len(),
+                    ))
+                    .chain(shape(&n.children))
+                })
+                .collect()
+        }
+        let tree = txn.tag_tree().await?;
+        assert_eq!(
+            shape(&tree),
+            vec![
+                ("lang".to_string(), false, 0, 2),
+                ("lang/Python".to_string(), true, 1, 0),
+                ("lang/rust".to_string(), true, 0, 1),
+                ("lang/rust/async".to_string(), true, 1, 0),
+                ("misc".to_string(), true, 0, 0),
+            ]
+        );
+        assert_eq!(tree[0].children[1].name, "rust");
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn tags_are_per_user(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin_for_user("someone else").await?;
+        let theirs = txn.ensure_tags(["rust", "private"]).await?;
+        txn.commit().await?;
+
+        let mut txn = ctx.begin().await?;
+        assert_eq!(txn.get_tags_with_names(["rust", "private"]).await?, vec![]);
+        assert_eq!(txn.tags_matching("riv").await?, vec![]);
+        assert!(txn.list_tags_with_counts().await?.is_empty());
+        assert!(matches!(
+            txn.get_user_tag(theirs[0].id).await,
+            Err(sqlx::Error::RowNotFound)
+        ));
+
+        let ours = txn.ensure_tags(["rust"]).await?;
+        assert_ne!(ours[0].id, theirs[0].id);
+        assert!(matches!(
+            txn.merge_tags(ours[0].id, theirs[1].id).await,
+            Err(TagError::Sqlx(sqlx::Error::RowNotFound))
+        ));
+        assert!(matches!(
+            txn.delete_tag(theirs[0].id).await,
+            Err(sqlx::Error::RowNotFound)
+        ));
+        // Renaming to another user's tag name makes a new name for ours:
+        let renamed = txn.rename_tag(ours[0].id, "private").await?;
+        assert_eq!(renamed.id, ours[0].id);
+        assert_eq!(
+            txn.list_tags_with_counts()
+                .await?
+                .into_iter()
+                .map(|t| (t.tag.name, t.count))
+                .collect::<Vec<_>>(),
+            vec![("private".to_string(), 0)]
+        );
+        txn.commit().await?;
+
+        let mut txn = ctx.begin_for_user("someone else").await?;
+        let mut names: Vec<_> = txn
+            .tags_matching("")
+            .await?
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["private", "rust"]);
+        Ok(())
+    }
 }
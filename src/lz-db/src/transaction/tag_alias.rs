@@ -0,0 +1,331 @@
+//! # Tag aliases
+//!
+//! Users can give their tags alternative names, like `js` for
+//! `javascript` or `k8s` for `kubernetes`: Tagging a bookmark with an
+//! alias tags it with the tag that the alias stands for, and searching
+//! for an alias finds the bookmarks with that tag.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::*;
+use utoipa::ToSchema;
+
+use crate::{
+    check_tag_name, normalize_tag, ExistingTag, ReadWrite, TagError, TagId, Transaction,
+    TransactionMode,
+};
+
+/// An alternative name for one of the user's tags.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, FromRow, ToSchema)]
+pub struct TagAlias {
+    /// The alias, as it was given.
+    pub name: String,
+
+    /// Normalized name of the alias; names with the same slug are the
+    /// same alias.
+    pub slug: String,
+
+    /// The tag that the alias stands for.
+    pub tag_id: TagId,
+
+    /// The name of the tag that the alias stands for.
+    pub tag_name: String,
+
+    /// When the alias was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// # Looking up tag aliases
+impl<M: TransactionMode> Transaction<M> {
+    /// List the user's tag aliases, by name.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn list_tag_aliases(&mut self) -> Result<Vec<TagAlias>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+              SELECT tag_aliases.name, tag_aliases.slug, tag_id, tags.name AS tag_name, tag_aliases.created_at
+              FROM tag_aliases JOIN tags USING (tag_id)
+              WHERE tag_aliases.user_id = ?
+              ORDER BY tag_aliases.name
+            "#,
+        )
+        .bind(self.user().id)
+        .fetch_all(&mut *self.txn)
+        .await
+    }
+
+    /// Return the user's alias with the given name (or one that is
+    /// equivalent to it).
+    ///
+    /// Names that aren't aliases are
+    /// [`RowNotFound`](sqlx::Error::RowNotFound).
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn get_tag_alias(&mut self, name: &str) -> Result<TagAlias, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+              SELECT tag_aliases.name, tag_aliases.slug, tag_id, tags.name AS tag_name, tag_aliases.created_at
+              FROM tag_aliases JOIN tags USING (tag_id)
+              WHERE tag_aliases.user_id = ? AND tag_aliases.slug = ?
+            "#,
+        )
+        .bind(self.user().id)
+        .bind(normalize_tag(name))
+        .fetch_one(&mut *self.txn)
+        .await
+    }
+
+    /// Return the slugs of those of the given names that are the user's
+    /// aliases.
+    pub(crate) async fn aliased_slugs<I, S>(
+        &mut self,
+        names: I,
+    ) -> Result<BTreeSet<String>, sqlx::Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let slugs: Vec<String> = names.into_iter().map(normalize_tag).collect();
+        let aliased: Vec<(String,)> = sqlx::query_as(
+            r#"
+              SELECT slug FROM tag_aliases
+              WHERE user_id = ? AND slug IN (SELECT value FROM json_each(?))
+            "#,
+        )
+        .bind(self.user().id)
+        .bind(sqlx::types::Json(slugs))
+        .fetch_all(&mut *self.txn)
+        .await?;
+        Ok(aliased.into_iter().map(|(slug,)| slug).collect())
+    }
+}
+
+/// # Managing tag aliases
+impl Transaction<ReadWrite> {
+    /// Make `name` an alias of the user's tag `tag`, and return the
+    /// alias. If `name` was an alias of another tag, it stands for
+    /// `tag` from now on.
+    ///
+    /// If the user has a tag named like the alias, its bookmarks keep
+    /// that tag, unless `retro_apply` is set: Then it gets merged into
+    /// `tag`, as with [`Transaction::merge_tags`].
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn add_tag_alias(
+        &mut self,
+        name: &str,
+        tag: TagId,
+        retro_apply: bool,
+    ) -> Result<TagAlias, TagError> {
+        check_tag_name(name)?;
+        let target = self.get_user_tag(tag).await?;
+        let slug = normalize_tag(name);
+        if slug == target.slug {
+            return Err(TagError::AliasOfItself(name.to_string()));
+        }
+        if retro_apply {
+            let existing: Option<ExistingTag> =
+                sqlx::query_as(r#"SELECT * FROM tags WHERE user_id = ? AND slug = ?"#)
+                    .bind(self.user().id)
+                    .bind(&slug)
+                    .fetch_optional(&mut *self.txn)
+                    .await?;
+            if let Some(existing) = existing {
+                self.merge_tags(existing.id, tag).await?;
+            }
+        }
+        sqlx::query(
+            r#"
+              INSERT INTO tag_aliases (user_id, tag_id, created_at, name, slug)
+              VALUES (?, ?, datetime(), ?, ?)
+              ON CONFLICT (user_id, slug) DO UPDATE SET tag_id = excluded.tag_id, name = excluded.name
+            "#,
+        )
+        .bind(self.user().id)
+        .bind(tag)
+        .bind(name)
+        .bind(&slug)
+        .execute(&mut *self.txn)
+        .await?;
+        Ok(self.get_tag_alias(name).await?)
+    }
+
+    /// Remove one of the user's aliases, and return it.
+    ///
+    /// Names that aren't aliases are
+    /// [`RowNotFound`](sqlx::Error::RowNotFound).
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn remove_tag_alias(&mut self, name: &str) -> Result<TagAlias, sqlx::Error> {
+        let alias = self.get_tag_alias(name).await?;
+        sqlx::query(r#"DELETE FROM tag_aliases WHERE user_id = ? AND slug = ?"#)
+            .bind(self.user().id)
+            .bind(&alias.slug)
+            .execute(&mut *self.txn)
+            .await?;
+        Ok(alias)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_context::test_context;
+    use testresult::TestResult;
+    use url::Url;
+
+    use super::*;
+    use crate::{Bookmark, BookmarkId, BookmarkSearch, Context, IdType as _, NoId, TagName};
+
+    async fn tagged(txn: &mut Transaction, link: &str, tags: &[&str]) -> TestResult<BookmarkId> {
+        let bookmark = txn
+            .add_bookmark(Bookmark {
+                id: NoId,
+                user_id: NoId,
+                created_at: Default::default(),
+                modified_at: None,
+                accessed_at: None,
+                url: Url::parse(link)?,
+                title: link.to_string(),
+                description: None,
+                website_title: None,
+                website_description: None,
+                notes: None,
+                import_properties: None,
+                shared: false,
+                unread: false,
+            })
+            .await?;
+        let tags = txn.ensure_tags(tags).await?;
+        txn.set_bookmark_tags(bookmark.id, tags).await?;
+        Ok(bookmark.id)
+    }
+
+    async fn search(txn: &mut Transaction, tag: &str) -> TestResult<Vec<BookmarkId>> {
+        let mut found: Vec<_> = txn
+            .list_bookmarks_matching(
+                &[
+                    BookmarkSearch::User { id: txn.user().id },
+                    BookmarkSearch::TagByName {
+                        tag: TagName::from(tag),
+                    },
+                ],
+                10,
+                None,
+            )
+            .await?
+            .into_iter()
+            .map(|bm| bm.id)
+            .collect();
+        found.sort_by_key(|id| id.id());
+        Ok(found)
+    }
+
+    async fn tag_names(txn: &mut Transaction, bookmark: BookmarkId) -> TestResult<Vec<String>> {
+        Ok(txn
+            .get_bookmark_tags(bookmark)
+            .await?
+            .into_iter()
+            .map(|t| t.name)
+            .collect())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn aliases_resolve(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let old = tagged(&mut txn, "https://example.com/old", &["js"]).await?;
+        let javascript = txn.ensure_tags(["javascript"]).await?.remove(0);
+        assert!(matches!(
+            txn.add_tag_alias("JavaScript", javascript.id, false).await,
+            Err(TagError::AliasOfItself(_))
+        ));
+        assert!(matches!(
+            txn.add_tag_alias("java script", javascript.id, false).await,
+            Err(TagError::InvalidName(_))
+        ));
+        let alias = txn.add_tag_alias("JS", javascript.id, false).await?;
+        assert_eq!((alias.slug.as_str(), alias.tag_id), ("js", javascript.id));
+
+        // New taggings get the aliased tag; old ones keep theirs:
+        let new = tagged(&mut txn, "https://example.com/new", &["js", "Js"]).await?;
+        assert_eq!(tag_names(&mut txn, new).await?, vec!["javascript"]);
+        assert_eq!(tag_names(&mut txn, old).await?, vec!["js"]);
+        assert_eq!(
+            txn.get_tags_with_names(["js", "javascript"]).await?,
+            vec![javascript.clone()]
+        );
+
+        // Searches find the aliased tag, like lookups by name do;
+        // autocompletion offers both:
+        assert_eq!(search(&mut txn, "js").await?, vec![new]);
+        let mut completed: Vec<_> = txn
+            .tags_matching("JS")
+            .await?
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        completed.sort();
+        assert_eq!(completed, vec!["javascript", "js"]);
+
+        // Retro-applying the alias merges the old tag:
+        txn.add_tag_alias("js", javascript.id, true).await?;
+        assert_eq!(tag_names(&mut txn, old).await?, vec!["javascript"]);
+        assert_eq!(txn.list_tag_aliases().await?.len(), 1);
+
+        let removed = txn.remove_tag_alias("JS").await?;
+        assert_eq!(removed.name, "js");
+        assert!(txn.list_tag_aliases().await?.is_empty());
+        assert!(matches!(
+            txn.remove_tag_alias("js").await,
+            Err(sqlx::Error::RowNotFound)
+        ));
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn aliases_follow_their_tag(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let tags = txn.ensure_tags(["k8s-stuff", "kubernetes"]).await?;
+        txn.add_tag_alias("k8s", tags[0].id, false).await?;
+        txn.merge_tags(tags[0].id, tags[1].id).await?;
+        assert_eq!(txn.get_tag_alias("k8s").await?.tag_name, "kubernetes");
+        txn.delete_tag(tags[1].id).await?;
+        assert!(txn.list_tag_aliases().await?.is_empty());
+        txn.commit().await?;
+
+        // Other users' aliases don't apply:
+        let mut txn = ctx.begin_for_user("someone else").await?;
+        let other = tagged(&mut txn, "https://example.com/", &["k8s"]).await?;
+        assert_eq!(tag_names(&mut txn, other).await?, vec!["k8s"]);
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn aliases_hide_tags_of_the_same_name(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let literal = tagged(&mut txn, "https://example.com/js", &["JS"]).await?;
+        let aliased = tagged(&mut txn, "https://example.com/javascript", &["javascript"]).await?;
+        let javascript = txn.get_tags_with_names(["javascript"]).await?.remove(0);
+        txn.add_tag_alias("js", javascript.id, false).await?;
+
+        // Looking the name up and searching for it agree on the tag:
+        assert_eq!(
+            txn.get_tags_with_names(["js"]).await?,
+            vec![javascript.clone()]
+        );
+        assert_eq!(search(&mut txn, "js").await?, vec![aliased]);
+        assert_eq!(search(&mut txn, "Js").await?, vec![aliased]);
+        txn.commit().await?;
+
+        // ...but only for the user whose alias it is:
+        let mut txn = ctx.begin_for_user("someone else").await?;
+        let other = tagged(&mut txn, "https://example.com/", &["js"]).await?;
+        assert_eq!(search(&mut txn, "js").await?, vec![other]);
+        txn.commit().await?;
+
+        // Without the alias, the tag of that name is found again:
+        let mut txn = ctx.begin().await?;
+        txn.remove_tag_alias("js").await?;
+        assert_eq!(search(&mut txn, "JS").await?, vec![literal]);
+        Ok(())
+    }
+}
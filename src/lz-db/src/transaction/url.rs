@@ -4,14 +4,37 @@
 //! We store links in a separate table from bookmarks, which allows
 //! for multiple associations between a bookmark and a related link
 //! ("discussed at", "referenced by")
+//!
+//! ## Canonical URLs
+//!
+//! Many different links lead to the same page: `http://` and
+//! `https://`, with and without `www.` or a trailing slash, with
+//! and with tracking parameters. Each stored URL also has a
+//! canonical form (see [`UrlCanonicalization`]), which finding
+//! bookmarks and telling duplicates apart goes by. Each link still
+//! gets stored as it is, in a URL of its own: A user can have only
+//! one bookmark of a page, but it points to the link they bookmarked.
+
+use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
-use sqlx::query_scalar;
 use sqlx::{prelude::*, types::Text};
 use url::Url;
 use utoipa::{ToResponse, ToSchema};
 
-use crate::{BookmarkId, IdType, ReadWrite, Transaction, TransactionMode};
+use crate::{BookmarkId, Connection, IdType, ReadWrite, Transaction, TransactionMode};
+
+/// Association context for snapshots of a bookmark's URL in the
+/// Internet Archive's Wayback Machine.
+pub const WAYBACK_CONTEXT: &str = "wayback";
+
+/// Association context for the URL that a bookmark pointed to before
+/// it was updated to follow a permanent redirect.
+pub const REDIRECTED_FROM_CONTEXT: &str = "redirected_from";
+
+/// Association context for the URL of a bookmark that was merged into
+/// another one.
+pub const MERGED_FROM_CONTEXT: &str = "merged_from";
 
 /// The database ID of a stored URL.
 #[derive(
@@ -52,6 +75,131 @@ pub struct StoredUrl<ID: IdType<StoredUrlId>> {
     pub link: Url,
 }
 
+/// Rules for telling whether two links point to the same page.
+///
+/// Each rule that is on ignores a difference between links; the
+/// canonical form of a link is what remains of it. The rules apply
+/// to `http` and `https` links only, and are all on by default,
+/// except for ignoring fragments: Pages that route with them, like
+/// `https://app.example/#/inbox`, would all be the same.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlCanonicalization {
+    /// Treat `http://` and `https://` links as the same
+    pub ignore_scheme: bool,
+
+    /// Ignore a leading `www.` in host names
+    pub ignore_www: bool,
+
+    /// Ignore a trailing slash in paths
+    pub ignore_trailing_slash: bool,
+
+    /// Ignore fragments (the part after `#`)
+    pub ignore_fragment: bool,
+
+    /// Query parameters that don't change which page a link points
+    /// to. A trailing `*` matches all parameters starting with what
+    /// comes before it.
+    pub ignored_params: Vec<String>,
+}
+
+fn default_ignored_params() -> Vec<String> {
+    ["utm_*", "fbclid", "gclid", "mc_cid", "mc_eid"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+impl Default for UrlCanonicalization {
+    fn default() -> Self {
+        Self {
+            ignore_scheme: true,
+            ignore_www: true,
+            ignore_trailing_slash: true,
+            ignore_fragment: false,
+            ignored_params: default_ignored_params(),
+        }
+    }
+}
+
+impl UrlCanonicalization {
+    /// Returns the canonical form of a link.
+    ///
+    /// The canonical form is only good for comparing links; the page
+    /// it points to might not exist.
+    pub fn canonicalize(&self, link: &Url) -> String {
+        let mut url = link.clone();
+        if !matches!(url.scheme(), "http" | "https") {
+            return url.into();
+        }
+        if self.ignore_scheme {
+            url.set_scheme("https")
+                .expect("http and https are interchangeable");
+        }
+        if self.ignore_www {
+            if let Some(host) = url
+                .host_str()
+                .and_then(|host| host.strip_prefix("www."))
+                .filter(|host| !host.is_empty())
+                .map(String::from)
+            {
+                url.set_host(Some(&host))
+                    .expect("a shorter host name is valid");
+            }
+        }
+        if self.ignore_fragment {
+            url.set_fragment(None);
+        }
+        if url.query().is_some() {
+            let kept: Vec<(String, String)> = url
+                .query_pairs()
+                .filter(|(name, _)| !self.ignores_param(name))
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect();
+            if kept.is_empty() {
+                url.set_query(None);
+            } else {
+                url.query_pairs_mut().clear().extend_pairs(kept);
+            }
+        }
+        if self.ignore_trailing_slash {
+            let path = url.path().trim_end_matches('/').to_string();
+            url.set_path(if path.is_empty() { "/" } else { &path });
+        }
+        url.into()
+    }
+
+    fn ignores_param(&self, name: &str) -> bool {
+        let name = name.to_ascii_lowercase();
+        self.ignored_params.iter().any(|pattern| {
+            let pattern = pattern.to_ascii_lowercase();
+            match pattern.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => name == pattern,
+            }
+        })
+    }
+}
+
+/// # Canonicalizing URLs
+impl Connection {
+    /// Use other rules for canonicalizing URLs than the default ones.
+    ///
+    /// All programs that use a database should use the same rules;
+    /// after changing them, run
+    /// [`refresh_canonical_urls`](Transaction::refresh_canonical_urls).
+    pub fn with_url_canonicalization(mut self, rules: UrlCanonicalization) -> Self {
+        self.url_canonicalization = Arc::new(rules);
+        self
+    }
+}
+
+impl<M: TransactionMode> Transaction<M> {
+    /// The rules that this transaction canonicalizes URLs with.
+    pub fn url_canonicalization(&self) -> &UrlCanonicalization {
+        &self.url_canonicalization
+    }
+}
+
 /// # Reading stored URLs
 impl<M: TransactionMode> Transaction<M> {
     /// Return a stored URL's ID if it exists in the database.
@@ -59,16 +207,10 @@ impl<M: TransactionMode> Transaction<M> {
         &mut self,
         link: &Url,
     ) -> Result<Option<StoredUrlId>, sqlx::Error> {
-        let bm_url = Text(link);
-        Ok(query_scalar!(
-            r#"
-              SELECT url_id FROM urls WHERE link = ?
-            "#,
-            bm_url,
-        )
-        .fetch_optional(&mut *self.txn)
-        .await?
-        .map(StoredUrlId))
+        sqlx::query_scalar(r#"SELECT url_id FROM urls WHERE link = ?"#)
+            .bind(Text(link))
+            .fetch_optional(&mut *self.txn)
+            .await
     }
 }
 
@@ -77,21 +219,51 @@ impl Transaction<ReadWrite> {
     /// Ensure a stored link exists in the database.
     #[tracing::instrument(skip(self))]
     pub async fn ensure_url(&mut self, link: &Url) -> Result<StoredUrlId, sqlx::Error> {
+        let canonical = self.url_canonicalization.canonicalize(link);
         if let Some(id) = self.url_id_for_link(link).await? {
+            // URLs stored before canonical forms existed get theirs now:
+            sqlx::query(r#"UPDATE urls SET canonical = ? WHERE url_id = ? AND canonical IS NULL"#)
+                .bind(&canonical)
+                .bind(id)
+                .execute(&mut *self.txn)
+                .await?;
             return Ok(id);
         }
-        let bm_url = Text(link);
-        Ok(StoredUrlId(
-            query_scalar!(
-                r#"
-                  INSERT INTO urls (link) VALUES (?)
-                  RETURNING url_id
-                "#,
-                bm_url,
-            )
-            .fetch_one(&mut *self.txn)
-            .await?,
-        ))
+        sqlx::query_scalar(
+            r#"
+              INSERT INTO urls (link, canonical) VALUES (?, ?)
+              RETURNING url_id
+            "#,
+        )
+        .bind(Text(link))
+        .bind(canonical)
+        .fetch_one(&mut *self.txn)
+        .await
+    }
+
+    /// Compute the canonical forms of all stored URLs again, with the
+    /// transaction's rules.
+    ///
+    /// Returns the number of URLs whose canonical form changed.
+    #[tracing::instrument(skip(self))]
+    pub async fn refresh_canonical_urls(&mut self) -> Result<usize, sqlx::Error> {
+        let urls: Vec<(StoredUrlId, Text<Url>, Option<String>)> =
+            sqlx::query_as(r#"SELECT url_id, link, canonical FROM urls"#)
+                .fetch_all(&mut *self.txn)
+                .await?;
+        let mut changed = 0;
+        for (id, Text(link), old) in urls {
+            let canonical = self.url_canonicalization.canonicalize(&link);
+            if old.as_ref() != Some(&canonical) {
+                sqlx::query(r#"UPDATE urls SET canonical = ? WHERE url_id = ?"#)
+                    .bind(canonical)
+                    .bind(id)
+                    .execute(&mut *self.txn)
+                    .await?;
+                changed += 1;
+            }
+        }
+        Ok(changed)
     }
 
     /// Associate a bookmark with an additional link
@@ -118,4 +290,205 @@ impl Transaction<ReadWrite> {
         .await?;
         Ok(())
     }
+
+    /// Remove all the links associated with a bookmark.
+    #[tracing::instrument(skip(self))]
+    pub async fn clear_bookmark_associations(
+        &mut self,
+        bm: &BookmarkId,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(r#"DELETE FROM bookmark_associations WHERE bookmark_id = ?"#)
+            .bind(bm)
+            .execute(&mut *self.txn)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+    use test_context::test_context;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{Bookmark, Context, NoId};
+
+    #[test_case("http://example.com/a", "https://example.com/a"; "scheme")]
+    #[test_case("https://www.Example.com/a", "https://example.com/a"; "www")]
+    #[test_case("https://example.com/a/", "https://example.com/a"; "trailing slash")]
+    #[test_case("https://example.com/", "https://example.com/"; "root path")]
+    #[test_case("https://example.com/a/#/inbox", "https://example.com/a#/inbox"; "fragment")]
+    #[test_case("https://example.com/a?utm_source=x&id=1&UTM_Medium=y&fbclid=z", "https://example.com/a?id=1"; "tracking params")]
+    #[test_case("https://example.com/a?utm_source=x", "https://example.com/a"; "only tracking params")]
+    #[test_case("https://example.com:8443/a", "https://example.com:8443/a"; "port")]
+    #[test_case("ftp://www.example.com/a/#x", "ftp://www.example.com/a/#x"; "other schemes")]
+    fn default_rules(link: &str, canonical: &str) {
+        let rules = UrlCanonicalization::default();
+        assert_eq!(rules.canonicalize(&Url::parse(link).unwrap()), canonical);
+    }
+
+    #[test]
+    fn rules_can_be_turned_off() {
+        let rules = UrlCanonicalization {
+            ignore_scheme: false,
+            ignore_www: false,
+            ignore_trailing_slash: false,
+            ignore_fragment: false,
+            ignored_params: vec!["ref".to_string()],
+        };
+        let link = "http://www.example.com/a/?utm_source=x&ref=y#z";
+        assert_eq!(
+            rules.canonicalize(&Url::parse(link).unwrap()),
+            "http://www.example.com/a/?utm_source=x#z"
+        );
+        let rules = UrlCanonicalization {
+            ignore_fragment: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            rules.canonicalize(&Url::parse(link).unwrap()),
+            "https://example.com/a?ref=y"
+        );
+    }
+
+    fn bookmark(link: &str) -> Bookmark<NoId, NoId> {
+        Bookmark {
+            id: NoId,
+            user_id: NoId,
+            created_at: Default::default(),
+            modified_at: None,
+            accessed_at: None,
+            url: Url::parse(link).unwrap(),
+            title: "A page".to_string(),
+            description: None,
+            website_title: None,
+            website_description: None,
+            notes: None,
+            import_properties: None,
+            shared: false,
+            unread: false,
+        }
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn equivalent_links_are_one_bookmark(ctx: &mut Context) -> TestResult {
+        let link = |link: &str| Url::parse(link).unwrap();
+        let mut txn = ctx.begin().await?;
+        let first = txn.ensure_url(&link("https://example.com/page")).await?;
+        assert_eq!(
+            txn.ensure_url(&link("https://example.com/page")).await?,
+            first
+        );
+        // Each link is stored as it is:
+        let equivalent = txn
+            .ensure_url(&link("http://www.example.com/page/?utm_source=rss"))
+            .await?;
+        assert_ne!(equivalent, first);
+        assert_eq!(
+            txn.url_id_for_link(&link("http://www.example.com/page/?utm_source=rss"))
+                .await?,
+            Some(equivalent)
+        );
+
+        let page = txn
+            .add_bookmark(bookmark("http://example.com/page"))
+            .await?;
+        let found = txn
+            .find_bookmark_with_url(&link("https://example.com/page/"))
+            .await?;
+        assert_eq!(found.map(|bm| bm.id), Some(page.id));
+        match txn
+            .add_bookmark(bookmark("https://www.example.com/page"))
+            .await
+        {
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {}
+            other => panic!("expected a unique violation, got {other:?}"),
+        }
+        let other = txn
+            .add_bookmark(bookmark("https://example.com/other"))
+            .await?;
+        let mut moved = other.clone();
+        moved.url = link("https://www.example.com/page/");
+        match txn.update_bookmark(&moved).await {
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {}
+            other => panic!("expected a unique violation, got {other:?}"),
+        }
+        // Moving a bookmark to an equivalent link of its own is fine:
+        let mut secure = page.clone();
+        secure.url = link("https://example.com/page");
+        txn.update_bookmark(&secure).await?;
+        assert_eq!(txn.get_bookmark_by_id(page.id.id()).await?.url, secure.url);
+        txn.commit().await?;
+
+        // Other users keep the link they bookmarked:
+        let mut txn = ctx.begin_for_user("other").await?;
+        let theirs = txn
+            .add_bookmark(bookmark("http://example.com/page/"))
+            .await?;
+        assert_eq!(
+            txn.get_bookmark_by_id(theirs.id.id()).await?.url,
+            link("http://example.com/page/")
+        );
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn fragments_tell_pages_apart(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let inbox = txn
+            .add_bookmark(bookmark("https://app.example/#/inbox"))
+            .await?;
+        let sent = txn
+            .add_bookmark(bookmark("https://app.example/#/sent"))
+            .await?;
+        assert_ne!(inbox.id, sent.id);
+        assert!(txn.duplicate_bookmarks().await?.is_empty());
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn finds_duplicates_after_refresh(ctx: &mut Context) -> TestResult {
+        // Bookmarks from before canonical URLs existed:
+        for (url_id, link) in [
+            (1, "https://example.com/a"),
+            (2, "http://www.example.com/a/"),
+            (3, "https://example.com/b"),
+        ] {
+            sqlx::query("INSERT INTO urls (url_id, link) VALUES (?, ?)")
+                .bind(url_id)
+                .bind(link)
+                .execute(ctx.db_pool())
+                .await?;
+        }
+        let mut txn = ctx.begin().await?;
+        let user_id = txn.user().id;
+        for (bookmark_id, url_id) in [(1, 2), (2, 1), (3, 3)] {
+            sqlx::query(
+                r#"INSERT INTO bookmarks (bookmark_id, user_id, created_at, url_id, title)
+                   VALUES (?, ?, ?, ?, 'Old')"#,
+            )
+            .bind(bookmark_id)
+            .bind(user_id)
+            .bind(format!("2024-01-0{bookmark_id}T00:00:00Z"))
+            .bind(url_id)
+            .execute(&mut *txn.txn)
+            .await?;
+        }
+        assert!(txn.duplicate_bookmarks().await?.is_empty());
+
+        assert_eq!(txn.refresh_canonical_urls().await?, 3);
+        assert_eq!(txn.refresh_canonical_urls().await?, 0);
+        let groups: Vec<Vec<i64>> = txn
+            .duplicate_bookmarks()
+            .await?
+            .into_iter()
+            .map(|group| group.into_iter().map(|bm| bm.id.id()).collect())
+            .collect();
+        assert_eq!(groups, vec![vec![1, 2]]);
+        Ok(())
+    }
 }
@@ -10,7 +10,7 @@ use sqlx::prelude::*;
 use sqlx::query_as;
 use utoipa::{ToResponse, ToSchema};
 
-use crate::{IdType, Transaction, TransactionMode};
+use crate::{IdType, ReadWrite, Timezone, Transaction, TransactionMode};
 
 /// The database ID of a user.
 #[derive(
@@ -56,6 +56,10 @@ pub struct User<ID: IdType<UserId>> {
     ///
     /// This field is assigned in the database.
     pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// The timezone that the user's date searches count days in, if
+    /// they set one.
+    pub timezone: Option<Timezone>,
 }
 
 impl crate::Connection {
@@ -112,6 +116,24 @@ impl<M: TransactionMode> Transaction<M> {
     }
 }
 
+impl Transaction<ReadWrite> {
+    /// Set the timezone that the current user's date searches count
+    /// days in; `None` goes back to the server's timezone.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn set_user_timezone(
+        &mut self,
+        timezone: Option<Timezone>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(r#"UPDATE users SET timezone = ? WHERE user_id = ?"#)
+            .bind(timezone)
+            .bind(self.user.id)
+            .execute(&mut *self.txn)
+            .await?;
+        self.user.timezone = timezone;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use test_context::test_context;
@@ -129,4 +151,18 @@ mod tests {
         assert_eq!(Some(txn.user()), user.as_ref());
         Ok(())
     }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn user_timezone(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        assert_eq!(txn.user().timezone, None);
+        let berlin: Timezone = "Europe/Berlin".parse()?;
+        txn.set_user_timezone(Some(berlin)).await?;
+        txn.commit().await?;
+
+        let txn = ctx.begin().await?;
+        assert_eq!(txn.user().timezone, Some(berlin));
+        Ok(())
+    }
 }
@@ -9,9 +9,11 @@ use sqlx::QueryBuilder;
 use url::Url;
 use utoipa::{ToResponse, ToSchema};
 
+use crate::criteria::{push_join_tables, push_where_clauses};
+use crate::order::{Position, SortKey};
 use crate::{
-    Bookmark, BookmarkId, BookmarkSearch, BookmarkSearchCriteria, Tag, TagId, Transaction,
-    TransactionMode, UserId,
+    Bookmark, BookmarkCursor, BookmarkId, BookmarkOrder, BookmarkSearch, FullTextQuery, ListError,
+    ListedBookmark, Tag, TagId, Transaction, TransactionMode, UserId,
 };
 
 /// # Queries relevant to the `lz` web app
@@ -46,41 +48,105 @@ impl<M: TransactionMode> Transaction<M> {
         // inefficient, but at "normal" numbers of bookmarks and tags,
         // sqlite can get a pretty fast query plan out of it.
         qb.push(" JOIN (");
-        let mut sep = qb.separated(" INTERSECT ");
-        for criterium in criteria.iter() {
-            sep = criterium.bookmarks_join_table(sep);
-        }
-        // A query for "all" bookmarks to ensure the JOIN works
-        // even if no criteria were given:
-        sep.push("SELECT bookmark_id FROM bookmarks");
+        push_join_tables(&mut qb, criteria);
         qb.push(") USING (bookmark_id)");
 
         // Limit the bookmarks by any "additional" criteria that might
         // apply (creation, user ID, and of course, pagination):
         qb.push(" WHERE ");
         if let Some(last_seen) = last_seen {
-            qb.push("created_at <= (SELECT created_at FROM bookmarks WHERE bookmark_id = ");
+            // Bookmarks can share a creation time, so the position
+            // includes the ID:
+            qb.push("(bookmarks.created_at, bookmarks.bookmark_id) <= (SELECT created_at, bookmark_id FROM bookmarks WHERE bookmark_id = ");
             qb.push_bind(last_seen);
             qb.push(") ");
             qb.push(" AND ");
         }
-        if !criteria.is_empty() {
-            qb.push("(");
-            let mut sep = qb.separated(") AND (");
-            for criterium in criteria.iter() {
-                sep = criterium.where_clause(sep);
-            }
-            qb.push(")");
-        } else {
-            qb.push("1=1");
-        }
-        qb.push(" ORDER BY created_at DESC, bookmark_id DESC LIMIT ");
+        push_where_clauses(&mut qb, criteria);
+        BookmarkOrder::Created.push_order_by(&mut qb);
+        qb.push(" LIMIT ");
         qb.push_bind(page_size + 1);
 
         tracing::debug!(sql = qb.sql());
         qb.build_query_as().fetch_all(&mut *self.txn).await
     }
 
+    /// Retrieve bookmarks matching the given criteria in the given
+    /// order, paginated with [cursors](BookmarkCursor).
+    ///
+    /// As with [`list_bookmarks_matching`](Self::list_bookmarks_matching),
+    /// one more bookmark than requested gets returned if there are
+    /// more; its cursor is the one for the next page.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn list_bookmarks_in_order(
+        &mut self,
+        criteria: &[BookmarkSearch],
+        order: BookmarkOrder,
+        page_size: u16,
+        cursor: Option<&BookmarkCursor>,
+    ) -> Result<Vec<ListedBookmark>, ListError> {
+        // Relevance is that of the full-text search; without one (or
+        // with one that has no words), all bookmarks would be equally
+        // relevant:
+        let full_text: Vec<String> = criteria
+            .iter()
+            .filter_map(|criterion| match criterion {
+                BookmarkSearch::FullText { query } => Some(query.to_fts5()),
+                _ => None,
+            })
+            .filter(|query| !query.is_empty())
+            .collect();
+        let order = match order {
+            BookmarkOrder::Relevance if full_text.is_empty() => BookmarkOrder::Created,
+            order => order,
+        };
+        let position = cursor.map(BookmarkCursor::position).transpose()?;
+        if position.as_ref().is_some_and(|p| p.order != order) {
+            return Err(ListError::InvalidCursor);
+        }
+
+        let mut qb = QueryBuilder::new("SELECT bookmarks.*, urls.link AS url, ");
+        qb.push(order.key_expression());
+        qb.push(" AS sort_key FROM bookmarks JOIN urls USING (url_id)");
+        qb.push(" JOIN (");
+        push_join_tables(&mut qb, criteria);
+        qb.push(") USING (bookmark_id)");
+        if order == BookmarkOrder::Relevance {
+            qb.push(" JOIN (SELECT rowid AS bookmark_id, rank FROM bookmarks_fts WHERE bookmarks_fts MATCH ");
+            qb.push_bind(full_text.join(" "));
+            qb.push(") AS relevance USING (bookmark_id)");
+        }
+        qb.push(" WHERE ");
+        if let Some(position) = &position {
+            position.push_from_here(&mut qb);
+            qb.push(" AND ");
+        }
+        push_where_clauses(&mut qb, criteria);
+        order.push_order_by(&mut qb);
+        qb.push(" LIMIT ");
+        qb.push_bind(page_size + 1);
+
+        #[derive(FromRow)]
+        struct Row {
+            #[sqlx(flatten)]
+            bookmark: Bookmark<BookmarkId, UserId>,
+            sort_key: SortKey,
+        }
+        tracing::debug!(sql = qb.sql());
+        let rows: Vec<Row> = qb.build_query_as().fetch_all(&mut *self.txn).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| ListedBookmark {
+                cursor: BookmarkCursor::new(&Position {
+                    order,
+                    key: row.sort_key,
+                    id: row.bookmark.id,
+                }),
+                bookmark: row.bookmark,
+            })
+            .collect())
+    }
+
     #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
     pub async fn tags_on_bookmarks<
         I: IntoIterator<Item = B, IntoIter = C> + Clone + fmt::Debug,
@@ -130,15 +196,67 @@ impl<M: TransactionMode> Transaction<M> {
     ) -> Result<Vec<Tag<TagId>>, sqlx::Error> {
         sqlx::query_as(
             r#"
-          SELECT * from tags WHERE name LIKE ?
+          SELECT * from tags WHERE user_id = ? AND (
+            name LIKE ?
+            OR tag_id IN (SELECT tag_id FROM tag_aliases WHERE user_id = ? AND name LIKE ?)
+          )
         "#,
         )
+        .bind(self.user().id)
+        .bind(format!("%{tag_fragment}%"))
+        .bind(self.user().id)
         .bind(format!("%{tag_fragment}%"))
         .fetch_all(&mut *self.txn)
         .await
     }
 }
 
+impl<M: TransactionMode> Transaction<M> {
+    /// Retrieve excerpts of the bookmarks' text that match a full-text query.
+    ///
+    /// The excerpt comes from whichever field matched the query best,
+    /// with the matching words surrounded by `**`. Bookmarks that do
+    /// not match the query have no entry in the returned map.
+    #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(self))]
+    pub async fn full_text_snippets<
+        I: IntoIterator<Item = B, IntoIter = C> + Clone + fmt::Debug,
+        C: Clone + std::iter::Iterator<Item = B>,
+        B: Into<BookmarkId>,
+    >(
+        &mut self,
+        query: &FullTextQuery,
+        bms: I,
+    ) -> Result<HashMap<BookmarkId, String>, sqlx::Error> {
+        let mut bms = bms.into_iter().peekable();
+        let fts_query = query.to_fts5();
+        if bms.peek().is_none() || fts_query.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let mut qb = QueryBuilder::new(
+            r#"
+              SELECT rowid AS bookmark_id, snippet(bookmarks_fts, -1, '**', '**', '…', 16) AS snippet
+              FROM bookmarks_fts
+              WHERE bookmarks_fts MATCH "#,
+        );
+        qb.push_bind(fts_query);
+        qb.push(" AND rowid IN ");
+        qb.push_tuples(bms, |mut b, bm| {
+            b.push_bind(bm.into());
+        });
+
+        #[derive(FromRow)]
+        struct Snippet {
+            bookmark_id: BookmarkId,
+            snippet: String,
+        }
+        let snippets: Vec<Snippet> = qb.build_query_as().fetch_all(&mut *self.txn).await?;
+        Ok(snippets
+            .into_iter()
+            .map(|s| (s.bookmark_id, s.snippet))
+            .collect())
+    }
+}
+
 /// A link associated with a bookmark.
 ///
 /// Links can have a "context" in which that association happens
@@ -195,6 +313,8 @@ impl<M: TransactionMode> Transaction<M> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use anyhow::Context as _;
     use test_context::test_context;
     use testresult::TestResult;
@@ -267,16 +387,692 @@ mod tests {
             .await
             .with_context(|| "adding backdated bookmark".to_string())?;
 
-        let bookmarks_batch_1 = txn
-            .list_bookmarks_matching(&vec![], page_size, None)
-            .await?;
+        let bookmarks_batch_1 = txn.list_bookmarks_matching(&[], page_size, None).await?;
         assert_eq!(bookmarks_batch_1.len(), (page_size + 1) as usize);
 
         let bookmarks_batch_2 = txn
-            .list_bookmarks_matching(&vec![], page_size, bookmarks_batch_1.last().map(|bm| bm.id))
+            .list_bookmarks_matching(&[], page_size, bookmarks_batch_1.last().map(|bm| bm.id))
             .await?;
         assert_eq!(bookmarks_batch_2.len(), 10);
         assert_eq!(bookmarks_batch_2.last().map(|bm| bm.id), Some(backdated.id));
         Ok(())
     }
+
+    async fn page_through(
+        txn: &mut Transaction,
+        criteria: &[BookmarkSearch],
+        order: BookmarkOrder,
+    ) -> TestResult<Vec<ExistingBookmark>> {
+        let page_size = 2;
+        let mut listed = vec![];
+        let mut cursor = None;
+        loop {
+            let mut page = txn
+                .list_bookmarks_in_order(criteria, order, page_size, cursor.as_ref())
+                .await?;
+            if page.len() > usize::from(page_size) {
+                cursor = page.pop().map(|next| next.cursor);
+                listed.extend(page.into_iter().map(|listed| listed.bookmark));
+            } else {
+                listed.extend(page.into_iter().map(|listed| listed.bookmark));
+                return Ok(listed);
+            }
+        }
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn paginate_in_order(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        // Imported bookmarks often share their creation time:
+        let created_at = chrono::DateTime::default();
+        let titles = [
+            "banana",
+            "Apple",
+            "cherry pie",
+            "apple pie",
+            "Date",
+            "elderberry",
+            "fig",
+        ];
+        let mut ids = vec![];
+        for (i, title) in titles.iter().enumerate() {
+            let later = |days| created_at.checked_add_days(chrono::Days::new(days));
+            let bookmark = txn
+                .add_bookmark(Bookmark {
+                    id: NoId,
+                    user_id: NoId,
+                    created_at,
+                    modified_at: if i % 2 == 0 { later(i as u64) } else { None },
+                    accessed_at: if i % 3 == 0 {
+                        later(10 - i as u64)
+                    } else {
+                        None
+                    },
+                    url: Url::parse(&format!("https://example.com/{i}"))?,
+                    title: title.to_string(),
+                    description: None,
+                    website_title: None,
+                    website_description: None,
+                    notes: Some(format!("fruit {}", "pie ".repeat(i))),
+                    import_properties: None,
+                    shared: false,
+                    unread: false,
+                })
+                .await?;
+            ids.push(bookmark.id);
+        }
+
+        // Listing by ID cursor doesn't repeat bookmarks with the same timestamp:
+        let mut seen = vec![];
+        let mut last_seen = None;
+        loop {
+            let page = txn.list_bookmarks_matching(&[], 2, last_seen).await?;
+            seen.extend(page.iter().take(2).map(|bm| bm.id));
+            last_seen = page.get(2).map(|bm| bm.id);
+            if last_seen.is_none() {
+                break;
+            }
+        }
+        assert_eq!(seen, ids.iter().rev().cloned().collect::<Vec<_>>());
+
+        let titles_in = |listed: Vec<ExistingBookmark>| -> Vec<String> {
+            listed.into_iter().map(|bm| bm.title).collect()
+        };
+        assert_eq!(
+            titles_in(page_through(&mut txn, &[], BookmarkOrder::Created).await?),
+            vec![
+                "fig",
+                "elderberry",
+                "Date",
+                "apple pie",
+                "cherry pie",
+                "Apple",
+                "banana"
+            ]
+        );
+        assert_eq!(
+            titles_in(page_through(&mut txn, &[], BookmarkOrder::Modified).await?),
+            vec![
+                "fig",
+                "Date",
+                "cherry pie",
+                "elderberry",
+                "apple pie",
+                "Apple",
+                "banana"
+            ]
+        );
+        assert_eq!(
+            titles_in(page_through(&mut txn, &[], BookmarkOrder::Accessed).await?),
+            vec![
+                "banana",
+                "apple pie",
+                "fig",
+                "elderberry",
+                "Date",
+                "cherry pie",
+                "Apple"
+            ]
+        );
+        assert_eq!(
+            titles_in(page_through(&mut txn, &[], BookmarkOrder::Title).await?),
+            vec![
+                "Apple",
+                "apple pie",
+                "banana",
+                "cherry pie",
+                "Date",
+                "elderberry",
+                "fig"
+            ]
+        );
+
+        // The more often "pie" occurs, the more relevant the bookmark
+        // (`apple pie` and `Date` have four each):
+        let pie = [BookmarkSearch::FullText {
+            query: FullTextQuery::from("pie"),
+        }];
+        assert_eq!(
+            titles_in(page_through(&mut txn, &pie, BookmarkOrder::Relevance).await?),
+            vec![
+                "fig",
+                "elderberry",
+                "apple pie",
+                "Date",
+                "cherry pie",
+                "Apple"
+            ]
+        );
+        // Without a full-text search, relevance is recency:
+        assert_eq!(
+            page_through(&mut txn, &[], BookmarkOrder::Relevance).await?,
+            page_through(&mut txn, &[], BookmarkOrder::Created).await?
+        );
+        // ...as it is with a search without any words, which matches
+        // nothing:
+        let blank = [BookmarkSearch::FullText {
+            query: FullTextQuery::from(r#" "" "#),
+        }];
+        assert!(page_through(&mut txn, &blank, BookmarkOrder::Relevance)
+            .await?
+            .is_empty());
+
+        // Cursors only work in the order they came from:
+        let page = txn
+            .list_bookmarks_in_order(&[], BookmarkOrder::Created, 2, None)
+            .await?;
+        assert!(matches!(
+            txn.list_bookmarks_in_order(&[], BookmarkOrder::Title, 2, Some(&page[2].cursor))
+                .await,
+            Err(ListError::InvalidCursor)
+        ));
+        assert!(matches!(
+            txn.list_bookmarks_in_order(
+                &[],
+                BookmarkOrder::Created,
+                2,
+                Some(&BookmarkCursor::from("nonsense".to_string()))
+            )
+            .await,
+            Err(ListError::InvalidCursor)
+        ));
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn order_by_time_across_formats(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        for (i, (title, created_at)) in [
+            ("early", "2024-01-05T01:00:00Z"),
+            ("morning", "2024-01-05T09:00:00Z"),
+            ("evening", "2024-01-05T18:00:00Z"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            txn.add_bookmark(Bookmark {
+                id: NoId,
+                user_id: NoId,
+                created_at: created_at.parse()?,
+                modified_at: None,
+                accessed_at: None,
+                url: Url::parse(&format!("https://example.com/{i}"))?,
+                title: title.to_string(),
+                description: None,
+                website_title: None,
+                website_description: None,
+                notes: None,
+                import_properties: None,
+                shared: false,
+                unread: false,
+            })
+            .await?;
+        }
+        // Modifications and accesses are stored in SQLite's own format,
+        // which doesn't sort against RFC 3339 as text:
+        sqlx::query(
+            r#"UPDATE bookmarks
+               SET modified_at = '2024-01-05 23:00:00', accessed_at = '2024-01-05 12:00:00'
+               WHERE title = 'early'"#,
+        )
+        .execute(&mut *txn.txn)
+        .await?;
+        sqlx::query(
+            r#"UPDATE bookmarks SET accessed_at = '2024-01-05T10:00:00+00:00' WHERE title = 'evening'"#,
+        )
+        .execute(&mut *txn.txn)
+        .await?;
+
+        let titles_in = |listed: Vec<ExistingBookmark>| -> Vec<String> {
+            listed.into_iter().map(|bm| bm.title).collect()
+        };
+        assert_eq!(
+            titles_in(page_through(&mut txn, &[], BookmarkOrder::Modified).await?),
+            vec!["early", "evening", "morning"]
+        );
+        assert_eq!(
+            titles_in(page_through(&mut txn, &[], BookmarkOrder::Accessed).await?),
+            vec!["early", "evening", "morning"]
+        );
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn full_text_search(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let mut lz = txn
+            .add_bookmark(Bookmark {
+                id: NoId,
+                user_id: NoId,
+                created_at: Default::default(),
+                modified_at: None,
+                accessed_at: None,
+                url: Url::parse("https://github.com/lz-bookmarks/lz")?,
+                title: "The lz repo".to_string(),
+                description: Some("A tagged bookmark manager".to_string()),
+                website_title: None,
+                website_description: None,
+                notes: Some("Reminds me of del.icio.us".to_string()),
+                import_properties: None,
+                shared: true,
+                unread: true,
+            })
+            .await?;
+        let wikipedia = txn
+            .add_bookmark(Bookmark {
+                id: NoId,
+                user_id: NoId,
+                created_at: Default::default(),
+                modified_at: None,
+                accessed_at: None,
+                url: Url::parse("https://en.wikipedia.org/wiki/Bookmark_(digital)")?,
+                title: "Bookmark (digital) - Wikipedia".to_string(),
+                description: None,
+                website_title: None,
+                website_description: None,
+                notes: None,
+                import_properties: None,
+                shared: true,
+                unread: true,
+            })
+            .await?;
+        let search = |text: &str| {
+            vec![BookmarkSearch::FullText {
+                query: FullTextQuery::from(text),
+            }]
+        };
+
+        let found = txn
+            .list_bookmarks_matching(&search("bookmark"), 10, None)
+            .await?;
+        assert_eq!(found.len(), 2);
+
+        // All words have to match, in any of the fields:
+        let found = txn
+            .list_bookmarks_matching(&search("tagged delicious"), 10, None)
+            .await?;
+        assert!(found.is_empty());
+        let found = txn
+            .list_bookmarks_matching(&search("tagged del.icio.us"), 10, None)
+            .await?;
+        assert_eq!(
+            found.iter().map(|bm| bm.id).collect::<Vec<_>>(),
+            vec![lz.id]
+        );
+
+        // URLs are indexed, and prefixes match:
+        let found = txn
+            .list_bookmarks_matching(&search("wikiped*"), 10, None)
+            .await?;
+        assert_eq!(
+            found.iter().map(|bm| bm.id).collect::<Vec<_>>(),
+            vec![wikipedia.id]
+        );
+
+        // Queries without any words match nothing:
+        for empty in ["", "   ", r#""""#, r#"" ""#] {
+            let found = txn
+                .list_bookmarks_matching(&search(empty), 10, None)
+                .await?;
+            assert!(found.is_empty(), "{empty:?} matched {found:?}");
+            let snippets = txn
+                .full_text_snippets(&FullTextQuery::from(empty), [lz.id])
+                .await?;
+            assert!(snippets.is_empty());
+        }
+
+        // Updates are reflected in the index:
+        lz.notes = Some("Reminds me of pinboard".to_string());
+        txn.update_bookmark(&lz).await?;
+        let found = txn
+            .list_bookmarks_matching(&search("pinboard"), 10, None)
+            .await?;
+        assert_eq!(
+            found.iter().map(|bm| bm.id).collect::<Vec<_>>(),
+            vec![lz.id]
+        );
+        let snippets = txn
+            .full_text_snippets(&FullTextQuery::from("pinboard"), &found)
+            .await?;
+        assert_eq!(
+            snippets.get(&lz.id).map(String::as_str),
+            Some("Reminds me of **pinboard**")
+        );
+
+        // ...and so are deletions:
+        txn.delete_bookmark(wikipedia.id).await?;
+        let found = txn
+            .list_bookmarks_matching(&search("bookmark"), 10, None)
+            .await?;
+        assert_eq!(
+            found.iter().map(|bm| bm.id).collect::<Vec<_>>(),
+            vec![lz.id]
+        );
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn boolean_criteria(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let mut ids = HashMap::new();
+        for (name, tags) in [
+            ("a", vec!["rust", "async"]),
+            ("b", vec!["rust", "tokio"]),
+            ("c", vec!["rust", "async", "archived"]),
+            ("d", vec!["python", "async"]),
+            ("e", vec![]),
+        ] {
+            let bm = txn
+                .add_bookmark(Bookmark {
+                    id: NoId,
+                    user_id: NoId,
+                    created_at: Default::default(),
+                    modified_at: None,
+                    accessed_at: None,
+                    url: Url::parse(&format!("https://example.com/{name}"))?,
+                    title: name.to_string(),
+                    description: None,
+                    website_title: None,
+                    website_description: None,
+                    notes: None,
+                    import_properties: None,
+                    shared: true,
+                    unread: true,
+                })
+                .await?;
+            let tags = txn.ensure_tags(tags).await?;
+            txn.set_bookmark_tags(bm.id, tags).await?;
+            ids.insert(bm.id, name);
+        }
+        let tag = |name: &str| BookmarkSearch::TagByName {
+            tag: TagName::from(name),
+        };
+        async fn names_matching<'a>(
+            txn: &mut Transaction,
+            ids: &HashMap<BookmarkId, &'a str>,
+            criteria: &[BookmarkSearch],
+        ) -> anyhow::Result<Vec<&'a str>> {
+            let mut names: Vec<&str> = txn
+                .list_bookmarks_matching(criteria, 10, None)
+                .await?
+                .iter()
+                .map(|bm| ids[&bm.id])
+                .collect();
+            names.sort();
+            Ok(names)
+        }
+
+        // rust AND (async OR tokio) AND NOT archived:
+        let query = vec![
+            tag("rust"),
+            BookmarkSearch::Any {
+                of: vec![tag("async"), tag("tokio")],
+            },
+            BookmarkSearch::Not {
+                criterion: Box::new(tag("archived")),
+            },
+        ];
+        assert_eq!(
+            names_matching(&mut txn, &ids, &query).await?,
+            vec!["a", "b"]
+        );
+
+        // NOT rust:
+        let query = vec![BookmarkSearch::Not {
+            criterion: Box::new(tag("rust")),
+        }];
+        assert_eq!(
+            names_matching(&mut txn, &ids, &query).await?,
+            vec!["d", "e"]
+        );
+
+        // (rust AND archived) OR python, with a grouping:
+        let query = vec![BookmarkSearch::Any {
+            of: vec![
+                BookmarkSearch::All {
+                    of: vec![tag("rust"), tag("archived")],
+                },
+                tag("python"),
+            ],
+        }];
+        assert_eq!(
+            names_matching(&mut txn, &ids, &query).await?,
+            vec!["c", "d"]
+        );
+
+        // Combinations respect the direct criteria of what they combine:
+        let me = txn.user().id;
+        let query = vec![BookmarkSearch::Not {
+            criterion: Box::new(BookmarkSearch::User { id: me }),
+        }];
+        assert_eq!(
+            names_matching(&mut txn, &ids, &query).await?,
+            Vec::<&str>::new()
+        );
+
+        // Any of nothing matches nothing:
+        let query = vec![BookmarkSearch::Any { of: vec![] }];
+        assert_eq!(
+            names_matching(&mut txn, &ids, &query).await?,
+            Vec::<&str>::new()
+        );
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn hierarchical_tags(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let mut ids = HashMap::new();
+        for (name, tags) in [
+            ("a", vec!["lang/rust/async"]),
+            ("b", vec!["lang/rust"]),
+            ("c", vec!["Lang/Rustacean"]),
+            ("d", vec!["lang/python", "lang/rust/async"]),
+            ("e", vec!["langs"]),
+        ] {
+            let bm = txn
+                .add_bookmark(Bookmark {
+                    id: NoId,
+                    user_id: NoId,
+                    created_at: Default::default(),
+                    modified_at: None,
+                    accessed_at: None,
+                    url: Url::parse(&format!("https://example.com/{name}"))?,
+                    title: name.to_string(),
+                    description: None,
+                    website_title: None,
+                    website_description: None,
+                    notes: None,
+                    import_properties: None,
+                    shared: true,
+                    unread: true,
+                })
+                .await?;
+            let tags = txn.ensure_tags(tags).await?;
+            txn.set_bookmark_tags(bm.id, tags).await?;
+            ids.insert(bm.id, name);
+        }
+        for (criterion, expected) in [
+            (
+                BookmarkSearch::TagByName {
+                    tag: TagName::from("lang/rust"),
+                },
+                vec!["b"],
+            ),
+            (
+                BookmarkSearch::TagPrefix {
+                    tag: TagName::from("lang/rust"),
+                },
+                vec!["a", "b", "d"],
+            ),
+            (
+                BookmarkSearch::TagPrefix {
+                    tag: TagName::from("lang/rust/"),
+                },
+                vec!["a", "b", "d"],
+            ),
+            (
+                BookmarkSearch::TagPrefix {
+                    tag: TagName::from("lang"),
+                },
+                vec!["a", "b", "c", "d"],
+            ),
+            (
+                BookmarkSearch::TagPrefix {
+                    tag: TagName::from("lang/rust/async"),
+                },
+                vec!["a", "d"],
+            ),
+            (
+                BookmarkSearch::TagPrefix {
+                    tag: TagName::from("LANG/rustacean"),
+                },
+                vec!["c"],
+            ),
+        ] {
+            let mut names: Vec<&str> = txn
+                .list_bookmarks_matching(std::slice::from_ref(&criterion), 10, None)
+                .await?
+                .iter()
+                .map(|bm| ids[&bm.id])
+                .collect();
+            names.sort();
+            assert_eq!(names, expected, "{criterion:?}");
+        }
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn site_and_flag_criteria(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let mut ids = HashMap::new();
+        for (name, url, unread) in [
+            ("a", "https://github.com/lz-bookmarks/lz", true),
+            ("b", "https://gist.github.com/someone", false),
+            ("c", "https://github.com.example.org/", false),
+            ("d", "http://github.com:8080", true),
+            ("e", "https://example.com/github.com/", false),
+        ] {
+            let bm = txn
+                .add_bookmark(Bookmark {
+                    id: NoId,
+                    user_id: NoId,
+                    created_at: Default::default(),
+                    modified_at: None,
+                    accessed_at: None,
+                    url: Url::parse(url)?,
+                    title: name.to_string(),
+                    description: None,
+                    website_title: None,
+                    website_description: None,
+                    notes: None,
+                    import_properties: None,
+                    shared: true,
+                    unread,
+                })
+                .await?;
+            ids.insert(bm.id, name);
+        }
+        for (query, expected) in [
+            ("site:github.com", vec!["a", "b", "d"]),
+            ("site:GIST.github.com", vec!["b"]),
+            ("site:github.com unread:true", vec!["a", "d"]),
+            ("-site:github.com unread:no", vec!["c", "e"]),
+        ] {
+            let criteria = parse_query(query)?;
+            let mut names: Vec<&str> = txn
+                .list_bookmarks_matching(&criteria, 10, None)
+                .await?
+                .iter()
+                .map(|bm| ids[&bm.id])
+                .collect();
+            names.sort();
+            assert_eq!(names, expected, "{query}");
+        }
+        Ok(())
+    }
+
+    #[test_context(Context)]
+    #[tokio::test]
+    async fn date_criteria(ctx: &mut Context) -> TestResult {
+        let mut txn = ctx.begin().await?;
+        let at = |timestamp: &str| -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
+            Ok(chrono::DateTime::parse_from_rfc3339(timestamp)?.into())
+        };
+        let mut ids = HashMap::new();
+        for (name, created, modified, accessed) in [
+            (
+                "a",
+                "2024-01-01T10:00:00Z",
+                Some("2024-02-01T23:30:00Z"),
+                None,
+            ),
+            ("b", "2024-01-02T00:30:00Z", None, None),
+            (
+                "c",
+                "2024-01-03T12:00:00Z",
+                Some("2024-02-02T12:00:00Z"),
+                Some("2024-03-02T00:00:00Z"),
+            ),
+        ] {
+            let bm = txn
+                .add_bookmark(Bookmark {
+                    id: NoId,
+                    user_id: NoId,
+                    created_at: at(created)?,
+                    modified_at: modified.map(at).transpose()?,
+                    accessed_at: accessed.map(at).transpose()?,
+                    url: Url::parse(&format!("https://example.com/{name}"))?,
+                    title: name.to_string(),
+                    description: None,
+                    website_title: None,
+                    website_description: None,
+                    notes: None,
+                    import_properties: None,
+                    shared: false,
+                    unread: false,
+                })
+                .await?;
+            ids.insert(bm.id, name);
+        }
+        // SQLite's own format for timestamps works too:
+        sqlx::query(
+            r#"UPDATE bookmarks SET accessed_at = '2024-03-01 12:00:00' WHERE title = 'b'"#,
+        )
+        .execute(&mut *txn.txn)
+        .await?;
+
+        for (tz, query, expected) in [
+            ("UTC", "on:2024-01-02", vec!["b"]),
+            ("UTC", "before:2024-01-02", vec!["a", "b"]),
+            ("UTC", "after:2024-01-02", vec!["b", "c"]),
+            ("UTC", "-modified_after:2000-01-01", vec!["b"]),
+            ("UTC", "modified_on:2024-02-02", vec!["c"]),
+            ("Pacific/Auckland", "modified_on:2024-02-02", vec!["a"]),
+            ("UTC", "accessed_before:2024-03-01T12:00:00Z", vec!["b"]),
+            ("UTC", "accessed_on:2024-03-01T13:00:00+01:00", vec!["b"]),
+            ("UTC", "accessed_after:7d", vec![]),
+            ("UTC", "after:last-year OR before:10000d", vec![]),
+        ] {
+            let mut criteria = parse_query(query)?;
+            for criterion in &mut criteria {
+                criterion.default_timezone(tz.parse()?);
+            }
+            let mut names: Vec<&str> = txn
+                .list_bookmarks_matching(&criteria, 10, None)
+                .await?
+                .iter()
+                .map(|bm| ids[&bm.id])
+                .collect();
+            names.sort();
+            assert_eq!(names, expected, "{query} in {tz}");
+        }
+        Ok(())
+    }
 }
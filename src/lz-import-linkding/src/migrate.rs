@@ -42,7 +42,8 @@ impl<'c> Migration<'c> {
     ///
     /// All created bookmarks get their import_properties.by_system
     /// JSON object filled with a `linkding` property, containing all
-    /// the fields we recognized.
+    /// the fields we recognized. Their Wayback Machine snapshots get
+    /// associated with them under the [`lz_db::WAYBACK_CONTEXT`].
     #[tracing::instrument(skip(self))]
     pub async fn migrate(mut self) -> anyhow::Result<()> {
         self.translate_tags().await.context("translating tags")?;
@@ -100,6 +101,7 @@ impl<'c> Migration<'c> {
                     DuplicateBehavior::Overwrite => {
                         bookmark.overwrite_into_lz_bookmark(&mut existing);
                         self.db.update_bookmark(&existing).await?;
+                        Self::associate_snapshot(&mut self.db, existing.id, &bookmark).await?;
                         self.bookmark_ids.insert(bookmark.id, existing.id);
                         tracing::debug!(url=%bookmark.url, id=?existing.id, "overwrote bookmark");
                     }
@@ -111,12 +113,33 @@ impl<'c> Migration<'c> {
                 tracing::error!(url=%bookmark.url, error=%e, error_debug=?e, "Could not add bookmark");
                 e
             })?;
+            Self::associate_snapshot(&mut self.db, added.id, &bookmark).await?;
             self.bookmark_ids.insert(bookmark.id, added.id);
             tracing::debug!(url=%to_add.url, ?added.id, "added bookmark");
         }
         Ok(())
     }
 
+    /// Associate the bookmark's web archive snapshot with its lz
+    /// counterpart, unless it already is.
+    async fn associate_snapshot(
+        db: &mut lz_db::Transaction,
+        id: lz_db::BookmarkId,
+        bookmark: &schema::Bookmark,
+    ) -> Result<(), sqlx::Error> {
+        let Some(snapshot) = bookmark.web_archive_snapshot() else {
+            return Ok(());
+        };
+        let url_id = db.ensure_url(snapshot).await?;
+        match db
+            .associate_bookmark_link(&id, &url_id, Some(lz_db::WAYBACK_CONTEXT))
+            .await
+        {
+            Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => Ok(()),
+            res => res,
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     async fn tag_bookmarks(&mut self) -> Result<(), sqlx::Error> {
         let mut tagging_stream = pin::pin!(self.linkding_tx.all_taggings().peekable());
@@ -158,3 +181,72 @@ impl<'c> Migration<'c> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::SqlitePoolOptions;
+    use testresult::TestResult;
+    use url::Url;
+
+    use super::*;
+
+    fn linkding_bookmark(url: &str, snapshot: &str) -> schema::Bookmark {
+        schema::Bookmark {
+            id: 1,
+            owner_id: 1,
+            date_added: Default::default(),
+            date_modified: None,
+            date_accessed: None,
+            url: Url::parse(url).unwrap(),
+            title: "A bookmark".to_string(),
+            description: None,
+            notes: None,
+            website_title: None,
+            website_description: None,
+            unread: false,
+            web_archive_snapshot_url: snapshot.try_into().unwrap(),
+            favicon_file: String::new(),
+            is_archived: false,
+            shared: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn associates_snapshots() -> TestResult {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        sqlx::migrate!("../lz-db/migrations").run(&pool).await?;
+        let mut db = lz_db::Connection::from_pool(pool)
+            .begin_for_user("tester")
+            .await?;
+
+        let snapshotted = linkding_bookmark(
+            "https://example.com/",
+            "https://web.archive.org/web/20240101000000/https://example.com/",
+        );
+        let snapshotted_id = db.add_bookmark(snapshotted.as_lz_bookmark()).await?.id;
+        let plain = linkding_bookmark("https://example.org/", "");
+        let plain_id = db.add_bookmark(plain.as_lz_bookmark()).await?.id;
+
+        // Associating the same snapshot again (as when overwriting a
+        // duplicate) is fine:
+        Migration::associate_snapshot(&mut db, snapshotted_id, &snapshotted).await?;
+        Migration::associate_snapshot(&mut db, snapshotted_id, &snapshotted).await?;
+        Migration::associate_snapshot(&mut db, plain_id, &plain).await?;
+
+        let associations = db
+            .associated_links_on_bookmarks([snapshotted_id, plain_id])
+            .await?;
+        assert_eq!(
+            associations.get(&snapshotted_id),
+            Some(&vec![lz_db::AssociatedLink {
+                context: Some(lz_db::WAYBACK_CONTEXT.to_string()),
+                link: snapshotted.web_archive_snapshot().unwrap().clone(),
+            }])
+        );
+        assert_eq!(associations.get(&plain_id), None);
+        Ok(())
+    }
+}
@@ -19,16 +19,16 @@ impl<'c> LinkdingTransaction<'c> {
 }
 
 impl<'c> LinkdingTransaction<'c> {
-    pub(crate) fn all_tags(&mut self) -> BoxStream<Result<Tag, sqlx::Error>> {
+    pub(crate) fn all_tags(&mut self) -> BoxStream<'_, Result<Tag, sqlx::Error>> {
         sqlx::query_as(r#"SELECT * FROM bookmarks_tag ORDER BY date_added"#).fetch(&mut *self.txn)
     }
 
-    pub(crate) fn all_bookmarks(&mut self) -> BoxStream<Result<Bookmark, sqlx::Error>> {
+    pub(crate) fn all_bookmarks(&mut self) -> BoxStream<'_, Result<Bookmark, sqlx::Error>> {
         sqlx::query_as(r#"SELECT * FROM bookmarks_bookmark ORDER BY date_added, id"#)
             .fetch(&mut *self.txn)
     }
 
-    pub(crate) fn all_taggings(&mut self) -> BoxStream<Result<BookmarkTag, sqlx::Error>> {
+    pub(crate) fn all_taggings(&mut self) -> BoxStream<'_, Result<BookmarkTag, sqlx::Error>> {
         sqlx::query_as(r#"SELECT * FROM bookmarks_bookmark_tags ORDER BY bookmark_id, id"#)
             .fetch(&mut *self.txn)
     }
@@ -82,6 +82,11 @@ pub(crate) struct Bookmark {
 }
 
 impl Bookmark {
+    /// The Wayback Machine snapshot that linkding recorded, if any.
+    pub fn web_archive_snapshot(&self) -> Option<&Url> {
+        self.web_archive_snapshot_url.0.as_ref()
+    }
+
     pub fn as_lz_bookmark(&self) -> lz_db::Bookmark<lz_db::NoId, lz_db::NoId> {
         let url = self.url.clone();
         let mut other = lz_db::Bookmark {
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use clap::Parser;
+pub use lz_db::DuplicateBehavior;
+
+pub mod schema;
+
+pub mod migrate;
+pub use migrate::Migration;
+
+/// Arguments for the Pinboard import
+#[derive(Clone, Debug, PartialEq, Eq, Parser)]
+pub struct Args {
+    /// The JSON file to import, as returned by Pinboard's
+    /// `posts/all?format=json` API endpoint
+    pinboard_export: PathBuf,
+
+    /// The username on the lz side to import as.
+    #[clap(long)]
+    user: String,
+}
+
+/// Start the import from the Pinboard export
+pub async fn run(
+    lz: lz_db::Connection,
+    args: &Args,
+    on_duplicate: DuplicateBehavior,
+) -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let file = std::fs::File::open(&args.pinboard_export)
+        .with_context(|| format!("origin file {}", args.pinboard_export.to_string_lossy()))?;
+    let posts: Vec<schema::Post> = serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("parsing {}", args.pinboard_export.to_string_lossy()))?;
+    tracing::info!(post_count = posts.len(), "Posts read");
+    let tx = lz.begin_for_user(&args.user).await?;
+    Migration::new(tx, on_duplicate).migrate(posts).await?;
+    Ok(())
+}
@@ -0,0 +1,143 @@
+//! Routines that translate Pinboard's posts into our bookmarks.
+
+use anyhow::Context as _;
+use lz_db::DuplicateBehavior;
+use url::Url;
+
+use crate::schema::Post;
+
+pub struct Migration {
+    db: lz_db::Transaction,
+    on_duplicate: DuplicateBehavior,
+}
+
+impl Migration {
+    pub fn new(db: lz_db::Transaction, on_duplicate: DuplicateBehavior) -> Self {
+        Self { db, on_duplicate }
+    }
+
+    /// Run the migration from a Pinboard export into lz.
+    ///
+    /// Each post gets created as a bookmark (skipping or overwriting
+    /// duplicates) and tagged with its tags; posts whose link isn't a
+    /// valid URL are skipped with a warning. All created bookmarks
+    /// get their import_properties.by_system JSON object filled with
+    /// a `Pinboard` property, containing the post as exported.
+    #[tracing::instrument(skip(self, posts))]
+    pub async fn migrate(mut self, posts: Vec<Post>) -> anyhow::Result<()> {
+        let mut imported = 0;
+        for post in posts {
+            let url = match post.url() {
+                Ok(url) => url,
+                Err(e) => {
+                    tracing::warn!(href=?post.href, error=%e, "Skipping post with an invalid URL");
+                    continue;
+                }
+            };
+            if self
+                .translate_post(&post, &url)
+                .await
+                .with_context(|| format!("importing {}", post.href))?
+            {
+                imported += 1;
+            }
+        }
+        tracing::info!(bookmark_count = imported, "Bookmarks imported");
+        self.db.commit().await?;
+        tracing::info!("Import done");
+        Ok(())
+    }
+
+    /// Add (or overwrite) a bookmark for the post and tag it. Returns
+    /// whether the post was imported.
+    async fn translate_post(&mut self, post: &Post, url: &Url) -> Result<bool, sqlx::Error> {
+        let id = match self.db.find_bookmark_with_url(url).await? {
+            Some(mut existing) => match self.on_duplicate {
+                DuplicateBehavior::Skip => {
+                    tracing::warn!(url=%url, existing_created_at=?existing.created_at, "Skipping duplicate bookmark");
+                    return Ok(false);
+                }
+                DuplicateBehavior::Overwrite => {
+                    post.overwrite_into_lz_bookmark(url, &mut existing);
+                    self.db.update_bookmark(&existing).await?;
+                    tracing::debug!(url=%url, id=?existing.id, "overwrote bookmark");
+                    existing.id
+                }
+            },
+            None => {
+                let added = self.db.add_bookmark(post.as_lz_bookmark(url)).await?;
+                tracing::debug!(url=%url, ?added.id, "added bookmark");
+                added.id
+            }
+        };
+        let tags = self.db.ensure_tags(post.tag_names()).await?;
+        self.db.set_bookmark_tags(id, tags).await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::SqlitePoolOptions;
+    use testresult::TestResult;
+
+    use super::*;
+
+    fn post(title: &str, tags: &str) -> Post {
+        Post {
+            href: "https://example.com/".to_string(),
+            description: title.to_string(),
+            extended: String::new(),
+            time: Default::default(),
+            shared: "no".to_string(),
+            toread: "no".to_string(),
+            tags: tags.to_string(),
+            other: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn duplicates() -> TestResult {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        sqlx::migrate!("../lz-db/migrations").run(&pool).await?;
+        let conn = lz_db::Connection::from_pool(pool);
+        let url = Url::parse("https://example.com/")?;
+
+        let import = |posts, on_duplicate| {
+            let conn = &conn;
+            async move {
+                let txn = conn.begin_for_user("tester").await?;
+                Migration::new(txn, on_duplicate).migrate(posts).await
+            }
+        };
+        import(vec![post("First", "a")], DuplicateBehavior::Skip).await?;
+
+        // Skipping leaves the bookmark (and its tags) alone:
+        import(vec![post("Second", "b")], DuplicateBehavior::Skip).await?;
+        let mut txn = conn.begin_for_user("tester").await?;
+        let bookmark = txn.find_bookmark_with_url(&url).await?.unwrap();
+        assert_eq!(bookmark.title, "First");
+        let tags = txn.get_bookmark_tags(bookmark.id).await?;
+        assert_eq!(
+            tags.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            vec!["a"]
+        );
+        txn.commit().await?;
+
+        // Overwriting updates the existing bookmark in place:
+        import(vec![post("Third", "c")], DuplicateBehavior::Overwrite).await?;
+        let mut txn = conn.begin_for_user("tester").await?;
+        let overwritten = txn.find_bookmark_with_url(&url).await?.unwrap();
+        assert_eq!(overwritten.id, bookmark.id);
+        assert_eq!(overwritten.title, "Third");
+        let tags = txn.get_bookmark_tags(bookmark.id).await?;
+        assert_eq!(
+            tags.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            vec!["c"]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+//! Pinboard's JSON export format
+//!
+//! This is what `https://api.pinboard.in/v1/posts/all?format=json`
+//! returns: An array of posts, each looking like this:
+//!
+//! ```json
+//! {
+//!   "href": "https://example.com/",
+//!   "description": "Title of the bookmark",
+//!   "extended": "Longer description",
+//!   "meta": "...",
+//!   "hash": "...",
+//!   "time": "2024-01-01T12:34:56Z",
+//!   "shared": "yes",
+//!   "toread": "no",
+//!   "tags": "space separated tags"
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A bookmark ("post") in Pinboard.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Post {
+    /// The bookmarked link. Pinboard doesn't check these, so it's
+    /// kept as text until [`url`](Self::url) parses it.
+    pub href: String,
+
+    /// The title of the bookmark.
+    pub description: String,
+
+    /// The user's description of the bookmark.
+    #[serde(default)]
+    pub extended: String,
+
+    pub time: chrono::DateTime<chrono::Utc>,
+
+    /// `"yes"` or `"no"`.
+    #[serde(default)]
+    pub shared: String,
+
+    /// `"yes"` or `"no"`.
+    #[serde(default)]
+    pub toread: String,
+
+    /// Space-separated list of tag names.
+    #[serde(default)]
+    pub tags: String,
+
+    /// All the other fields (`meta`, `hash`, ...) that Pinboard returns.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Post {
+    /// The bookmarked URL.
+    pub fn url(&self) -> Result<Url, url::ParseError> {
+        Url::parse(&self.href)
+    }
+
+    /// The names of the tags on the post.
+    pub fn tag_names(&self) -> std::str::SplitWhitespace<'_> {
+        self.tags.split_whitespace()
+    }
+
+    pub fn as_lz_bookmark(&self, url: &Url) -> lz_db::Bookmark<lz_db::NoId, lz_db::NoId> {
+        let mut other = lz_db::Bookmark {
+            id: lz_db::NoId,
+            user_id: lz_db::NoId,
+            url: url.clone(),
+            created_at: Default::default(),
+            modified_at: Default::default(),
+            accessed_at: Default::default(),
+            title: Default::default(),
+            description: Default::default(),
+            website_title: Default::default(),
+            website_description: Default::default(),
+            notes: Default::default(),
+            unread: Default::default(),
+            shared: Default::default(),
+            import_properties: Default::default(),
+        };
+        self.overwrite_into_lz_bookmark(url, &mut other);
+        other
+    }
+
+    pub fn overwrite_into_lz_bookmark<
+        ID: lz_db::IdType<lz_db::BookmarkId>,
+        UID: lz_db::IdType<lz_db::UserId>,
+    >(
+        &self,
+        url: &Url,
+        other: &mut lz_db::Bookmark<ID, UID>,
+    ) {
+        let mut import_properties = other
+            .import_properties
+            .take()
+            .map(|props| props.0)
+            .unwrap_or_else(|| lz_db::ImportProperties {
+                by_system: HashMap::new(),
+            });
+        import_properties.by_system.insert(
+            lz_db::ImportableSystem::Pinboard,
+            serde_json::to_value(self).expect("cleanly convert to serde_json::Value"),
+        );
+
+        other.created_at = self.time;
+        other.url = url.clone();
+        // Pinboard allows bookmarks without a title; we don't:
+        other.title = if self.description.is_empty() {
+            url.to_string()
+        } else {
+            self.description.clone()
+        };
+        other.description = Some(self.extended.clone()).filter(|e| !e.is_empty());
+        other.unread = self.toread == "yes";
+        other.shared = self.shared == "yes";
+        other.import_properties = Some(sqlx::types::Json(import_properties));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn parse_and_translate() {
+        let dump = indoc! {r#"
+            [
+              {"href":"https:\/\/example.com\/a","description":"A","extended":"Longer",
+               "meta":"abc","hash":"def","time":"2024-01-02T03:04:05Z","shared":"no",
+               "toread":"yes","tags":"rust  async"},
+              {"href":"https:\/\/example.com\/b","description":"","extended":"",
+               "meta":"ghi","hash":"jkl","time":"2014-01-02T03:04:05Z","shared":"yes",
+               "toread":"no","tags":""}
+            ]
+        "#};
+        let posts: Vec<Post> = serde_json::from_str(dump).unwrap();
+        assert_eq!(
+            posts[0].tag_names().collect::<Vec<_>>(),
+            vec!["rust", "async"]
+        );
+
+        let a = posts[0].as_lz_bookmark(&posts[0].url().unwrap());
+        assert_eq!(a.title, "A");
+        assert_eq!(a.description.as_deref(), Some("Longer"));
+        assert!(a.unread);
+        assert!(!a.shared);
+        assert_eq!(a.created_at.to_rfc3339(), "2024-01-02T03:04:05+00:00");
+        let raw = &a.import_properties.unwrap().0.by_system[&lz_db::ImportableSystem::Pinboard];
+        assert_eq!(raw["meta"], "abc");
+        assert_eq!(raw["hash"], "def");
+
+        let b = posts[1].as_lz_bookmark(&posts[1].url().unwrap());
+        assert_eq!(b.title, "https://example.com/b");
+        assert_eq!(b.description, None);
+        assert!(!b.unread);
+        assert!(b.shared);
+        assert_eq!(posts[1].tag_names().count(), 0);
+    }
+
+    #[test]
+    fn invalid_urls() {
+        let dump = indoc! {r#"
+            [
+              {"href":"not a url","description":"Broken","time":"2024-01-02T03:04:05Z"},
+              {"href":"https:\/\/example.com\/","description":"Fine","time":"2024-01-02T03:04:05Z"}
+            ]
+        "#};
+        // A bad link doesn't keep the other posts from being read:
+        let posts: Vec<Post> = serde_json::from_str(dump).unwrap();
+        assert_eq!(posts.len(), 2);
+        assert!(posts[0].url().is_err());
+        assert_eq!(posts[1].url().unwrap().as_str(), "https://example.com/");
+    }
+}
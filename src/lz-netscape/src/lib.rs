@@ -0,0 +1,96 @@
+//! Import and export of the Netscape bookmark file format.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use clap::Parser;
+use lz_db::{BookmarkSearch, DuplicateBehavior};
+
+pub mod schema;
+
+pub mod migrate;
+pub use migrate::Migration;
+
+/// Arguments for the Netscape bookmark file import
+#[derive(Clone, Debug, PartialEq, Eq, Parser)]
+pub struct ImportArgs {
+    /// The bookmarks.html file to import
+    bookmarks_file: PathBuf,
+
+    /// The username on the lz side to import as.
+    #[clap(long)]
+    user: String,
+}
+
+/// Start the import from a Netscape bookmark file
+pub async fn run_import(
+    lz: lz_db::Connection,
+    args: &ImportArgs,
+    on_duplicate: DuplicateBehavior,
+) -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let html = std::fs::read_to_string(&args.bookmarks_file)
+        .with_context(|| format!("reading {}", args.bookmarks_file.to_string_lossy()))?;
+    let bookmarks = schema::parse_bookmarks(&html);
+    tracing::info!(bookmark_count = bookmarks.len(), "Bookmarks read");
+    let tx = lz.begin_for_user(&args.user).await?;
+    Migration::new(tx, on_duplicate).migrate(bookmarks).await?;
+    Ok(())
+}
+
+/// Arguments for the Netscape bookmark file export
+#[derive(Clone, Debug, PartialEq, Eq, Parser)]
+pub struct ExportArgs {
+    /// The file to write the bookmarks to; stdout if not given.
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+
+    /// The username on the lz side whose bookmarks to export.
+    #[clap(long)]
+    user: String,
+}
+
+/// Export all of a user's bookmarks into a Netscape bookmark file
+pub async fn run_export(lz: lz_db::Connection, args: &ExportArgs) -> anyhow::Result<()> {
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => {
+            Box::new(BufWriter::new(File::create(path).with_context(|| {
+                format!("creating {}", path.to_string_lossy())
+            })?))
+        }
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    let mut txn = lz.begin_ro_for_user(&args.user).await?;
+    let criteria = [BookmarkSearch::User { id: txn.user().id }];
+    let page_size = 1000;
+    let mut last_seen = None;
+
+    schema::write_header(&mut out)?;
+    loop {
+        let mut bookmarks = txn
+            .list_bookmarks_matching(&criteria, page_size, last_seen)
+            .await?;
+        let next = if bookmarks.len() > usize::from(page_size) {
+            bookmarks.pop().map(|bm| bm.id)
+        } else {
+            None
+        };
+        let mut tags = txn
+            .tags_on_bookmarks(bookmarks.iter().map(|bm| bm.id).collect::<Vec<_>>())
+            .await?;
+        for bm in &bookmarks {
+            let tags = tags.remove(&bm.id).unwrap_or_default();
+            schema::Bookmark::from_lz_bookmark(bm, &tags).write_entry(&mut out)?;
+        }
+        match next {
+            Some(next) => last_seen = Some(next),
+            None => break,
+        }
+    }
+    schema::write_footer(&mut out)?;
+    out.flush()?;
+    Ok(())
+}
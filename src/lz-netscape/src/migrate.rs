@@ -0,0 +1,70 @@
+//! Routines that import bookmarks from a Netscape bookmark file into lz.
+
+use anyhow::Context as _;
+use lz_db::DuplicateBehavior;
+
+use crate::schema;
+
+pub struct Migration {
+    db: lz_db::Transaction,
+    on_duplicate: DuplicateBehavior,
+}
+
+impl Migration {
+    pub fn new(db: lz_db::Transaction, on_duplicate: DuplicateBehavior) -> Self {
+        Self { db, on_duplicate }
+    }
+
+    /// Import the bookmarks into lz, tagging them with their tags.
+    ///
+    /// All created bookmarks get their import_properties.by_system
+    /// JSON object filled with a `Netscape` property, containing all
+    /// the fields we recognized.
+    #[tracing::instrument(skip(self, bookmarks))]
+    pub async fn migrate(mut self, bookmarks: Vec<schema::Bookmark>) -> anyhow::Result<()> {
+        let mut imported = 0;
+        for bookmark in bookmarks {
+            if self
+                .translate_bookmark(&bookmark)
+                .await
+                .with_context(|| format!("importing {}", bookmark.href))?
+            {
+                imported += 1;
+            }
+        }
+        tracing::info!(bookmark_count = imported, "Bookmarks imported");
+        self.db.commit().await?;
+        tracing::info!("Import done");
+        Ok(())
+    }
+
+    /// Add (or overwrite) a single bookmark and its tags. Returns
+    /// whether the bookmark was imported.
+    async fn translate_bookmark(
+        &mut self,
+        bookmark: &schema::Bookmark,
+    ) -> Result<bool, sqlx::Error> {
+        let id = match self.db.find_bookmark_with_url(&bookmark.href).await? {
+            Some(mut existing) => match self.on_duplicate {
+                DuplicateBehavior::Skip => {
+                    tracing::warn!(url=%bookmark.href, existing_created_at=?existing.created_at, "Skipping duplicate bookmark");
+                    return Ok(false);
+                }
+                DuplicateBehavior::Overwrite => {
+                    bookmark.overwrite_into_lz_bookmark(&mut existing);
+                    self.db.update_bookmark(&existing).await?;
+                    tracing::debug!(url=%bookmark.href, id=?existing.id, "overwrote bookmark");
+                    existing.id
+                }
+            },
+            None => {
+                let added = self.db.add_bookmark(bookmark.as_lz_bookmark()).await?;
+                tracing::debug!(url=%bookmark.href, ?added.id, "added bookmark");
+                added.id
+            }
+        };
+        let tags = self.db.ensure_tags(&bookmark.tags).await?;
+        self.db.set_bookmark_tags(id, tags).await?;
+        Ok(true)
+    }
+}
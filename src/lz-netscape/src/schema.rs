@@ -0,0 +1,289 @@
+//! The Netscape bookmark file format
+//!
+//! This is the `bookmarks.html` format that browsers, Pinboard and
+//! Linkding export and import. It is not really specified anywhere
+//! and not even well-formed HTML; each bookmark is a `<DT>` element
+//! containing a link, optionally followed by a `<DD>` element with
+//! notes:
+//!
+//! ```html
+//! <DT><A HREF="https://example.com/" ADD_DATE="1712345678" PRIVATE="1" TAGS="a,b">Title</A>
+//! <DD>Notes
+//! ```
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use chrono::{DateTime, Utc};
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A bookmark as it is represented in a Netscape bookmark file.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Bookmark {
+    pub href: Url,
+    pub title: String,
+    pub add_date: Option<DateTime<Utc>>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub private: Option<bool>,
+    pub toread: Option<bool>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+}
+
+/// Parse all the bookmarks out of a Netscape bookmark file.
+///
+/// Links that don't have a valid URL (such as `place:` queries
+/// exported by Firefox without a host) are skipped with a warning.
+pub fn parse_bookmarks(html: &str) -> Vec<Bookmark> {
+    let document = Html::parse_document(html);
+    let links = Selector::parse("dt > a[href]").expect("selector to be valid");
+    document
+        .select(&links)
+        .filter_map(|a| match Bookmark::from_element(a) {
+            Ok(bm) => Some(bm),
+            Err(e) => {
+                tracing::warn!(href=?a.value().attr("href"), error=%e, "Skipping bookmark with an invalid URL");
+                None
+            }
+        })
+        .collect()
+}
+
+impl Bookmark {
+    fn from_element(a: ElementRef) -> Result<Self, url::ParseError> {
+        let attrs: HashMap<&str, &str> = a.value().attrs().collect();
+        let href = Url::parse(attrs.get("href").copied().unwrap_or_default())?;
+        let timestamp = |name: &str| {
+            attrs
+                .get(name)
+                .and_then(|value| value.trim().parse::<i64>().ok())
+                .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        };
+        let flag = |name: &str| attrs.get(name).map(|value| value.trim() == "1");
+        let tags = attrs
+            .get("tags")
+            .map(|tags| {
+                tags.split(',')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // The notes are in a `<DD>` immediately following the link's `<DT>`:
+        let notes = a
+            .parent()
+            .into_iter()
+            .flat_map(|dt| dt.next_siblings())
+            .filter_map(ElementRef::wrap)
+            .next()
+            .filter(|el| el.value().name() == "dd")
+            .map(|dd| dd.text().collect::<String>().trim().to_string())
+            .filter(|notes| !notes.is_empty());
+
+        Ok(Bookmark {
+            href,
+            title: a.text().collect::<String>().trim().to_string(),
+            add_date: timestamp("add_date"),
+            last_modified: timestamp("last_modified"),
+            private: flag("private"),
+            toread: flag("toread"),
+            tags,
+            notes,
+        })
+    }
+
+    /// Convert the bookmark into one that can be added to lz.
+    pub fn as_lz_bookmark(&self) -> lz_db::Bookmark<lz_db::NoId, lz_db::NoId> {
+        let mut other = lz_db::Bookmark {
+            id: lz_db::NoId,
+            user_id: lz_db::NoId,
+            url: self.href.clone(),
+            created_at: Default::default(),
+            modified_at: Default::default(),
+            accessed_at: Default::default(),
+            title: Default::default(),
+            description: Default::default(),
+            website_title: Default::default(),
+            website_description: Default::default(),
+            notes: Default::default(),
+            unread: Default::default(),
+            shared: Default::default(),
+            import_properties: Default::default(),
+        };
+        self.overwrite_into_lz_bookmark(&mut other);
+        other
+    }
+
+    /// Overwrite the fields of an lz bookmark that this format knows about.
+    pub fn overwrite_into_lz_bookmark<
+        ID: lz_db::IdType<lz_db::BookmarkId>,
+        UID: lz_db::IdType<lz_db::UserId>,
+    >(
+        &self,
+        other: &mut lz_db::Bookmark<ID, UID>,
+    ) {
+        let mut import_properties = other
+            .import_properties
+            .take()
+            .map(|props| props.0)
+            .unwrap_or_else(|| lz_db::ImportProperties {
+                by_system: HashMap::new(),
+            });
+        import_properties.by_system.insert(
+            lz_db::ImportableSystem::Netscape,
+            serde_json::to_value(self).expect("cleanly convert to serde_json::Value"),
+        );
+
+        other.created_at = self.add_date.unwrap_or_else(Utc::now);
+        other.modified_at = self.last_modified;
+        other.url = self.href.clone();
+        other.title = if self.title.is_empty() {
+            self.href.to_string()
+        } else {
+            self.title.clone()
+        };
+        other.notes.clone_from(&self.notes);
+        other.unread = self.toread.unwrap_or(false);
+        other.shared = !self.private.unwrap_or(false);
+        other.import_properties = Some(sqlx::types::Json(import_properties));
+    }
+
+    /// Represent an lz bookmark and its tags in this format.
+    pub fn from_lz_bookmark<
+        ID: lz_db::IdType<lz_db::BookmarkId>,
+        UID: lz_db::IdType<lz_db::UserId>,
+    >(
+        bookmark: &lz_db::Bookmark<ID, UID>,
+        tags: &[lz_db::Tag<lz_db::TagId>],
+    ) -> Self {
+        Bookmark {
+            href: bookmark.url.clone(),
+            title: bookmark.title.clone(),
+            add_date: Some(bookmark.created_at),
+            last_modified: bookmark.modified_at,
+            private: Some(!bookmark.shared),
+            toread: Some(bookmark.unread),
+            tags: tags.iter().map(|t| t.name.clone()).collect(),
+            notes: bookmark.notes.clone(),
+        }
+    }
+
+    /// Write the bookmark as a `<DT>` (and, if it has notes, `<DD>`) entry.
+    pub fn write_entry<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(out, r#"<DT><A HREF="{}""#, escape(self.href.as_str()))?;
+        if let Some(add_date) = self.add_date {
+            write!(out, r#" ADD_DATE="{}""#, add_date.timestamp())?;
+        }
+        if let Some(last_modified) = self.last_modified {
+            write!(out, r#" LAST_MODIFIED="{}""#, last_modified.timestamp())?;
+        }
+        if let Some(private) = self.private {
+            write!(out, r#" PRIVATE="{}""#, u8::from(private))?;
+        }
+        if let Some(toread) = self.toread {
+            write!(out, r#" TOREAD="{}""#, u8::from(toread))?;
+        }
+        if !self.tags.is_empty() {
+            write!(out, r#" TAGS="{}""#, escape(&self.tags.join(",")))?;
+        }
+        writeln!(out, ">{}</A>", escape(&self.title))?;
+        if let Some(notes) = &self.notes {
+            writeln!(out, "<DD>{}", escape(notes))?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the preamble of a bookmark file, up to where the entries go.
+pub fn write_header<W: Write>(out: &mut W) -> io::Result<()> {
+    writeln!(out, "<!DOCTYPE NETSCAPE-Bookmark-file-1>")?;
+    writeln!(
+        out,
+        r#"<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">"#
+    )?;
+    writeln!(out, "<TITLE>Bookmarks</TITLE>")?;
+    writeln!(out, "<H1>Bookmarks</H1>")?;
+    writeln!(out, "<DL><p>")
+}
+
+/// Write the end of a bookmark file, after all entries.
+pub fn write_footer<W: Write>(out: &mut W) -> io::Result<()> {
+    writeln!(out, "</DL><p>")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn parse_pinboard_style() {
+        let html = indoc! {r#"
+            <!DOCTYPE NETSCAPE-Bookmark-file-1>
+            <META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+            <TITLE>Pinboard Bookmarks</TITLE>
+            <H1>Bookmarks</H1>
+            <DL><p>
+            <DT><A HREF="https://example.com/a" ADD_DATE="1712345678" PRIVATE="1" TOREAD="1" TAGS="rust,async">A &amp; B</A>
+            <DD>Some <notes>
+            <DT><A HREF="https://example.com/b" ADD_DATE="1712345600">B</A>
+            <DT><H3>A folder</H3>
+            <DL><p>
+                <DT><A HREF="not a url">Broken</A>
+                <DT><A HREF="https://example.com/c">C</A>
+            </DL><p>
+            </DL><p>
+        "#};
+        let parsed = parse_bookmarks(html);
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(
+            parsed[0],
+            Bookmark {
+                href: Url::parse("https://example.com/a").unwrap(),
+                title: "A & B".to_string(),
+                add_date: DateTime::from_timestamp(1712345678, 0),
+                last_modified: None,
+                private: Some(true),
+                toread: Some(true),
+                tags: vec!["rust".to_string(), "async".to_string()],
+                notes: Some("Some".to_string()),
+            }
+        );
+        assert_eq!(parsed[1].notes, None);
+        assert_eq!(parsed[1].tags, Vec::<String>::new());
+        assert_eq!(parsed[2].href.as_str(), "https://example.com/c");
+    }
+
+    #[test]
+    fn roundtrip() {
+        let bookmark = Bookmark {
+            href: Url::parse("https://example.com/?a=1&b=\"2\"").unwrap(),
+            title: "<Title> & \"quotes\"".to_string(),
+            add_date: DateTime::from_timestamp(1712345678, 0),
+            last_modified: DateTime::from_timestamp(1712349999, 0),
+            private: Some(false),
+            toread: Some(true),
+            tags: vec!["a".to_string(), "b:c".to_string()],
+            notes: Some("Multiple\nlines & <markup>".to_string()),
+        };
+        let mut out = vec![];
+        write_header(&mut out).unwrap();
+        bookmark.write_entry(&mut out).unwrap();
+        bookmark.write_entry(&mut out).unwrap();
+        write_footer(&mut out).unwrap();
+        let parsed = parse_bookmarks(&String::from_utf8(out).unwrap());
+        assert_eq!(parsed, vec![bookmark.clone(), bookmark]);
+    }
+}
@@ -60,6 +60,13 @@ pub mod types {
     ///    "bookmark": {
     ///      "$ref": "#/components/schemas/ExistingBookmark"
     ///    },
+    ///    "snippet": {
+    ///      "description": "An excerpt of the bookmark's text that matched the full-text\nsearch, if the query contained one.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
     ///    "tags": {
     ///      "description": "Tags associated with the bookmark",
     ///      "type": "array",
@@ -76,6 +83,10 @@ pub mod types {
         ///Links associated with the bookmark
         pub associations: Vec<AssociatedLink>,
         pub bookmark: ExistingBookmark,
+        /**An excerpt of the bookmark's text that matched the full-text
+        search, if the query contained one.*/
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub snippet: Option<String>,
         ///Tags associated with the bookmark
         pub tags: Vec<ExistingTag>,
     }
@@ -89,6 +100,76 @@ pub mod types {
             Default::default()
         }
     }
+    ///A local copy of the page that a stored URL points to.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A local copy of the page that a stored URL points to.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "archived_at",
+    ///    "html_sha256",
+    ///    "retrieved_from",
+    ///    "text",
+    ///    "text_sha256",
+    ///    "url_id"
+    ///  ],
+    ///  "properties": {
+    ///    "archived_at": {
+    ///      "description": "When the page was archived",
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "html_sha256": {
+    ///      "description": "SHA-256 digest of the archived HTML, in hex",
+    ///      "type": "string"
+    ///    },
+    ///    "retrieved_from": {
+    ///      "description": "Where the page was retrieved from, after following redirects",
+    ///      "type": "string",
+    ///      "format": "uri"
+    ///    },
+    ///    "text": {
+    ///      "description": "The page's readable text",
+    ///      "type": "string"
+    ///    },
+    ///    "text_sha256": {
+    ///      "description": "SHA-256 digest of the archived text, in hex",
+    ///      "type": "string"
+    ///    },
+    ///    "url_id": {
+    ///      "$ref": "#/components/schemas/StoredUrlId"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Archive {
+        ///When the page was archived
+        pub archived_at: chrono::DateTime<chrono::offset::Utc>,
+        ///SHA-256 digest of the archived HTML, in hex
+        pub html_sha256: String,
+        ///Where the page was retrieved from, after following redirects
+        pub retrieved_from: String,
+        ///The page's readable text
+        pub text: String,
+        ///SHA-256 digest of the archived text, in hex
+        pub text_sha256: String,
+        pub url_id: StoredUrlId,
+    }
+    impl From<&Archive> for Archive {
+        fn from(value: &Archive) -> Self {
+            value.clone()
+        }
+    }
+    impl Archive {
+        pub fn builder() -> builder::Archive {
+            Default::default()
+        }
+    }
     /**A link associated with a bookmark.
 
     Links can have a "context" in which that association happens
@@ -187,6 +268,55 @@ pub mod types {
             Default::default()
         }
     }
+    /**A position in a listing of bookmarks.
+
+    Cursors are opaque: Each page of a listing comes with the cursor
+    of the next page's first bookmark, and passing it back lists that
+    page. A cursor only works with the order that it came from.*/
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A position in a listing of bookmarks.\n\nCursors are opaque: Each page of a listing comes with the cursor\nof the next page's first bookmark, and passing it back lists that\npage. A cursor only works with the order that it came from.",
+    ///  "type": "string"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub struct BookmarkCursor(pub String);
+    impl std::ops::Deref for BookmarkCursor {
+        type Target = String;
+        fn deref(&self) -> &String {
+            &self.0
+        }
+    }
+    impl From<BookmarkCursor> for String {
+        fn from(value: BookmarkCursor) -> Self {
+            value.0
+        }
+    }
+    impl From<&BookmarkCursor> for BookmarkCursor {
+        fn from(value: &BookmarkCursor) -> Self {
+            value.clone()
+        }
+    }
+    impl From<String> for BookmarkCursor {
+        fn from(value: String) -> Self {
+            Self(value)
+        }
+    }
+    impl std::str::FromStr for BookmarkCursor {
+        type Err = std::convert::Infallible;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            Ok(Self(value.to_string()))
+        }
+    }
+    impl ToString for BookmarkCursor {
+        fn to_string(&self) -> String {
+            self.0.to_string()
+        }
+    }
     ///The database ID of a bookmark.
     ///
     /// <details><summary>JSON schema</summary>
@@ -251,6 +381,117 @@ pub mod types {
             self.0.to_string()
         }
     }
+    ///A request to merge another bookmark into a bookmark.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A request to merge another bookmark into a bookmark.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "absorb"
+    ///  ],
+    ///  "properties": {
+    ///    "absorb": {
+    ///      "$ref": "#/components/schemas/BookmarkId"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct BookmarkMergeRequest {
+        pub absorb: BookmarkId,
+    }
+    impl From<&BookmarkMergeRequest> for BookmarkMergeRequest {
+        fn from(value: &BookmarkMergeRequest) -> Self {
+            value.clone()
+        }
+    }
+    impl BookmarkMergeRequest {
+        pub fn builder() -> builder::BookmarkMergeRequest {
+            Default::default()
+        }
+    }
+    ///The orders that bookmarks can be listed in.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The orders that bookmarks can be listed in.",
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "created",
+    ///    "modified",
+    ///    "accessed",
+    ///    "title",
+    ///    "relevance"
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum BookmarkOrder {
+        #[serde(rename = "created")]
+        Created,
+        #[serde(rename = "modified")]
+        Modified,
+        #[serde(rename = "accessed")]
+        Accessed,
+        #[serde(rename = "title")]
+        Title,
+        #[serde(rename = "relevance")]
+        Relevance,
+    }
+    impl From<&BookmarkOrder> for BookmarkOrder {
+        fn from(value: &BookmarkOrder) -> Self {
+            value.clone()
+        }
+    }
+    impl ToString for BookmarkOrder {
+        fn to_string(&self) -> String {
+            match *self {
+                Self::Created => "created".to_string(),
+                Self::Modified => "modified".to_string(),
+                Self::Accessed => "accessed".to_string(),
+                Self::Title => "title".to_string(),
+                Self::Relevance => "relevance".to_string(),
+            }
+        }
+    }
+    impl std::str::FromStr for BookmarkOrder {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
+            match value {
+                "created" => Ok(Self::Created),
+                "modified" => Ok(Self::Modified),
+                "accessed" => Ok(Self::Accessed),
+                "title" => Ok(Self::Title),
+                "relevance" => Ok(Self::Relevance),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+    impl std::convert::TryFrom<&str> for BookmarkOrder {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<&String> for BookmarkOrder {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<String> for BookmarkOrder {
+        type Error = self::error::ConversionError;
+        fn try_from(value: String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
     /**The possible criteria that we can search for in a bookmark
     query. See [BookmarkSearchCriteria].*/
     ///
@@ -273,7 +514,7 @@ pub mod types {
     ///      }
     ///    },
     ///    {
-    ///      "description": "Only list bookmarks that are tagged with a tag of the given name.",
+    ///      "description": "Only list bookmarks that are tagged with the tag that the given\nname is an alias of, or else with the tag of that name.",
     ///      "type": "object",
     ///      "required": [
     ///        "tag"
@@ -285,6 +526,18 @@ pub mod types {
     ///      }
     ///    },
     ///    {
+    ///      "description": "Only list bookmarks that are tagged with a tag of the given\nname, or with a tag below it in the hierarchy: `lang/rust` also\nfinds bookmarks tagged `lang/rust/async`.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "tag_prefix"
+    ///      ],
+    ///      "properties": {
+    ///        "tag_prefix": {
+    ///          "$ref": "#/components/schemas/TagName"
+    ///        }
+    ///      }
+    ///    },
+    ///    {
     ///      "description": "Only list bookmarks that are tagged with a tag with the given ID.",
     ///      "type": "object",
     ///      "required": [
@@ -307,6 +560,108 @@ pub mod types {
     ///          "$ref": "#/components/schemas/UserId"
     ///        }
     ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks whose text (title, descriptions, notes, URL\nor archived page) contains all the words in the query.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "fulltext"
+    ///      ],
+    ///      "properties": {
+    ///        "fulltext": {
+    ///          "$ref": "#/components/schemas/FullTextQuery"
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks whose URL points to the given site, or to\nany of its subdomains.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "site"
+    ///      ],
+    ///      "properties": {
+    ///        "site": {
+    ///          "$ref": "#/components/schemas/SiteName"
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks that are (or aren't) marked as unread.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "unread"
+    ///      ],
+    ///      "properties": {
+    ///        "unread": {
+    ///          "type": "boolean"
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks that are (or aren't) shared.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "shared"
+    ///      ],
+    ///      "properties": {
+    ///        "shared": {
+    ///          "type": "boolean"
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks whose URL's latest link check found the\ngiven status. Bookmarks whose URL wasn't checked yet never match.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "link_status"
+    ///      ],
+    ///      "properties": {
+    ///        "link_status": {
+    ///          "$ref": "#/components/schemas/LinkStatus"
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks that match all of the given criteria.\n\nThis is how criteria at the top level of a query get combined\nalready; use it to group criteria inside [`BookmarkSearch::Any`].",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "all"
+    ///      ],
+    ///      "properties": {
+    ///        "all": {
+    ///          "type": "array",
+    ///          "items": {
+    ///            "$ref": "#/components/schemas/BookmarkSearch"
+    ///          }
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks that match at least one of the given criteria.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "any"
+    ///      ],
+    ///      "properties": {
+    ///        "any": {
+    ///          "type": "array",
+    ///          "items": {
+    ///            "$ref": "#/components/schemas/BookmarkSearch"
+    ///          }
+    ///        }
+    ///      }
+    ///    },
+    ///    {
+    ///      "description": "Only list bookmarks that do not match the given criterion.",
+    ///      "type": "object",
+    ///      "required": [
+    ///        "not"
+    ///      ],
+    ///      "properties": {
+    ///        "not": {
+    ///          "$ref": "#/components/schemas/BookmarkSearch"
+    ///        }
+    ///      }
     ///    }
     ///  ]
     ///}
@@ -317,15 +672,51 @@ pub mod types {
         ///Only list bookmarks that have matching timestamps
         #[serde(rename = "date")]
         Date(BookmarkSearchDateParams),
-        ///Only list bookmarks that are tagged with a tag of the given name.
+        /**Only list bookmarks that are tagged with the tag that the given
+        name is an alias of, or else with the tag of that name.*/
         #[serde(rename = "tag")]
         Tag(TagName),
+        /**Only list bookmarks that are tagged with a tag of the given
+        name, or with a tag below it in the hierarchy: `lang/rust` also
+        finds bookmarks tagged `lang/rust/async`.*/
+        #[serde(rename = "tag_prefix")]
+        TagPrefix(TagName),
         ///Only list bookmarks that are tagged with a tag with the given ID.
         #[serde(rename = "tag_id")]
         TagId(TagId),
         ///Only list bookmarks belonging to the given user.
         #[serde(rename = "user_id")]
         UserId(UserId),
+        /**Only list bookmarks whose text (title, descriptions, notes, URL
+        or archived page) contains all the words in the query.*/
+        #[serde(rename = "fulltext")]
+        Fulltext(FullTextQuery),
+        /**Only list bookmarks whose URL points to the given site, or to
+        any of its subdomains.*/
+        #[serde(rename = "site")]
+        Site(SiteName),
+        ///Only list bookmarks that are (or aren't) marked as unread.
+        #[serde(rename = "unread")]
+        Unread(bool),
+        ///Only list bookmarks that are (or aren't) shared.
+        #[serde(rename = "shared")]
+        Shared(bool),
+        /**Only list bookmarks whose URL's latest link check found the
+        given status. Bookmarks whose URL wasn't checked yet never match.*/
+        #[serde(rename = "link_status")]
+        LinkStatus(LinkStatus),
+        /**Only list bookmarks that match all of the given criteria.
+
+        This is how criteria at the top level of a query get combined
+        already; use it to group criteria inside [`BookmarkSearch::Any`].*/
+        #[serde(rename = "all")]
+        All(Vec<BookmarkSearch>),
+        ///Only list bookmarks that match at least one of the given criteria.
+        #[serde(rename = "any")]
+        Any(Vec<BookmarkSearch>),
+        ///Only list bookmarks that do not match the given criterion.
+        #[serde(rename = "not")]
+        Not(Box<BookmarkSearch>),
     }
     impl From<&BookmarkSearch> for BookmarkSearch {
         fn from(value: &BookmarkSearch) -> Self {
@@ -337,11 +728,6 @@ pub mod types {
             Self::Date(value)
         }
     }
-    impl From<TagName> for BookmarkSearch {
-        fn from(value: TagName) -> Self {
-            Self::Tag(value)
-        }
-    }
     impl From<TagId> for BookmarkSearch {
         fn from(value: TagId) -> Self {
             Self::TagId(value)
@@ -352,6 +738,26 @@ pub mod types {
             Self::UserId(value)
         }
     }
+    impl From<FullTextQuery> for BookmarkSearch {
+        fn from(value: FullTextQuery) -> Self {
+            Self::Fulltext(value)
+        }
+    }
+    impl From<SiteName> for BookmarkSearch {
+        fn from(value: SiteName) -> Self {
+            Self::Site(value)
+        }
+    }
+    impl From<LinkStatus> for BookmarkSearch {
+        fn from(value: LinkStatus) -> Self {
+            Self::LinkStatus(value)
+        }
+    }
+    impl From<Box<BookmarkSearch>> for BookmarkSearch {
+        fn from(value: Box<BookmarkSearch>) -> Self {
+            Self::Not(value)
+        }
+    }
     ///BookmarkSearchDateParams
     ///
     /// <details><summary>JSON schema</summary>
@@ -373,6 +779,13 @@ pub mod types {
     ///    },
     ///    "orientation": {
     ///      "$ref": "#/components/schemas/BookmarkSearchDatetimeOrientation"
+    ///    },
+    ///    "timezone": {
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/Timezone"
+    ///        }
+    ///      ]
     ///    }
     ///  }
     ///}
@@ -383,6 +796,8 @@ pub mod types {
         pub date: DateInput,
         pub field: BookmarkSearchDatetimeField,
         pub orientation: BookmarkSearchDatetimeOrientation,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub timezone: Option<Timezone>,
     }
     impl From<&BookmarkSearchDateParams> for BookmarkSearchDateParams {
         fn from(value: &BookmarkSearchDateParams) -> Self {
@@ -394,15 +809,18 @@ pub mod types {
             Default::default()
         }
     }
-    ///BookmarkSearchDatetimeField
+    ///The timestamp of a bookmark that a date search compares.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
+    ///  "description": "The timestamp of a bookmark that a date search compares.",
     ///  "type": "string",
     ///  "enum": [
-    ///    "Created"
+    ///    "Created",
+    ///    "Modified",
+    ///    "Accessed"
     ///  ]
     ///}
     /// ```
@@ -410,6 +828,8 @@ pub mod types {
     #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
     pub enum BookmarkSearchDatetimeField {
         Created,
+        Modified,
+        Accessed,
     }
     impl From<&BookmarkSearchDatetimeField> for BookmarkSearchDatetimeField {
         fn from(value: &BookmarkSearchDatetimeField) -> Self {
@@ -420,6 +840,8 @@ pub mod types {
         fn to_string(&self) -> String {
             match *self {
                 Self::Created => "Created".to_string(),
+                Self::Modified => "Modified".to_string(),
+                Self::Accessed => "Accessed".to_string(),
             }
         }
     }
@@ -428,6 +850,8 @@ pub mod types {
         fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
             match value {
                 "Created" => Ok(Self::Created),
+                "Modified" => Ok(Self::Modified),
+                "Accessed" => Ok(Self::Accessed),
                 _ => Err("invalid value".into()),
             }
         }
@@ -450,16 +874,18 @@ pub mod types {
             value.parse()
         }
     }
-    ///BookmarkSearchDatetimeOrientation
+    ///How a date search compares a bookmark's timestamp to the date.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
+    ///  "description": "How a date search compares a bookmark's timestamp to the date.",
     ///  "type": "string",
     ///  "enum": [
     ///    "After",
-    ///    "Before"
+    ///    "Before",
+    ///    "On"
     ///  ]
     ///}
     /// ```
@@ -468,6 +894,7 @@ pub mod types {
     pub enum BookmarkSearchDatetimeOrientation {
         After,
         Before,
+        On,
     }
     impl From<&BookmarkSearchDatetimeOrientation> for BookmarkSearchDatetimeOrientation {
         fn from(value: &BookmarkSearchDatetimeOrientation) -> Self {
@@ -479,6 +906,7 @@ pub mod types {
             match *self {
                 Self::After => "After".to_string(),
                 Self::Before => "Before".to_string(),
+                Self::On => "On".to_string(),
             }
         }
     }
@@ -488,6 +916,7 @@ pub mod types {
             match value {
                 "After" => Ok(Self::After),
                 "Before" => Ok(Self::Before),
+                "On" => Ok(Self::On),
                 _ => Err("invalid value".into()),
             }
         }
@@ -510,48 +939,139 @@ pub mod types {
             value.parse()
         }
     }
-    /**A named tag, possibly assigned to multiple bookmarks.
+    /**A partial update to a bookmark.
 
-    See the section in [Transaction][Transaction#working-with-tags]*/
+    Only the fields that are present in the request get changed;
+    setting an optional text field to `null` clears it.*/
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "A named tag, possibly assigned to multiple bookmarks.\n\nSee the section in [Transaction][Transaction#working-with-tags]",
+    ///  "description": "A partial update to a bookmark.\n\nOnly the fields that are present in the request get changed;\nsetting an optional text field to `null` clears it.",
     ///  "type": "object",
-    ///  "required": [
-    ///    "created_at",
-    ///    "name"
-    ///  ],
     ///  "properties": {
-    ///    "created_at": {
-    ///      "description": "When the tag was first created.",
-    ///      "type": "string",
-    ///      "format": "date-time"
-    ///    },
-    ///    "name": {
-    ///      "description": "Name of the tag.",
-    ///      "type": "string"
-    ///    }
-    ///  }
+    ///    "associations": {
+    ///      "description": "Links to replace the bookmark's associated links with",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/AssociatedLink"
+    ///      }
+    ///    },
+    ///    "description": {
+    ///      "description": "The bookmark's description",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "notes": {
+    ///      "description": "The user's notes on the bookmark",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "shared": {
+    ///      "description": "Whether the bookmark is shared",
+    ///      "type": [
+    ///        "boolean",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "tag_names": {
+    ///      "description": "Tags to replace the bookmark's tags with",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "title": {
+    ///      "description": "The bookmark's title",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "unread": {
+    ///      "description": "Whether the bookmark is unread",
+    ///      "type": [
+    ///        "boolean",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "url": {
+    ///      "description": "The URL that the bookmark points to",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uri"
+    ///    },
+    ///    "website_description": {
+    ///      "description": "The description of the website that the bookmark points to",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "website_title": {
+    ///      "description": "The title of the website that the bookmark points to",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
     ///}
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-    pub struct CompleteTagResponseItem {
-        ///When the tag was first created.
-        pub created_at: chrono::DateTime<chrono::offset::Utc>,
-        ///Name of the tag.
-        pub name: String,
+    pub struct BookmarkUpdateRequest {
+        ///Links to replace the bookmark's associated links with
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub associations: Option<Vec<AssociatedLink>>,
+        ///The bookmark's description
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        ///The user's notes on the bookmark
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub notes: Option<String>,
+        ///Whether the bookmark is shared
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub shared: Option<bool>,
+        ///Tags to replace the bookmark's tags with
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub tag_names: Option<Vec<String>>,
+        ///The bookmark's title
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub title: Option<String>,
+        ///Whether the bookmark is unread
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub unread: Option<bool>,
+        ///The URL that the bookmark points to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub url: Option<String>,
+        ///The description of the website that the bookmark points to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub website_description: Option<String>,
+        ///The title of the website that the bookmark points to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub website_title: Option<String>,
     }
-    impl From<&CompleteTagResponseItem> for CompleteTagResponseItem {
-        fn from(value: &CompleteTagResponseItem) -> Self {
+    impl From<&BookmarkUpdateRequest> for BookmarkUpdateRequest {
+        fn from(value: &BookmarkUpdateRequest) -> Self {
             value.clone()
         }
     }
-    impl CompleteTagResponseItem {
-        pub fn builder() -> builder::CompleteTagResponseItem {
+    impl BookmarkUpdateRequest {
+        pub fn builder() -> builder::BookmarkUpdateRequest {
             Default::default()
         }
     }
@@ -579,6 +1099,13 @@ pub mod types {
     ///    "bookmark": {
     ///      "$ref": "#/components/schemas/ExistingBookmark"
     ///    },
+    ///    "snippet": {
+    ///      "description": "An excerpt of the bookmark's text that matched the full-text\nsearch, if the query contained one.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
     ///    "tags": {
     ///      "description": "Tags associated with the bookmark",
     ///      "type": "array",
@@ -595,6 +1122,10 @@ pub mod types {
         ///Links associated with the bookmark
         pub associations: Vec<AssociatedLink>,
         pub bookmark: ExistingBookmark,
+        /**An excerpt of the bookmark's text that matched the full-text
+        search, if the query contained one.*/
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub snippet: Option<String>,
         ///Tags associated with the bookmark
         pub tags: Vec<ExistingTag>,
     }
@@ -608,12 +1139,26 @@ pub mod types {
             Default::default()
         }
     }
-    ///DateInput
+    /**A date or time to search for bookmarks by.
+
+    These forms are accepted:
+
+    * `YYYY-MM-DD`, a calendar day.
+    * An RFC3339 timestamp like `2024-01-31T12:00:00+01:00`, an exact
+    point in time.
+    * A number of hours, days, weeks, months or years ago: `12h`,
+    `7d`, `2w`, `3m`, `1y`.
+    * `today`, `yesterday`, and `this-` or `last-` followed by `week`
+    (starting on Monday), `month` or `year`.*/
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
+    ///  "description": "A date or time to search for bookmarks by.\n\nThese forms are accepted:\n\n* `YYYY-MM-DD`, a calendar day.\n* An RFC3339 timestamp like `2024-01-31T12:00:00+01:00`, an exact\npoint in time.\n* A number of hours, days, weeks, months or years ago: `12h`,\n`7d`, `2w`, `3m`, `1y`.\n* `today`, `yesterday`, and `this-` or `last-` followed by `week`\n(starting on Monday), `month` or `year`.",
+    ///  "examples": [
+    ///    "2024-01-31"
+    ///  ],
     ///  "type": "string"
     ///}
     /// ```
@@ -652,6 +1197,41 @@ pub mod types {
             self.0.to_string()
         }
     }
+    ///The body of an API response that reports an error.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The body of an API response that reports an error.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "error_message"
+    ///  ],
+    ///  "properties": {
+    ///    "error_message": {
+    ///      "description": "A human-readable description of the error.",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct ErrorResponse {
+        ///A human-readable description of the error.
+        pub error_message: String,
+    }
+    impl From<&ErrorResponse> for ErrorResponse {
+        fn from(value: &ErrorResponse) -> Self {
+            value.clone()
+        }
+    }
+    impl ErrorResponse {
+        pub fn builder() -> builder::ErrorResponse {
+            Default::default()
+        }
+    }
     /**A bookmark saved by a user.
 
     See the section in [Transaction][Transaction#working-with-bookmarks]*/
@@ -798,17 +1378,22 @@ pub mod types {
     }
     /**A named tag, possibly assigned to multiple bookmarks.
 
+    Each user has their own tags: Other users' tags, even ones with
+    the same name, are different tags.
+
     See the section in [Transaction][Transaction#working-with-tags]*/
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "A named tag, possibly assigned to multiple bookmarks.\n\nSee the section in [Transaction][Transaction#working-with-tags]",
+    ///  "description": "A named tag, possibly assigned to multiple bookmarks.\n\nEach user has their own tags: Other users' tags, even ones with\nthe same name, are different tags.\n\nSee the section in [Transaction][Transaction#working-with-tags]",
     ///  "type": "object",
     ///  "required": [
     ///    "created_at",
-    ///    "name"
+    ///    "id",
+    ///    "name",
+    ///    "slug"
     ///  ],
     ///  "properties": {
     ///    "created_at": {
@@ -816,9 +1401,16 @@ pub mod types {
     ///      "type": "string",
     ///      "format": "date-time"
     ///    },
+    ///    "id": {
+    ///      "$ref": "#/components/schemas/TagId"
+    ///    },
     ///    "name": {
     ///      "description": "Name of the tag.",
     ///      "type": "string"
+    ///    },
+    ///    "slug": {
+    ///      "description": "Normalized name of tag, as for URLs.",
+    ///      "type": "string"
     ///    }
     ///  }
     ///}
@@ -828,8 +1420,11 @@ pub mod types {
     pub struct ExistingTag {
         ///When the tag was first created.
         pub created_at: chrono::DateTime<chrono::offset::Utc>,
+        pub id: TagId,
         ///Name of the tag.
         pub name: String,
+        ///Normalized name of tag, as for URLs.
+        pub slug: String,
     }
     impl From<&ExistingTag> for ExistingTag {
         fn from(value: &ExistingTag) -> Self {
@@ -853,15 +1448,61 @@ pub mod types {
     ///    "title"
     ///  ],
     ///  "properties": {
+    ///    "author": {
+    ///      "description": "The page's author",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "canonical_url": {
+    ///      "description": "The URL that the page declares as its canonical one",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uri"
+    ///    },
     ///    "description": {
-    ///      "description": "A description (probably from a meta tag on HTML)",
+    ///      "description": "A description (from a meta tag, OpenGraph or Twitter card)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "favicon": {
+    ///      "description": "The page's icon",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uri"
+    ///    },
+    ///    "image": {
+    ///      "description": "A preview image of the page (OpenGraph or Twitter card)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uri"
+    ///    },
+    ///    "language": {
+    ///      "description": "The language that the page declares in `<html lang>`",
     ///      "type": [
     ///        "string",
     ///        "null"
     ///      ]
     ///    },
+    ///    "published_at": {
+    ///      "description": "When the page was published",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
+    ///    },
     ///    "title": {
-    ///      "description": "The title of the document retrieved",
+    ///      "description": "The title of the document retrieved, from its `<title>` or\nelse its OpenGraph or Twitter card title. Empty if the page\nhas none of these.",
     ///      "type": "string"
     ///    }
     ///  }
@@ -870,10 +1511,30 @@ pub mod types {
     /// </details>
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct FetchPageMetadataResponse {
-        ///A description (probably from a meta tag on HTML)
+        ///The page's author
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub author: Option<String>,
+        ///The URL that the page declares as its canonical one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub canonical_url: Option<String>,
+        ///A description (from a meta tag, OpenGraph or Twitter card)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
-        ///The title of the document retrieved
+        ///The page's icon
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub favicon: Option<String>,
+        ///A preview image of the page (OpenGraph or Twitter card)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub image: Option<String>,
+        ///The language that the page declares in `<html lang>`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub language: Option<String>,
+        ///When the page was published
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub published_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+        /**The title of the document retrieved, from its `<title>` or
+        else its OpenGraph or Twitter card title. Empty if the page
+        has none of these.*/
         pub title: String,
     }
     impl From<&FetchPageMetadataResponse> for FetchPageMetadataResponse {
@@ -886,111 +1547,373 @@ pub mod types {
             Default::default()
         }
     }
-    /**The response returned by the `list_bookmarks` API endpoint.
+    /**A free-text query, matched against the full-text index of bookmarks.
 
-    This response contains pagination information; if `next_cursor` is
-    set, passing that value to the `cursor` pagination parameter will
-    fetch the next page.*/
+    The query is split into words on whitespace; each word must occur
+    somewhere in a bookmark for it to match. A word ending in `*`
+    matches all words starting with it, and words enclosed in double
+    quotes must occur next to each other, as a phrase.*/
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "The response returned by the `list_bookmarks` API endpoint.\n\nThis response contains pagination information; if `next_cursor` is\nset, passing that value to the `cursor` pagination parameter will\nfetch the next page.",
-    ///  "type": "object",
-    ///  "required": [
-    ///    "bookmarks"
-    ///  ],
-    ///  "properties": {
-    ///    "bookmarks": {
-    ///      "type": "array",
-    ///      "items": {
-    ///        "$ref": "#/components/schemas/AnnotatedBookmark"
-    ///      }
-    ///    },
-    ///    "next_cursor": {
-    ///      "allOf": [
-    ///        {
-    ///          "$ref": "#/components/schemas/BookmarkId"
-    ///        }
-    ///      ]
-    ///    }
-    ///  }
+    ///  "description": "A free-text query, matched against the full-text index of bookmarks.\n\nThe query is split into words on whitespace; each word must occur\nsomewhere in a bookmark for it to match. A word ending in `*`\nmatches all words starting with it, and words enclosed in double\nquotes must occur next to each other, as a phrase.",
+    ///  "type": "string"
     ///}
     /// ```
     /// </details>
-    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-    pub struct ListBookmarkResult {
-        pub bookmarks: Vec<AnnotatedBookmark>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub next_cursor: Option<BookmarkId>,
+    #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub struct FullTextQuery(pub String);
+    impl std::ops::Deref for FullTextQuery {
+        type Target = String;
+        fn deref(&self) -> &String {
+            &self.0
+        }
     }
-    impl From<&ListBookmarkResult> for ListBookmarkResult {
-        fn from(value: &ListBookmarkResult) -> Self {
+    impl From<FullTextQuery> for String {
+        fn from(value: FullTextQuery) -> Self {
+            value.0
+        }
+    }
+    impl From<&FullTextQuery> for FullTextQuery {
+        fn from(value: &FullTextQuery) -> Self {
             value.clone()
         }
     }
-    impl ListBookmarkResult {
-        pub fn builder() -> builder::ListBookmarkResult {
-            Default::default()
+    impl From<String> for FullTextQuery {
+        fn from(value: String) -> Self {
+            Self(value)
         }
     }
-    /**The response returned by the `list_bookmarks` API endpoint.
-
-    This response contains pagination information; if `next_cursor` is
-    set, passing that value to the `cursor` pagination parameter will
-    fetch the next page.*/
+    impl std::str::FromStr for FullTextQuery {
+        type Err = std::convert::Infallible;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            Ok(Self(value.to_string()))
+        }
+    }
+    impl ToString for FullTextQuery {
+        fn to_string(&self) -> String {
+            self.0.to_string()
+        }
+    }
+    ///A local copy of the page that a stored URL points to.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "The response returned by the `list_bookmarks` API endpoint.\n\nThis response contains pagination information; if `next_cursor` is\nset, passing that value to the `cursor` pagination parameter will\nfetch the next page.",
+    ///  "description": "A local copy of the page that a stored URL points to.",
     ///  "type": "object",
     ///  "required": [
-    ///    "bookmarks"
+    ///    "archived_at",
+    ///    "html_sha256",
+    ///    "retrieved_from",
+    ///    "text",
+    ///    "text_sha256",
+    ///    "url_id"
     ///  ],
     ///  "properties": {
-    ///    "bookmarks": {
-    ///      "type": "array",
-    ///      "items": {
-    ///        "$ref": "#/components/schemas/AnnotatedBookmark"
-    ///      }
+    ///    "archived_at": {
+    ///      "description": "When the page was archived",
+    ///      "type": "string",
+    ///      "format": "date-time"
     ///    },
-    ///    "next_cursor": {
-    ///      "allOf": [
-    ///        {
-    ///          "$ref": "#/components/schemas/BookmarkId"
-    ///        }
-    ///      ]
+    ///    "html_sha256": {
+    ///      "description": "SHA-256 digest of the archived HTML, in hex",
+    ///      "type": "string"
+    ///    },
+    ///    "retrieved_from": {
+    ///      "description": "Where the page was retrieved from, after following redirects",
+    ///      "type": "string",
+    ///      "format": "uri"
+    ///    },
+    ///    "text": {
+    ///      "description": "The page's readable text",
+    ///      "type": "string"
+    ///    },
+    ///    "text_sha256": {
+    ///      "description": "SHA-256 digest of the archived text, in hex",
+    ///      "type": "string"
+    ///    },
+    ///    "url_id": {
+    ///      "$ref": "#/components/schemas/StoredUrlId"
     ///    }
     ///  }
     ///}
     /// ```
     /// </details>
-    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-    pub struct ListBookmarksMatchingResponse {
-        pub bookmarks: Vec<AnnotatedBookmark>,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub next_cursor: Option<BookmarkId>,
-    }
-    impl From<&ListBookmarksMatchingResponse> for ListBookmarksMatchingResponse {
-        fn from(value: &ListBookmarksMatchingResponse) -> Self {
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct GetBookmarkArchiveResponse {
+        ///When the page was archived
+        pub archived_at: chrono::DateTime<chrono::offset::Utc>,
+        ///SHA-256 digest of the archived HTML, in hex
+        pub html_sha256: String,
+        ///Where the page was retrieved from, after following redirects
+        pub retrieved_from: String,
+        ///The page's readable text
+        pub text: String,
+        ///SHA-256 digest of the archived text, in hex
+        pub text_sha256: String,
+        pub url_id: StoredUrlId,
+    }
+    impl From<&GetBookmarkArchiveResponse> for GetBookmarkArchiveResponse {
+        fn from(value: &GetBookmarkArchiveResponse) -> Self {
             value.clone()
         }
     }
-    impl ListBookmarksMatchingResponse {
-        pub fn builder() -> builder::ListBookmarksMatchingResponse {
+    impl GetBookmarkArchiveResponse {
+        pub fn builder() -> builder::GetBookmarkArchiveResponse {
             Default::default()
         }
     }
-    ///A bookmark search query request
+    ///A bookmark, including tags and associations on it.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "A bookmark search query request",
+    ///  "description": "A bookmark, including tags and associations on it.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "associations",
+    ///    "bookmark",
+    ///    "tags"
+    ///  ],
+    ///  "properties": {
+    ///    "associations": {
+    ///      "description": "Links associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/AssociatedLink"
+    ///      }
+    ///    },
+    ///    "bookmark": {
+    ///      "$ref": "#/components/schemas/ExistingBookmark"
+    ///    },
+    ///    "snippet": {
+    ///      "description": "An excerpt of the bookmark's text that matched the full-text\nsearch, if the query contained one.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "tags": {
+    ///      "description": "Tags associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ExistingTag"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct GetBookmarkResponse {
+        ///Links associated with the bookmark
+        pub associations: Vec<AssociatedLink>,
+        pub bookmark: ExistingBookmark,
+        /**An excerpt of the bookmark's text that matched the full-text
+        search, if the query contained one.*/
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub snippet: Option<String>,
+        ///Tags associated with the bookmark
+        pub tags: Vec<ExistingTag>,
+    }
+    impl From<&GetBookmarkResponse> for GetBookmarkResponse {
+        fn from(value: &GetBookmarkResponse) -> Self {
+            value.clone()
+        }
+    }
+    impl GetBookmarkResponse {
+        pub fn builder() -> builder::GetBookmarkResponse {
+            Default::default()
+        }
+    }
+    ///What checking a link found.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "What checking a link found.",
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "ok",
+    ///    "redirected",
+    ///    "not_found",
+    ///    "failed",
+    ///    "unreachable"
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum LinkStatus {
+        #[serde(rename = "ok")]
+        Ok,
+        #[serde(rename = "redirected")]
+        Redirected,
+        #[serde(rename = "not_found")]
+        NotFound,
+        #[serde(rename = "failed")]
+        Failed,
+        #[serde(rename = "unreachable")]
+        Unreachable,
+    }
+    impl From<&LinkStatus> for LinkStatus {
+        fn from(value: &LinkStatus) -> Self {
+            value.clone()
+        }
+    }
+    impl ToString for LinkStatus {
+        fn to_string(&self) -> String {
+            match *self {
+                Self::Ok => "ok".to_string(),
+                Self::Redirected => "redirected".to_string(),
+                Self::NotFound => "not_found".to_string(),
+                Self::Failed => "failed".to_string(),
+                Self::Unreachable => "unreachable".to_string(),
+            }
+        }
+    }
+    impl std::str::FromStr for LinkStatus {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
+            match value {
+                "ok" => Ok(Self::Ok),
+                "redirected" => Ok(Self::Redirected),
+                "not_found" => Ok(Self::NotFound),
+                "failed" => Ok(Self::Failed),
+                "unreachable" => Ok(Self::Unreachable),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+    impl std::convert::TryFrom<&str> for LinkStatus {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<&String> for LinkStatus {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<String> for LinkStatus {
+        type Error = self::error::ConversionError;
+        fn try_from(value: String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    /**The response returned by the `list_bookmarks` API endpoint.
+
+    This response contains pagination information; if `next_cursor` is
+    set, passing that value to the `cursor` pagination parameter will
+    fetch the next page.*/
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The response returned by the `list_bookmarks` API endpoint.\n\nThis response contains pagination information; if `next_cursor` is\nset, passing that value to the `cursor` pagination parameter will\nfetch the next page.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bookmarks"
+    ///  ],
+    ///  "properties": {
+    ///    "bookmarks": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/AnnotatedBookmark"
+    ///      }
+    ///    },
+    ///    "next_cursor": {
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/BookmarkCursor"
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct ListBookmarkResult {
+        pub bookmarks: Vec<AnnotatedBookmark>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub next_cursor: Option<BookmarkCursor>,
+    }
+    impl From<&ListBookmarkResult> for ListBookmarkResult {
+        fn from(value: &ListBookmarkResult) -> Self {
+            value.clone()
+        }
+    }
+    impl ListBookmarkResult {
+        pub fn builder() -> builder::ListBookmarkResult {
+            Default::default()
+        }
+    }
+    /**The response returned by the `list_bookmarks` API endpoint.
+
+    This response contains pagination information; if `next_cursor` is
+    set, passing that value to the `cursor` pagination parameter will
+    fetch the next page.*/
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The response returned by the `list_bookmarks` API endpoint.\n\nThis response contains pagination information; if `next_cursor` is\nset, passing that value to the `cursor` pagination parameter will\nfetch the next page.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bookmarks"
+    ///  ],
+    ///  "properties": {
+    ///    "bookmarks": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/AnnotatedBookmark"
+    ///      }
+    ///    },
+    ///    "next_cursor": {
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/BookmarkCursor"
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct ListBookmarksMatchingResponse {
+        pub bookmarks: Vec<AnnotatedBookmark>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub next_cursor: Option<BookmarkCursor>,
+    }
+    impl From<&ListBookmarksMatchingResponse> for ListBookmarksMatchingResponse {
+        fn from(value: &ListBookmarksMatchingResponse) -> Self {
+            value.clone()
+        }
+    }
+    impl ListBookmarksMatchingResponse {
+        pub fn builder() -> builder::ListBookmarksMatchingResponse {
+            Default::default()
+        }
+    }
+    ///A bookmark search query request
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A bookmark search query request",
     ///  "allOf": [
     ///    {
     ///      "allOf": [
@@ -1002,12 +1925,22 @@ pub mod types {
     ///    {
     ///      "type": "object",
     ///      "properties": {
+    ///        "order": {
+    ///          "$ref": "#/components/schemas/BookmarkOrder"
+    ///        },
     ///        "query": {
-    ///          "description": "A search of criteria, restricting the set of bookmarks that qualify.\n\nAll criteria are merged using logical AND / set intersection.",
+    ///          "description": "A search of criteria, restricting the set of bookmarks that qualify.\n\nAll criteria are merged using logical AND / set intersection;\nuse the `any`, `all` and `not` criteria to express other\ncombinations.",
     ///          "type": "array",
     ///          "items": {
     ///            "$ref": "#/components/schemas/BookmarkSearch"
     ///          }
+    ///        },
+    ///        "timezone": {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/Timezone"
+    ///            }
+    ///          ]
     ///        }
     ///      }
     ///    }
@@ -1018,15 +1951,21 @@ pub mod types {
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct ListRequest {
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub cursor: Option<BookmarkId>,
+        pub cursor: Option<BookmarkCursor>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub order: Option<BookmarkOrder>,
         ///How many items to return
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub per_page: Option<i64>,
         /**A search of criteria, restricting the set of bookmarks that qualify.
 
-        All criteria are merged using logical AND / set intersection.*/
+        All criteria are merged using logical AND / set intersection;
+        use the `any`, `all` and `not` criteria to express other
+        combinations.*/
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub query: Vec<BookmarkSearch>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub timezone: Option<Timezone>,
     }
     impl From<&ListRequest> for ListRequest {
         fn from(value: &ListRequest) -> Self {
@@ -1038,99 +1977,270 @@ pub mod types {
             Default::default()
         }
     }
-    ///Metadata retrieved from a URL
+    ///A tag, with the number of the user's bookmarks that have it.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "Metadata retrieved from a URL",
+    ///  "description": "A tag, with the number of the user's bookmarks that have it.",
     ///  "type": "object",
     ///  "required": [
-    ///    "title"
+    ///    "count",
+    ///    "tag"
     ///  ],
     ///  "properties": {
-    ///    "description": {
-    ///      "description": "A description (probably from a meta tag on HTML)",
-    ///      "type": [
-    ///        "string",
-    ///        "null"
-    ///      ]
+    ///    "count": {
+    ///      "description": "How many of the user's bookmarks are tagged with it.",
+    ///      "type": "integer",
+    ///      "format": "int64"
     ///    },
-    ///    "title": {
-    ///      "description": "The title of the document retrieved",
-    ///      "type": "string"
+    ///    "tag": {
+    ///      "$ref": "#/components/schemas/ExistingTag"
     ///    }
     ///  }
     ///}
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-    pub struct Metadata {
-        ///A description (probably from a meta tag on HTML)
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub description: Option<String>,
-        ///The title of the document retrieved
-        pub title: String,
+    pub struct ListTagsResponseItem {
+        ///How many of the user's bookmarks are tagged with it.
+        pub count: i64,
+        pub tag: ExistingTag,
     }
-    impl From<&Metadata> for Metadata {
-        fn from(value: &Metadata) -> Self {
+    impl From<&ListTagsResponseItem> for ListTagsResponseItem {
+        fn from(value: &ListTagsResponseItem) -> Self {
             value.clone()
         }
     }
-    impl Metadata {
-        pub fn builder() -> builder::Metadata {
+    impl ListTagsResponseItem {
+        pub fn builder() -> builder::ListTagsResponseItem {
             Default::default()
         }
     }
-    /**A bookmark saved by a user.
-
-    See the section in [Transaction][Transaction#working-with-bookmarks]*/
+    ///A bookmark, including tags and associations on it.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "A bookmark saved by a user.\n\nSee the section in [Transaction][Transaction#working-with-bookmarks]",
+    ///  "description": "A bookmark, including tags and associations on it.",
     ///  "type": "object",
     ///  "required": [
-    ///    "created_at",
-    ///    "id",
-    ///    "title",
-    ///    "url",
-    ///    "user_id"
+    ///    "associations",
+    ///    "bookmark",
+    ///    "tags"
     ///  ],
     ///  "properties": {
-    ///    "accessed_at": {
-    ///      "description": "Last time the bookmark was accessed via the web",
-    ///      "type": [
-    ///        "string",
-    ///        "null"
-    ///      ],
-    ///      "format": "date-time"
+    ///    "associations": {
+    ///      "description": "Links associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/AssociatedLink"
+    ///      }
     ///    },
-    ///    "created_at": {
-    ///      "description": "Time at which the bookmark was created.\n\nThis time is assigned in code here, not in the database.",
-    ///      "type": "string",
-    ///      "format": "date-time"
+    ///    "bookmark": {
+    ///      "$ref": "#/components/schemas/ExistingBookmark"
     ///    },
-    ///    "description": {
-    ///      "description": "Description of the bookmark, possibly extracted from the website.",
+    ///    "snippet": {
+    ///      "description": "An excerpt of the bookmark's text that matched the full-text\nsearch, if the query contained one.",
     ///      "type": [
     ///        "string",
     ///        "null"
     ///      ]
     ///    },
-    ///    "id": {
-    ///      "$ref": "#/components/schemas/NoId"
-    ///    },
-    ///    "modified_at": {
-    ///      "description": "Last time the bookmark was modified.\n\nThis field indicates modifications to the bookmark data itself\nonly, not changes to tags or related models.",
-    ///      "type": [
-    ///        "string",
-    ///        "null"
-    ///      ],
-    ///      "format": "date-time"
+    ///    "tags": {
+    ///      "description": "Tags associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ExistingTag"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct MergeBookmarksResponse {
+        ///Links associated with the bookmark
+        pub associations: Vec<AssociatedLink>,
+        pub bookmark: ExistingBookmark,
+        /**An excerpt of the bookmark's text that matched the full-text
+        search, if the query contained one.*/
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub snippet: Option<String>,
+        ///Tags associated with the bookmark
+        pub tags: Vec<ExistingTag>,
+    }
+    impl From<&MergeBookmarksResponse> for MergeBookmarksResponse {
+        fn from(value: &MergeBookmarksResponse) -> Self {
+            value.clone()
+        }
+    }
+    impl MergeBookmarksResponse {
+        pub fn builder() -> builder::MergeBookmarksResponse {
+            Default::default()
+        }
+    }
+    ///Metadata retrieved from a URL
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "Metadata retrieved from a URL",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "title"
+    ///  ],
+    ///  "properties": {
+    ///    "author": {
+    ///      "description": "The page's author",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "canonical_url": {
+    ///      "description": "The URL that the page declares as its canonical one",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uri"
+    ///    },
+    ///    "description": {
+    ///      "description": "A description (from a meta tag, OpenGraph or Twitter card)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "favicon": {
+    ///      "description": "The page's icon",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uri"
+    ///    },
+    ///    "image": {
+    ///      "description": "A preview image of the page (OpenGraph or Twitter card)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uri"
+    ///    },
+    ///    "language": {
+    ///      "description": "The language that the page declares in `<html lang>`",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "published_at": {
+    ///      "description": "When the page was published",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
+    ///    },
+    ///    "title": {
+    ///      "description": "The title of the document retrieved, from its `<title>` or\nelse its OpenGraph or Twitter card title. Empty if the page\nhas none of these.",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Metadata {
+        ///The page's author
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub author: Option<String>,
+        ///The URL that the page declares as its canonical one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub canonical_url: Option<String>,
+        ///A description (from a meta tag, OpenGraph or Twitter card)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        ///The page's icon
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub favicon: Option<String>,
+        ///A preview image of the page (OpenGraph or Twitter card)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub image: Option<String>,
+        ///The language that the page declares in `<html lang>`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub language: Option<String>,
+        ///When the page was published
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub published_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+        /**The title of the document retrieved, from its `<title>` or
+        else its OpenGraph or Twitter card title. Empty if the page
+        has none of these.*/
+        pub title: String,
+    }
+    impl From<&Metadata> for Metadata {
+        fn from(value: &Metadata) -> Self {
+            value.clone()
+        }
+    }
+    impl Metadata {
+        pub fn builder() -> builder::Metadata {
+            Default::default()
+        }
+    }
+    /**A bookmark saved by a user.
+
+    See the section in [Transaction][Transaction#working-with-bookmarks]*/
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A bookmark saved by a user.\n\nSee the section in [Transaction][Transaction#working-with-bookmarks]",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "created_at",
+    ///    "id",
+    ///    "title",
+    ///    "url",
+    ///    "user_id"
+    ///  ],
+    ///  "properties": {
+    ///    "accessed_at": {
+    ///      "description": "Last time the bookmark was accessed via the web",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
+    ///    },
+    ///    "created_at": {
+    ///      "description": "Time at which the bookmark was created.\n\nThis time is assigned in code here, not in the database.",
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "description": {
+    ///      "description": "Description of the bookmark, possibly extracted from the website.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "$ref": "#/components/schemas/NoId"
+    ///    },
+    ///    "modified_at": {
+    ///      "description": "Last time the bookmark was modified.\n\nThis field indicates modifications to the bookmark data itself\nonly, not changes to tags or related models.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
     ///    },
     ///    "notes": {
     ///      "description": "Private notes that the user attached to the bookmark.",
@@ -1263,19 +2373,20 @@ pub mod types {
     Pagination in `lz` works by getting the next page based on what
     the previous page's last element was, aka "cursor-based
     pagination". To that end, use the previous call's `nextCursor`
-    parameter into this call's `cursor` parameter.*/
+    parameter into this call's `cursor` parameter, and keep the order
+    the same.*/
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "Parameters that govern non-offset based pagination.\n\nPagination in `lz` works by getting the next page based on what\nthe previous page's last element was, aka \"cursor-based\npagination\". To that end, use the previous call's `nextCursor`\nparameter into this call's `cursor` parameter.",
+    ///  "description": "Parameters that govern non-offset based pagination.\n\nPagination in `lz` works by getting the next page based on what\nthe previous page's last element was, aka \"cursor-based\npagination\". To that end, use the previous call's `nextCursor`\nparameter into this call's `cursor` parameter, and keep the order\nthe same.",
     ///  "type": "object",
     ///  "properties": {
     ///    "cursor": {
     ///      "allOf": [
     ///        {
-    ///          "$ref": "#/components/schemas/BookmarkId"
+    ///          "$ref": "#/components/schemas/BookmarkCursor"
     ///        }
     ///      ]
     ///    },
@@ -1298,7 +2409,7 @@ pub mod types {
     #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
     pub struct Pagination {
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub cursor: Option<BookmarkId>,
+        pub cursor: Option<BookmarkCursor>,
         ///How many items to return
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub per_page: Option<i64>,
@@ -1313,739 +2424,1367 @@ pub mod types {
             Default::default()
         }
     }
-    ///The database ID of a tag.
+    ///A bookmark, including tags and associations on it.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "The database ID of a tag.",
-    ///  "type": "integer",
-    ///  "format": "int64"
+    ///  "description": "A bookmark, including tags and associations on it.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "associations",
+    ///    "bookmark",
+    ///    "tags"
+    ///  ],
+    ///  "properties": {
+    ///    "associations": {
+    ///      "description": "Links associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/AssociatedLink"
+    ///      }
+    ///    },
+    ///    "bookmark": {
+    ///      "$ref": "#/components/schemas/ExistingBookmark"
+    ///    },
+    ///    "snippet": {
+    ///      "description": "An excerpt of the bookmark's text that matched the full-text\nsearch, if the query contained one.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "tags": {
+    ///      "description": "Tags associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ExistingTag"
+    ///      }
+    ///    }
+    ///  }
     ///}
     /// ```
     /// </details>
-    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-    pub struct TagId(pub i64);
-    impl std::ops::Deref for TagId {
-        type Target = i64;
-        fn deref(&self) -> &i64 {
-            &self.0
-        }
-    }
-    impl From<TagId> for i64 {
-        fn from(value: TagId) -> Self {
-            value.0
-        }
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct ReplaceBookmarkResponse {
+        ///Links associated with the bookmark
+        pub associations: Vec<AssociatedLink>,
+        pub bookmark: ExistingBookmark,
+        /**An excerpt of the bookmark's text that matched the full-text
+        search, if the query contained one.*/
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub snippet: Option<String>,
+        ///Tags associated with the bookmark
+        pub tags: Vec<ExistingTag>,
     }
-    impl From<&TagId> for TagId {
-        fn from(value: &TagId) -> Self {
+    impl From<&ReplaceBookmarkResponse> for ReplaceBookmarkResponse {
+        fn from(value: &ReplaceBookmarkResponse) -> Self {
             value.clone()
         }
     }
-    impl From<i64> for TagId {
-        fn from(value: i64) -> Self {
-            Self(value)
-        }
-    }
-    impl std::str::FromStr for TagId {
-        type Err = <i64 as std::str::FromStr>::Err;
-        fn from_str(value: &str) -> Result<Self, Self::Err> {
-            Ok(Self(value.parse()?))
-        }
-    }
-    impl std::convert::TryFrom<&str> for TagId {
-        type Error = <i64 as std::str::FromStr>::Err;
-        fn try_from(value: &str) -> Result<Self, Self::Error> {
-            value.parse()
-        }
-    }
-    impl std::convert::TryFrom<&String> for TagId {
-        type Error = <i64 as std::str::FromStr>::Err;
-        fn try_from(value: &String) -> Result<Self, Self::Error> {
-            value.parse()
-        }
-    }
-    impl std::convert::TryFrom<String> for TagId {
-        type Error = <i64 as std::str::FromStr>::Err;
-        fn try_from(value: String) -> Result<Self, Self::Error> {
-            value.parse()
-        }
-    }
-    impl ToString for TagId {
-        fn to_string(&self) -> String {
-            self.0.to_string()
+    impl ReplaceBookmarkResponse {
+        pub fn builder() -> builder::ReplaceBookmarkResponse {
+            Default::default()
         }
     }
-    ///The name representation of a tag.
+    ///The host name of a website, as searched for by [`BookmarkSearch::Site`].
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "The name representation of a tag.",
+    ///  "description": "The host name of a website, as searched for by [`BookmarkSearch::Site`].",
     ///  "type": "string"
     ///}
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-    pub struct TagName(pub String);
-    impl std::ops::Deref for TagName {
+    pub struct SiteName(pub String);
+    impl std::ops::Deref for SiteName {
         type Target = String;
         fn deref(&self) -> &String {
             &self.0
         }
     }
-    impl From<TagName> for String {
-        fn from(value: TagName) -> Self {
+    impl From<SiteName> for String {
+        fn from(value: SiteName) -> Self {
             value.0
         }
     }
-    impl From<&TagName> for TagName {
-        fn from(value: &TagName) -> Self {
+    impl From<&SiteName> for SiteName {
+        fn from(value: &SiteName) -> Self {
             value.clone()
         }
     }
-    impl From<String> for TagName {
+    impl From<String> for SiteName {
         fn from(value: String) -> Self {
             Self(value)
         }
     }
-    impl std::str::FromStr for TagName {
+    impl std::str::FromStr for SiteName {
         type Err = std::convert::Infallible;
         fn from_str(value: &str) -> Result<Self, Self::Err> {
             Ok(Self(value.to_string()))
         }
     }
-    impl ToString for TagName {
+    impl ToString for SiteName {
         fn to_string(&self) -> String {
             self.0.to_string()
         }
     }
-    /**A search query for retrieving bookmarks via the tags assigned to them.
-
-    Each*/
+    ///The database ID of a stored URL.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "A search query for retrieving bookmarks via the tags assigned to them.\n\nEach",
+    ///  "description": "The database ID of a stored URL.",
+    ///  "type": "integer",
+    ///  "format": "int64"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct StoredUrlId(pub i64);
+    impl std::ops::Deref for StoredUrlId {
+        type Target = i64;
+        fn deref(&self) -> &i64 {
+            &self.0
+        }
+    }
+    impl From<StoredUrlId> for i64 {
+        fn from(value: StoredUrlId) -> Self {
+            value.0
+        }
+    }
+    impl From<&StoredUrlId> for StoredUrlId {
+        fn from(value: &StoredUrlId) -> Self {
+            value.clone()
+        }
+    }
+    impl From<i64> for StoredUrlId {
+        fn from(value: i64) -> Self {
+            Self(value)
+        }
+    }
+    impl std::str::FromStr for StoredUrlId {
+        type Err = <i64 as std::str::FromStr>::Err;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            Ok(Self(value.parse()?))
+        }
+    }
+    impl std::convert::TryFrom<&str> for StoredUrlId {
+        type Error = <i64 as std::str::FromStr>::Err;
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<&String> for StoredUrlId {
+        type Error = <i64 as std::str::FromStr>::Err;
+        fn try_from(value: &String) -> Result<Self, Self::Error> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<String> for StoredUrlId {
+        type Error = <i64 as std::str::FromStr>::Err;
+        fn try_from(value: String) -> Result<Self, Self::Error> {
+            value.parse()
+        }
+    }
+    impl ToString for StoredUrlId {
+        fn to_string(&self) -> String {
+            self.0.to_string()
+        }
+    }
+    ///An alternative name for one of the user's tags.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "An alternative name for one of the user's tags.",
     ///  "type": "object",
+    ///  "required": [
+    ///    "created_at",
+    ///    "name",
+    ///    "slug",
+    ///    "tag_id",
+    ///    "tag_name"
+    ///  ],
     ///  "properties": {
-    ///    "tags": {
-    ///      "description": "Tags that all returned items should have.",
-    ///      "type": "array",
-    ///      "items": {
-    ///        "$ref": "#/components/schemas/TagName"
-    ///      }
+    ///    "created_at": {
+    ///      "description": "When the alias was created.",
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "name": {
+    ///      "description": "The alias, as it was given.",
+    ///      "type": "string"
+    ///    },
+    ///    "slug": {
+    ///      "description": "Normalized name of the alias; names with the same slug are the\nsame alias.",
+    ///      "type": "string"
+    ///    },
+    ///    "tag_id": {
+    ///      "$ref": "#/components/schemas/TagId"
+    ///    },
+    ///    "tag_name": {
+    ///      "description": "The name of the tag that the alias stands for.",
+    ///      "type": "string"
     ///    }
     ///  }
     ///}
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-    pub struct TagQuery {
-        ///Tags that all returned items should have.
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub tags: Vec<TagName>,
+    pub struct TagAlias {
+        ///When the alias was created.
+        pub created_at: chrono::DateTime<chrono::offset::Utc>,
+        ///The alias, as it was given.
+        pub name: String,
+        /**Normalized name of the alias; names with the same slug are the
+        same alias.*/
+        pub slug: String,
+        pub tag_id: TagId,
+        ///The name of the tag that the alias stands for.
+        pub tag_name: String,
+    }
+    impl From<&TagAlias> for TagAlias {
+        fn from(value: &TagAlias) -> Self {
+            value.clone()
+        }
     }
-    impl From<&TagQuery> for TagQuery {
-        fn from(value: &TagQuery) -> Self {
+    impl TagAlias {
+        pub fn builder() -> builder::TagAlias {
+            Default::default()
+        }
+    }
+    ///A request to add an alias to a tag.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A request to add an alias to a tag.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "name": {
+    ///      "description": "The alias.",
+    ///      "type": "string"
+    ///    },
+    ///    "retro_apply": {
+    ///      "description": "Whether to also merge a tag named like the alias (if the user\nhas one) into the tag, so that its bookmarks get the tag.",
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct TagAliasRequest {
+        ///The alias.
+        pub name: String,
+        /**Whether to also merge a tag named like the alias (if the user
+        has one) into the tag, so that its bookmarks get the tag.*/
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub retro_apply: Option<bool>,
+    }
+    impl From<&TagAliasRequest> for TagAliasRequest {
+        fn from(value: &TagAliasRequest) -> Self {
             value.clone()
         }
     }
-    impl TagQuery {
-        pub fn builder() -> builder::TagQuery {
+    impl TagAliasRequest {
+        pub fn builder() -> builder::TagAliasRequest {
             Default::default()
         }
     }
-    ///The database ID of a user.
+    ///A tag, with the number of the user's bookmarks that have it.
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     ///{
-    ///  "description": "The database ID of a user.",
+    ///  "description": "A tag, with the number of the user's bookmarks that have it.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "count",
+    ///    "tag"
+    ///  ],
+    ///  "properties": {
+    ///    "count": {
+    ///      "description": "How many of the user's bookmarks are tagged with it.",
+    ///      "type": "integer",
+    ///      "format": "int64"
+    ///    },
+    ///    "tag": {
+    ///      "$ref": "#/components/schemas/ExistingTag"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct TagCount {
+        ///How many of the user's bookmarks are tagged with it.
+        pub count: i64,
+        pub tag: ExistingTag,
+    }
+    impl From<&TagCount> for TagCount {
+        fn from(value: &TagCount) -> Self {
+            value.clone()
+        }
+    }
+    impl TagCount {
+        pub fn builder() -> builder::TagCount {
+            Default::default()
+        }
+    }
+    ///The database ID of a tag.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The database ID of a tag.",
     ///  "type": "integer",
     ///  "format": "int64"
     ///}
     /// ```
     /// </details>
     #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-    pub struct UserId(pub i64);
-    impl std::ops::Deref for UserId {
+    pub struct TagId(pub i64);
+    impl std::ops::Deref for TagId {
         type Target = i64;
         fn deref(&self) -> &i64 {
             &self.0
         }
     }
-    impl From<UserId> for i64 {
-        fn from(value: UserId) -> Self {
+    impl From<TagId> for i64 {
+        fn from(value: TagId) -> Self {
             value.0
         }
     }
-    impl From<&UserId> for UserId {
-        fn from(value: &UserId) -> Self {
+    impl From<&TagId> for TagId {
+        fn from(value: &TagId) -> Self {
             value.clone()
         }
     }
-    impl From<i64> for UserId {
+    impl From<i64> for TagId {
         fn from(value: i64) -> Self {
             Self(value)
         }
     }
-    impl std::str::FromStr for UserId {
+    impl std::str::FromStr for TagId {
         type Err = <i64 as std::str::FromStr>::Err;
         fn from_str(value: &str) -> Result<Self, Self::Err> {
             Ok(Self(value.parse()?))
         }
     }
-    impl std::convert::TryFrom<&str> for UserId {
+    impl std::convert::TryFrom<&str> for TagId {
         type Error = <i64 as std::str::FromStr>::Err;
         fn try_from(value: &str) -> Result<Self, Self::Error> {
             value.parse()
         }
     }
-    impl std::convert::TryFrom<&String> for UserId {
+    impl std::convert::TryFrom<&String> for TagId {
         type Error = <i64 as std::str::FromStr>::Err;
         fn try_from(value: &String) -> Result<Self, Self::Error> {
             value.parse()
         }
     }
-    impl std::convert::TryFrom<String> for UserId {
+    impl std::convert::TryFrom<String> for TagId {
         type Error = <i64 as std::str::FromStr>::Err;
         fn try_from(value: String) -> Result<Self, Self::Error> {
             value.parse()
         }
     }
-    impl ToString for UserId {
+    impl ToString for TagId {
         fn to_string(&self) -> String {
             self.0.to_string()
         }
     }
-    /// Types for composing complex structures.
-    pub mod builder {
-        #[derive(Clone, Debug)]
-        pub struct AnnotatedBookmark {
-            associations: Result<Vec<super::AssociatedLink>, String>,
-            bookmark: Result<super::ExistingBookmark, String>,
-            tags: Result<Vec<super::ExistingTag>, String>,
-        }
-        impl Default for AnnotatedBookmark {
-            fn default() -> Self {
-                Self {
-                    associations: Err("no value supplied for associations".to_string()),
-                    bookmark: Err("no value supplied for bookmark".to_string()),
-                    tags: Err("no value supplied for tags".to_string()),
-                }
-            }
+    ///A request to merge a tag into another one.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A request to merge a tag into another one.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "into"
+    ///  ],
+    ///  "properties": {
+    ///    "into": {
+    ///      "$ref": "#/components/schemas/TagId"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct TagMergeRequest {
+        pub into: TagId,
+    }
+    impl From<&TagMergeRequest> for TagMergeRequest {
+        fn from(value: &TagMergeRequest) -> Self {
+            value.clone()
         }
-        impl AnnotatedBookmark {
-            pub fn associations<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
-                T::Error: std::fmt::Display,
-            {
-                self.associations = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for associations: {}", e)
-                });
-                self
-            }
-            pub fn bookmark<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::ExistingBookmark>,
-                T::Error: std::fmt::Display,
-            {
-                self.bookmark = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
-                self
-            }
-            pub fn tags<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::ExistingTag>>,
-                T::Error: std::fmt::Display,
-            {
-                self.tags = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
-                self
-            }
+    }
+    impl TagMergeRequest {
+        pub fn builder() -> builder::TagMergeRequest {
+            Default::default()
         }
-        impl std::convert::TryFrom<AnnotatedBookmark> for super::AnnotatedBookmark {
-            type Error = super::error::ConversionError;
-            fn try_from(value: AnnotatedBookmark) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    associations: value.associations?,
-                    bookmark: value.bookmark?,
-                    tags: value.tags?,
-                })
-            }
+    }
+    ///The name representation of a tag.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The name representation of a tag.",
+    ///  "type": "string"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub struct TagName(pub String);
+    impl std::ops::Deref for TagName {
+        type Target = String;
+        fn deref(&self) -> &String {
+            &self.0
         }
-        impl From<super::AnnotatedBookmark> for AnnotatedBookmark {
-            fn from(value: super::AnnotatedBookmark) -> Self {
-                Self {
-                    associations: Ok(value.associations),
-                    bookmark: Ok(value.bookmark),
-                    tags: Ok(value.tags),
-                }
-            }
+    }
+    impl From<TagName> for String {
+        fn from(value: TagName) -> Self {
+            value.0
         }
-        #[derive(Clone, Debug)]
-        pub struct AssociatedLink {
-            context: Result<Option<String>, String>,
-            link: Result<String, String>,
+    }
+    impl From<&TagName> for TagName {
+        fn from(value: &TagName) -> Self {
+            value.clone()
         }
-        impl Default for AssociatedLink {
-            fn default() -> Self {
-                Self {
-                    context: Ok(Default::default()),
-                    link: Err("no value supplied for link".to_string()),
-                }
-            }
+    }
+    impl From<String> for TagName {
+        fn from(value: String) -> Self {
+            Self(value)
         }
-        impl AssociatedLink {
-            pub fn context<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.context = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for context: {}", e));
-                self
-            }
-            pub fn link<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.link = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for link: {}", e));
-                self
-            }
+    }
+    impl std::str::FromStr for TagName {
+        type Err = std::convert::Infallible;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            Ok(Self(value.to_string()))
         }
-        impl std::convert::TryFrom<AssociatedLink> for super::AssociatedLink {
-            type Error = super::error::ConversionError;
-            fn try_from(value: AssociatedLink) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    context: value.context?,
-                    link: value.link?,
-                })
-            }
+    }
+    impl ToString for TagName {
+        fn to_string(&self) -> String {
+            self.0.to_string()
         }
-        impl From<super::AssociatedLink> for AssociatedLink {
-            fn from(value: super::AssociatedLink) -> Self {
-                Self {
-                    context: Ok(value.context),
-                    link: Ok(value.link),
-                }
-            }
+    }
+    /**A search query for retrieving bookmarks via the tags assigned to them.
+
+    Each*/
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A search query for retrieving bookmarks via the tags assigned to them.\n\nEach",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "tags": {
+    ///      "description": "Tags that all returned items should have.",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/TagName"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct TagQuery {
+        ///Tags that all returned items should have.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub tags: Vec<TagName>,
+    }
+    impl From<&TagQuery> for TagQuery {
+        fn from(value: &TagQuery) -> Self {
+            value.clone()
         }
-        #[derive(Clone, Debug)]
-        pub struct BookmarkCreateRequest {
-            associations: Result<Vec<super::AssociatedLink>, String>,
-            bookmark: Result<super::NewBookmark, String>,
-            tag_names: Result<Vec<String>, String>,
+    }
+    impl TagQuery {
+        pub fn builder() -> builder::TagQuery {
+            Default::default()
         }
-        impl Default for BookmarkCreateRequest {
-            fn default() -> Self {
-                Self {
-                    associations: Ok(Default::default()),
-                    bookmark: Err("no value supplied for bookmark".to_string()),
-                    tag_names: Ok(Default::default()),
-                }
-            }
+    }
+    ///A request to rename a tag.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A request to rename a tag.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "name": {
+    ///      "description": "The new name of the tag.",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct TagRenameRequest {
+        ///The new name of the tag.
+        pub name: String,
+    }
+    impl From<&TagRenameRequest> for TagRenameRequest {
+        fn from(value: &TagRenameRequest) -> Self {
+            value.clone()
         }
-        impl BookmarkCreateRequest {
-            pub fn associations<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
-                T::Error: std::fmt::Display,
-            {
-                self.associations = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for associations: {}", e)
-                });
-                self
-            }
-            pub fn bookmark<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::NewBookmark>,
-                T::Error: std::fmt::Display,
-            {
-                self.bookmark = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
-                self
-            }
-            pub fn tag_names<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.tag_names = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for tag_names: {}", e));
-                self
-            }
+    }
+    impl TagRenameRequest {
+        pub fn builder() -> builder::TagRenameRequest {
+            Default::default()
         }
-        impl std::convert::TryFrom<BookmarkCreateRequest> for super::BookmarkCreateRequest {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: BookmarkCreateRequest,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    associations: value.associations?,
-                    bookmark: value.bookmark?,
-                    tag_names: value.tag_names?,
-                })
-            }
+    }
+    /**A level in the hierarchy of the user's tags.
+
+    Tags with `/` in their names form a tree: The tag `lang/rust/async`
+    is below `lang/rust`, which is below `lang`. Levels that no tag is
+    named for (`lang`, if the user only has `lang/rust`) are in the
+    tree too, without a tag.*/
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A level in the hierarchy of the user's tags.\n\nTags with `/` in their names form a tree: The tag `lang/rust/async`\nis below `lang/rust`, which is below `lang`. Levels that no tag is\nnamed for (`lang`, if the user only has `lang/rust`) are in the\ntree too, without a tag.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "children",
+    ///    "count",
+    ///    "name",
+    ///    "path"
+    ///  ],
+    ///  "properties": {
+    ///    "children": {
+    ///      "description": "The levels below this one, by name.",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/TagTreeNode"
+    ///      }
+    ///    },
+    ///    "count": {
+    ///      "description": "How many of the user's bookmarks are tagged with the tag named\n`path` itself.",
+    ///      "type": "integer",
+    ///      "format": "int64"
+    ///    },
+    ///    "name": {
+    ///      "description": "The name of this level, e.g. `async`.",
+    ///      "type": "string"
+    ///    },
+    ///    "path": {
+    ///      "description": "The full name of the tag at this level, e.g. `lang/rust/async`.",
+    ///      "type": "string"
+    ///    },
+    ///    "tag_id": {
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/TagId"
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct TagTreeNode {
+        ///The levels below this one, by name.
+        pub children: Vec<TagTreeNode>,
+        /**How many of the user's bookmarks are tagged with the tag named
+        `path` itself.*/
+        pub count: i64,
+        ///The name of this level, e.g. `async`.
+        pub name: String,
+        ///The full name of the tag at this level, e.g. `lang/rust/async`.
+        pub path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub tag_id: Option<TagId>,
+    }
+    impl From<&TagTreeNode> for TagTreeNode {
+        fn from(value: &TagTreeNode) -> Self {
+            value.clone()
         }
-        impl From<super::BookmarkCreateRequest> for BookmarkCreateRequest {
-            fn from(value: super::BookmarkCreateRequest) -> Self {
-                Self {
-                    associations: Ok(value.associations),
-                    bookmark: Ok(value.bookmark),
-                    tag_names: Ok(value.tag_names),
-                }
-            }
+    }
+    impl TagTreeNode {
+        pub fn builder() -> builder::TagTreeNode {
+            Default::default()
         }
-        #[derive(Clone, Debug)]
-        pub struct BookmarkSearchDateParams {
-            date: Result<super::DateInput, String>,
-            field: Result<super::BookmarkSearchDatetimeField, String>,
-            orientation: Result<super::BookmarkSearchDatetimeOrientation, String>,
+    }
+    ///A timezone from the IANA timezone database, like `Europe/Berlin`.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A timezone from the IANA timezone database, like `Europe/Berlin`.",
+    ///  "examples": [
+    ///    "Europe/Berlin"
+    ///  ],
+    ///  "type": "string"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub struct Timezone(pub String);
+    impl std::ops::Deref for Timezone {
+        type Target = String;
+        fn deref(&self) -> &String {
+            &self.0
         }
-        impl Default for BookmarkSearchDateParams {
+    }
+    impl From<Timezone> for String {
+        fn from(value: Timezone) -> Self {
+            value.0
+        }
+    }
+    impl From<&Timezone> for Timezone {
+        fn from(value: &Timezone) -> Self {
+            value.clone()
+        }
+    }
+    impl From<String> for Timezone {
+        fn from(value: String) -> Self {
+            Self(value)
+        }
+    }
+    impl std::str::FromStr for Timezone {
+        type Err = std::convert::Infallible;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            Ok(Self(value.to_string()))
+        }
+    }
+    impl ToString for Timezone {
+        fn to_string(&self) -> String {
+            self.0.to_string()
+        }
+    }
+    ///A bookmark, including tags and associations on it.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "A bookmark, including tags and associations on it.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "associations",
+    ///    "bookmark",
+    ///    "tags"
+    ///  ],
+    ///  "properties": {
+    ///    "associations": {
+    ///      "description": "Links associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/AssociatedLink"
+    ///      }
+    ///    },
+    ///    "bookmark": {
+    ///      "$ref": "#/components/schemas/ExistingBookmark"
+    ///    },
+    ///    "snippet": {
+    ///      "description": "An excerpt of the bookmark's text that matched the full-text\nsearch, if the query contained one.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "tags": {
+    ///      "description": "Tags associated with the bookmark",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ExistingTag"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct UpdateBookmarkResponse {
+        ///Links associated with the bookmark
+        pub associations: Vec<AssociatedLink>,
+        pub bookmark: ExistingBookmark,
+        /**An excerpt of the bookmark's text that matched the full-text
+        search, if the query contained one.*/
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub snippet: Option<String>,
+        ///Tags associated with the bookmark
+        pub tags: Vec<ExistingTag>,
+    }
+    impl From<&UpdateBookmarkResponse> for UpdateBookmarkResponse {
+        fn from(value: &UpdateBookmarkResponse) -> Self {
+            value.clone()
+        }
+    }
+    impl UpdateBookmarkResponse {
+        pub fn builder() -> builder::UpdateBookmarkResponse {
+            Default::default()
+        }
+    }
+    ///The database ID of a user.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The database ID of a user.",
+    ///  "type": "integer",
+    ///  "format": "int64"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+    pub struct UserId(pub i64);
+    impl std::ops::Deref for UserId {
+        type Target = i64;
+        fn deref(&self) -> &i64 {
+            &self.0
+        }
+    }
+    impl From<UserId> for i64 {
+        fn from(value: UserId) -> Self {
+            value.0
+        }
+    }
+    impl From<&UserId> for UserId {
+        fn from(value: &UserId) -> Self {
+            value.clone()
+        }
+    }
+    impl From<i64> for UserId {
+        fn from(value: i64) -> Self {
+            Self(value)
+        }
+    }
+    impl std::str::FromStr for UserId {
+        type Err = <i64 as std::str::FromStr>::Err;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            Ok(Self(value.parse()?))
+        }
+    }
+    impl std::convert::TryFrom<&str> for UserId {
+        type Error = <i64 as std::str::FromStr>::Err;
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<&String> for UserId {
+        type Error = <i64 as std::str::FromStr>::Err;
+        fn try_from(value: &String) -> Result<Self, Self::Error> {
+            value.parse()
+        }
+    }
+    impl std::convert::TryFrom<String> for UserId {
+        type Error = <i64 as std::str::FromStr>::Err;
+        fn try_from(value: String) -> Result<Self, Self::Error> {
+            value.parse()
+        }
+    }
+    impl ToString for UserId {
+        fn to_string(&self) -> String {
+            self.0.to_string()
+        }
+    }
+    ///The user's settings.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "description": "The user's settings.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "timezone": {
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/Timezone"
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct UserSettings {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub timezone: Option<Timezone>,
+    }
+    impl From<&UserSettings> for UserSettings {
+        fn from(value: &UserSettings) -> Self {
+            value.clone()
+        }
+    }
+    impl UserSettings {
+        pub fn builder() -> builder::UserSettings {
+            Default::default()
+        }
+    }
+    /// Types for composing complex structures.
+    pub mod builder {
+        #[derive(Clone, Debug)]
+        pub struct AnnotatedBookmark {
+            associations: Result<Vec<super::AssociatedLink>, String>,
+            bookmark: Result<super::ExistingBookmark, String>,
+            snippet: Result<Option<String>, String>,
+            tags: Result<Vec<super::ExistingTag>, String>,
+        }
+        impl Default for AnnotatedBookmark {
             fn default() -> Self {
                 Self {
-                    date: Err("no value supplied for date".to_string()),
-                    field: Err("no value supplied for field".to_string()),
-                    orientation: Err("no value supplied for orientation".to_string()),
+                    associations: Err("no value supplied for associations".to_string()),
+                    bookmark: Err("no value supplied for bookmark".to_string()),
+                    snippet: Ok(Default::default()),
+                    tags: Err("no value supplied for tags".to_string()),
                 }
             }
         }
-        impl BookmarkSearchDateParams {
-            pub fn date<T>(mut self, value: T) -> Self
+        impl AnnotatedBookmark {
+            pub fn associations<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::DateInput>,
+                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
                 T::Error: std::fmt::Display,
             {
-                self.date = value
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
+                self
+            }
+            pub fn bookmark<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ExistingBookmark>,
+                T::Error: std::fmt::Display,
+            {
+                self.bookmark = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for date: {}", e));
+                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
                 self
             }
-            pub fn field<T>(mut self, value: T) -> Self
+            pub fn snippet<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::BookmarkSearchDatetimeField>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.field = value
+                self.snippet = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for field: {}", e));
+                    .map_err(|e| format!("error converting supplied value for snippet: {}", e));
                 self
             }
-            pub fn orientation<T>(mut self, value: T) -> Self
+            pub fn tags<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::BookmarkSearchDatetimeOrientation>,
+                T: std::convert::TryInto<Vec<super::ExistingTag>>,
                 T::Error: std::fmt::Display,
             {
-                self.orientation = value
+                self.tags = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for orientation: {}", e));
+                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
                 self
             }
         }
-        impl std::convert::TryFrom<BookmarkSearchDateParams> for super::BookmarkSearchDateParams {
+        impl std::convert::TryFrom<AnnotatedBookmark> for super::AnnotatedBookmark {
             type Error = super::error::ConversionError;
-            fn try_from(
-                value: BookmarkSearchDateParams,
-            ) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: AnnotatedBookmark) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    date: value.date?,
-                    field: value.field?,
-                    orientation: value.orientation?,
+                    associations: value.associations?,
+                    bookmark: value.bookmark?,
+                    snippet: value.snippet?,
+                    tags: value.tags?,
                 })
             }
         }
-        impl From<super::BookmarkSearchDateParams> for BookmarkSearchDateParams {
-            fn from(value: super::BookmarkSearchDateParams) -> Self {
+        impl From<super::AnnotatedBookmark> for AnnotatedBookmark {
+            fn from(value: super::AnnotatedBookmark) -> Self {
                 Self {
-                    date: Ok(value.date),
-                    field: Ok(value.field),
-                    orientation: Ok(value.orientation),
+                    associations: Ok(value.associations),
+                    bookmark: Ok(value.bookmark),
+                    snippet: Ok(value.snippet),
+                    tags: Ok(value.tags),
                 }
             }
         }
         #[derive(Clone, Debug)]
-        pub struct CompleteTagResponseItem {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            name: Result<String, String>,
-        }
-        impl Default for CompleteTagResponseItem {
+        pub struct Archive {
+            archived_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            html_sha256: Result<String, String>,
+            retrieved_from: Result<String, String>,
+            text: Result<String, String>,
+            text_sha256: Result<String, String>,
+            url_id: Result<super::StoredUrlId, String>,
+        }
+        impl Default for Archive {
             fn default() -> Self {
                 Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    name: Err("no value supplied for name".to_string()),
+                    archived_at: Err("no value supplied for archived_at".to_string()),
+                    html_sha256: Err("no value supplied for html_sha256".to_string()),
+                    retrieved_from: Err("no value supplied for retrieved_from".to_string()),
+                    text: Err("no value supplied for text".to_string()),
+                    text_sha256: Err("no value supplied for text_sha256".to_string()),
+                    url_id: Err("no value supplied for url_id".to_string()),
                 }
             }
         }
-        impl CompleteTagResponseItem {
-            pub fn created_at<T>(mut self, value: T) -> Self
+        impl Archive {
+            pub fn archived_at<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
                 T::Error: std::fmt::Display,
             {
-                self.created_at = value
+                self.archived_at = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                    .map_err(|e| format!("error converting supplied value for archived_at: {}", e));
                 self
             }
-            pub fn name<T>(mut self, value: T) -> Self
+            pub fn html_sha256<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.name = value
+                self.html_sha256 = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                    .map_err(|e| format!("error converting supplied value for html_sha256: {}", e));
                 self
             }
-        }
-        impl std::convert::TryFrom<CompleteTagResponseItem> for super::CompleteTagResponseItem {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: CompleteTagResponseItem,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    name: value.name?,
-                })
-            }
-        }
-        impl From<super::CompleteTagResponseItem> for CompleteTagResponseItem {
-            fn from(value: super::CompleteTagResponseItem) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    name: Ok(value.name),
-                }
-            }
-        }
-        #[derive(Clone, Debug)]
-        pub struct CreateBookmarkResponse {
-            associations: Result<Vec<super::AssociatedLink>, String>,
-            bookmark: Result<super::ExistingBookmark, String>,
-            tags: Result<Vec<super::ExistingTag>, String>,
-        }
-        impl Default for CreateBookmarkResponse {
-            fn default() -> Self {
-                Self {
-                    associations: Err("no value supplied for associations".to_string()),
-                    bookmark: Err("no value supplied for bookmark".to_string()),
-                    tags: Err("no value supplied for tags".to_string()),
-                }
-            }
-        }
-        impl CreateBookmarkResponse {
-            pub fn associations<T>(mut self, value: T) -> Self
+            pub fn retrieved_from<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.associations = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for associations: {}", e)
+                self.retrieved_from = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for retrieved_from: {}", e)
                 });
                 self
             }
-            pub fn bookmark<T>(mut self, value: T) -> Self
+            pub fn text<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::ExistingBookmark>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.bookmark = value
+                self.text = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
+                    .map_err(|e| format!("error converting supplied value for text: {}", e));
                 self
             }
-            pub fn tags<T>(mut self, value: T) -> Self
+            pub fn text_sha256<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Vec<super::ExistingTag>>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.tags = value
+                self.text_sha256 = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
+                    .map_err(|e| format!("error converting supplied value for text_sha256: {}", e));
+                self
+            }
+            pub fn url_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::StoredUrlId>,
+                T::Error: std::fmt::Display,
+            {
+                self.url_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url_id: {}", e));
                 self
             }
         }
-        impl std::convert::TryFrom<CreateBookmarkResponse> for super::CreateBookmarkResponse {
+        impl std::convert::TryFrom<Archive> for super::Archive {
             type Error = super::error::ConversionError;
-            fn try_from(
-                value: CreateBookmarkResponse,
-            ) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: Archive) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    associations: value.associations?,
-                    bookmark: value.bookmark?,
-                    tags: value.tags?,
+                    archived_at: value.archived_at?,
+                    html_sha256: value.html_sha256?,
+                    retrieved_from: value.retrieved_from?,
+                    text: value.text?,
+                    text_sha256: value.text_sha256?,
+                    url_id: value.url_id?,
                 })
             }
         }
-        impl From<super::CreateBookmarkResponse> for CreateBookmarkResponse {
-            fn from(value: super::CreateBookmarkResponse) -> Self {
+        impl From<super::Archive> for Archive {
+            fn from(value: super::Archive) -> Self {
                 Self {
-                    associations: Ok(value.associations),
-                    bookmark: Ok(value.bookmark),
-                    tags: Ok(value.tags),
+                    archived_at: Ok(value.archived_at),
+                    html_sha256: Ok(value.html_sha256),
+                    retrieved_from: Ok(value.retrieved_from),
+                    text: Ok(value.text),
+                    text_sha256: Ok(value.text_sha256),
+                    url_id: Ok(value.url_id),
                 }
             }
         }
         #[derive(Clone, Debug)]
-        pub struct ExistingBookmark {
-            accessed_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            description: Result<Option<String>, String>,
-            id: Result<super::BookmarkId, String>,
-            modified_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            notes: Result<Option<String>, String>,
-            shared: Result<Option<bool>, String>,
-            title: Result<String, String>,
-            unread: Result<Option<bool>, String>,
-            url: Result<String, String>,
-            user_id: Result<super::UserId, String>,
-            website_description: Result<Option<String>, String>,
-            website_title: Result<Option<String>, String>,
+        pub struct AssociatedLink {
+            context: Result<Option<String>, String>,
+            link: Result<String, String>,
         }
-        impl Default for ExistingBookmark {
+        impl Default for AssociatedLink {
             fn default() -> Self {
                 Self {
-                    accessed_at: Ok(Default::default()),
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    description: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    modified_at: Ok(Default::default()),
-                    notes: Ok(Default::default()),
-                    shared: Ok(Default::default()),
-                    title: Err("no value supplied for title".to_string()),
-                    unread: Ok(Default::default()),
-                    url: Err("no value supplied for url".to_string()),
-                    user_id: Err("no value supplied for user_id".to_string()),
-                    website_description: Ok(Default::default()),
-                    website_title: Ok(Default::default()),
+                    context: Ok(Default::default()),
+                    link: Err("no value supplied for link".to_string()),
                 }
             }
         }
-        impl ExistingBookmark {
-            pub fn accessed_at<T>(mut self, value: T) -> Self
+        impl AssociatedLink {
+            pub fn context<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.accessed_at = value
+                self.context = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for accessed_at: {}", e));
+                    .map_err(|e| format!("error converting supplied value for context: {}", e));
                 self
             }
-            pub fn created_at<T>(mut self, value: T) -> Self
+            pub fn link<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.created_at = value
+                self.link = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                    .map_err(|e| format!("error converting supplied value for link: {}", e));
                 self
             }
-            pub fn description<T>(mut self, value: T) -> Self
+        }
+        impl std::convert::TryFrom<AssociatedLink> for super::AssociatedLink {
+            type Error = super::error::ConversionError;
+            fn try_from(value: AssociatedLink) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    context: value.context?,
+                    link: value.link?,
+                })
+            }
+        }
+        impl From<super::AssociatedLink> for AssociatedLink {
+            fn from(value: super::AssociatedLink) -> Self {
+                Self {
+                    context: Ok(value.context),
+                    link: Ok(value.link),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct BookmarkCreateRequest {
+            associations: Result<Vec<super::AssociatedLink>, String>,
+            bookmark: Result<super::NewBookmark, String>,
+            tag_names: Result<Vec<String>, String>,
+        }
+        impl Default for BookmarkCreateRequest {
+            fn default() -> Self {
+                Self {
+                    associations: Ok(Default::default()),
+                    bookmark: Err("no value supplied for bookmark".to_string()),
+                    tag_names: Ok(Default::default()),
+                }
+            }
+        }
+        impl BookmarkCreateRequest {
+            pub fn associations<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
                 T::Error: std::fmt::Display,
             {
-                self.description = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
                 self
             }
-            pub fn id<T>(mut self, value: T) -> Self
+            pub fn bookmark<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::BookmarkId>,
+                T: std::convert::TryInto<super::NewBookmark>,
                 T::Error: std::fmt::Display,
             {
-                self.id = value
+                self.bookmark = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
                 self
             }
-            pub fn modified_at<T>(mut self, value: T) -> Self
+            pub fn tag_names<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T: std::convert::TryInto<Vec<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.modified_at = value
+                self.tag_names = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for modified_at: {}", e));
+                    .map_err(|e| format!("error converting supplied value for tag_names: {}", e));
                 self
             }
-            pub fn notes<T>(mut self, value: T) -> Self
+        }
+        impl std::convert::TryFrom<BookmarkCreateRequest> for super::BookmarkCreateRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: BookmarkCreateRequest,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    associations: value.associations?,
+                    bookmark: value.bookmark?,
+                    tag_names: value.tag_names?,
+                })
+            }
+        }
+        impl From<super::BookmarkCreateRequest> for BookmarkCreateRequest {
+            fn from(value: super::BookmarkCreateRequest) -> Self {
+                Self {
+                    associations: Ok(value.associations),
+                    bookmark: Ok(value.bookmark),
+                    tag_names: Ok(value.tag_names),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct BookmarkMergeRequest {
+            absorb: Result<super::BookmarkId, String>,
+        }
+        impl Default for BookmarkMergeRequest {
+            fn default() -> Self {
+                Self {
+                    absorb: Err("no value supplied for absorb".to_string()),
+                }
+            }
+        }
+        impl BookmarkMergeRequest {
+            pub fn absorb<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<super::BookmarkId>,
                 T::Error: std::fmt::Display,
             {
-                self.notes = value
+                self.absorb = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for notes: {}", e));
+                    .map_err(|e| format!("error converting supplied value for absorb: {}", e));
                 self
             }
-            pub fn shared<T>(mut self, value: T) -> Self
+        }
+        impl std::convert::TryFrom<BookmarkMergeRequest> for super::BookmarkMergeRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: BookmarkMergeRequest,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    absorb: value.absorb?,
+                })
+            }
+        }
+        impl From<super::BookmarkMergeRequest> for BookmarkMergeRequest {
+            fn from(value: super::BookmarkMergeRequest) -> Self {
+                Self {
+                    absorb: Ok(value.absorb),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct BookmarkSearchDateParams {
+            date: Result<super::DateInput, String>,
+            field: Result<super::BookmarkSearchDatetimeField, String>,
+            orientation: Result<super::BookmarkSearchDatetimeOrientation, String>,
+            timezone: Result<Option<super::Timezone>, String>,
+        }
+        impl Default for BookmarkSearchDateParams {
+            fn default() -> Self {
+                Self {
+                    date: Err("no value supplied for date".to_string()),
+                    field: Err("no value supplied for field".to_string()),
+                    orientation: Err("no value supplied for orientation".to_string()),
+                    timezone: Ok(Default::default()),
+                }
+            }
+        }
+        impl BookmarkSearchDateParams {
+            pub fn date<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<bool>>,
+                T: std::convert::TryInto<super::DateInput>,
                 T::Error: std::fmt::Display,
             {
-                self.shared = value
+                self.date = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for shared: {}", e));
+                    .map_err(|e| format!("error converting supplied value for date: {}", e));
                 self
             }
-            pub fn title<T>(mut self, value: T) -> Self
+            pub fn field<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<super::BookmarkSearchDatetimeField>,
                 T::Error: std::fmt::Display,
             {
-                self.title = value
+                self.field = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                    .map_err(|e| format!("error converting supplied value for field: {}", e));
                 self
             }
-            pub fn unread<T>(mut self, value: T) -> Self
+            pub fn orientation<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<bool>>,
+                T: std::convert::TryInto<super::BookmarkSearchDatetimeOrientation>,
                 T::Error: std::fmt::Display,
             {
-                self.unread = value
+                self.orientation = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for unread: {}", e));
+                    .map_err(|e| format!("error converting supplied value for orientation: {}", e));
                 self
             }
-            pub fn url<T>(mut self, value: T) -> Self
+            pub fn timezone<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Option<super::Timezone>>,
                 T::Error: std::fmt::Display,
             {
-                self.url = value
+                self.timezone = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for url: {}", e));
+                    .map_err(|e| format!("error converting supplied value for timezone: {}", e));
                 self
             }
-            pub fn user_id<T>(mut self, value: T) -> Self
+        }
+        impl std::convert::TryFrom<BookmarkSearchDateParams> for super::BookmarkSearchDateParams {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: BookmarkSearchDateParams,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    date: value.date?,
+                    field: value.field?,
+                    orientation: value.orientation?,
+                    timezone: value.timezone?,
+                })
+            }
+        }
+        impl From<super::BookmarkSearchDateParams> for BookmarkSearchDateParams {
+            fn from(value: super::BookmarkSearchDateParams) -> Self {
+                Self {
+                    date: Ok(value.date),
+                    field: Ok(value.field),
+                    orientation: Ok(value.orientation),
+                    timezone: Ok(value.timezone),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct BookmarkUpdateRequest {
+            associations: Result<Option<Vec<super::AssociatedLink>>, String>,
+            description: Result<Option<String>, String>,
+            notes: Result<Option<String>, String>,
+            shared: Result<Option<bool>, String>,
+            tag_names: Result<Option<Vec<String>>, String>,
+            title: Result<Option<String>, String>,
+            unread: Result<Option<bool>, String>,
+            url: Result<Option<String>, String>,
+            website_description: Result<Option<String>, String>,
+            website_title: Result<Option<String>, String>,
+        }
+        impl Default for BookmarkUpdateRequest {
+            fn default() -> Self {
+                Self {
+                    associations: Ok(Default::default()),
+                    description: Ok(Default::default()),
+                    notes: Ok(Default::default()),
+                    shared: Ok(Default::default()),
+                    tag_names: Ok(Default::default()),
+                    title: Ok(Default::default()),
+                    unread: Ok(Default::default()),
+                    url: Ok(Default::default()),
+                    website_description: Ok(Default::default()),
+                    website_title: Ok(Default::default()),
+                }
+            }
+        }
+        impl BookmarkUpdateRequest {
+            pub fn associations<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::UserId>,
+                T: std::convert::TryInto<Option<Vec<super::AssociatedLink>>>,
                 T::Error: std::fmt::Display,
             {
-                self.user_id = value
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.description = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for user_id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self
+            }
+            pub fn notes<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.notes = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for notes: {}", e));
+                self
+            }
+            pub fn shared<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<bool>>,
+                T::Error: std::fmt::Display,
+            {
+                self.shared = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for shared: {}", e));
+                self
+            }
+            pub fn tag_names<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<Vec<String>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.tag_names = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tag_names: {}", e));
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+            pub fn unread<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<bool>>,
+                T::Error: std::fmt::Display,
+            {
+                self.unread = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for unread: {}", e));
+                self
+            }
+            pub fn url<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.url = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
                 self
             }
             pub fn website_description<T>(mut self, value: T) -> Self
@@ -2072,844 +3811,3475 @@ pub mod types {
                 self
             }
         }
-        impl std::convert::TryFrom<ExistingBookmark> for super::ExistingBookmark {
+        impl std::convert::TryFrom<BookmarkUpdateRequest> for super::BookmarkUpdateRequest {
             type Error = super::error::ConversionError;
-            fn try_from(value: ExistingBookmark) -> Result<Self, super::error::ConversionError> {
+            fn try_from(
+                value: BookmarkUpdateRequest,
+            ) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    accessed_at: value.accessed_at?,
-                    created_at: value.created_at?,
+                    associations: value.associations?,
                     description: value.description?,
-                    id: value.id?,
-                    modified_at: value.modified_at?,
                     notes: value.notes?,
                     shared: value.shared?,
+                    tag_names: value.tag_names?,
                     title: value.title?,
                     unread: value.unread?,
                     url: value.url?,
-                    user_id: value.user_id?,
                     website_description: value.website_description?,
                     website_title: value.website_title?,
                 })
             }
         }
-        impl From<super::ExistingBookmark> for ExistingBookmark {
-            fn from(value: super::ExistingBookmark) -> Self {
+        impl From<super::BookmarkUpdateRequest> for BookmarkUpdateRequest {
+            fn from(value: super::BookmarkUpdateRequest) -> Self {
                 Self {
-                    accessed_at: Ok(value.accessed_at),
-                    created_at: Ok(value.created_at),
+                    associations: Ok(value.associations),
                     description: Ok(value.description),
-                    id: Ok(value.id),
-                    modified_at: Ok(value.modified_at),
                     notes: Ok(value.notes),
                     shared: Ok(value.shared),
+                    tag_names: Ok(value.tag_names),
                     title: Ok(value.title),
                     unread: Ok(value.unread),
                     url: Ok(value.url),
-                    user_id: Ok(value.user_id),
                     website_description: Ok(value.website_description),
                     website_title: Ok(value.website_title),
                 }
             }
         }
         #[derive(Clone, Debug)]
-        pub struct ExistingTag {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            name: Result<String, String>,
+        pub struct CreateBookmarkResponse {
+            associations: Result<Vec<super::AssociatedLink>, String>,
+            bookmark: Result<super::ExistingBookmark, String>,
+            snippet: Result<Option<String>, String>,
+            tags: Result<Vec<super::ExistingTag>, String>,
         }
-        impl Default for ExistingTag {
+        impl Default for CreateBookmarkResponse {
             fn default() -> Self {
                 Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    name: Err("no value supplied for name".to_string()),
+                    associations: Err("no value supplied for associations".to_string()),
+                    bookmark: Err("no value supplied for bookmark".to_string()),
+                    snippet: Ok(Default::default()),
+                    tags: Err("no value supplied for tags".to_string()),
                 }
             }
         }
-        impl ExistingTag {
-            pub fn created_at<T>(mut self, value: T) -> Self
+        impl CreateBookmarkResponse {
+            pub fn associations<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
                 T::Error: std::fmt::Display,
             {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
                 self
             }
-            pub fn name<T>(mut self, value: T) -> Self
+            pub fn bookmark<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<super::ExistingBookmark>,
                 T::Error: std::fmt::Display,
             {
-                self.name = value
+                self.bookmark = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
                 self
             }
-        }
-        impl std::convert::TryFrom<ExistingTag> for super::ExistingTag {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ExistingTag) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    name: value.name?,
-                })
-            }
-        }
-        impl From<super::ExistingTag> for ExistingTag {
-            fn from(value: super::ExistingTag) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    name: Ok(value.name),
-                }
-            }
-        }
-        #[derive(Clone, Debug)]
-        pub struct FetchPageMetadataResponse {
-            description: Result<Option<String>, String>,
-            title: Result<String, String>,
-        }
-        impl Default for FetchPageMetadataResponse {
-            fn default() -> Self {
-                Self {
-                    description: Ok(Default::default()),
-                    title: Err("no value supplied for title".to_string()),
-                }
-            }
-        }
-        impl FetchPageMetadataResponse {
-            pub fn description<T>(mut self, value: T) -> Self
+            pub fn snippet<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.description = value
+                self.snippet = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                    .map_err(|e| format!("error converting supplied value for snippet: {}", e));
                 self
             }
-            pub fn title<T>(mut self, value: T) -> Self
+            pub fn tags<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Vec<super::ExistingTag>>,
                 T::Error: std::fmt::Display,
             {
-                self.title = value
+                self.tags = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
                 self
             }
         }
-        impl std::convert::TryFrom<FetchPageMetadataResponse> for super::FetchPageMetadataResponse {
+        impl std::convert::TryFrom<CreateBookmarkResponse> for super::CreateBookmarkResponse {
             type Error = super::error::ConversionError;
             fn try_from(
-                value: FetchPageMetadataResponse,
+                value: CreateBookmarkResponse,
             ) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    description: value.description?,
-                    title: value.title?,
+                    associations: value.associations?,
+                    bookmark: value.bookmark?,
+                    snippet: value.snippet?,
+                    tags: value.tags?,
                 })
             }
         }
-        impl From<super::FetchPageMetadataResponse> for FetchPageMetadataResponse {
-            fn from(value: super::FetchPageMetadataResponse) -> Self {
+        impl From<super::CreateBookmarkResponse> for CreateBookmarkResponse {
+            fn from(value: super::CreateBookmarkResponse) -> Self {
                 Self {
-                    description: Ok(value.description),
-                    title: Ok(value.title),
+                    associations: Ok(value.associations),
+                    bookmark: Ok(value.bookmark),
+                    snippet: Ok(value.snippet),
+                    tags: Ok(value.tags),
                 }
             }
         }
         #[derive(Clone, Debug)]
-        pub struct ListBookmarkResult {
-            bookmarks: Result<Vec<super::AnnotatedBookmark>, String>,
-            next_cursor: Result<Option<super::BookmarkId>, String>,
+        pub struct ErrorResponse {
+            error_message: Result<String, String>,
         }
-        impl Default for ListBookmarkResult {
+        impl Default for ErrorResponse {
             fn default() -> Self {
                 Self {
-                    bookmarks: Err("no value supplied for bookmarks".to_string()),
-                    next_cursor: Ok(Default::default()),
+                    error_message: Err("no value supplied for error_message".to_string()),
                 }
             }
         }
-        impl ListBookmarkResult {
-            pub fn bookmarks<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::AnnotatedBookmark>>,
-                T::Error: std::fmt::Display,
-            {
-                self.bookmarks = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for bookmarks: {}", e));
-                self
-            }
-            pub fn next_cursor<T>(mut self, value: T) -> Self
+        impl ErrorResponse {
+            pub fn error_message<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<super::BookmarkId>>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.next_cursor = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for next_cursor: {}", e));
+                self.error_message = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for error_message: {}", e)
+                });
                 self
             }
         }
-        impl std::convert::TryFrom<ListBookmarkResult> for super::ListBookmarkResult {
+        impl std::convert::TryFrom<ErrorResponse> for super::ErrorResponse {
             type Error = super::error::ConversionError;
-            fn try_from(value: ListBookmarkResult) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: ErrorResponse) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    bookmarks: value.bookmarks?,
-                    next_cursor: value.next_cursor?,
+                    error_message: value.error_message?,
+                })
+            }
+        }
+        impl From<super::ErrorResponse> for ErrorResponse {
+            fn from(value: super::ErrorResponse) -> Self {
+                Self {
+                    error_message: Ok(value.error_message),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct ExistingBookmark {
+            accessed_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            description: Result<Option<String>, String>,
+            id: Result<super::BookmarkId, String>,
+            modified_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            notes: Result<Option<String>, String>,
+            shared: Result<Option<bool>, String>,
+            title: Result<String, String>,
+            unread: Result<Option<bool>, String>,
+            url: Result<String, String>,
+            user_id: Result<super::UserId, String>,
+            website_description: Result<Option<String>, String>,
+            website_title: Result<Option<String>, String>,
+        }
+        impl Default for ExistingBookmark {
+            fn default() -> Self {
+                Self {
+                    accessed_at: Ok(Default::default()),
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    description: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    modified_at: Ok(Default::default()),
+                    notes: Ok(Default::default()),
+                    shared: Ok(Default::default()),
+                    title: Err("no value supplied for title".to_string()),
+                    unread: Ok(Default::default()),
+                    url: Err("no value supplied for url".to_string()),
+                    user_id: Err("no value supplied for user_id".to_string()),
+                    website_description: Ok(Default::default()),
+                    website_title: Ok(Default::default()),
+                }
+            }
+        }
+        impl ExistingBookmark {
+            pub fn accessed_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.accessed_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for accessed_at: {}", e));
+                self
+            }
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::BookmarkId>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn modified_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.modified_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for modified_at: {}", e));
+                self
+            }
+            pub fn notes<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.notes = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for notes: {}", e));
+                self
+            }
+            pub fn shared<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<bool>>,
+                T::Error: std::fmt::Display,
+            {
+                self.shared = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for shared: {}", e));
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+            pub fn unread<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<bool>>,
+                T::Error: std::fmt::Display,
+            {
+                self.unread = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for unread: {}", e));
+                self
+            }
+            pub fn url<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.url = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
+                self
+            }
+            pub fn user_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::UserId>,
+                T::Error: std::fmt::Display,
+            {
+                self.user_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for user_id: {}", e));
+                self
+            }
+            pub fn website_description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.website_description = value.try_into().map_err(|e| {
+                    format!(
+                        "error converting supplied value for website_description: {}",
+                        e
+                    )
+                });
+                self
+            }
+            pub fn website_title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.website_title = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for website_title: {}", e)
+                });
+                self
+            }
+        }
+        impl std::convert::TryFrom<ExistingBookmark> for super::ExistingBookmark {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ExistingBookmark) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    accessed_at: value.accessed_at?,
+                    created_at: value.created_at?,
+                    description: value.description?,
+                    id: value.id?,
+                    modified_at: value.modified_at?,
+                    notes: value.notes?,
+                    shared: value.shared?,
+                    title: value.title?,
+                    unread: value.unread?,
+                    url: value.url?,
+                    user_id: value.user_id?,
+                    website_description: value.website_description?,
+                    website_title: value.website_title?,
                 })
             }
         }
-        impl From<super::ListBookmarkResult> for ListBookmarkResult {
-            fn from(value: super::ListBookmarkResult) -> Self {
-                Self {
-                    bookmarks: Ok(value.bookmarks),
-                    next_cursor: Ok(value.next_cursor),
-                }
-            }
+        impl From<super::ExistingBookmark> for ExistingBookmark {
+            fn from(value: super::ExistingBookmark) -> Self {
+                Self {
+                    accessed_at: Ok(value.accessed_at),
+                    created_at: Ok(value.created_at),
+                    description: Ok(value.description),
+                    id: Ok(value.id),
+                    modified_at: Ok(value.modified_at),
+                    notes: Ok(value.notes),
+                    shared: Ok(value.shared),
+                    title: Ok(value.title),
+                    unread: Ok(value.unread),
+                    url: Ok(value.url),
+                    user_id: Ok(value.user_id),
+                    website_description: Ok(value.website_description),
+                    website_title: Ok(value.website_title),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct ExistingTag {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            id: Result<super::TagId, String>,
+            name: Result<String, String>,
+            slug: Result<String, String>,
+        }
+        impl Default for ExistingTag {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    slug: Err("no value supplied for slug".to_string()),
+                }
+            }
+        }
+        impl ExistingTag {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::TagId>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn slug<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.slug = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for slug: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<ExistingTag> for super::ExistingTag {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ExistingTag) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    id: value.id?,
+                    name: value.name?,
+                    slug: value.slug?,
+                })
+            }
+        }
+        impl From<super::ExistingTag> for ExistingTag {
+            fn from(value: super::ExistingTag) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    id: Ok(value.id),
+                    name: Ok(value.name),
+                    slug: Ok(value.slug),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct FetchPageMetadataResponse {
+            author: Result<Option<String>, String>,
+            canonical_url: Result<Option<String>, String>,
+            description: Result<Option<String>, String>,
+            favicon: Result<Option<String>, String>,
+            image: Result<Option<String>, String>,
+            language: Result<Option<String>, String>,
+            published_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            title: Result<String, String>,
+        }
+        impl Default for FetchPageMetadataResponse {
+            fn default() -> Self {
+                Self {
+                    author: Ok(Default::default()),
+                    canonical_url: Ok(Default::default()),
+                    description: Ok(Default::default()),
+                    favicon: Ok(Default::default()),
+                    image: Ok(Default::default()),
+                    language: Ok(Default::default()),
+                    published_at: Ok(Default::default()),
+                    title: Err("no value supplied for title".to_string()),
+                }
+            }
+        }
+        impl FetchPageMetadataResponse {
+            pub fn author<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.author = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for author: {}", e));
+                self
+            }
+            pub fn canonical_url<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.canonical_url = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for canonical_url: {}", e)
+                });
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self
+            }
+            pub fn favicon<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.favicon = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for favicon: {}", e));
+                self
+            }
+            pub fn image<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.image = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for image: {}", e));
+                self
+            }
+            pub fn language<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.language = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for language: {}", e));
+                self
+            }
+            pub fn published_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.published_at = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for published_at: {}", e)
+                });
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<FetchPageMetadataResponse> for super::FetchPageMetadataResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: FetchPageMetadataResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    author: value.author?,
+                    canonical_url: value.canonical_url?,
+                    description: value.description?,
+                    favicon: value.favicon?,
+                    image: value.image?,
+                    language: value.language?,
+                    published_at: value.published_at?,
+                    title: value.title?,
+                })
+            }
+        }
+        impl From<super::FetchPageMetadataResponse> for FetchPageMetadataResponse {
+            fn from(value: super::FetchPageMetadataResponse) -> Self {
+                Self {
+                    author: Ok(value.author),
+                    canonical_url: Ok(value.canonical_url),
+                    description: Ok(value.description),
+                    favicon: Ok(value.favicon),
+                    image: Ok(value.image),
+                    language: Ok(value.language),
+                    published_at: Ok(value.published_at),
+                    title: Ok(value.title),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct GetBookmarkArchiveResponse {
+            archived_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            html_sha256: Result<String, String>,
+            retrieved_from: Result<String, String>,
+            text: Result<String, String>,
+            text_sha256: Result<String, String>,
+            url_id: Result<super::StoredUrlId, String>,
+        }
+        impl Default for GetBookmarkArchiveResponse {
+            fn default() -> Self {
+                Self {
+                    archived_at: Err("no value supplied for archived_at".to_string()),
+                    html_sha256: Err("no value supplied for html_sha256".to_string()),
+                    retrieved_from: Err("no value supplied for retrieved_from".to_string()),
+                    text: Err("no value supplied for text".to_string()),
+                    text_sha256: Err("no value supplied for text_sha256".to_string()),
+                    url_id: Err("no value supplied for url_id".to_string()),
+                }
+            }
+        }
+        impl GetBookmarkArchiveResponse {
+            pub fn archived_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.archived_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for archived_at: {}", e));
+                self
+            }
+            pub fn html_sha256<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.html_sha256 = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for html_sha256: {}", e));
+                self
+            }
+            pub fn retrieved_from<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.retrieved_from = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for retrieved_from: {}", e)
+                });
+                self
+            }
+            pub fn text<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.text = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for text: {}", e));
+                self
+            }
+            pub fn text_sha256<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.text_sha256 = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for text_sha256: {}", e));
+                self
+            }
+            pub fn url_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::StoredUrlId>,
+                T::Error: std::fmt::Display,
+            {
+                self.url_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url_id: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<GetBookmarkArchiveResponse> for super::GetBookmarkArchiveResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: GetBookmarkArchiveResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    archived_at: value.archived_at?,
+                    html_sha256: value.html_sha256?,
+                    retrieved_from: value.retrieved_from?,
+                    text: value.text?,
+                    text_sha256: value.text_sha256?,
+                    url_id: value.url_id?,
+                })
+            }
+        }
+        impl From<super::GetBookmarkArchiveResponse> for GetBookmarkArchiveResponse {
+            fn from(value: super::GetBookmarkArchiveResponse) -> Self {
+                Self {
+                    archived_at: Ok(value.archived_at),
+                    html_sha256: Ok(value.html_sha256),
+                    retrieved_from: Ok(value.retrieved_from),
+                    text: Ok(value.text),
+                    text_sha256: Ok(value.text_sha256),
+                    url_id: Ok(value.url_id),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct GetBookmarkResponse {
+            associations: Result<Vec<super::AssociatedLink>, String>,
+            bookmark: Result<super::ExistingBookmark, String>,
+            snippet: Result<Option<String>, String>,
+            tags: Result<Vec<super::ExistingTag>, String>,
+        }
+        impl Default for GetBookmarkResponse {
+            fn default() -> Self {
+                Self {
+                    associations: Err("no value supplied for associations".to_string()),
+                    bookmark: Err("no value supplied for bookmark".to_string()),
+                    snippet: Ok(Default::default()),
+                    tags: Err("no value supplied for tags".to_string()),
+                }
+            }
+        }
+        impl GetBookmarkResponse {
+            pub fn associations<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
+                T::Error: std::fmt::Display,
+            {
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
+                self
+            }
+            pub fn bookmark<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ExistingBookmark>,
+                T::Error: std::fmt::Display,
+            {
+                self.bookmark = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
+                self
+            }
+            pub fn snippet<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.snippet = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for snippet: {}", e));
+                self
+            }
+            pub fn tags<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::ExistingTag>>,
+                T::Error: std::fmt::Display,
+            {
+                self.tags = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<GetBookmarkResponse> for super::GetBookmarkResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(value: GetBookmarkResponse) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    associations: value.associations?,
+                    bookmark: value.bookmark?,
+                    snippet: value.snippet?,
+                    tags: value.tags?,
+                })
+            }
+        }
+        impl From<super::GetBookmarkResponse> for GetBookmarkResponse {
+            fn from(value: super::GetBookmarkResponse) -> Self {
+                Self {
+                    associations: Ok(value.associations),
+                    bookmark: Ok(value.bookmark),
+                    snippet: Ok(value.snippet),
+                    tags: Ok(value.tags),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct ListBookmarkResult {
+            bookmarks: Result<Vec<super::AnnotatedBookmark>, String>,
+            next_cursor: Result<Option<super::BookmarkCursor>, String>,
+        }
+        impl Default for ListBookmarkResult {
+            fn default() -> Self {
+                Self {
+                    bookmarks: Err("no value supplied for bookmarks".to_string()),
+                    next_cursor: Ok(Default::default()),
+                }
+            }
+        }
+        impl ListBookmarkResult {
+            pub fn bookmarks<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::AnnotatedBookmark>>,
+                T::Error: std::fmt::Display,
+            {
+                self.bookmarks = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for bookmarks: {}", e));
+                self
+            }
+            pub fn next_cursor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::BookmarkCursor>>,
+                T::Error: std::fmt::Display,
+            {
+                self.next_cursor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for next_cursor: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<ListBookmarkResult> for super::ListBookmarkResult {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ListBookmarkResult) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    bookmarks: value.bookmarks?,
+                    next_cursor: value.next_cursor?,
+                })
+            }
+        }
+        impl From<super::ListBookmarkResult> for ListBookmarkResult {
+            fn from(value: super::ListBookmarkResult) -> Self {
+                Self {
+                    bookmarks: Ok(value.bookmarks),
+                    next_cursor: Ok(value.next_cursor),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct ListBookmarksMatchingResponse {
+            bookmarks: Result<Vec<super::AnnotatedBookmark>, String>,
+            next_cursor: Result<Option<super::BookmarkCursor>, String>,
+        }
+        impl Default for ListBookmarksMatchingResponse {
+            fn default() -> Self {
+                Self {
+                    bookmarks: Err("no value supplied for bookmarks".to_string()),
+                    next_cursor: Ok(Default::default()),
+                }
+            }
+        }
+        impl ListBookmarksMatchingResponse {
+            pub fn bookmarks<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::AnnotatedBookmark>>,
+                T::Error: std::fmt::Display,
+            {
+                self.bookmarks = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for bookmarks: {}", e));
+                self
+            }
+            pub fn next_cursor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::BookmarkCursor>>,
+                T::Error: std::fmt::Display,
+            {
+                self.next_cursor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for next_cursor: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<ListBookmarksMatchingResponse> for super::ListBookmarksMatchingResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ListBookmarksMatchingResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    bookmarks: value.bookmarks?,
+                    next_cursor: value.next_cursor?,
+                })
+            }
+        }
+        impl From<super::ListBookmarksMatchingResponse> for ListBookmarksMatchingResponse {
+            fn from(value: super::ListBookmarksMatchingResponse) -> Self {
+                Self {
+                    bookmarks: Ok(value.bookmarks),
+                    next_cursor: Ok(value.next_cursor),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct ListRequest {
+            cursor: Result<Option<super::BookmarkCursor>, String>,
+            order: Result<Option<super::BookmarkOrder>, String>,
+            per_page: Result<Option<i64>, String>,
+            query: Result<Vec<super::BookmarkSearch>, String>,
+            timezone: Result<Option<super::Timezone>, String>,
+        }
+        impl Default for ListRequest {
+            fn default() -> Self {
+                Self {
+                    cursor: Ok(Default::default()),
+                    order: Ok(Default::default()),
+                    per_page: Ok(Default::default()),
+                    query: Ok(Default::default()),
+                    timezone: Ok(Default::default()),
+                }
+            }
+        }
+        impl ListRequest {
+            pub fn cursor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::BookmarkCursor>>,
+                T::Error: std::fmt::Display,
+            {
+                self.cursor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for cursor: {}", e));
+                self
+            }
+            pub fn order<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::BookmarkOrder>>,
+                T::Error: std::fmt::Display,
+            {
+                self.order = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for order: {}", e));
+                self
+            }
+            pub fn per_page<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<i64>>,
+                T::Error: std::fmt::Display,
+            {
+                self.per_page = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for per_page: {}", e));
+                self
+            }
+            pub fn query<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::BookmarkSearch>>,
+                T::Error: std::fmt::Display,
+            {
+                self.query = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for query: {}", e));
+                self
+            }
+            pub fn timezone<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::Timezone>>,
+                T::Error: std::fmt::Display,
+            {
+                self.timezone = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for timezone: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<ListRequest> for super::ListRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ListRequest) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    cursor: value.cursor?,
+                    order: value.order?,
+                    per_page: value.per_page?,
+                    query: value.query?,
+                    timezone: value.timezone?,
+                })
+            }
+        }
+        impl From<super::ListRequest> for ListRequest {
+            fn from(value: super::ListRequest) -> Self {
+                Self {
+                    cursor: Ok(value.cursor),
+                    order: Ok(value.order),
+                    per_page: Ok(value.per_page),
+                    query: Ok(value.query),
+                    timezone: Ok(value.timezone),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct ListTagsResponseItem {
+            count: Result<i64, String>,
+            tag: Result<super::ExistingTag, String>,
+        }
+        impl Default for ListTagsResponseItem {
+            fn default() -> Self {
+                Self {
+                    count: Err("no value supplied for count".to_string()),
+                    tag: Err("no value supplied for tag".to_string()),
+                }
+            }
+        }
+        impl ListTagsResponseItem {
+            pub fn count<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i64>,
+                T::Error: std::fmt::Display,
+            {
+                self.count = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for count: {}", e));
+                self
+            }
+            pub fn tag<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ExistingTag>,
+                T::Error: std::fmt::Display,
+            {
+                self.tag = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tag: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<ListTagsResponseItem> for super::ListTagsResponseItem {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ListTagsResponseItem,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    count: value.count?,
+                    tag: value.tag?,
+                })
+            }
+        }
+        impl From<super::ListTagsResponseItem> for ListTagsResponseItem {
+            fn from(value: super::ListTagsResponseItem) -> Self {
+                Self {
+                    count: Ok(value.count),
+                    tag: Ok(value.tag),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct MergeBookmarksResponse {
+            associations: Result<Vec<super::AssociatedLink>, String>,
+            bookmark: Result<super::ExistingBookmark, String>,
+            snippet: Result<Option<String>, String>,
+            tags: Result<Vec<super::ExistingTag>, String>,
+        }
+        impl Default for MergeBookmarksResponse {
+            fn default() -> Self {
+                Self {
+                    associations: Err("no value supplied for associations".to_string()),
+                    bookmark: Err("no value supplied for bookmark".to_string()),
+                    snippet: Ok(Default::default()),
+                    tags: Err("no value supplied for tags".to_string()),
+                }
+            }
+        }
+        impl MergeBookmarksResponse {
+            pub fn associations<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
+                T::Error: std::fmt::Display,
+            {
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
+                self
+            }
+            pub fn bookmark<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ExistingBookmark>,
+                T::Error: std::fmt::Display,
+            {
+                self.bookmark = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
+                self
+            }
+            pub fn snippet<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.snippet = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for snippet: {}", e));
+                self
+            }
+            pub fn tags<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::ExistingTag>>,
+                T::Error: std::fmt::Display,
+            {
+                self.tags = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<MergeBookmarksResponse> for super::MergeBookmarksResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: MergeBookmarksResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    associations: value.associations?,
+                    bookmark: value.bookmark?,
+                    snippet: value.snippet?,
+                    tags: value.tags?,
+                })
+            }
+        }
+        impl From<super::MergeBookmarksResponse> for MergeBookmarksResponse {
+            fn from(value: super::MergeBookmarksResponse) -> Self {
+                Self {
+                    associations: Ok(value.associations),
+                    bookmark: Ok(value.bookmark),
+                    snippet: Ok(value.snippet),
+                    tags: Ok(value.tags),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct Metadata {
+            author: Result<Option<String>, String>,
+            canonical_url: Result<Option<String>, String>,
+            description: Result<Option<String>, String>,
+            favicon: Result<Option<String>, String>,
+            image: Result<Option<String>, String>,
+            language: Result<Option<String>, String>,
+            published_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            title: Result<String, String>,
+        }
+        impl Default for Metadata {
+            fn default() -> Self {
+                Self {
+                    author: Ok(Default::default()),
+                    canonical_url: Ok(Default::default()),
+                    description: Ok(Default::default()),
+                    favicon: Ok(Default::default()),
+                    image: Ok(Default::default()),
+                    language: Ok(Default::default()),
+                    published_at: Ok(Default::default()),
+                    title: Err("no value supplied for title".to_string()),
+                }
+            }
+        }
+        impl Metadata {
+            pub fn author<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.author = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for author: {}", e));
+                self
+            }
+            pub fn canonical_url<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.canonical_url = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for canonical_url: {}", e)
+                });
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self
+            }
+            pub fn favicon<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.favicon = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for favicon: {}", e));
+                self
+            }
+            pub fn image<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.image = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for image: {}", e));
+                self
+            }
+            pub fn language<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.language = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for language: {}", e));
+                self
+            }
+            pub fn published_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.published_at = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for published_at: {}", e)
+                });
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<Metadata> for super::Metadata {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Metadata) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    author: value.author?,
+                    canonical_url: value.canonical_url?,
+                    description: value.description?,
+                    favicon: value.favicon?,
+                    image: value.image?,
+                    language: value.language?,
+                    published_at: value.published_at?,
+                    title: value.title?,
+                })
+            }
+        }
+        impl From<super::Metadata> for Metadata {
+            fn from(value: super::Metadata) -> Self {
+                Self {
+                    author: Ok(value.author),
+                    canonical_url: Ok(value.canonical_url),
+                    description: Ok(value.description),
+                    favicon: Ok(value.favicon),
+                    image: Ok(value.image),
+                    language: Ok(value.language),
+                    published_at: Ok(value.published_at),
+                    title: Ok(value.title),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct NewBookmark {
+            accessed_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            description: Result<Option<String>, String>,
+            id: Result<super::NoId, String>,
+            modified_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            notes: Result<Option<String>, String>,
+            shared: Result<Option<bool>, String>,
+            title: Result<String, String>,
+            unread: Result<Option<bool>, String>,
+            url: Result<String, String>,
+            user_id: Result<super::NoId, String>,
+            website_description: Result<Option<String>, String>,
+            website_title: Result<Option<String>, String>,
+        }
+        impl Default for NewBookmark {
+            fn default() -> Self {
+                Self {
+                    accessed_at: Ok(Default::default()),
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    description: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    modified_at: Ok(Default::default()),
+                    notes: Ok(Default::default()),
+                    shared: Ok(Default::default()),
+                    title: Err("no value supplied for title".to_string()),
+                    unread: Ok(Default::default()),
+                    url: Err("no value supplied for url".to_string()),
+                    user_id: Err("no value supplied for user_id".to_string()),
+                    website_description: Ok(Default::default()),
+                    website_title: Ok(Default::default()),
+                }
+            }
+        }
+        impl NewBookmark {
+            pub fn accessed_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.accessed_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for accessed_at: {}", e));
+                self
+            }
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::NoId>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn modified_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.modified_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for modified_at: {}", e));
+                self
+            }
+            pub fn notes<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.notes = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for notes: {}", e));
+                self
+            }
+            pub fn shared<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<bool>>,
+                T::Error: std::fmt::Display,
+            {
+                self.shared = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for shared: {}", e));
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+            pub fn unread<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<bool>>,
+                T::Error: std::fmt::Display,
+            {
+                self.unread = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for unread: {}", e));
+                self
+            }
+            pub fn url<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.url = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
+                self
+            }
+            pub fn user_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::NoId>,
+                T::Error: std::fmt::Display,
+            {
+                self.user_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for user_id: {}", e));
+                self
+            }
+            pub fn website_description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.website_description = value.try_into().map_err(|e| {
+                    format!(
+                        "error converting supplied value for website_description: {}",
+                        e
+                    )
+                });
+                self
+            }
+            pub fn website_title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.website_title = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for website_title: {}", e)
+                });
+                self
+            }
+        }
+        impl std::convert::TryFrom<NewBookmark> for super::NewBookmark {
+            type Error = super::error::ConversionError;
+            fn try_from(value: NewBookmark) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    accessed_at: value.accessed_at?,
+                    created_at: value.created_at?,
+                    description: value.description?,
+                    id: value.id?,
+                    modified_at: value.modified_at?,
+                    notes: value.notes?,
+                    shared: value.shared?,
+                    title: value.title?,
+                    unread: value.unread?,
+                    url: value.url?,
+                    user_id: value.user_id?,
+                    website_description: value.website_description?,
+                    website_title: value.website_title?,
+                })
+            }
+        }
+        impl From<super::NewBookmark> for NewBookmark {
+            fn from(value: super::NewBookmark) -> Self {
+                Self {
+                    accessed_at: Ok(value.accessed_at),
+                    created_at: Ok(value.created_at),
+                    description: Ok(value.description),
+                    id: Ok(value.id),
+                    modified_at: Ok(value.modified_at),
+                    notes: Ok(value.notes),
+                    shared: Ok(value.shared),
+                    title: Ok(value.title),
+                    unread: Ok(value.unread),
+                    url: Ok(value.url),
+                    user_id: Ok(value.user_id),
+                    website_description: Ok(value.website_description),
+                    website_title: Ok(value.website_title),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct Pagination {
+            cursor: Result<Option<super::BookmarkCursor>, String>,
+            per_page: Result<Option<i64>, String>,
+        }
+        impl Default for Pagination {
+            fn default() -> Self {
+                Self {
+                    cursor: Ok(Default::default()),
+                    per_page: Ok(Default::default()),
+                }
+            }
+        }
+        impl Pagination {
+            pub fn cursor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::BookmarkCursor>>,
+                T::Error: std::fmt::Display,
+            {
+                self.cursor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for cursor: {}", e));
+                self
+            }
+            pub fn per_page<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<i64>>,
+                T::Error: std::fmt::Display,
+            {
+                self.per_page = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for per_page: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<Pagination> for super::Pagination {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Pagination) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    cursor: value.cursor?,
+                    per_page: value.per_page?,
+                })
+            }
+        }
+        impl From<super::Pagination> for Pagination {
+            fn from(value: super::Pagination) -> Self {
+                Self {
+                    cursor: Ok(value.cursor),
+                    per_page: Ok(value.per_page),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct ReplaceBookmarkResponse {
+            associations: Result<Vec<super::AssociatedLink>, String>,
+            bookmark: Result<super::ExistingBookmark, String>,
+            snippet: Result<Option<String>, String>,
+            tags: Result<Vec<super::ExistingTag>, String>,
+        }
+        impl Default for ReplaceBookmarkResponse {
+            fn default() -> Self {
+                Self {
+                    associations: Err("no value supplied for associations".to_string()),
+                    bookmark: Err("no value supplied for bookmark".to_string()),
+                    snippet: Ok(Default::default()),
+                    tags: Err("no value supplied for tags".to_string()),
+                }
+            }
+        }
+        impl ReplaceBookmarkResponse {
+            pub fn associations<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
+                T::Error: std::fmt::Display,
+            {
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
+                self
+            }
+            pub fn bookmark<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ExistingBookmark>,
+                T::Error: std::fmt::Display,
+            {
+                self.bookmark = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
+                self
+            }
+            pub fn snippet<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.snippet = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for snippet: {}", e));
+                self
+            }
+            pub fn tags<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::ExistingTag>>,
+                T::Error: std::fmt::Display,
+            {
+                self.tags = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<ReplaceBookmarkResponse> for super::ReplaceBookmarkResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ReplaceBookmarkResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    associations: value.associations?,
+                    bookmark: value.bookmark?,
+                    snippet: value.snippet?,
+                    tags: value.tags?,
+                })
+            }
+        }
+        impl From<super::ReplaceBookmarkResponse> for ReplaceBookmarkResponse {
+            fn from(value: super::ReplaceBookmarkResponse) -> Self {
+                Self {
+                    associations: Ok(value.associations),
+                    bookmark: Ok(value.bookmark),
+                    snippet: Ok(value.snippet),
+                    tags: Ok(value.tags),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct TagAlias {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            name: Result<String, String>,
+            slug: Result<String, String>,
+            tag_id: Result<super::TagId, String>,
+            tag_name: Result<String, String>,
+        }
+        impl Default for TagAlias {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    slug: Err("no value supplied for slug".to_string()),
+                    tag_id: Err("no value supplied for tag_id".to_string()),
+                    tag_name: Err("no value supplied for tag_name".to_string()),
+                }
+            }
+        }
+        impl TagAlias {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn slug<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.slug = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for slug: {}", e));
+                self
+            }
+            pub fn tag_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::TagId>,
+                T::Error: std::fmt::Display,
+            {
+                self.tag_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tag_id: {}", e));
+                self
+            }
+            pub fn tag_name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.tag_name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tag_name: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<TagAlias> for super::TagAlias {
+            type Error = super::error::ConversionError;
+            fn try_from(value: TagAlias) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    name: value.name?,
+                    slug: value.slug?,
+                    tag_id: value.tag_id?,
+                    tag_name: value.tag_name?,
+                })
+            }
+        }
+        impl From<super::TagAlias> for TagAlias {
+            fn from(value: super::TagAlias) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    name: Ok(value.name),
+                    slug: Ok(value.slug),
+                    tag_id: Ok(value.tag_id),
+                    tag_name: Ok(value.tag_name),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct TagAliasRequest {
+            name: Result<String, String>,
+            retro_apply: Result<Option<bool>, String>,
+        }
+        impl Default for TagAliasRequest {
+            fn default() -> Self {
+                Self {
+                    name: Err("no value supplied for name".to_string()),
+                    retro_apply: Ok(Default::default()),
+                }
+            }
+        }
+        impl TagAliasRequest {
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn retro_apply<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<bool>>,
+                T::Error: std::fmt::Display,
+            {
+                self.retro_apply = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for retro_apply: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<TagAliasRequest> for super::TagAliasRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(value: TagAliasRequest) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    name: value.name?,
+                    retro_apply: value.retro_apply?,
+                })
+            }
+        }
+        impl From<super::TagAliasRequest> for TagAliasRequest {
+            fn from(value: super::TagAliasRequest) -> Self {
+                Self {
+                    name: Ok(value.name),
+                    retro_apply: Ok(value.retro_apply),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct TagCount {
+            count: Result<i64, String>,
+            tag: Result<super::ExistingTag, String>,
+        }
+        impl Default for TagCount {
+            fn default() -> Self {
+                Self {
+                    count: Err("no value supplied for count".to_string()),
+                    tag: Err("no value supplied for tag".to_string()),
+                }
+            }
+        }
+        impl TagCount {
+            pub fn count<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i64>,
+                T::Error: std::fmt::Display,
+            {
+                self.count = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for count: {}", e));
+                self
+            }
+            pub fn tag<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ExistingTag>,
+                T::Error: std::fmt::Display,
+            {
+                self.tag = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tag: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<TagCount> for super::TagCount {
+            type Error = super::error::ConversionError;
+            fn try_from(value: TagCount) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    count: value.count?,
+                    tag: value.tag?,
+                })
+            }
+        }
+        impl From<super::TagCount> for TagCount {
+            fn from(value: super::TagCount) -> Self {
+                Self {
+                    count: Ok(value.count),
+                    tag: Ok(value.tag),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct TagMergeRequest {
+            into: Result<super::TagId, String>,
+        }
+        impl Default for TagMergeRequest {
+            fn default() -> Self {
+                Self {
+                    into: Err("no value supplied for into".to_string()),
+                }
+            }
+        }
+        impl TagMergeRequest {
+            pub fn into<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::TagId>,
+                T::Error: std::fmt::Display,
+            {
+                self.into = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for into: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<TagMergeRequest> for super::TagMergeRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(value: TagMergeRequest) -> Result<Self, super::error::ConversionError> {
+                Ok(Self { into: value.into? })
+            }
+        }
+        impl From<super::TagMergeRequest> for TagMergeRequest {
+            fn from(value: super::TagMergeRequest) -> Self {
+                Self {
+                    into: Ok(value.into),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct TagQuery {
+            tags: Result<Vec<super::TagName>, String>,
+        }
+        impl Default for TagQuery {
+            fn default() -> Self {
+                Self {
+                    tags: Ok(Default::default()),
+                }
+            }
+        }
+        impl TagQuery {
+            pub fn tags<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::TagName>>,
+                T::Error: std::fmt::Display,
+            {
+                self.tags = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<TagQuery> for super::TagQuery {
+            type Error = super::error::ConversionError;
+            fn try_from(value: TagQuery) -> Result<Self, super::error::ConversionError> {
+                Ok(Self { tags: value.tags? })
+            }
+        }
+        impl From<super::TagQuery> for TagQuery {
+            fn from(value: super::TagQuery) -> Self {
+                Self {
+                    tags: Ok(value.tags),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct TagRenameRequest {
+            name: Result<String, String>,
+        }
+        impl Default for TagRenameRequest {
+            fn default() -> Self {
+                Self {
+                    name: Err("no value supplied for name".to_string()),
+                }
+            }
+        }
+        impl TagRenameRequest {
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<TagRenameRequest> for super::TagRenameRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(value: TagRenameRequest) -> Result<Self, super::error::ConversionError> {
+                Ok(Self { name: value.name? })
+            }
+        }
+        impl From<super::TagRenameRequest> for TagRenameRequest {
+            fn from(value: super::TagRenameRequest) -> Self {
+                Self {
+                    name: Ok(value.name),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct TagTreeNode {
+            children: Result<Vec<super::TagTreeNode>, String>,
+            count: Result<i64, String>,
+            name: Result<String, String>,
+            path: Result<String, String>,
+            tag_id: Result<Option<super::TagId>, String>,
+        }
+        impl Default for TagTreeNode {
+            fn default() -> Self {
+                Self {
+                    children: Err("no value supplied for children".to_string()),
+                    count: Err("no value supplied for count".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    path: Err("no value supplied for path".to_string()),
+                    tag_id: Ok(Default::default()),
+                }
+            }
+        }
+        impl TagTreeNode {
+            pub fn children<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::TagTreeNode>>,
+                T::Error: std::fmt::Display,
+            {
+                self.children = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for children: {}", e));
+                self
+            }
+            pub fn count<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i64>,
+                T::Error: std::fmt::Display,
+            {
+                self.count = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for count: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn path<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.path = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for path: {}", e));
+                self
+            }
+            pub fn tag_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::TagId>>,
+                T::Error: std::fmt::Display,
+            {
+                self.tag_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tag_id: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<TagTreeNode> for super::TagTreeNode {
+            type Error = super::error::ConversionError;
+            fn try_from(value: TagTreeNode) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    children: value.children?,
+                    count: value.count?,
+                    name: value.name?,
+                    path: value.path?,
+                    tag_id: value.tag_id?,
+                })
+            }
+        }
+        impl From<super::TagTreeNode> for TagTreeNode {
+            fn from(value: super::TagTreeNode) -> Self {
+                Self {
+                    children: Ok(value.children),
+                    count: Ok(value.count),
+                    name: Ok(value.name),
+                    path: Ok(value.path),
+                    tag_id: Ok(value.tag_id),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct UpdateBookmarkResponse {
+            associations: Result<Vec<super::AssociatedLink>, String>,
+            bookmark: Result<super::ExistingBookmark, String>,
+            snippet: Result<Option<String>, String>,
+            tags: Result<Vec<super::ExistingTag>, String>,
+        }
+        impl Default for UpdateBookmarkResponse {
+            fn default() -> Self {
+                Self {
+                    associations: Err("no value supplied for associations".to_string()),
+                    bookmark: Err("no value supplied for bookmark".to_string()),
+                    snippet: Ok(Default::default()),
+                    tags: Err("no value supplied for tags".to_string()),
+                }
+            }
+        }
+        impl UpdateBookmarkResponse {
+            pub fn associations<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::AssociatedLink>>,
+                T::Error: std::fmt::Display,
+            {
+                self.associations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for associations: {}", e)
+                });
+                self
+            }
+            pub fn bookmark<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ExistingBookmark>,
+                T::Error: std::fmt::Display,
+            {
+                self.bookmark = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for bookmark: {}", e));
+                self
+            }
+            pub fn snippet<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.snippet = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for snippet: {}", e));
+                self
+            }
+            pub fn tags<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::ExistingTag>>,
+                T::Error: std::fmt::Display,
+            {
+                self.tags = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<UpdateBookmarkResponse> for super::UpdateBookmarkResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: UpdateBookmarkResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    associations: value.associations?,
+                    bookmark: value.bookmark?,
+                    snippet: value.snippet?,
+                    tags: value.tags?,
+                })
+            }
+        }
+        impl From<super::UpdateBookmarkResponse> for UpdateBookmarkResponse {
+            fn from(value: super::UpdateBookmarkResponse) -> Self {
+                Self {
+                    associations: Ok(value.associations),
+                    bookmark: Ok(value.bookmark),
+                    snippet: Ok(value.snippet),
+                    tags: Ok(value.tags),
+                }
+            }
+        }
+        #[derive(Clone, Debug)]
+        pub struct UserSettings {
+            timezone: Result<Option<super::Timezone>, String>,
+        }
+        impl Default for UserSettings {
+            fn default() -> Self {
+                Self {
+                    timezone: Ok(Default::default()),
+                }
+            }
+        }
+        impl UserSettings {
+            pub fn timezone<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::Timezone>>,
+                T::Error: std::fmt::Display,
+            {
+                self.timezone = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for timezone: {}", e));
+                self
+            }
+        }
+        impl std::convert::TryFrom<UserSettings> for super::UserSettings {
+            type Error = super::error::ConversionError;
+            fn try_from(value: UserSettings) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    timezone: value.timezone?,
+                })
+            }
+        }
+        impl From<super::UserSettings> for UserSettings {
+            fn from(value: super::UserSettings) -> Self {
+                Self {
+                    timezone: Ok(value.timezone),
+                }
+            }
+        }
+    }
+}
+#[derive(Clone, Debug)]
+/**Client for lz-web
+
+
+
+Version: 0.1.0*/
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+}
+impl Client {
+    /// Create a new client.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = std::time::Duration::from_secs(15);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+    /// Construct a new client with an existing `reqwest::Client`,
+    /// allowing more control over its configuration.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+        }
+    }
+    /// Get the base URL to which requests are made.
+    pub fn baseurl(&self) -> &String {
+        &self.baseurl
+    }
+    /// Get the internal `reqwest::Client` used to make requests.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+    /// Get the version of this API.
+    ///
+    /// This string is pulled directly from the source OpenAPI
+    /// document and may be in any format the API selects.
+    pub fn api_version(&self) -> &'static str {
+        "0.1.0"
+    }
+}
+impl Client {
+    /**Create a new bookmark
+
+    Create a new bookmark
+
+    If the server keeps local copies of bookmarked pages, the new
+    bookmark's page gets archived in the background.
+
+    Sends a `POST` request to `/bookmark/create`
+
+    Arguments:
+    - `body`:
+    ```ignore
+    let response = client.create_bookmark()
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn create_bookmark(&self) -> builder::CreateBookmark {
+        builder::CreateBookmark::new(self)
+    }
+    /**Retrieve a bookmark
+
+    Retrieve a bookmark
+
+    Sends a `GET` request to `/bookmark/{id}`
+
+    Arguments:
+    - `id`: ID of the bookmark
+    ```ignore
+    let response = client.get_bookmark()
+        .id(id)
+        .send()
+        .await;
+    ```*/
+    pub fn get_bookmark(&self) -> builder::GetBookmark {
+        builder::GetBookmark::new(self)
+    }
+    /**Replace a bookmark's data, tags and associations
+
+    Replace a bookmark's data, tags and associations
+
+    The request body is the same as for creating a bookmark. The
+    bookmark's `created_at` and `accessed_at` timestamps and its
+    import properties are kept as they were.
+
+    Sends a `PUT` request to `/bookmark/{id}`
+
+    Arguments:
+    - `id`: ID of the bookmark
+    - `body`:
+    ```ignore
+    let response = client.replace_bookmark()
+        .id(id)
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn replace_bookmark(&self) -> builder::ReplaceBookmark {
+        builder::ReplaceBookmark::new(self)
+    }
+    /**Delete a bookmark
+
+    Delete a bookmark
+
+    Sends a `DELETE` request to `/bookmark/{id}`
+
+    Arguments:
+    - `id`: ID of the bookmark
+    ```ignore
+    let response = client.delete_bookmark()
+        .id(id)
+        .send()
+        .await;
+    ```*/
+    pub fn delete_bookmark(&self) -> builder::DeleteBookmark {
+        builder::DeleteBookmark::new(self)
+    }
+    /**Update some of a bookmark's fields
+
+    Update some of a bookmark's fields
+
+    Sends a `PATCH` request to `/bookmark/{id}`
+
+    Arguments:
+    - `id`: ID of the bookmark
+    - `body`:
+    ```ignore
+    let response = client.update_bookmark()
+        .id(id)
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn update_bookmark(&self) -> builder::UpdateBookmark {
+        builder::UpdateBookmark::new(self)
+    }
+    /**Retrieve the local archive of a bookmark's page
+
+    Retrieve the local archive of a bookmark's page
+
+    The archive contains the page's readable text; the archived page
+    itself is available separately.
+
+    Sends a `GET` request to `/bookmark/{id}/archive`
+
+    Arguments:
+    - `id`: ID of the bookmark
+    ```ignore
+    let response = client.get_bookmark_archive()
+        .id(id)
+        .send()
+        .await;
+    ```*/
+    pub fn get_bookmark_archive(&self) -> builder::GetBookmarkArchive {
+        builder::GetBookmarkArchive::new(self)
+    }
+    /**Retrieve the locally archived copy of a bookmark's page
+
+    Retrieve the locally archived copy of a bookmark's page
+
+    The page's scripts are not allowed to run.
+
+    Sends a `GET` request to `/bookmark/{id}/archive/page`
+
+    Arguments:
+    - `id`: ID of the bookmark
+    ```ignore
+    let response = client.get_archived_page()
+        .id(id)
+        .send()
+        .await;
+    ```*/
+    pub fn get_archived_page(&self) -> builder::GetArchivedPage {
+        builder::GetArchivedPage::new(self)
+    }
+    /**Merge another bookmark into a bookmark
+
+    Merge another bookmark into a bookmark
+
+    The bookmark gets the other bookmark's tags, associated links and
+    notes, and the earlier of their creation times. The other
+    bookmark is deleted.
+
+    Sends a `POST` request to `/bookmark/{id}/merge`
+
+    Arguments:
+    - `id`: ID of the bookmark to keep
+    - `body`:
+    ```ignore
+    let response = client.merge_bookmarks()
+        .id(id)
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn merge_bookmarks(&self) -> builder::MergeBookmarks {
+        builder::MergeBookmarks::new(self)
+    }
+    /**List the user's bookmarks matching a query, newest to oldest
+
+    List the user's bookmarks matching a query, newest to oldest
+    unless another order is requested
+
+    The query can be given as structured criteria in the request body,
+    or as a search string in the `q` parameter (like `tag:rust -tag:old
+    "exact phrase" site:github.com`), or both.
+
+    If the query contains a full-text search, each bookmark comes with
+    a snippet of the text that matched it.
+
+    Sends a `POST` request to `/bookmarks`
+
+    Arguments:
+    - `q`: Search string whose criteria get added to the query
+    - `body`:
+    ```ignore
+    let response = client.list_bookmarks_matching()
+        .q(q)
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn list_bookmarks_matching(&self) -> builder::ListBookmarksMatching {
+        builder::ListBookmarksMatching::new(self)
+    }
+    /**Sends a `GET` request to `/http/fetch_metadata`
+
+    Arguments:
+    - `url`: URL to retrieve and inspect for metadata
+    ```ignore
+    let response = client.fetch_page_metadata()
+        .url(url)
+        .send()
+        .await;
+    ```*/
+    pub fn fetch_page_metadata(&self) -> builder::FetchPageMetadata {
+        builder::FetchPageMetadata::new(self)
+    }
+    /**List the user's tag aliases
+
+    List the user's tag aliases
+
+    Sends a `GET` request to `/tag/aliases`
+
+    ```ignore
+    let response = client.list_tag_aliases()
+        .send()
+        .await;
+    ```*/
+    pub fn list_tag_aliases(&self) -> builder::ListTagAliases {
+        builder::ListTagAliases::new(self)
+    }
+    /**Remove a tag alias
+
+    Remove a tag alias
+
+    Sends a `DELETE` request to `/tag/aliases/{name}`
+
+    Arguments:
+    - `name`: The alias
+    ```ignore
+    let response = client.remove_tag_alias()
+        .name(name)
+        .send()
+        .await;
+    ```*/
+    pub fn remove_tag_alias(&self) -> builder::RemoveTagAlias {
+        builder::RemoveTagAlias::new(self)
+    }
+    /**Sends a `GET` request to `/tag/complete`
+
+    Arguments:
+    - `tag_fragment`: Substring of the tag name that must match
+    ```ignore
+    let response = client.complete_tag()
+        .tag_fragment(tag_fragment)
+        .send()
+        .await;
+    ```*/
+    pub fn complete_tag(&self) -> builder::CompleteTag {
+        builder::CompleteTag::new(self)
+    }
+    /**Delete one of the user's tags, removing it from all bookmarks
+
+    Delete one of the user's tags, removing it from all bookmarks
+
+    Sends a `DELETE` request to `/tag/{id}`
+
+    Arguments:
+    - `id`: ID of the tag
+    ```ignore
+    let response = client.delete_tag()
+        .id(id)
+        .send()
+        .await;
+    ```*/
+    pub fn delete_tag(&self) -> builder::DeleteTag {
+        builder::DeleteTag::new(self)
+    }
+    /**Add an alias to a tag
+
+    Add an alias to a tag
+
+    Tagging bookmarks with the alias tags them with the tag from then
+    on, and searching for the alias finds the bookmarks with the tag.
+
+    Sends a `POST` request to `/tag/{id}/aliases`
+
+    Arguments:
+    - `id`: ID of the tag
+    - `body`
+    ```ignore
+    let response = client.add_tag_alias()
+        .id(id)
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn add_tag_alias(&self) -> builder::AddTagAlias {
+        builder::AddTagAlias::new(self)
+    }
+    /**Replace a tag with another one on all the user's bookmarks
+
+    Replace a tag with another one on all the user's bookmarks
+
+    Sends a `POST` request to `/tag/{id}/merge`
+
+    Arguments:
+    - `id`: ID of the tag to replace
+    - `body`:
+    ```ignore
+    let response = client.merge_tags()
+        .id(id)
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn merge_tags(&self) -> builder::MergeTags {
+        builder::MergeTags::new(self)
+    }
+    /**Rename one of the user's tags
+
+    Rename one of the user's tags
+
+    If the user has a tag with an equivalent name, the bookmarks get that
+    tag instead.
+
+    Sends a `POST` request to `/tag/{id}/rename`
+
+    Arguments:
+    - `id`: ID of the tag
+    - `body`:
+    ```ignore
+    let response = client.rename_tag()
+        .id(id)
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn rename_tag(&self) -> builder::RenameTag {
+        builder::RenameTag::new(self)
+    }
+    /**List the user's tags
+
+    List the user's tags
+
+    Sends a `GET` request to `/tags`
+
+    ```ignore
+    let response = client.list_tags()
+        .send()
+        .await;
+    ```*/
+    pub fn list_tags(&self) -> builder::ListTags {
+        builder::ListTags::new(self)
+    }
+    /**List the user's tags as a hierarchy
+
+    List the user's tags as a hierarchy
+
+    Tags with `/` in their names, like `lang/rust/async`, are listed
+    below the levels of the hierarchy that their names start with.
+
+    Sends a `GET` request to `/tags/tree`
+
+    ```ignore
+    let response = client.tag_tree()
+        .send()
+        .await;
+    ```*/
+    pub fn tag_tree(&self) -> builder::TagTree {
+        builder::TagTree::new(self)
+    }
+    /**Retrieve the user's settings
+
+    Retrieve the user's settings
+
+    Sends a `GET` request to `/user/settings`
+
+    ```ignore
+    let response = client.get_user_settings()
+        .send()
+        .await;
+    ```*/
+    pub fn get_user_settings(&self) -> builder::GetUserSettings {
+        builder::GetUserSettings::new(self)
+    }
+    /**Change the user's settings
+
+    Change the user's settings
+
+    Sends a `PUT` request to `/user/settings`
+
+    ```ignore
+    let response = client.update_user_settings()
+        .body(body)
+        .send()
+        .await;
+    ```*/
+    pub fn update_user_settings(&self) -> builder::UpdateUserSettings {
+        builder::UpdateUserSettings::new(self)
+    }
+}
+/// Types for composing operation parameters.
+#[allow(clippy::all)]
+pub mod builder {
+    use super::types;
+    #[allow(unused_imports)]
+    use super::{
+        encode_path, ByteStream, Error, HeaderMap, HeaderValue, RequestBuilderExt, ResponseValue,
+    };
+    /**Builder for [`Client::create_bookmark`]
+
+    [`Client::create_bookmark`]: super::Client::create_bookmark*/
+    #[derive(Debug, Clone)]
+    pub struct CreateBookmark<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::BookmarkCreateRequest, String>,
+    }
+    impl<'a> CreateBookmark<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                body: Ok(types::builder::BookmarkCreateRequest::default()),
+            }
+        }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkCreateRequest>,
+            <V as std::convert::TryInto<types::BookmarkCreateRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `BookmarkCreateRequest` for body failed: {}",
+                    s
+                )
+            });
+            self
         }
-        #[derive(Clone, Debug)]
-        pub struct ListBookmarksMatchingResponse {
-            bookmarks: Result<Vec<super::AnnotatedBookmark>, String>,
-            next_cursor: Result<Option<super::BookmarkId>, String>,
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::BookmarkCreateRequest,
+            ) -> types::builder::BookmarkCreateRequest,
+        {
+            self.body = self.body.map(f);
+            self
         }
-        impl Default for ListBookmarksMatchingResponse {
-            fn default() -> Self {
-                Self {
-                    bookmarks: Err("no value supplied for bookmarks".to_string()),
-                    next_cursor: Ok(Default::default()),
-                }
+        ///Sends a `POST` request to `/bookmark/create`
+        pub async fn send(self) -> Result<ResponseValue<types::CreateBookmarkResponse>, Error<()>> {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| types::BookmarkCreateRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/bookmark/create", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl ListBookmarksMatchingResponse {
-            pub fn bookmarks<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::AnnotatedBookmark>>,
-                T::Error: std::fmt::Display,
-            {
-                self.bookmarks = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for bookmarks: {}", e));
-                self
-            }
-            pub fn next_cursor<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<super::BookmarkId>>,
-                T::Error: std::fmt::Display,
-            {
-                self.next_cursor = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for next_cursor: {}", e));
-                self
+    }
+    /**Builder for [`Client::get_bookmark`]
+
+    [`Client::get_bookmark`]: super::Client::get_bookmark*/
+    #[derive(Debug, Clone)]
+    pub struct GetBookmark<'a> {
+        client: &'a super::Client,
+        id: Result<types::BookmarkId, String>,
+    }
+    impl<'a> GetBookmark<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
             }
         }
-        impl std::convert::TryFrom<ListBookmarksMatchingResponse> for super::ListBookmarksMatchingResponse {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: ListBookmarksMatchingResponse,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    bookmarks: value.bookmarks?,
-                    next_cursor: value.next_cursor?,
-                })
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `BookmarkId` for id failed".to_string());
+            self
+        }
+        ///Sends a `GET` request to `/bookmark/{id}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::GetBookmarkResponse>, Error<types::ErrorResponse>>
+        {
+            let Self { client, id } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bookmark/{}",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl From<super::ListBookmarksMatchingResponse> for ListBookmarksMatchingResponse {
-            fn from(value: super::ListBookmarksMatchingResponse) -> Self {
-                Self {
-                    bookmarks: Ok(value.bookmarks),
-                    next_cursor: Ok(value.next_cursor),
-                }
+    }
+    /**Builder for [`Client::replace_bookmark`]
+
+    [`Client::replace_bookmark`]: super::Client::replace_bookmark*/
+    #[derive(Debug, Clone)]
+    pub struct ReplaceBookmark<'a> {
+        client: &'a super::Client,
+        id: Result<types::BookmarkId, String>,
+        body: Result<types::builder::BookmarkCreateRequest, String>,
+    }
+    impl<'a> ReplaceBookmark<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
+                body: Ok(types::builder::BookmarkCreateRequest::default()),
             }
         }
-        #[derive(Clone, Debug)]
-        pub struct ListRequest {
-            cursor: Result<Option<super::BookmarkId>, String>,
-            per_page: Result<Option<i64>, String>,
-            query: Result<Vec<super::BookmarkSearch>, String>,
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `BookmarkId` for id failed".to_string());
+            self
         }
-        impl Default for ListRequest {
-            fn default() -> Self {
-                Self {
-                    cursor: Ok(Default::default()),
-                    per_page: Ok(Default::default()),
-                    query: Ok(Default::default()),
-                }
-            }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkCreateRequest>,
+            <V as std::convert::TryInto<types::BookmarkCreateRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `BookmarkCreateRequest` for body failed: {}",
+                    s
+                )
+            });
+            self
         }
-        impl ListRequest {
-            pub fn cursor<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<super::BookmarkId>>,
-                T::Error: std::fmt::Display,
-            {
-                self.cursor = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for cursor: {}", e));
-                self
-            }
-            pub fn per_page<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<i64>>,
-                T::Error: std::fmt::Display,
-            {
-                self.per_page = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for per_page: {}", e));
-                self
-            }
-            pub fn query<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::BookmarkSearch>>,
-                T::Error: std::fmt::Display,
-            {
-                self.query = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for query: {}", e));
-                self
-            }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::BookmarkCreateRequest,
+            ) -> types::builder::BookmarkCreateRequest,
+        {
+            self.body = self.body.map(f);
+            self
         }
-        impl std::convert::TryFrom<ListRequest> for super::ListRequest {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ListRequest) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    cursor: value.cursor?,
-                    per_page: value.per_page?,
-                    query: value.query?,
-                })
+        ///Sends a `PUT` request to `/bookmark/{id}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ReplaceBookmarkResponse>, Error<types::ErrorResponse>>
+        {
+            let Self { client, id, body } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::BookmarkCreateRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bookmark/{}",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .put(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl From<super::ListRequest> for ListRequest {
-            fn from(value: super::ListRequest) -> Self {
-                Self {
-                    cursor: Ok(value.cursor),
-                    per_page: Ok(value.per_page),
-                    query: Ok(value.query),
-                }
+    }
+    /**Builder for [`Client::delete_bookmark`]
+
+    [`Client::delete_bookmark`]: super::Client::delete_bookmark*/
+    #[derive(Debug, Clone)]
+    pub struct DeleteBookmark<'a> {
+        client: &'a super::Client,
+        id: Result<types::BookmarkId, String>,
+    }
+    impl<'a> DeleteBookmark<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
             }
         }
-        #[derive(Clone, Debug)]
-        pub struct Metadata {
-            description: Result<Option<String>, String>,
-            title: Result<String, String>,
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `BookmarkId` for id failed".to_string());
+            self
         }
-        impl Default for Metadata {
-            fn default() -> Self {
-                Self {
-                    description: Ok(Default::default()),
-                    title: Err("no value supplied for title".to_string()),
-                }
+        ///Sends a `DELETE` request to `/bookmark/{id}`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::ErrorResponse>> {
+            let Self { client, id } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bookmark/{}",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                204u16 => Ok(ResponseValue::empty(response)),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl Metadata {
-            pub fn description<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.description = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for description: {}", e));
-                self
-            }
-            pub fn title<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.title = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {}", e));
-                self
+    }
+    /**Builder for [`Client::update_bookmark`]
+
+    [`Client::update_bookmark`]: super::Client::update_bookmark*/
+    #[derive(Debug, Clone)]
+    pub struct UpdateBookmark<'a> {
+        client: &'a super::Client,
+        id: Result<types::BookmarkId, String>,
+        body: Result<types::builder::BookmarkUpdateRequest, String>,
+    }
+    impl<'a> UpdateBookmark<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
+                body: Ok(types::builder::BookmarkUpdateRequest::default()),
             }
         }
-        impl std::convert::TryFrom<Metadata> for super::Metadata {
-            type Error = super::error::ConversionError;
-            fn try_from(value: Metadata) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    description: value.description?,
-                    title: value.title?,
-                })
-            }
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `BookmarkId` for id failed".to_string());
+            self
         }
-        impl From<super::Metadata> for Metadata {
-            fn from(value: super::Metadata) -> Self {
-                Self {
-                    description: Ok(value.description),
-                    title: Ok(value.title),
-                }
-            }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkUpdateRequest>,
+            <V as std::convert::TryInto<types::BookmarkUpdateRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `BookmarkUpdateRequest` for body failed: {}",
+                    s
+                )
+            });
+            self
         }
-        #[derive(Clone, Debug)]
-        pub struct NewBookmark {
-            accessed_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            description: Result<Option<String>, String>,
-            id: Result<super::NoId, String>,
-            modified_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            notes: Result<Option<String>, String>,
-            shared: Result<Option<bool>, String>,
-            title: Result<String, String>,
-            unread: Result<Option<bool>, String>,
-            url: Result<String, String>,
-            user_id: Result<super::NoId, String>,
-            website_description: Result<Option<String>, String>,
-            website_title: Result<Option<String>, String>,
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::BookmarkUpdateRequest,
+            ) -> types::builder::BookmarkUpdateRequest,
+        {
+            self.body = self.body.map(f);
+            self
         }
-        impl Default for NewBookmark {
-            fn default() -> Self {
-                Self {
-                    accessed_at: Ok(Default::default()),
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    description: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    modified_at: Ok(Default::default()),
-                    notes: Ok(Default::default()),
-                    shared: Ok(Default::default()),
-                    title: Err("no value supplied for title".to_string()),
-                    unread: Ok(Default::default()),
-                    url: Err("no value supplied for url".to_string()),
-                    user_id: Err("no value supplied for user_id".to_string()),
-                    website_description: Ok(Default::default()),
-                    website_title: Ok(Default::default()),
-                }
+        ///Sends a `PATCH` request to `/bookmark/{id}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::UpdateBookmarkResponse>, Error<types::ErrorResponse>>
+        {
+            let Self { client, id, body } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::BookmarkUpdateRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bookmark/{}",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .patch(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl NewBookmark {
-            pub fn accessed_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.accessed_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for accessed_at: {}", e));
-                self
-            }
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn description<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.description = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for description: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::NoId>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn modified_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.modified_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for modified_at: {}", e));
-                self
-            }
-            pub fn notes<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.notes = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for notes: {}", e));
-                self
-            }
-            pub fn shared<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<bool>>,
-                T::Error: std::fmt::Display,
-            {
-                self.shared = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for shared: {}", e));
-                self
-            }
-            pub fn title<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.title = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {}", e));
-                self
-            }
-            pub fn unread<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<bool>>,
-                T::Error: std::fmt::Display,
-            {
-                self.unread = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for unread: {}", e));
-                self
-            }
-            pub fn url<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.url = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for url: {}", e));
-                self
-            }
-            pub fn user_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::NoId>,
-                T::Error: std::fmt::Display,
-            {
-                self.user_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for user_id: {}", e));
-                self
+    }
+    /**Builder for [`Client::get_bookmark_archive`]
+
+    [`Client::get_bookmark_archive`]: super::Client::get_bookmark_archive*/
+    #[derive(Debug, Clone)]
+    pub struct GetBookmarkArchive<'a> {
+        client: &'a super::Client,
+        id: Result<types::BookmarkId, String>,
+    }
+    impl<'a> GetBookmarkArchive<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
             }
-            pub fn website_description<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.website_description = value.try_into().map_err(|e| {
-                    format!(
-                        "error converting supplied value for website_description: {}",
-                        e
-                    )
-                });
-                self
+        }
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `BookmarkId` for id failed".to_string());
+            self
+        }
+        ///Sends a `GET` request to `/bookmark/{id}/archive`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::GetBookmarkArchiveResponse>, Error<types::ErrorResponse>>
+        {
+            let Self { client, id } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bookmark/{}/archive",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
-            pub fn website_title<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.website_title = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for website_title: {}", e)
-                });
-                self
+        }
+    }
+    /**Builder for [`Client::get_archived_page`]
+
+    [`Client::get_archived_page`]: super::Client::get_archived_page*/
+    #[derive(Debug, Clone)]
+    pub struct GetArchivedPage<'a> {
+        client: &'a super::Client,
+        id: Result<types::BookmarkId, String>,
+    }
+    impl<'a> GetArchivedPage<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
             }
         }
-        impl std::convert::TryFrom<NewBookmark> for super::NewBookmark {
-            type Error = super::error::ConversionError;
-            fn try_from(value: NewBookmark) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    accessed_at: value.accessed_at?,
-                    created_at: value.created_at?,
-                    description: value.description?,
-                    id: value.id?,
-                    modified_at: value.modified_at?,
-                    notes: value.notes?,
-                    shared: value.shared?,
-                    title: value.title?,
-                    unread: value.unread?,
-                    url: value.url?,
-                    user_id: value.user_id?,
-                    website_description: value.website_description?,
-                    website_title: value.website_title?,
-                })
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `BookmarkId` for id failed".to_string());
+            self
+        }
+        ///Sends a `GET` request to `/bookmark/{id}/archive/page`
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<types::ErrorResponse>> {
+            let Self { client, id } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bookmark/{}/archive/page",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client.client.get(url).build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => Ok(ResponseValue::stream(response)),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl From<super::NewBookmark> for NewBookmark {
-            fn from(value: super::NewBookmark) -> Self {
-                Self {
-                    accessed_at: Ok(value.accessed_at),
-                    created_at: Ok(value.created_at),
-                    description: Ok(value.description),
-                    id: Ok(value.id),
-                    modified_at: Ok(value.modified_at),
-                    notes: Ok(value.notes),
-                    shared: Ok(value.shared),
-                    title: Ok(value.title),
-                    unread: Ok(value.unread),
-                    url: Ok(value.url),
-                    user_id: Ok(value.user_id),
-                    website_description: Ok(value.website_description),
-                    website_title: Ok(value.website_title),
-                }
+    }
+    /**Builder for [`Client::merge_bookmarks`]
+
+    [`Client::merge_bookmarks`]: super::Client::merge_bookmarks*/
+    #[derive(Debug, Clone)]
+    pub struct MergeBookmarks<'a> {
+        client: &'a super::Client,
+        id: Result<types::BookmarkId, String>,
+        body: Result<types::builder::BookmarkMergeRequest, String>,
+    }
+    impl<'a> MergeBookmarks<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
+                body: Ok(types::builder::BookmarkMergeRequest::default()),
             }
         }
-        #[derive(Clone, Debug)]
-        pub struct Pagination {
-            cursor: Result<Option<super::BookmarkId>, String>,
-            per_page: Result<Option<i64>, String>,
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `BookmarkId` for id failed".to_string());
+            self
         }
-        impl Default for Pagination {
-            fn default() -> Self {
-                Self {
-                    cursor: Ok(Default::default()),
-                    per_page: Ok(Default::default()),
-                }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::BookmarkMergeRequest>,
+            <V as std::convert::TryInto<types::BookmarkMergeRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `BookmarkMergeRequest` for body failed: {}",
+                    s
+                )
+            });
+            self
+        }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::BookmarkMergeRequest,
+            ) -> types::builder::BookmarkMergeRequest,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+        ///Sends a `POST` request to `/bookmark/{id}/merge`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::MergeBookmarksResponse>, Error<types::ErrorResponse>>
+        {
+            let Self { client, id, body } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::BookmarkMergeRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bookmark/{}/merge",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl Pagination {
-            pub fn cursor<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<super::BookmarkId>>,
-                T::Error: std::fmt::Display,
-            {
-                self.cursor = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for cursor: {}", e));
-                self
+    }
+    /**Builder for [`Client::list_bookmarks_matching`]
+
+    [`Client::list_bookmarks_matching`]: super::Client::list_bookmarks_matching*/
+    #[derive(Debug, Clone)]
+    pub struct ListBookmarksMatching<'a> {
+        client: &'a super::Client,
+        q: Result<Option<String>, String>,
+        body: Result<types::builder::ListRequest, String>,
+    }
+    impl<'a> ListBookmarksMatching<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                q: Ok(None),
+                body: Ok(types::builder::ListRequest::default()),
             }
-            pub fn per_page<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<i64>>,
-                T::Error: std::fmt::Display,
-            {
-                self.per_page = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for per_page: {}", e));
-                self
+        }
+        pub fn q<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.q = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for q failed".to_string());
+            self
+        }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::ListRequest>,
+            <V as std::convert::TryInto<types::ListRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `ListRequest` for body failed: {}", s));
+            self
+        }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::ListRequest) -> types::builder::ListRequest,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+        ///Sends a `POST` request to `/bookmarks`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ListBookmarksMatchingResponse>, Error<types::ErrorResponse>>
+        {
+            let Self { client, q, body } = self;
+            let q = q.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::ListRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/bookmarks", client.baseurl,);
+            let mut query = Vec::with_capacity(1usize);
+            if let Some(v) = &q {
+                query.push(("q", v.to_string()));
+            }
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .query(&query)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+    /**Builder for [`Client::fetch_page_metadata`]
+
+    [`Client::fetch_page_metadata`]: super::Client::fetch_page_metadata*/
+    #[derive(Debug, Clone)]
+    pub struct FetchPageMetadata<'a> {
+        client: &'a super::Client,
+        url: Result<String, String>,
+    }
+    impl<'a> FetchPageMetadata<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                url: Err("url was not initialized".to_string()),
             }
         }
-        impl std::convert::TryFrom<Pagination> for super::Pagination {
-            type Error = super::error::ConversionError;
-            fn try_from(value: Pagination) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    cursor: value.cursor?,
-                    per_page: value.per_page?,
-                })
-            }
+        pub fn url<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.url = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for url failed".to_string());
+            self
         }
-        impl From<super::Pagination> for Pagination {
-            fn from(value: super::Pagination) -> Self {
-                Self {
-                    cursor: Ok(value.cursor),
-                    per_page: Ok(value.per_page),
-                }
+        ///Sends a `GET` request to `/http/fetch_metadata`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::FetchPageMetadataResponse>, Error<types::ErrorResponse>>
+        {
+            let Self { client, url } = self;
+            let url = url.map_err(Error::InvalidRequest)?;
+            let _url = format!("{}/http/fetch_metadata", client.baseurl,);
+            let mut query = Vec::with_capacity(1usize);
+            query.push(("url", url.to_string()));
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(_url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&query)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                403u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        #[derive(Clone, Debug)]
-        pub struct TagQuery {
-            tags: Result<Vec<super::TagName>, String>,
+    }
+    /**Builder for [`Client::list_tag_aliases`]
+
+    [`Client::list_tag_aliases`]: super::Client::list_tag_aliases*/
+    #[derive(Debug, Clone)]
+    pub struct ListTagAliases<'a> {
+        client: &'a super::Client,
+    }
+    impl<'a> ListTagAliases<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
         }
-        impl Default for TagQuery {
-            fn default() -> Self {
-                Self {
-                    tags: Ok(Default::default()),
-                }
+        ///Sends a `GET` request to `/tag/aliases`
+        pub async fn send(self) -> Result<ResponseValue<Vec<types::TagAlias>>, Error<()>> {
+            let Self { client } = self;
+            let url = format!("{}/tag/aliases", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-        impl TagQuery {
-            pub fn tags<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::TagName>>,
-                T::Error: std::fmt::Display,
-            {
-                self.tags = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for tags: {}", e));
-                self
+    }
+    /**Builder for [`Client::remove_tag_alias`]
+
+    [`Client::remove_tag_alias`]: super::Client::remove_tag_alias*/
+    #[derive(Debug, Clone)]
+    pub struct RemoveTagAlias<'a> {
+        client: &'a super::Client,
+        name: Result<String, String>,
+    }
+    impl<'a> RemoveTagAlias<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                name: Err("name was not initialized".to_string()),
             }
         }
-        impl std::convert::TryFrom<TagQuery> for super::TagQuery {
-            type Error = super::error::ConversionError;
-            fn try_from(value: TagQuery) -> Result<Self, super::error::ConversionError> {
-                Ok(Self { tags: value.tags? })
-            }
+        pub fn name<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for name failed".to_string());
+            self
         }
-        impl From<super::TagQuery> for TagQuery {
-            fn from(value: super::TagQuery) -> Self {
-                Self {
-                    tags: Ok(value.tags),
-                }
+        ///Sends a `DELETE` request to `/tag/aliases/{name}`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::ErrorResponse>> {
+            let Self { client, name } = self;
+            let name = name.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/tag/aliases/{}",
+                client.baseurl,
+                encode_path(&name.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                204u16 => Ok(ResponseValue::empty(response)),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
     }
-}
-#[derive(Clone, Debug)]
-/**Client for lz-web
-
-
+    /**Builder for [`Client::complete_tag`]
 
-Version: 0.1.0*/
-pub struct Client {
-    pub(crate) baseurl: String,
-    pub(crate) client: reqwest::Client,
-}
-impl Client {
-    /// Create a new client.
-    ///
-    /// `baseurl` is the base URL provided to the internal
-    /// `reqwest::Client`, and should include a scheme and hostname,
-    /// as well as port and a path stem if applicable.
-    pub fn new(baseurl: &str) -> Self {
-        #[cfg(not(target_arch = "wasm32"))]
-        let client = {
-            let dur = std::time::Duration::from_secs(15);
-            reqwest::ClientBuilder::new()
-                .connect_timeout(dur)
-                .timeout(dur)
-        };
-        #[cfg(target_arch = "wasm32")]
-        let client = reqwest::ClientBuilder::new();
-        Self::new_with_client(baseurl, client.build().unwrap())
+    [`Client::complete_tag`]: super::Client::complete_tag*/
+    #[derive(Debug, Clone)]
+    pub struct CompleteTag<'a> {
+        client: &'a super::Client,
+        tag_fragment: Result<String, String>,
     }
-    /// Construct a new client with an existing `reqwest::Client`,
-    /// allowing more control over its configuration.
-    ///
-    /// `baseurl` is the base URL provided to the internal
-    /// `reqwest::Client`, and should include a scheme and hostname,
-    /// as well as port and a path stem if applicable.
-    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
-        Self {
-            baseurl: baseurl.to_string(),
-            client,
+    impl<'a> CompleteTag<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                tag_fragment: Err("tag_fragment was not initialized".to_string()),
+            }
+        }
+        pub fn tag_fragment<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.tag_fragment = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for tag_fragment failed".to_string());
+            self
+        }
+        ///Sends a `GET` request to `/tag/complete`
+        pub async fn send(self) -> Result<ResponseValue<Vec<types::ExistingTag>>, Error<()>> {
+            let Self {
+                client,
+                tag_fragment,
+            } = self;
+            let tag_fragment = tag_fragment.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/tag/complete", client.baseurl,);
+            let mut query = Vec::with_capacity(1usize);
+            query.push(("tag_fragment", tag_fragment.to_string()));
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&query)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
         }
     }
-    /// Get the base URL to which requests are made.
-    pub fn baseurl(&self) -> &String {
-        &self.baseurl
-    }
-    /// Get the internal `reqwest::Client` used to make requests.
-    pub fn client(&self) -> &reqwest::Client {
-        &self.client
-    }
-    /// Get the version of this API.
-    ///
-    /// This string is pulled directly from the source OpenAPI
-    /// document and may be in any format the API selects.
-    pub fn api_version(&self) -> &'static str {
-        "0.1.0"
-    }
-}
-impl Client {
-    /**Create a new bookmark
-
-    Create a new bookmark
-
-    Sends a `POST` request to `/bookmark/create`
-
-    Arguments:
-    - `body`:
-    ```ignore
-    let response = client.create_bookmark()
-        .body(body)
-        .send()
-        .await;
-    ```*/
-    pub fn create_bookmark(&self) -> builder::CreateBookmark {
-        builder::CreateBookmark::new(self)
-    }
-    /**List the user's bookmarks matching a query, newest to oldest
-
-    List the user's bookmarks matching a query, newest to oldest
-
-    Sends a `POST` request to `/bookmarks`
-
-    Arguments:
-    - `body`:
-    ```ignore
-    let response = client.list_bookmarks_matching()
-        .body(body)
-        .send()
-        .await;
-    ```*/
-    pub fn list_bookmarks_matching(&self) -> builder::ListBookmarksMatching {
-        builder::ListBookmarksMatching::new(self)
-    }
-    /**Sends a `GET` request to `/http/fetch_metadata`
+    /**Builder for [`Client::delete_tag`]
 
-    Arguments:
-    - `url`: URL to retrieve and inspect for metadata
-    ```ignore
-    let response = client.fetch_page_metadata()
-        .url(url)
-        .send()
-        .await;
-    ```*/
-    pub fn fetch_page_metadata(&self) -> builder::FetchPageMetadata {
-        builder::FetchPageMetadata::new(self)
+    [`Client::delete_tag`]: super::Client::delete_tag*/
+    #[derive(Debug, Clone)]
+    pub struct DeleteTag<'a> {
+        client: &'a super::Client,
+        id: Result<types::TagId, String>,
     }
-    /**Sends a `GET` request to `/tag/complete`
-
-    Arguments:
-    - `tag_fragment`: Substring of the tag name that must match
-    ```ignore
-    let response = client.complete_tag()
-        .tag_fragment(tag_fragment)
-        .send()
-        .await;
-    ```*/
-    pub fn complete_tag(&self) -> builder::CompleteTag {
-        builder::CompleteTag::new(self)
+    impl<'a> DeleteTag<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                id: Err("id was not initialized".to_string()),
+            }
+        }
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::TagId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `TagId` for id failed".to_string());
+            self
+        }
+        ///Sends a `DELETE` request to `/tag/{id}`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::ErrorResponse>> {
+            let Self { client, id } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/tag/{}", client.baseurl, encode_path(&id.to_string()),);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                204u16 => Ok(ResponseValue::empty(response)),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
-}
-/// Types for composing operation parameters.
-#[allow(clippy::all)]
-pub mod builder {
-    use super::types;
-    #[allow(unused_imports)]
-    use super::{
-        encode_path, ByteStream, Error, HeaderMap, HeaderValue, RequestBuilderExt, ResponseValue,
-    };
-    /**Builder for [`Client::create_bookmark`]
+    /**Builder for [`Client::add_tag_alias`]
 
-    [`Client::create_bookmark`]: super::Client::create_bookmark*/
+    [`Client::add_tag_alias`]: super::Client::add_tag_alias*/
     #[derive(Debug, Clone)]
-    pub struct CreateBookmark<'a> {
+    pub struct AddTagAlias<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::BookmarkCreateRequest, String>,
+        id: Result<types::TagId, String>,
+        body: Result<types::builder::TagAliasRequest, String>,
     }
-    impl<'a> CreateBookmark<'a> {
+    impl<'a> AddTagAlias<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                body: Ok(types::builder::BookmarkCreateRequest::default()),
+                id: Err("id was not initialized".to_string()),
+                body: Ok(types::builder::TagAliasRequest::default()),
             }
         }
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::TagId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `TagId` for id failed".to_string());
+            self
+        }
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::BookmarkCreateRequest>,
-            <V as std::convert::TryInto<types::BookmarkCreateRequest>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::TagAliasRequest>,
+            <V as std::convert::TryInto<types::TagAliasRequest>>::Error: std::fmt::Display,
         {
-            self.body = value.try_into().map(From::from).map_err(|s| {
-                format!(
-                    "conversion to `BookmarkCreateRequest` for body failed: {}",
-                    s
-                )
-            });
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `TagAliasRequest` for body failed: {}", s));
             self
         }
         pub fn body_map<F>(mut self, f: F) -> Self
         where
-            F: std::ops::FnOnce(
-                types::builder::BookmarkCreateRequest,
-            ) -> types::builder::BookmarkCreateRequest,
+            F: std::ops::FnOnce(types::builder::TagAliasRequest) -> types::builder::TagAliasRequest,
         {
             self.body = self.body.map(f);
             self
         }
-        ///Sends a `POST` request to `/bookmark/create`
-        pub async fn send(self) -> Result<ResponseValue<types::CreateBookmarkResponse>, Error<()>> {
-            let Self { client, body } = self;
+        ///Sends a `POST` request to `/tag/{id}/aliases`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::TagAlias>, Error<types::ErrorResponse>> {
+            let Self { client, id, body } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| types::BookmarkCreateRequest::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| types::TagAliasRequest::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/bookmark/create", client.baseurl,);
+            let url = format!(
+                "{}/tag/{}/aliases",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -2924,52 +7294,72 @@ pub mod builder {
             let response = result?;
             match response.status().as_u16() {
                 200u16 => ResponseValue::from_response(response).await,
+                400u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
                 _ => Err(Error::UnexpectedResponse(response)),
             }
         }
     }
-    /**Builder for [`Client::list_bookmarks_matching`]
+    /**Builder for [`Client::merge_tags`]
 
-    [`Client::list_bookmarks_matching`]: super::Client::list_bookmarks_matching*/
+    [`Client::merge_tags`]: super::Client::merge_tags*/
     #[derive(Debug, Clone)]
-    pub struct ListBookmarksMatching<'a> {
+    pub struct MergeTags<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::ListRequest, String>,
+        id: Result<types::TagId, String>,
+        body: Result<types::builder::TagMergeRequest, String>,
     }
-    impl<'a> ListBookmarksMatching<'a> {
+    impl<'a> MergeTags<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                body: Ok(types::builder::ListRequest::default()),
+                id: Err("id was not initialized".to_string()),
+                body: Ok(types::builder::TagMergeRequest::default()),
             }
         }
+        pub fn id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::TagId>,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|_| "conversion to `TagId` for id failed".to_string());
+            self
+        }
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::ListRequest>,
-            <V as std::convert::TryInto<types::ListRequest>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::TagMergeRequest>,
+            <V as std::convert::TryInto<types::TagMergeRequest>>::Error: std::fmt::Display,
         {
             self.body = value
                 .try_into()
                 .map(From::from)
-                .map_err(|s| format!("conversion to `ListRequest` for body failed: {}", s));
+                .map_err(|s| format!("conversion to `TagMergeRequest` for body failed: {}", s));
             self
         }
         pub fn body_map<F>(mut self, f: F) -> Self
         where
-            F: std::ops::FnOnce(types::builder::ListRequest) -> types::builder::ListRequest,
+            F: std::ops::FnOnce(types::builder::TagMergeRequest) -> types::builder::TagMergeRequest,
         {
             self.body = self.body.map(f);
             self
         }
-        ///Sends a `POST` request to `/bookmarks`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::ListBookmarksMatchingResponse>, Error<()>> {
-            let Self { client, body } = self;
+        ///Sends a `POST` request to `/tag/{id}/merge`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::ErrorResponse>> {
+            let Self { client, id, body } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| types::ListRequest::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| types::TagMergeRequest::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/bookmarks", client.baseurl,);
+            let url = format!(
+                "{}/tag/{}/merge",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -2983,107 +7373,248 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                204u16 => Ok(ResponseValue::empty(response)),
+                400u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
                 _ => Err(Error::UnexpectedResponse(response)),
             }
         }
     }
-    /**Builder for [`Client::fetch_page_metadata`]
+    /**Builder for [`Client::rename_tag`]
 
-    [`Client::fetch_page_metadata`]: super::Client::fetch_page_metadata*/
+    [`Client::rename_tag`]: super::Client::rename_tag*/
     #[derive(Debug, Clone)]
-    pub struct FetchPageMetadata<'a> {
+    pub struct RenameTag<'a> {
         client: &'a super::Client,
-        url: Result<String, String>,
+        id: Result<types::TagId, String>,
+        body: Result<types::builder::TagRenameRequest, String>,
     }
-    impl<'a> FetchPageMetadata<'a> {
+    impl<'a> RenameTag<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                url: Err("url was not initialized".to_string()),
+                id: Err("id was not initialized".to_string()),
+                body: Ok(types::builder::TagRenameRequest::default()),
             }
         }
-        pub fn url<V>(mut self, value: V) -> Self
+        pub fn id<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<types::TagId>,
         {
-            self.url = value
+            self.id = value
                 .try_into()
-                .map_err(|_| "conversion to `String` for url failed".to_string());
+                .map_err(|_| "conversion to `TagId` for id failed".to_string());
             self
         }
-        ///Sends a `GET` request to `/http/fetch_metadata`
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::TagRenameRequest>,
+            <V as std::convert::TryInto<types::TagRenameRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `TagRenameRequest` for body failed: {}", s));
+            self
+        }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::TagRenameRequest,
+            ) -> types::builder::TagRenameRequest,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+        ///Sends a `POST` request to `/tag/{id}/rename`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::FetchPageMetadataResponse>, Error<()>> {
-            let Self { client, url } = self;
-            let url = url.map_err(Error::InvalidRequest)?;
-            let _url = format!("{}/http/fetch_metadata", client.baseurl,);
-            let mut query = Vec::with_capacity(1usize);
-            query.push(("url", url.to_string()));
+        ) -> Result<ResponseValue<types::ExistingTag>, Error<types::ErrorResponse>> {
+            let Self { client, id, body } = self;
+            let id = id.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::TagRenameRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/tag/{}/rename",
+                client.baseurl,
+                encode_path(&id.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(_url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .query(&query)
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
                 200u16 => ResponseValue::from_response(response).await,
+                400u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                404u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
                 _ => Err(Error::UnexpectedResponse(response)),
             }
         }
     }
-    /**Builder for [`Client::complete_tag`]
+    /**Builder for [`Client::list_tags`]
 
-    [`Client::complete_tag`]: super::Client::complete_tag*/
+    [`Client::list_tags`]: super::Client::list_tags*/
     #[derive(Debug, Clone)]
-    pub struct CompleteTag<'a> {
+    pub struct ListTags<'a> {
         client: &'a super::Client,
-        tag_fragment: Result<String, String>,
     }
-    impl<'a> CompleteTag<'a> {
+    impl<'a> ListTags<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+        ///Sends a `GET` request to `/tags`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<Vec<types::ListTagsResponseItem>>, Error<()>> {
+            let Self { client } = self;
+            let url = format!("{}/tags", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+    /**Builder for [`Client::tag_tree`]
+
+    [`Client::tag_tree`]: super::Client::tag_tree*/
+    #[derive(Debug, Clone)]
+    pub struct TagTree<'a> {
+        client: &'a super::Client,
+    }
+    impl<'a> TagTree<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+        ///Sends a `GET` request to `/tags/tree`
+        pub async fn send(self) -> Result<ResponseValue<Vec<types::TagTreeNode>>, Error<()>> {
+            let Self { client } = self;
+            let url = format!("{}/tags/tree", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+    /**Builder for [`Client::get_user_settings`]
+
+    [`Client::get_user_settings`]: super::Client::get_user_settings*/
+    #[derive(Debug, Clone)]
+    pub struct GetUserSettings<'a> {
+        client: &'a super::Client,
+    }
+    impl<'a> GetUserSettings<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+        ///Sends a `GET` request to `/user/settings`
+        pub async fn send(self) -> Result<ResponseValue<types::UserSettings>, Error<()>> {
+            let Self { client } = self;
+            let url = format!("{}/user/settings", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+    /**Builder for [`Client::update_user_settings`]
+
+    [`Client::update_user_settings`]: super::Client::update_user_settings*/
+    #[derive(Debug, Clone)]
+    pub struct UpdateUserSettings<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::UserSettings, String>,
+    }
+    impl<'a> UpdateUserSettings<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                tag_fragment: Err("tag_fragment was not initialized".to_string()),
+                body: Ok(types::builder::UserSettings::default()),
             }
         }
-        pub fn tag_fragment<V>(mut self, value: V) -> Self
+        pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<types::UserSettings>,
+            <V as std::convert::TryInto<types::UserSettings>>::Error: std::fmt::Display,
         {
-            self.tag_fragment = value
+            self.body = value
                 .try_into()
-                .map_err(|_| "conversion to `String` for tag_fragment failed".to_string());
+                .map(From::from)
+                .map_err(|s| format!("conversion to `UserSettings` for body failed: {}", s));
             self
         }
-        ///Sends a `GET` request to `/tag/complete`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<Vec<types::CompleteTagResponseItem>>, Error<()>> {
-            let Self {
-                client,
-                tag_fragment,
-            } = self;
-            let tag_fragment = tag_fragment.map_err(Error::InvalidRequest)?;
-            let url = format!("{}/tag/complete", client.baseurl,);
-            let mut query = Vec::with_capacity(1usize);
-            query.push(("tag_fragment", tag_fragment.to_string()));
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::UserSettings) -> types::builder::UserSettings,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+        ///Sends a `PUT` request to `/user/settings`
+        pub async fn send(self) -> Result<ResponseValue<types::UserSettings>, Error<()>> {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| types::UserSettings::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/user/settings", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(url)
+                .put(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .query(&query)
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
@@ -10,5 +10,14 @@ pub use layout::*;
 mod create_form;
 pub use create_form::*;
 
+mod edit_form;
+pub use edit_form::*;
+
 mod tag_select;
 pub use tag_select::*;
+
+mod search_box;
+pub use search_box::*;
+
+mod tags;
+pub use tags::*;
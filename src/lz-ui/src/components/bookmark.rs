@@ -1,9 +1,13 @@
+use crate::dispatch_callback;
 use crate::route::*;
+use bounce::use_slice;
 use lz_openapi::types::AnnotatedBookmark;
 use patternfly_yew::prelude::*;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use super::{ModalAction, ModalState};
+
 #[derive(Properties, PartialEq)]
 pub struct BookmarkProps {
     pub bookmark: AnnotatedBookmark,
@@ -11,6 +15,10 @@ pub struct BookmarkProps {
 
 #[function_component(Bookmark)]
 pub fn bookmark(BookmarkProps { bookmark }: &BookmarkProps) -> Html {
+    let modal = use_slice::<ModalState>();
+    let id = bookmark.bookmark.id;
+    let open_edit = dispatch_callback(&modal, move |_| ModalAction::OpenEditBookmark(id));
+    let open_delete = dispatch_callback(&modal, move |_| ModalAction::OpenDeleteBookmark(id));
     let tags = bookmark
         .tags
         .iter()
@@ -51,6 +59,10 @@ pub fn bookmark(BookmarkProps { bookmark }: &BookmarkProps) -> Html {
             <CardBody>
                 <Content>{ description }{ notes }</Content>
             </CardBody>
+            <CardFooter>
+                <Button variant={ButtonVariant::Link} label="Edit" onclick={open_edit} />
+                <Button variant={ButtonVariant::Link} label="Delete" onclick={open_delete} />
+            </CardFooter>
         </Card>
     }
 }
@@ -2,29 +2,47 @@ use std::ops::Deref;
 use std::rc::Rc;
 
 use async_trait::async_trait;
+use bounce::prelude::*;
 use bounce::query::{use_query_value, Query, QueryResult};
-use bounce::BounceStates;
 use lz_openapi::types::builder::ListRequest;
-use lz_openapi::types::{BookmarkId, BookmarkSearch, ListBookmarksMatchingResponse};
+use lz_openapi::types::{BookmarkCursor, BookmarkSearch, ListBookmarksMatchingResponse};
 use patternfly_yew::prelude::*;
 use yew::prelude::*;
 
-use crate::{components::*, GoddamnIt};
+use crate::{client, components::*, GoddamnIt};
 
 #[derive(Properties, Default, PartialEq, Clone, Eq, Hash, Debug)]
 pub struct BookmarksProps {
-    pub cursor: Option<BookmarkId>,
+    pub cursor: Option<BookmarkCursor>,
     pub query: Vec<BookmarkSearch>,
+    /// A search string, in the syntax of the `q` API parameter.
+    #[prop_or_default]
+    pub q: Option<String>,
 }
 
 impl BookmarksProps {
     fn as_body(&self) -> ListRequest {
         ListRequest::default()
-            .cursor(self.cursor)
+            .cursor(self.cursor.clone())
             .query(self.query.clone())
     }
 }
 
+/// Applied whenever a bookmark got created, changed or deleted.
+pub struct BookmarksChanged;
+
+/// A counter that increments on every [`BookmarksChanged`], so
+/// queries that include it re-fetch their bookmarks.
+#[derive(Default, PartialEq, Eq, Hash, Clone, Copy, Debug, Atom)]
+#[bounce(with_notion(BookmarksChanged))]
+pub struct BookmarksVersion(u32);
+
+impl WithNotion<BookmarksChanged> for BookmarksVersion {
+    fn apply(self: Rc<Self>, _notion: Rc<BookmarksChanged>) -> Rc<Self> {
+        BookmarksVersion(self.0.wrapping_add(1)).into()
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 struct BookmarkBatch(ListBookmarksMatchingResponse);
 
@@ -38,24 +56,23 @@ impl Deref for BookmarkBatch {
 
 #[async_trait(?Send)]
 impl Query for BookmarkBatch {
-    type Input = BookmarksProps;
+    type Input = (BookmarksProps, BookmarksVersion);
     type Error = GoddamnIt;
 
-    async fn query(_states: &BounceStates, input: Rc<BookmarksProps>) -> QueryResult<Self> {
-        let loc = web_sys::window().unwrap().location();
-        let base_url = format!(
-            "{}//{}/api/v1",
-            loc.protocol().unwrap(),
-            loc.host().unwrap()
-        );
-
-        let client = lz_openapi::Client::new(&base_url);
-        let response = client
-            .list_bookmarks_matching()
-            .body(input.as_body())
-            .send()
-            .await
-            .map_err(GoddamnIt::new)?;
+    async fn query(
+        _states: &BounceStates,
+        input: Rc<(BookmarksProps, BookmarksVersion)>,
+    ) -> QueryResult<Self> {
+        let (input, _) = &*input;
+        let client = client();
+        let mut request = client.list_bookmarks_matching().body(input.as_body());
+        if let Some(q) = &input.q {
+            request = request.q(q.clone());
+        }
+        let response = request.send().await.map_err(|e| match e {
+            lz_openapi::Error::ErrorResponse(response) => GoddamnIt::new(&response.error_message),
+            e => GoddamnIt::new(e),
+        })?;
         Ok(BookmarkBatch(response.into_inner()).into())
     }
 }
@@ -63,7 +80,8 @@ impl Query for BookmarkBatch {
 #[function_component(Bookmarks)]
 pub fn bookmarks(props: &BookmarksProps) -> Html {
     let load_next = use_state(|| false);
-    let bookmarks = use_query_value::<BookmarkBatch>(Rc::new(props.clone()));
+    let version = use_atom_value::<BookmarksVersion>();
+    let bookmarks = use_query_value::<BookmarkBatch>(Rc::new((props.clone(), *version)));
     match bookmarks.result() {
         None => html! { <Spinner size={SpinnerSize::Lg} /> },
         Some(Ok(b)) => {
@@ -76,7 +94,7 @@ pub fn bookmarks(props: &BookmarksProps) -> Html {
                 <section>
                     <>
                         { bookmark_items }
-                        if let Some(next) = b.next_cursor {
+                        if let Some(next) = b.next_cursor.clone() {
                             if !*load_next {
                                 <Button
                                     onclick={move |_ev| {
@@ -87,7 +105,7 @@ pub fn bookmarks(props: &BookmarksProps) -> Html {
                                     variant={ButtonVariant::Control}
                                 />
                             } else {
-                                <Bookmarks cursor={next} query={props.query.clone()} />
+                                <Bookmarks cursor={next} query={props.query.clone()} q={props.q.clone()} />
                             }
                         }
                     </>
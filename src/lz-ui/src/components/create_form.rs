@@ -7,16 +7,16 @@ use bounce::query::{
 };
 use chrono::Utc;
 use lz_openapi::types::{
-    BookmarkCreateRequest, CreateBookmarkResponse, Metadata, NewBookmark, NoId,
+    AssociatedLink, BookmarkCreateRequest, CreateBookmarkResponse, Metadata, NewBookmark, NoId,
 };
 use patternfly_yew::prelude::*;
 use url::Url;
-use yew::platform::spawn_local;
 use yew::prelude::*;
 
-use crate::{dispatch_callback, GoddamnIt};
+use crate::{client, dispatch_callback, GoddamnIt};
 
-use super::{CloseModal, TagSelect};
+use super::edit_form::{mutation_error, run_and_close};
+use super::{BookmarksChanged, CloseModal, TagSelect};
 
 #[derive(Properties, PartialEq)]
 pub struct VisibleProps {
@@ -31,6 +31,9 @@ struct BookmarkData {
     description: String,
     notes: String,
     tags: Vec<String>,
+    website_title: Option<String>,
+    website_description: Option<String>,
+    associations: Vec<AssociatedLink>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -79,9 +82,12 @@ impl Reducible for BookmarkData {
                 ..(*self).clone()
             }
             .into(),
-            BookmarkAction::FromMetadata(Metadata { title, description }) => Self {
-                title,
-                description: description.unwrap_or("".to_string()),
+            BookmarkAction::FromMetadata(metadata) => Self {
+                associations: associated_links(&self.url, &metadata),
+                website_title: Some(metadata.title.clone()).filter(|t| !t.is_empty()),
+                website_description: metadata.description.clone(),
+                title: metadata.title,
+                description: metadata.description.unwrap_or("".to_string()),
                 ..(*self).clone()
             }
             .into(),
@@ -89,6 +95,28 @@ impl Reducible for BookmarkData {
     }
 }
 
+/// Links from the page's metadata that get stored along with the
+/// bookmark, each only once.
+fn associated_links(url: &str, metadata: &Metadata) -> Vec<AssociatedLink> {
+    let candidates = [
+        ("canonical", &metadata.canonical_url),
+        ("image", &metadata.image),
+        ("icon", &metadata.favicon),
+    ];
+    let mut links: Vec<AssociatedLink> = vec![];
+    for (context, link) in candidates {
+        if let Some(link) = link {
+            if link != url && links.iter().all(|seen| &seen.link != link) {
+                links.push(AssociatedLink {
+                    context: Some(context.to_string()),
+                    link: link.clone(),
+                });
+            }
+        }
+    }
+    links
+}
+
 impl WithNotion<CloseModal> for BookmarkData {
     fn apply(self: std::rc::Rc<Self>, _notion: std::rc::Rc<CloseModal>) -> std::rc::Rc<Self> {
         Default::default()
@@ -99,7 +127,7 @@ impl BookmarkData {
     fn to_create_request(&self) -> BookmarkCreateRequest {
         let created_at = Utc::now();
         BookmarkCreateRequest {
-            associations: vec![],
+            associations: self.associations.clone(),
             bookmark: NewBookmark {
                 id: NoId(serde_json::Value::Null),
                 user_id: NoId(serde_json::Value::Null),
@@ -116,8 +144,8 @@ impl BookmarkData {
                 title: self.title.to_string(),
                 unread: None,
                 url: self.url.to_string(),
-                website_description: None, // TODO
-                website_title: None,       // TODO
+                website_description: self.website_description.clone(),
+                website_title: self.website_title.clone(),
             },
             tag_names: self.tags.clone(),
         }
@@ -134,15 +162,7 @@ impl Query for SaveBookmarkQuery {
     type Error = GoddamnIt;
 
     async fn query(_states: &BounceStates, input: Rc<Url>) -> QueryResult<Self> {
-        let loc = web_sys::window().unwrap().location();
-        let base_url = format!(
-            "{}//{}/api/v1",
-            loc.protocol().unwrap(),
-            loc.host().unwrap()
-        );
-
-        let client = lz_openapi::Client::new(&base_url);
-        let res = client
+        let res = client()
             .fetch_page_metadata()
             .url(input.to_string())
             .send()
@@ -150,8 +170,14 @@ impl Query for SaveBookmarkQuery {
             .map_err(GoddamnIt::new)?;
         let md = res.into_inner();
         Ok(SaveBookmarkQuery(Metadata {
-            title: md.title,
+            author: md.author,
+            canonical_url: md.canonical_url,
             description: md.description,
+            favicon: md.favicon,
+            image: md.image,
+            language: md.language,
+            published_at: md.published_at,
+            title: md.title,
         })
         .into())
     }
@@ -168,15 +194,7 @@ impl Mutation for SaveBookmarkMutation {
 
     async fn run(states: &BounceStates, _input: Rc<()>) -> MutationResult<Self> {
         let bookmark_data = states.get_slice_value::<BookmarkData>();
-        let loc = web_sys::window().unwrap().location();
-        let base_url = format!(
-            "{}//{}/api/v1",
-            loc.protocol().unwrap(),
-            loc.host().unwrap()
-        );
-
-        let client = lz_openapi::Client::new(&base_url);
-        let result = client
+        let result = client()
             .create_bookmark()
             .body(bookmark_data.to_create_request())
             .send()
@@ -193,14 +211,11 @@ pub fn create_form(VisibleProps { onclose }: &VisibleProps) -> Html {
     let onchange = dispatch_callback(&bookmark_data, BookmarkAction::SetUrl);
     let valid = use_state(|| true);
     let onvalidated = use_callback(valid.clone(), |state, valid| {
-        valid.set(match state {
-            InputState::Default | InputState::Success => true,
-            _ => false,
-        })
+        valid.set(matches!(state, InputState::Default | InputState::Success))
     });
 
-    let inner = match &*state {
-        &State::EnteringUrl => {
+    let inner = match *state {
+        State::EnteringUrl => {
             let onsubmit = Callback::from(move |ev: SubmitEvent| {
                 ev.prevent_default();
                 if *valid {
@@ -234,7 +249,7 @@ pub fn create_form(VisibleProps { onclose }: &VisibleProps) -> Html {
                 </Form>
             }
         }
-        &State::EnteringData => html! {
+        State::EnteringData => html! {
             <FillBookmark onclose={onclose.clone()} url={Url::parse(&bookmark_data.url).unwrap()} />
         },
     };
@@ -265,10 +280,7 @@ struct FillBookmarkProps {
 fn fill_bookmark(FillBookmarkProps { url, onclose }: &FillBookmarkProps) -> Html {
     let valid = use_state(|| false);
     let onvalidated = use_callback(valid.clone(), move |state, valid| {
-        valid.set(match state {
-            InputState::Default | InputState::Success => true,
-            _ => false,
-        })
+        valid.set(matches!(state, InputState::Default | InputState::Success))
     });
     let bookmark_data = use_slice::<BookmarkData>();
     let set_title = dispatch_callback(&bookmark_data, BookmarkAction::SetTitle);
@@ -277,7 +289,7 @@ fn fill_bookmark(FillBookmarkProps { url, onclose }: &FillBookmarkProps) -> Html
     let set_notes = dispatch_callback(&bookmark_data, BookmarkAction::SetNotes);
     let metadata_query = use_query_value::<SaveBookmarkQuery>(Rc::new(url.clone()));
     {
-        let res = metadata_query.result().map(|x| x.clone());
+        let res = metadata_query.result().cloned();
         let valid_set = valid.setter();
         let bookmark_data = bookmark_data.clone();
         use_effect_with(res, move |res| match res {
@@ -291,22 +303,16 @@ fn fill_bookmark(FillBookmarkProps { url, onclose }: &FillBookmarkProps) -> Html
         });
     }
     let save_bookmark = use_mutation::<SaveBookmarkMutation>();
+    let changed = use_notion_applier::<BookmarksChanged>();
     let save = {
         let save_bookmark = save_bookmark.clone();
         let onclose = onclose.clone();
         let valid = valid.clone();
         Callback::from(move |ev: SubmitEvent| {
             ev.prevent_default();
-            if !*valid {
-                return;
+            if *valid {
+                run_and_close(&save_bookmark, (), &onclose, &changed);
             }
-            let onclose = onclose.clone();
-            let save_bookmark = save_bookmark.clone();
-            spawn_local(async move {
-                let _ = save_bookmark.run(()).await;
-                // TODO: error-handle & close only when creation went through.
-                onclose.emit(());
-            })
         })
     };
 
@@ -324,6 +330,7 @@ fn fill_bookmark(FillBookmarkProps { url, onclose }: &FillBookmarkProps) -> Html
                     <label class="font-medium" for="bookmark_tags">{ "Tags" }</label>
                     <TagSelect on_change={set_tags} />
                 </div>
+                { mutation_error(&save_bookmark) }
                 <ActionGroup>
                     <Button
                         loading={save_bookmark.state() == MutationState::Loading}
@@ -340,14 +347,14 @@ fn fill_bookmark(FillBookmarkProps { url, onclose }: &FillBookmarkProps) -> Html
 }
 
 #[derive(Properties, PartialEq)]
-struct TitleInputProps {
-    onchange: Callback<String>,
-    value: String,
+pub(crate) struct TitleInputProps {
+    pub onchange: Callback<String>,
+    pub value: String,
 }
 
 // Workaround for https://github.com/patternfly-yew/patternfly-yew/issues/145:
 #[function_component(TitleInput)]
-fn title_input(TitleInputProps { onchange, value }: &TitleInputProps) -> Html {
+pub(crate) fn title_input(TitleInputProps { onchange, value }: &TitleInputProps) -> Html {
     let validator = Validator::from(|ctx: ValidationContext<String>| {
         if ctx.initial {
             ValidationResult::ok()
@@ -0,0 +1,310 @@
+use std::rc::Rc;
+
+use async_trait::async_trait;
+use bounce::prelude::*;
+use bounce::query::{
+    use_mutation, use_query_value, Mutation, MutationResult, MutationState, Query, QueryResult,
+};
+use lz_openapi::types::{
+    AnnotatedBookmark, BookmarkCreateRequest, BookmarkId, ErrorResponse, NewBookmark, NoId,
+    ReplaceBookmarkResponse,
+};
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+use crate::{client, GoddamnIt};
+
+use super::{BookmarksChanged, BookmarksVersion, TagSelect, TitleInput};
+
+#[derive(Properties, PartialEq)]
+pub struct EditProps {
+    pub id: BookmarkId,
+    pub onclose: Callback<()>,
+}
+
+pub(super) fn api_error(e: lz_openapi::Error<ErrorResponse>) -> GoddamnIt {
+    match e {
+        lz_openapi::Error::ErrorResponse(response) => GoddamnIt::new(&response.error_message),
+        e => GoddamnIt::new(e),
+    }
+}
+
+/// The bookmark that is being edited or deleted.
+#[derive(PartialEq, Debug, Clone)]
+struct BookmarkQuery(AnnotatedBookmark);
+
+#[async_trait(?Send)]
+impl Query for BookmarkQuery {
+    type Input = (BookmarkId, BookmarksVersion);
+    type Error = GoddamnIt;
+
+    async fn query(
+        _states: &BounceStates,
+        input: Rc<(BookmarkId, BookmarksVersion)>,
+    ) -> QueryResult<Self> {
+        let response = client()
+            .get_bookmark()
+            .id(input.0)
+            .send()
+            .await
+            .map_err(api_error)?
+            .into_inner();
+        Ok(BookmarkQuery(AnnotatedBookmark {
+            associations: response.associations,
+            bookmark: response.bookmark,
+            snippet: response.snippet,
+            tags: response.tags,
+        })
+        .into())
+    }
+}
+
+/// Replaces a bookmark with the edited version.
+#[derive(PartialEq, Clone, Debug)]
+struct UpdateBookmarkMutation(ReplaceBookmarkResponse);
+
+#[async_trait(?Send)]
+impl Mutation for UpdateBookmarkMutation {
+    type Input = (BookmarkId, BookmarkCreateRequest);
+    type Error = GoddamnIt;
+
+    async fn run(
+        _states: &BounceStates,
+        input: Rc<(BookmarkId, BookmarkCreateRequest)>,
+    ) -> MutationResult<Self> {
+        let (id, request) = &*input;
+        let result = client()
+            .replace_bookmark()
+            .id(*id)
+            .body(request.clone())
+            .send()
+            .await
+            .map_err(api_error)?;
+        Ok(Rc::new(UpdateBookmarkMutation(result.into_inner())))
+    }
+}
+
+/// Deletes a bookmark.
+#[derive(PartialEq, Clone, Debug)]
+struct DeleteBookmarkMutation;
+
+#[async_trait(?Send)]
+impl Mutation for DeleteBookmarkMutation {
+    type Input = BookmarkId;
+    type Error = GoddamnIt;
+
+    async fn run(_states: &BounceStates, input: Rc<BookmarkId>) -> MutationResult<Self> {
+        client()
+            .delete_bookmark()
+            .id(*input)
+            .send()
+            .await
+            .map_err(api_error)?;
+        Ok(Rc::new(DeleteBookmarkMutation))
+    }
+}
+
+/// Run a mutation, and on success notify the bookmark lists and close
+/// the modal.
+pub(super) fn run_and_close<M: Mutation + 'static>(
+    mutation: &bounce::query::UseMutationHandle<M>,
+    input: M::Input,
+    onclose: &Callback<()>,
+    changed: &Rc<dyn Fn(BookmarksChanged)>,
+) {
+    let mutation = mutation.clone();
+    let onclose = onclose.clone();
+    let changed = changed.clone();
+    spawn_local(async move {
+        if mutation.run(input).await.is_ok() {
+            changed(BookmarksChanged);
+            onclose.emit(());
+        }
+    });
+}
+
+pub(super) fn mutation_error<M: Mutation<Error = GoddamnIt>>(
+    mutation: &bounce::query::UseMutationHandle<M>,
+) -> Html {
+    match mutation.result() {
+        Some(Err(e)) => html! {
+            <Alert inline=true r#type={AlertType::Danger} title={e.to_string()} />
+        },
+        _ => html! {},
+    }
+}
+
+#[function_component(EditForm)]
+pub fn edit_form(EditProps { id, onclose }: &EditProps) -> Html {
+    let version = use_atom_value::<BookmarksVersion>();
+    let bookmark = use_query_value::<BookmarkQuery>(Rc::new((*id, *version)));
+    let inner = match bookmark.result() {
+        None => html! { <Spinner size={SpinnerSize::Lg} /> },
+        Some(Ok(bookmark)) => html! {
+            <EditBookmark bookmark={bookmark.0.clone()} onclose={onclose.clone()} />
+        },
+        Some(Err(e)) => html! { <p>{ e.to_string() }</p> },
+    };
+    html! {
+        <Bullseye plain=true>
+            <Modal
+                disable_close_click_outside=true
+                title="Edit bookmark"
+                variant={ModalVariant::Medium}
+                onclose={let onclose = onclose.clone(); move |_ev| onclose.emit(())}
+            >
+                { inner }
+            </Modal>
+        </Bullseye>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct EditBookmarkProps {
+    bookmark: AnnotatedBookmark,
+    onclose: Callback<()>,
+}
+
+#[function_component(EditBookmark)]
+fn edit_bookmark(EditBookmarkProps { bookmark, onclose }: &EditBookmarkProps) -> Html {
+    let existing = &bookmark.bookmark;
+    let title = use_state(|| existing.title.clone());
+    let description = use_state(|| existing.description.clone().unwrap_or_default());
+    let notes = use_state(|| existing.notes.clone().unwrap_or_default());
+    let initial_tags = use_memo(bookmark.tags.clone(), |tags| {
+        tags.iter().map(|tag| tag.name.clone()).collect::<Vec<_>>()
+    });
+    let tags = use_state(|| (*initial_tags).clone());
+    let valid = use_state(|| true);
+    let onvalidated = use_callback(valid.clone(), |state, valid| {
+        valid.set(matches!(state, InputState::Default | InputState::Success))
+    });
+    let update = use_mutation::<UpdateBookmarkMutation>();
+    let changed = use_notion_applier::<BookmarksChanged>();
+
+    let onsubmit = {
+        let (title, description, notes, tags) = (
+            title.clone(),
+            description.clone(),
+            notes.clone(),
+            tags.clone(),
+        );
+        let (bookmark, update, onclose, valid) = (
+            bookmark.clone(),
+            update.clone(),
+            onclose.clone(),
+            valid.clone(),
+        );
+        Callback::from(move |ev: SubmitEvent| {
+            ev.prevent_default();
+            if !*valid {
+                return;
+            }
+            let existing = &bookmark.bookmark;
+            let request = BookmarkCreateRequest {
+                associations: bookmark.associations.clone(),
+                bookmark: NewBookmark {
+                    id: NoId(serde_json::Value::Null),
+                    user_id: NoId(serde_json::Value::Null),
+                    accessed_at: existing.accessed_at,
+                    created_at: existing.created_at,
+                    description: Some((*description).clone()).filter(|d| !d.is_empty()),
+                    modified_at: existing.modified_at,
+                    notes: Some((*notes).clone()).filter(|n| !n.is_empty()),
+                    shared: existing.shared,
+                    title: (*title).clone(),
+                    unread: existing.unread,
+                    url: existing.url.clone(),
+                    website_description: existing.website_description.clone(),
+                    website_title: existing.website_title.clone(),
+                },
+                tag_names: (*tags).clone(),
+            };
+            run_and_close(&update, (existing.id, request), &onclose, &changed);
+        })
+    };
+
+    html! {
+        <Form {onvalidated} {onsubmit}>
+            { mutation_error(&update) }
+            <TitleInput onchange={let title = title.clone(); move |v| title.set(v)} value={(*title).clone()} />
+            <FormGroup label="Description">
+                <TextArea
+                    onchange={let description = description.clone(); move |v| description.set(v)}
+                    value={(*description).clone()}
+                />
+            </FormGroup>
+            <FormGroup label="Notes">
+                <TextArea
+                    onchange={let notes = notes.clone(); move |v| notes.set(v)}
+                    value={(*notes).clone()}
+                />
+            </FormGroup>
+            <div class="grid grid-cols-1 gap-1">
+                <label class="font-medium" for="bookmark_tags">{ "Tags" }</label>
+                <TagSelect
+                    on_change={let tags = tags.clone(); move |v| tags.set(v)}
+                    value={(*initial_tags).clone()}
+                />
+            </div>
+            <ActionGroup>
+                <Button
+                    loading={update.state() == MutationState::Loading}
+                    variant={ButtonVariant::Primary}
+                    r#type={ButtonType::Submit}
+                    disabled={!*valid}
+                    label="Save"
+                />
+                <Button
+                    variant={ButtonVariant::Link}
+                    label="Cancel"
+                    onclick={let onclose = onclose.clone(); move |_| onclose.emit(())}
+                />
+            </ActionGroup>
+        </Form>
+    }
+}
+
+#[function_component(DeleteConfirmation)]
+pub fn delete_confirmation(EditProps { id, onclose }: &EditProps) -> Html {
+    let version = use_atom_value::<BookmarksVersion>();
+    let bookmark = use_query_value::<BookmarkQuery>(Rc::new((*id, *version)));
+    let delete = use_mutation::<DeleteBookmarkMutation>();
+    let changed = use_notion_applier::<BookmarksChanged>();
+    let ondelete = {
+        let (id, delete, onclose) = (*id, delete.clone(), onclose.clone());
+        Callback::from(move |_| run_and_close(&delete, id, &onclose, &changed))
+    };
+    let title = match bookmark.result() {
+        Some(Ok(bookmark)) => bookmark.0.bookmark.title.clone(),
+        _ => String::new(),
+    };
+    html! {
+        <Bullseye plain=true>
+            <Modal
+                title="Delete bookmark?"
+                variant={ModalVariant::Small}
+                onclose={let onclose = onclose.clone(); move |_ev| onclose.emit(())}
+            >
+                { mutation_error(&delete) }
+                <Content>
+                    <p>{ "This deletes the bookmark " }<strong>{ title }</strong>{ " and its tags." }</p>
+                </Content>
+                <ActionGroup>
+                    <Button
+                        loading={delete.state() == MutationState::Loading}
+                        variant={ButtonVariant::Danger}
+                        label="Delete"
+                        onclick={ondelete}
+                    />
+                    <Button
+                        variant={ButtonVariant::Link}
+                        label="Cancel"
+                        onclick={let onclose = onclose.clone(); move |_| onclose.emit(())}
+                    />
+                </ActionGroup>
+            </Modal>
+        </Bullseye>
+    }
+}
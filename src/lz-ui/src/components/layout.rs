@@ -1,10 +1,14 @@
 use bounce::prelude::*;
 use patternfly_yew::prelude::*;
 use yew::prelude::*;
+use yew_router::prelude::*;
 
 use crate::dispatch_callback;
+use crate::route::Route;
 
-use super::CreateForm;
+use lz_openapi::types::BookmarkId;
+
+use super::{CreateForm, DeleteConfirmation, EditForm, SearchBox};
 
 pub struct CloseModal;
 
@@ -17,11 +21,19 @@ pub enum ModalState {
 
     /// "Create bookmark" modal visible
     CreateBookmark,
+
+    /// "Edit bookmark" modal visible for the given bookmark
+    EditBookmark(BookmarkId),
+
+    /// Confirmation for deleting the given bookmark visible
+    DeleteBookmark(BookmarkId),
 }
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum ModalAction {
     OpenCreateBookmark,
+    OpenEditBookmark(BookmarkId),
+    OpenDeleteBookmark(BookmarkId),
     Close,
 }
 
@@ -33,8 +45,19 @@ impl Reducible for ModalState {
             (ModalAction::OpenCreateBookmark, ModalState::Normal) => {
                 ModalState::CreateBookmark.into()
             }
+            (ModalAction::OpenEditBookmark(id), ModalState::Normal) => {
+                ModalState::EditBookmark(id).into()
+            }
+            (ModalAction::OpenDeleteBookmark(id), ModalState::Normal) => {
+                ModalState::DeleteBookmark(id).into()
+            }
             (ModalAction::Close, _) => ModalState::Normal.into(),
-            (ModalAction::OpenCreateBookmark, _) => self.into(),
+            (
+                ModalAction::OpenCreateBookmark
+                | ModalAction::OpenEditBookmark(_)
+                | ModalAction::OpenDeleteBookmark(_),
+                _,
+            ) => self,
         }
     }
 }
@@ -55,17 +78,21 @@ pub fn layout(LayoutProps { children }: &LayoutProps) -> Html {
     let state = use_slice::<ModalState>();
     let open_create = dispatch_callback(&state, |_| ModalAction::OpenCreateBookmark);
     let onclose = use_notion_applier::<CloseModal>();
+    let onclose = Callback::from(move |_| onclose(CloseModal));
     match *state {
         ModalState::Normal => html! {
             <Grid gutter=true>
-                <GridItem cols={[6]}>{ children.clone() }</GridItem>
+                <GridItem cols={[6]}><SearchBox />{ children.clone() }</GridItem>
                 <GridItem cols={[2]}>
                     <Button label="Add" onclick={open_create} />
+                    <Link<Route> to={Route::Tags}>
+                        <Button label="Tags" variant={ButtonVariant::Link} />
+                    </Link<Route>>
                 </GridItem>
             </Grid>
         },
-        ModalState::CreateBookmark => {
-            html! { <CreateForm onclose={move |_| onclose(CloseModal)} /> }
-        }
+        ModalState::CreateBookmark => html! { <CreateForm {onclose} /> },
+        ModalState::EditBookmark(id) => html! { <EditForm {id} {onclose} /> },
+        ModalState::DeleteBookmark(id) => html! { <DeleteConfirmation {id} {onclose} /> },
     }
 }
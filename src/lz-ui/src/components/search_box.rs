@@ -0,0 +1,50 @@
+use patternfly_yew::prelude::*;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::route::Route;
+
+/// The query string of the [`Route::Search`] page.
+#[derive(Serialize, Deserialize, Default, PartialEq, Eq, Clone, Debug)]
+pub struct SearchQuery {
+    pub q: String,
+}
+
+/// A search input that takes the user to the results for their query.
+#[function_component(SearchBox)]
+pub fn search_box() -> Html {
+    let navigator = use_navigator().unwrap();
+    let location = use_location();
+    let value = use_state(|| {
+        location
+            .and_then(|loc| loc.query::<SearchQuery>().ok())
+            .unwrap_or_default()
+            .q
+    });
+    let onchange = {
+        let value = value.clone();
+        Callback::from(move |new_value| value.set(new_value))
+    };
+    let onclear = {
+        let value = value.clone();
+        Callback::from(move |_| value.set(String::new()))
+    };
+    let onsearch = Callback::from(move |(_, q): (_, String)| {
+        if q.trim().is_empty() {
+            navigator.push(&Route::Home);
+        } else if let Err(e) = navigator.push_with_query(&Route::Search, &SearchQuery { q }) {
+            tracing::error!(error=%e, "could not navigate to search results");
+        }
+    });
+    html! {
+        <SearchInput
+            aria_label="Search bookmarks"
+            placeholder="tag:rust -tag:old \"exact phrase\" site:github.com"
+            value={(*value).clone()}
+            {onchange}
+            {onclear}
+            {onsearch}
+        />
+    }
+}
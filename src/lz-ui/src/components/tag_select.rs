@@ -7,7 +7,7 @@ use yew::prelude::*;
 use yew_hooks::prelude::use_async;
 use yew_hooks::{use_click_away, use_event_with_window};
 
-use crate::dispatch_callback;
+use crate::{client, dispatch_callback};
 
 const ID_SEARCH_ELEMENT: &str = "search-input";
 
@@ -36,7 +36,7 @@ enum TagSelectAction {
 }
 
 #[derive(PartialEq, Default, Slice, Clone, Debug)]
-pub(self) struct TagSelectState {
+struct TagSelectState {
     /// The verbatim text field value
     input_value: String,
 
@@ -79,7 +79,7 @@ impl Reducible for TagSelectState {
                     _ => None,
                 };
                 Self {
-                    autocomplete_open: possibilities.len() > 0,
+                    autocomplete_open: !possibilities.is_empty(),
                     hint,
                     possibilities: possibilities.clone(),
                     ..(*self).clone()
@@ -125,7 +125,7 @@ impl TagSelectState {
             + after
                 .chars()
                 .position(char::is_whitespace)
-                .unwrap_or_else(|| after.len());
+                .unwrap_or(after.len());
         let before_chars = before.chars().collect::<Vec<char>>();
         let start_position = if let Some(whitespace_before) =
             before_chars.into_iter().rposition(char::is_whitespace)
@@ -178,11 +178,30 @@ impl Selector for SelectedTags {
 #[derive(Properties, Clone, PartialEq, Default)]
 pub struct TagSelectProps {
     pub on_change: Callback<Vec<String>>,
+
+    /// The tags to pre-fill the input with.
+    #[prop_or_default]
+    pub value: Vec<String>,
 }
 
 #[function_component(TagSelect)]
-pub fn tag_auto_complete(TagSelectProps { on_change }: &TagSelectProps) -> Html {
+pub fn tag_auto_complete(TagSelectProps { on_change, value }: &TagSelectProps) -> Html {
     let state = use_slice::<TagSelectState>();
+    // The state is shared between all tag inputs, so start over from
+    // the given tags:
+    use_effect_with(value.clone(), {
+        let state = state.clone();
+        move |value| {
+            let mut input_value = value.join(" ");
+            if !input_value.is_empty() {
+                input_value.push(' ');
+            }
+            state.dispatch(TagSelectAction::TextChange {
+                position: input_value.len(),
+                input_value,
+            });
+        }
+    });
     let tags = use_selector_value::<SelectedTags>();
     // the user provided value
     // clear the value
@@ -192,14 +211,12 @@ pub fn tag_auto_complete(TagSelectProps { on_change }: &TagSelectProps) -> Html
         let state = state.clone();
         async move {
             let incomplete = state.incomplete();
-            let loc = web_sys::window().unwrap().location();
-            let base_url = format!(
-                "{}//{}/api/v1",
-                loc.protocol().unwrap(),
-                loc.host().unwrap()
-            );
-            let client = lz_openapi::Client::new(&base_url);
-            match client.complete_tag().tag_fragment(incomplete).send().await {
+            match client()
+                .complete_tag()
+                .tag_fragment(incomplete)
+                .send()
+                .await
+            {
                 Ok(results) => {
                     state.dispatch(TagSelectAction::FillAutocomplete {
                         possibilities: (*results)
@@ -232,13 +249,12 @@ pub fn tag_auto_complete(TagSelectProps { on_change }: &TagSelectProps) -> Html
         move |input_value: String, ()| {
             let position = input_ref
                 .cast::<HtmlInputElement>()
-                .map(|elt| {
+                .and_then(|elt| {
                     elt.selection_start()
                         .unwrap_or(None)
                         .map(|sel| sel as usize)
                 })
-                .flatten()
-                .unwrap_or_else(|| input_value.len());
+                .unwrap_or(input_value.len());
             state.dispatch(TagSelectAction::TextChange {
                 input_value,
                 position,
@@ -270,17 +286,15 @@ pub fn tag_auto_complete(TagSelectProps { on_change }: &TagSelectProps) -> Html
             let in_input = input_ref.get().as_deref() == e.target().as_ref();
 
             match e.key().as_str() {
-                "Tab" | "ArrowRight" if in_input => {
-                    // if we have a hint (single remaining value)
-                    if state.hint.is_some() {
-                        if state.autocomplete_open {
-                            e.prevent_default();
-                        }
-                        // set the value
-                        state.dispatch(TagSelectAction::AcceptHint);
-                        // focus back on the input
-                        input_ref.focus();
+                // if we have a hint (single remaining value)
+                "Tab" | "ArrowRight" if in_input && state.hint.is_some() => {
+                    if state.autocomplete_open {
+                        e.prevent_default();
                     }
+                    // set the value
+                    state.dispatch(TagSelectAction::AcceptHint);
+                    // focus back on the input
+                    input_ref.focus();
                 }
                 "ArrowUp" | "ArrowDown" if in_input => {
                     // start the menu navigation, the menu component will pick it up from here
@@ -0,0 +1,323 @@
+use std::rc::Rc;
+
+use async_trait::async_trait;
+use bounce::prelude::*;
+use bounce::query::{
+    use_mutation, use_query_value, Mutation, MutationResult, MutationState, Query, QueryResult,
+};
+use lz_openapi::types::{
+    ListTagsResponseItem, TagId, TagMergeRequest, TagRenameRequest, TagTreeNode,
+};
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::route::Route;
+use crate::{client, GoddamnIt};
+
+use super::edit_form::{api_error, mutation_error};
+use super::{BookmarksChanged, BookmarksVersion, SearchQuery};
+
+/// The user's tags, with how many bookmarks have each.
+#[derive(PartialEq, Debug, Clone)]
+struct TagCounts(Vec<ListTagsResponseItem>);
+
+#[async_trait(?Send)]
+impl Query for TagCounts {
+    type Input = BookmarksVersion;
+    type Error = GoddamnIt;
+
+    async fn query(_states: &BounceStates, _input: Rc<BookmarksVersion>) -> QueryResult<Self> {
+        let response = client().list_tags().send().await.map_err(GoddamnIt::new)?;
+        Ok(TagCounts(response.into_inner()).into())
+    }
+}
+
+/// The hierarchy of the user's tags.
+#[derive(PartialEq, Debug, Clone)]
+struct TagTree(Vec<TagTreeNode>);
+
+#[async_trait(?Send)]
+impl Query for TagTree {
+    type Input = BookmarksVersion;
+    type Error = GoddamnIt;
+
+    async fn query(_states: &BounceStates, _input: Rc<BookmarksVersion>) -> QueryResult<Self> {
+        let response = client().tag_tree().send().await.map_err(GoddamnIt::new)?;
+        Ok(TagTree(response.into_inner()).into())
+    }
+}
+
+/// Renames a tag.
+#[derive(PartialEq, Clone, Debug)]
+struct RenameTagMutation;
+
+#[async_trait(?Send)]
+impl Mutation for RenameTagMutation {
+    type Input = (TagId, String);
+    type Error = GoddamnIt;
+
+    async fn run(_states: &BounceStates, input: Rc<(TagId, String)>) -> MutationResult<Self> {
+        let (id, name) = &*input;
+        client()
+            .rename_tag()
+            .id(*id)
+            .body(TagRenameRequest { name: name.clone() })
+            .send()
+            .await
+            .map_err(api_error)?;
+        Ok(Rc::new(RenameTagMutation))
+    }
+}
+
+/// Replaces a tag with another one.
+#[derive(PartialEq, Clone, Debug)]
+struct MergeTagsMutation;
+
+#[async_trait(?Send)]
+impl Mutation for MergeTagsMutation {
+    type Input = (TagId, TagId);
+    type Error = GoddamnIt;
+
+    async fn run(_states: &BounceStates, input: Rc<(TagId, TagId)>) -> MutationResult<Self> {
+        let (from, into) = &*input;
+        client()
+            .merge_tags()
+            .id(*from)
+            .body(TagMergeRequest { into: *into })
+            .send()
+            .await
+            .map_err(api_error)?;
+        Ok(Rc::new(MergeTagsMutation))
+    }
+}
+
+/// Removes a tag from all bookmarks.
+#[derive(PartialEq, Clone, Debug)]
+struct DeleteTagMutation;
+
+#[async_trait(?Send)]
+impl Mutation for DeleteTagMutation {
+    type Input = TagId;
+    type Error = GoddamnIt;
+
+    async fn run(_states: &BounceStates, input: Rc<TagId>) -> MutationResult<Self> {
+        client()
+            .delete_tag()
+            .id(*input)
+            .send()
+            .await
+            .map_err(api_error)?;
+        Ok(Rc::new(DeleteTagMutation))
+    }
+}
+
+/// Run a mutation, and on success notify the bookmark lists (and
+/// with them, the tag list).
+fn run_and_refresh<M: Mutation + 'static>(
+    mutation: &bounce::query::UseMutationHandle<M>,
+    input: M::Input,
+    changed: &Rc<dyn Fn(BookmarksChanged)>,
+) {
+    let mutation = mutation.clone();
+    let changed = changed.clone();
+    spawn_local(async move {
+        if mutation.run(input).await.is_ok() {
+            changed(BookmarksChanged);
+        }
+    });
+}
+
+/// The tag management page.
+#[function_component(Tags)]
+pub fn tags() -> Html {
+    let version = use_atom_value::<BookmarksVersion>();
+    let tags = use_query_value::<TagCounts>(Rc::new(*version));
+    match tags.result() {
+        None => html! { <Spinner size={SpinnerSize::Lg} /> },
+        Some(Ok(tags)) => {
+            let TagCounts(tags) = &**tags;
+            let all = Rc::new(tags.clone());
+            let rows = tags
+                .iter()
+                .map(|tag| {
+                    html! {
+                        <TagRow key={tag.tag.id.to_string()} tag={tag.clone()} all={all.clone()} />
+                    }
+                })
+                .collect::<Html>();
+            html! {
+                <section>
+                    <Title level={Level::H1}>{ "Tags" }</Title>
+                    <TagTreeView />
+                    <table class="w-full">
+                        <thead>
+                            <tr>
+                                <th class="text-left">{ "Tag" }</th>
+                                <th class="text-left">{ "Bookmarks" }</th>
+                                <th />
+                            </tr>
+                        </thead>
+                        <tbody>{ rows }</tbody>
+                    </table>
+                </section>
+            }
+        }
+        Some(Err(e)) => html! { <h1>{ e.to_string() }</h1> },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct TagRowProps {
+    tag: ListTagsResponseItem,
+    /// All the user's tags, to find the one to merge into.
+    all: Rc<Vec<ListTagsResponseItem>>,
+}
+
+#[function_component(TagRow)]
+fn tag_row(TagRowProps { tag, all }: &TagRowProps) -> Html {
+    let name = use_state(String::new);
+    let rename = use_mutation::<RenameTagMutation>();
+    let merge = use_mutation::<MergeTagsMutation>();
+    let delete = use_mutation::<DeleteTagMutation>();
+    let changed = use_notion_applier::<BookmarksChanged>();
+    let id = tag.tag.id;
+    let merge_into = all
+        .iter()
+        .find(|other| other.tag.id != id && other.tag.name == *name)
+        .map(|other| other.tag.id);
+
+    let onrename = {
+        let (name, rename, changed) = (name.clone(), rename.clone(), changed.clone());
+        Callback::from(move |_| run_and_refresh(&rename, (id, (*name).clone()), &changed))
+    };
+    let onmerge = {
+        let (merge, changed) = (merge.clone(), changed.clone());
+        Callback::from(move |_| {
+            if let Some(into) = merge_into {
+                run_and_refresh(&merge, (id, into), &changed)
+            }
+        })
+    };
+    let ondelete = {
+        let (delete, changed) = (delete.clone(), changed.clone());
+        Callback::from(move |_| run_and_refresh(&delete, id, &changed))
+    };
+    let loading = rename.state() == MutationState::Loading
+        || merge.state() == MutationState::Loading
+        || delete.state() == MutationState::Loading;
+
+    html! {
+        <tr>
+            <td>
+                <Link<Route> to={Route::SearchTag{tag: tag.tag.name.clone()}}>
+                    <Chip text={tag.tag.name.clone()} />
+                </Link<Route>>
+            </td>
+            <td>{ tag.count }</td>
+            <td>
+                { mutation_error(&rename) }
+                { mutation_error(&merge) }
+                { mutation_error(&delete) }
+                <InputGroup>
+                    <InputGroupItem fill=true>
+                        <TextInput
+                            placeholder="New name, or tag to merge into"
+                            onchange={let name = name.clone(); move |v| name.set(v)}
+                            value={(*name).clone()}
+                        />
+                    </InputGroupItem>
+                    <InputGroupItem>
+                        <Button
+                            label="Rename"
+                            variant={ButtonVariant::Control}
+                            disabled={name.is_empty() || loading}
+                            onclick={onrename}
+                        />
+                    </InputGroupItem>
+                    <InputGroupItem>
+                        <Button
+                            label="Merge into"
+                            variant={ButtonVariant::Control}
+                            disabled={merge_into.is_none() || loading}
+                            onclick={onmerge}
+                        />
+                    </InputGroupItem>
+                    <InputGroupItem>
+                        <Button
+                            label="Remove"
+                            variant={ButtonVariant::Danger}
+                            disabled={loading}
+                            onclick={ondelete}
+                        />
+                    </InputGroupItem>
+                </InputGroup>
+            </td>
+        </tr>
+    }
+}
+
+/// The user's tags as a tree of collapsible levels, each linking to
+/// the bookmarks with tags at or below that level.
+#[function_component(TagTreeView)]
+fn tag_tree_view() -> Html {
+    let version = use_atom_value::<BookmarksVersion>();
+    let tree = use_query_value::<TagTree>(Rc::new(*version));
+    match tree.result() {
+        None => html! { <Spinner size={SpinnerSize::Md} /> },
+        Some(Ok(tree)) => {
+            let TagTree(nodes) = &**tree;
+            html! {
+                <ul class="mb-4">
+                    { for nodes.iter().map(|node| html! {
+                        <TagTreeBranch key={node.path.clone()} node={node.clone()} />
+                    }) }
+                </ul>
+            }
+        }
+        Some(Err(e)) => html! { <p>{ e.to_string() }</p> },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct TagTreeBranchProps {
+    node: TagTreeNode,
+}
+
+#[function_component(TagTreeBranch)]
+fn tag_tree_branch(TagTreeBranchProps { node }: &TagTreeBranchProps) -> Html {
+    let expanded = use_state(|| false);
+    let toggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_| expanded.set(!*expanded))
+    };
+    let q = format!("tag:{}/*", node.path);
+    html! {
+        <li class="ml-4">
+            if node.children.is_empty() {
+                <span class="inline-block w-6" />
+            } else {
+                <Button
+                    variant={ButtonVariant::Plain}
+                    icon={if *expanded { Icon::AngleDown } else { Icon::AngleRight }}
+                    aria_label={if *expanded { "Collapse" } else { "Expand" }}
+                    onclick={toggle}
+                />
+            }
+            <Link<Route, SearchQuery> to={Route::Search} query={Some(SearchQuery { q })}>
+                { &node.name }
+            </Link<Route, SearchQuery>>
+            if node.count > 0 {
+                { format!(" ({})", node.count) }
+            }
+            if *expanded {
+                <ul>
+                    { for node.children.iter().map(|child| html! {
+                        <TagTreeBranch key={child.path.clone()} node={child.clone()} />
+                    }) }
+                </ul>
+            }
+        </li>
+    }
+}
@@ -16,6 +16,17 @@ impl GoddamnIt {
     }
 }
 
+/// A client for the lz API of the server that the UI was loaded from.
+pub(crate) fn client() -> lz_openapi::Client {
+    let loc = web_sys::window().unwrap().location();
+    let base_url = format!(
+        "{}//{}/api/v1",
+        loc.protocol().unwrap(),
+        loc.host().unwrap()
+    );
+    lz_openapi::Client::new(&base_url)
+}
+
 /// Dispatch a [`Slice`] reducer and return a callback that applies
 /// the reducer.
 pub(crate) fn dispatch_callback<
@@ -2,7 +2,6 @@ use bounce::BounceRoot;
 use tracing_subscriber::fmt::format::Pretty;
 use tracing_subscriber::prelude::*;
 use tracing_web::{performance_layer, MakeWebConsoleWriter};
-use wasm_bindgen::prelude::*;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
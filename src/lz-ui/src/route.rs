@@ -12,6 +12,12 @@ pub enum Route {
     #[at("/tag/:tag")]
     SearchTag { tag: String },
 
+    #[at("/search")]
+    Search,
+
+    #[at("/tags")]
+    Tags,
+
     #[not_found]
     #[at("/404")]
     NotFound,
@@ -23,7 +29,19 @@ pub fn switch(routes: Route) -> Html {
         Route::SearchTag { tag } => {
             html! { <Bookmarks cursor={None} query={vec![BookmarkSearch::Tag(tag.into())]} /> }
         }
+        Route::Search => html! { <SearchResults /> },
+        Route::Tags => html! { <Tags /> },
         Route::NotFound => html! { <h1>{ "404, not found" }</h1> },
     };
     html! { <Layout>{ main }</Layout> }
 }
+
+/// The bookmarks matching the search string in the `q` query parameter.
+#[function_component(SearchResults)]
+fn search_results() -> Html {
+    let q = use_location()
+        .and_then(|loc| loc.query::<SearchQuery>().ok())
+        .map(|SearchQuery { q }| q)
+        .filter(|q| !q.trim().is_empty());
+    html! { <Bookmarks cursor={None} query={vec![]} {q} /> }
+}
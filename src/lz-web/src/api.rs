@@ -7,35 +7,42 @@ mod searching;
 
 use std::sync::Arc;
 
-use axum::extract::Query;
-use axum::routing::{get, post};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::{delete, get, post};
 use axum::{debug_handler, Json, Router};
 use lz_db::{
-    AssociatedLink, BookmarkId, BookmarkSearch, BookmarkSearchDateParams,
-    BookmarkSearchDatetimeField, BookmarkSearchDatetimeOrientation, DateInput, ExistingBookmark,
-    ExistingTag, NewBookmark, NoId, ReadWrite, TagId, TagName, UserId,
+    Archive, AssociatedLink, BookmarkCursor, BookmarkId, BookmarkOrder, BookmarkSearch,
+    BookmarkSearchDateParams, BookmarkSearchDatetimeField, BookmarkSearchDatetimeOrientation,
+    DateInput, ExistingBookmark, ExistingTag, FullTextQuery, IdType as _, LinkStatus, NewBookmark,
+    NoId, ReadWrite, SiteName, StoredUrlId, TagAlias, TagCount, TagId, TagName, TagTreeNode,
+    Timezone, UserId,
 };
 use searching::TagQuery;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use tower_http::cors::CorsLayer;
 use url::Url;
 use utoipa::{IntoParams, OpenApi, ToResponse, ToSchema};
 
-use crate::db::queries::{list_bookmarks, AnnotatedBookmark, ListResult, Pagination};
+use crate::archive::ArchiveError;
+use crate::db::queries::{
+    get_annotated_bookmark, list_bookmarks, AnnotatedBookmark, ListResult, Pagination,
+};
 use crate::db::{DbTransaction, GlobalWebAppState};
-use crate::http::{lookup_page_from_web, Metadata};
+use crate::http::Metadata;
 
 mod error;
-use error::ApiError;
+use error::{ApiError, ErrorResponse};
 
 #[derive(OpenApi)]
 #[openapi(
-    tags((name = "Bookmarks", description = "Managing one's bookmarks")),
-    paths(list_bookmarks_matching, create_bookmark, complete_tag, fetch_page_metadata),
+    tags((name = "Bookmarks", description = "Managing one's bookmarks"), (name = "Tags", description = "Managing one's tags"), (name = "Settings", description = "Managing one's settings")),
+    paths(get_user_settings, update_user_settings, list_bookmarks_matching, create_bookmark, get_bookmark, replace_bookmark, update_bookmark, delete_bookmark, merge_bookmarks, get_bookmark_archive, get_archived_page, complete_tag, list_tags, tag_tree, rename_tag, merge_tags, delete_tag, list_tag_aliases, add_tag_alias, remove_tag_alias, fetch_page_metadata),
     security(),
     servers((url = "/api/v1/")),
     components(
-        schemas(ListBookmarkResult, AnnotatedBookmark, AssociatedLink, UserId, BookmarkId, ExistingBookmark, ExistingTag, Pagination, TagName, TagQuery, ListRequest, BookmarkSearch, BookmarkSearchDateParams, DateInput, BookmarkSearchDatetimeField, BookmarkSearchDatetimeOrientation, TagId, NoId, BookmarkCreateRequest, Metadata),
+        schemas(ListBookmarkResult, AnnotatedBookmark, AssociatedLink, UserId, BookmarkId, ExistingBookmark, ExistingTag, TagCount, TagTreeNode, TagRenameRequest, TagMergeRequest, TagAlias, TagAliasRequest, UserSettings, Timezone, Pagination, BookmarkCursor, BookmarkOrder, TagName, TagQuery, ListRequest, BookmarkSearch, BookmarkSearchDateParams, DateInput, BookmarkSearchDatetimeField, BookmarkSearchDatetimeOrientation, FullTextQuery, SiteName, LinkStatus, TagId, NoId, BookmarkCreateRequest, BookmarkUpdateRequest, BookmarkMergeRequest, Metadata, Archive, StoredUrlId, ErrorResponse),
         responses(ListBookmarkResult, AnnotatedBookmark, AssociatedLink, UserId, ExistingBookmark, ExistingTag)
     )
 )]
@@ -45,8 +52,30 @@ pub fn router() -> Router<Arc<GlobalWebAppState>> {
     let router = Router::new()
         .route("/bookmarks", post(list_bookmarks_matching))
         .route("/bookmark/create", post(create_bookmark))
+        .route(
+            "/bookmark/:id",
+            get(get_bookmark)
+                .put(replace_bookmark)
+                .patch(update_bookmark)
+                .delete(delete_bookmark),
+        )
+        .route("/bookmark/:id/merge", post(merge_bookmarks))
+        .route("/bookmark/:id/archive", get(get_bookmark_archive))
+        .route("/bookmark/:id/archive/page", get(get_archived_page))
         .route("/http/fetch_metadata", get(fetch_page_metadata))
         .route("/tag/complete", get(complete_tag))
+        .route("/tags", get(list_tags))
+        .route("/tags/tree", get(tag_tree))
+        .route("/tag/:id", delete(delete_tag))
+        .route("/tag/:id/rename", post(rename_tag))
+        .route("/tag/:id/merge", post(merge_tags))
+        .route("/tag/:id/aliases", post(add_tag_alias))
+        .route("/tag/aliases", get(list_tag_aliases))
+        .route("/tag/aliases/:name", delete(remove_tag_alias))
+        .route(
+            "/user/settings",
+            get(get_user_settings).put(update_user_settings),
+        )
         .layer(CorsLayer::permissive());
     observability::add_layers(router)
 }
@@ -59,7 +88,7 @@ pub fn router() -> Router<Arc<GlobalWebAppState>> {
 #[derive(Serialize, Debug, ToSchema, ToResponse)]
 pub struct ListBookmarkResult {
     bookmarks: Vec<AnnotatedBookmark>,
-    next_cursor: Option<BookmarkId>,
+    next_cursor: Option<BookmarkCursor>,
 }
 
 /// A bookmark search query request
@@ -67,30 +96,70 @@ pub struct ListBookmarkResult {
 pub struct ListRequest {
     /// A search of criteria, restricting the set of bookmarks that qualify.
     ///
-    /// All criteria are merged using logical AND / set intersection.
+    /// All criteria are merged using logical AND / set intersection;
+    /// use the `any`, `all` and `not` criteria to express other
+    /// combinations.
     #[serde(default)]
     query: Vec<BookmarkSearch>,
 
+    /// The order to list the bookmarks in; newest first by default.
+    #[serde(default)]
+    order: BookmarkOrder,
+
+    /// The timezone that dates in the query count days in, unless
+    /// they name their own; defaults to the user's timezone setting.
+    #[serde(default)]
+    timezone: Option<Timezone>,
+
     #[serde(flatten)]
     pagination: Option<Pagination>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+struct ListQuery {
+    q: Option<String>,
+}
+
 /// List the user's bookmarks matching a query, newest to oldest
+/// unless another order is requested
+///
+/// The query can be given as structured criteria in the request body,
+/// or as a search string in the `q` parameter (like `tag:rust -tag:old
+/// "exact phrase" site:github.com`), or both.
+///
+/// If the query contains a full-text search, each bookmark comes with
+/// a snippet of the text that matched it.
 #[debug_handler(state = Arc<GlobalWebAppState>)]
 #[utoipa::path(post,
     path = "/bookmarks",
+    params(("q" = Option<String>, Query, description = "Search string whose criteria get added to the query")),
     tag = "Bookmarks",
     responses(
         (status = 200, body = inline(ListBookmarkResult), description = "Lists bookmarks matching the tag"),
+        (status = 400, body = ErrorResponse, description = "The search string or the cursor could not be parsed"),
     ),
 )]
 #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
 async fn list_bookmarks_matching(
     mut txn: DbTransaction,
-    Json(ListRequest { query, pagination }): Json<ListRequest>,
+    Query(ListQuery { q }): Query<ListQuery>,
+    Json(ListRequest {
+        mut query,
+        order,
+        timezone,
+        pagination,
+    }): Json<ListRequest>,
 ) -> Result<Json<ListBookmarkResult>, ApiError> {
+    if let Some(q) = q {
+        query.extend(lz_db::parse_query(&q)?);
+    }
+    if let Some(tz) = timezone.or(txn.user().timezone) {
+        for criterion in &mut query {
+            criterion.default_timezone(tz);
+        }
+    }
     let ListResult { batch, next_cursor } =
-        list_bookmarks(&mut txn, &query, &pagination.unwrap_or_default()).await?;
+        list_bookmarks(&mut txn, &query, order, &pagination.unwrap_or_default()).await?;
     Ok(Json(ListBookmarkResult {
         bookmarks: batch,
         next_cursor,
@@ -112,6 +181,9 @@ pub struct BookmarkCreateRequest {
 }
 
 /// Create a new bookmark
+///
+/// If the server keeps local copies of bookmarked pages, the new
+/// bookmark's page gets archived in the background.
 #[debug_handler(state = Arc<GlobalWebAppState>)]
 #[utoipa::path(post,
     path = "/bookmark/create",
@@ -120,8 +192,9 @@ pub struct BookmarkCreateRequest {
         (status = 200, body = inline(AnnotatedBookmark), description = "Creates a new bookmark"),
     ),
 )]
-#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(state, txn))]
 async fn create_bookmark(
+    State(state): State<Arc<GlobalWebAppState>>,
     mut txn: DbTransaction<ReadWrite>,
     Json(BookmarkCreateRequest {
         bookmark,
@@ -137,15 +210,366 @@ async fn create_bookmark(
             .await?;
     }
     txn.set_bookmark_tags(bookmark.id, &tags).await?;
+    let url_id = txn.ensure_url(&bookmark.url).await?;
+    let user = txn.user().name.clone();
 
     txn.commit().await?;
+    state.archive_new_bookmark(&user, url_id, &bookmark.url);
     Ok(Json(AnnotatedBookmark {
         bookmark,
         tags,
         associations,
+        snippet: None,
     }))
 }
 
+/// Retrieve a bookmark
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(get,
+    path = "/bookmark/{id}",
+    params(("id" = BookmarkId, Path, description = "ID of the bookmark")),
+    tag = "Bookmarks",
+    responses(
+        (status = 200, body = inline(AnnotatedBookmark), description = "Returns the bookmark"),
+        (status = 404, body = ErrorResponse, description = "The user has no bookmark with that ID"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn get_bookmark(
+    mut txn: DbTransaction,
+    Path(id): Path<BookmarkId>,
+) -> Result<Json<AnnotatedBookmark>, ApiError> {
+    Ok(Json(
+        get_annotated_bookmark(&mut txn, id)
+            .await
+            .map_err(ApiError::record_not_found)?,
+    ))
+}
+
+/// Replace a bookmark's data, tags and associations
+///
+/// The request body is the same as for creating a bookmark. The
+/// bookmark's `created_at` and `accessed_at` timestamps and its
+/// import properties are kept as they were.
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(put,
+    path = "/bookmark/{id}",
+    params(("id" = BookmarkId, Path, description = "ID of the bookmark")),
+    tag = "Bookmarks",
+    responses(
+        (status = 200, body = inline(AnnotatedBookmark), description = "Returns the updated bookmark"),
+        (status = 404, body = ErrorResponse, description = "The user has no bookmark with that ID"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn replace_bookmark(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<BookmarkId>,
+    Json(BookmarkCreateRequest {
+        bookmark,
+        tag_names,
+        associations,
+    }): Json<BookmarkCreateRequest>,
+) -> Result<Json<AnnotatedBookmark>, ApiError> {
+    let mut existing = txn
+        .get_bookmark_by_id(id.id())
+        .await
+        .map_err(ApiError::record_not_found)?;
+    existing.url = bookmark.url;
+    existing.title = bookmark.title;
+    existing.description = bookmark.description;
+    existing.website_title = bookmark.website_title;
+    existing.website_description = bookmark.website_description;
+    existing.notes = bookmark.notes;
+    existing.unread = bookmark.unread;
+    existing.shared = bookmark.shared;
+    txn.update_bookmark(&existing).await?;
+    replace_tags(&mut txn, id, &tag_names).await?;
+    replace_associations(&mut txn, id, &associations).await?;
+
+    let bookmark = get_annotated_bookmark(&mut txn, id).await?;
+    txn.commit().await?;
+    Ok(Json(bookmark))
+}
+
+/// A partial update to a bookmark.
+///
+/// Only the fields that are present in the request get changed;
+/// setting an optional text field to `null` clears it.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, ToSchema, PartialEq, Eq)]
+pub struct BookmarkUpdateRequest {
+    /// The URL that the bookmark points to
+    pub url: Option<Url>,
+
+    /// The bookmark's title
+    pub title: Option<String>,
+
+    /// The bookmark's description
+    #[serde(
+        default,
+        deserialize_with = "present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<String>, nullable)]
+    pub description: Option<Option<String>>,
+
+    /// The title of the website that the bookmark points to
+    #[serde(
+        default,
+        deserialize_with = "present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<String>, nullable)]
+    pub website_title: Option<Option<String>>,
+
+    /// The description of the website that the bookmark points to
+    #[serde(
+        default,
+        deserialize_with = "present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<String>, nullable)]
+    pub website_description: Option<Option<String>>,
+
+    /// The user's notes on the bookmark
+    #[serde(
+        default,
+        deserialize_with = "present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<String>, nullable)]
+    pub notes: Option<Option<String>>,
+
+    /// Whether the bookmark is unread
+    pub unread: Option<bool>,
+
+    /// Whether the bookmark is shared
+    pub shared: Option<bool>,
+
+    /// Tags to replace the bookmark's tags with
+    pub tag_names: Option<Vec<String>>,
+
+    /// Links to replace the bookmark's associated links with
+    pub associations: Option<Vec<AssociatedLink>>,
+}
+
+/// Distinguishes a field that is present but `null` from one that is absent.
+fn present<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Update some of a bookmark's fields
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(patch,
+    path = "/bookmark/{id}",
+    params(("id" = BookmarkId, Path, description = "ID of the bookmark")),
+    tag = "Bookmarks",
+    responses(
+        (status = 200, body = inline(AnnotatedBookmark), description = "Returns the updated bookmark"),
+        (status = 404, body = ErrorResponse, description = "The user has no bookmark with that ID"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn update_bookmark(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<BookmarkId>,
+    Json(update): Json<BookmarkUpdateRequest>,
+) -> Result<Json<AnnotatedBookmark>, ApiError> {
+    let mut existing = txn
+        .get_bookmark_by_id(id.id())
+        .await
+        .map_err(ApiError::record_not_found)?;
+    if let Some(url) = update.url {
+        existing.url = url;
+    }
+    if let Some(title) = update.title {
+        existing.title = title;
+    }
+    if let Some(description) = update.description {
+        existing.description = description;
+    }
+    if let Some(website_title) = update.website_title {
+        existing.website_title = website_title;
+    }
+    if let Some(website_description) = update.website_description {
+        existing.website_description = website_description;
+    }
+    if let Some(notes) = update.notes {
+        existing.notes = notes;
+    }
+    if let Some(unread) = update.unread {
+        existing.unread = unread;
+    }
+    if let Some(shared) = update.shared {
+        existing.shared = shared;
+    }
+    txn.update_bookmark(&existing).await?;
+    if let Some(tag_names) = update.tag_names {
+        replace_tags(&mut txn, id, &tag_names).await?;
+    }
+    if let Some(associations) = update.associations {
+        replace_associations(&mut txn, id, &associations).await?;
+    }
+
+    let bookmark = get_annotated_bookmark(&mut txn, id).await?;
+    txn.commit().await?;
+    Ok(Json(bookmark))
+}
+
+/// Delete a bookmark
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(delete,
+    path = "/bookmark/{id}",
+    params(("id" = BookmarkId, Path, description = "ID of the bookmark")),
+    tag = "Bookmarks",
+    responses(
+        (status = 204, description = "The bookmark was deleted"),
+        (status = 404, body = ErrorResponse, description = "The user has no bookmark with that ID"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn delete_bookmark(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<BookmarkId>,
+) -> Result<StatusCode, ApiError> {
+    if !txn.delete_bookmark(id).await? {
+        return Err(ApiError::NotFound);
+    }
+    txn.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// A request to merge another bookmark into a bookmark.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct BookmarkMergeRequest {
+    /// The bookmark to merge into the other one; it gets deleted.
+    pub absorb: BookmarkId,
+}
+
+/// Merge another bookmark into a bookmark
+///
+/// The bookmark gets the other bookmark's tags, associated links and
+/// notes, and the earlier of their creation times. The other
+/// bookmark is deleted.
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(post,
+    path = "/bookmark/{id}/merge",
+    params(("id" = BookmarkId, Path, description = "ID of the bookmark to keep")),
+    tag = "Bookmarks",
+    responses(
+        (status = 200, body = inline(AnnotatedBookmark), description = "Returns the merged bookmark"),
+        (status = 400, body = ErrorResponse, description = "The bookmark can't be merged into itself"),
+        (status = 404, body = ErrorResponse, description = "The user has no bookmark with one of the IDs"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn merge_bookmarks(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<BookmarkId>,
+    Json(request): Json<BookmarkMergeRequest>,
+) -> Result<Json<AnnotatedBookmark>, ApiError> {
+    txn.merge_bookmarks(id, request.absorb)
+        .await
+        .map_err(ApiError::record_not_found)?;
+    let bookmark = get_annotated_bookmark(&mut txn, id).await?;
+    txn.commit().await?;
+    Ok(Json(bookmark))
+}
+
+/// Retrieve the local archive of a bookmark's page
+///
+/// The archive contains the page's readable text; the archived page
+/// itself is available separately.
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(get,
+    path = "/bookmark/{id}/archive",
+    params(("id" = BookmarkId, Path, description = "ID of the bookmark")),
+    tag = "Bookmarks",
+    responses(
+        (status = 200, body = inline(Archive), description = "Returns the archive"),
+        (status = 404, body = ErrorResponse, description = "The user has no bookmark with that ID, or its page wasn't archived"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn get_bookmark_archive(
+    mut txn: DbTransaction,
+    Path(id): Path<BookmarkId>,
+) -> Result<Json<Archive>, ApiError> {
+    Ok(Json(
+        txn.archive_for_bookmark(id)
+            .await?
+            .ok_or(ApiError::NotFound)?,
+    ))
+}
+
+/// Retrieve the locally archived copy of a bookmark's page
+///
+/// The page's scripts are not allowed to run.
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(get,
+    path = "/bookmark/{id}/archive/page",
+    params(("id" = BookmarkId, Path, description = "ID of the bookmark")),
+    tag = "Bookmarks",
+    responses(
+        (status = 200, content_type = "text/html", body = String, description = "Returns the archived HTML"),
+        (status = 404, body = ErrorResponse, description = "The user has no bookmark with that ID, or its page wasn't archived"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(state, txn))]
+async fn get_archived_page(
+    State(state): State<Arc<GlobalWebAppState>>,
+    mut txn: DbTransaction,
+    Path(id): Path<BookmarkId>,
+) -> Result<impl IntoResponse, ApiError> {
+    let archive = txn
+        .archive_for_bookmark(id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    let html = state
+        .archiver()
+        .store()
+        .get(&archive.html_sha256)
+        .await
+        .map_err(ArchiveError::Storage)?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/html; charset=utf-8"),
+            // The page is someone else's content, served from our
+            // origin; it must not get to run scripts here:
+            (header::CONTENT_SECURITY_POLICY, "sandbox"),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff"),
+        ],
+        html,
+    ))
+}
+
+async fn replace_tags(
+    txn: &mut DbTransaction<ReadWrite>,
+    id: BookmarkId,
+    tag_names: &[String],
+) -> Result<(), sqlx::Error> {
+    let tags = txn.ensure_tags(tag_names).await?;
+    txn.set_bookmark_tags(id, tags).await
+}
+
+async fn replace_associations(
+    txn: &mut DbTransaction<ReadWrite>,
+    id: BookmarkId,
+    associations: &[AssociatedLink],
+) -> Result<(), sqlx::Error> {
+    txn.clear_bookmark_associations(&id).await?;
+    for a in associations {
+        let url_id = txn.ensure_url(&a.link).await?;
+        txn.associate_bookmark_link(&id, &url_id, a.context.as_deref())
+            .await?;
+    }
+    Ok(())
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 struct CompleteQuery {
     tag_fragment: String,
@@ -157,7 +581,7 @@ struct CompleteQuery {
     params(("tag_fragment" = String, Query, description = "Substring of the tag name that must match")),
     tag = "Tags",
     responses(
-        (status = 200, body = inline(Vec<ExistingTag>), description = "Return tags for autocompletion"),
+        (status = 200, body = Vec<ExistingTag>, description = "Return tags for autocompletion"),
     ),
 )]
 #[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
@@ -168,6 +592,252 @@ async fn complete_tag(
     Ok(Json(txn.tags_matching(&tag_fragment).await?))
 }
 
+/// List the user's tags
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(get,
+    path = "/tags",
+    tag = "Tags",
+    responses(
+        (status = 200, body = inline(Vec<TagCount>), description = "Returns the tags, by name, with how many bookmarks have them"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn list_tags(mut txn: DbTransaction) -> Result<Json<Vec<TagCount>>, ApiError> {
+    Ok(Json(txn.list_tags_with_counts().await?))
+}
+
+/// List the user's tags as a hierarchy
+///
+/// Tags with `/` in their names, like `lang/rust/async`, are listed
+/// below the levels of the hierarchy that their names start with.
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(get,
+    path = "/tags/tree",
+    tag = "Tags",
+    responses(
+        (status = 200, body = Vec<TagTreeNode>, description = "Returns the top levels of the hierarchy, by name"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn tag_tree(mut txn: DbTransaction) -> Result<Json<Vec<TagTreeNode>>, ApiError> {
+    Ok(Json(txn.tag_tree().await?))
+}
+
+/// A request to rename a tag.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct TagRenameRequest {
+    /// The new name of the tag.
+    pub name: String,
+}
+
+/// Rename one of the user's tags
+///
+/// If the user has a tag with an equivalent name, the bookmarks get that
+/// tag instead.
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(post,
+    path = "/tag/{id}/rename",
+    params(("id" = TagId, Path, description = "ID of the tag")),
+    tag = "Tags",
+    responses(
+        (status = 200, body = ExistingTag, description = "Returns the tag that the bookmarks have now"),
+        (status = 400, body = ErrorResponse, description = "The name is not a valid tag name"),
+        (status = 404, body = ErrorResponse, description = "The user has no such tag"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn rename_tag(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<TagId>,
+    Json(request): Json<TagRenameRequest>,
+) -> Result<Json<ExistingTag>, ApiError> {
+    let tag = txn
+        .rename_tag(id, &request.name)
+        .await
+        .map_err(ApiError::record_not_found)?;
+    txn.commit().await?;
+    Ok(Json(tag))
+}
+
+/// A request to merge a tag into another one.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct TagMergeRequest {
+    /// The tag that replaces the merged tag.
+    pub into: TagId,
+}
+
+/// Replace a tag with another one on all the user's bookmarks
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(post,
+    path = "/tag/{id}/merge",
+    params(("id" = TagId, Path, description = "ID of the tag to replace")),
+    tag = "Tags",
+    responses(
+        (status = 204, description = "The tag was replaced"),
+        (status = 400, body = ErrorResponse, description = "The tag can't be merged into itself"),
+        (status = 404, body = ErrorResponse, description = "Either tag isn't one of the user's tags"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn merge_tags(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<TagId>,
+    Json(request): Json<TagMergeRequest>,
+) -> Result<StatusCode, ApiError> {
+    txn.merge_tags(id, request.into)
+        .await
+        .map_err(ApiError::record_not_found)?;
+    txn.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Delete one of the user's tags, removing it from all bookmarks
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(delete,
+    path = "/tag/{id}",
+    params(("id" = TagId, Path, description = "ID of the tag")),
+    tag = "Tags",
+    responses(
+        (status = 204, description = "The tag was removed"),
+        (status = 404, body = ErrorResponse, description = "The user has no such tag"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn delete_tag(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<TagId>,
+) -> Result<StatusCode, ApiError> {
+    txn.delete_tag(id)
+        .await
+        .map_err(ApiError::record_not_found)?;
+    txn.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the user's tag aliases
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(get,
+    path = "/tag/aliases",
+    tag = "Tags",
+    responses(
+        (status = 200, body = Vec<TagAlias>, description = "Returns the aliases, by name"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn list_tag_aliases(mut txn: DbTransaction) -> Result<Json<Vec<TagAlias>>, ApiError> {
+    Ok(Json(txn.list_tag_aliases().await?))
+}
+
+/// A request to add an alias to a tag.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct TagAliasRequest {
+    /// The alias.
+    pub name: String,
+
+    /// Whether to also merge a tag named like the alias (if the user
+    /// has one) into the tag, so that its bookmarks get the tag.
+    #[serde(default)]
+    pub retro_apply: bool,
+}
+
+/// Add an alias to a tag
+///
+/// Tagging bookmarks with the alias tags them with the tag from then
+/// on, and searching for the alias finds the bookmarks with the tag.
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(post,
+    path = "/tag/{id}/aliases",
+    params(("id" = TagId, Path, description = "ID of the tag")),
+    request_body = TagAliasRequest,
+    tag = "Tags",
+    responses(
+        (status = 200, body = TagAlias, description = "Returns the alias"),
+        (status = 400, body = ErrorResponse, description = "The name is not a valid alias of the tag"),
+        (status = 404, body = ErrorResponse, description = "The user has no such tag"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn add_tag_alias(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(id): Path<TagId>,
+    Json(request): Json<TagAliasRequest>,
+) -> Result<Json<TagAlias>, ApiError> {
+    let alias = txn
+        .add_tag_alias(&request.name, id, request.retro_apply)
+        .await
+        .map_err(ApiError::record_not_found)?;
+    txn.commit().await?;
+    Ok(Json(alias))
+}
+
+/// Remove a tag alias
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(delete,
+    path = "/tag/aliases/{name}",
+    params(("name" = String, Path, description = "The alias")),
+    tag = "Tags",
+    responses(
+        (status = 204, description = "The alias was removed"),
+        (status = 404, body = ErrorResponse, description = "The user has no such alias"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn remove_tag_alias(
+    mut txn: DbTransaction<ReadWrite>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    txn.remove_tag_alias(&name)
+        .await
+        .map_err(ApiError::record_not_found)?;
+    txn.commit().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// The user's settings.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct UserSettings {
+    /// The timezone that date searches count days in, like
+    /// `Europe/Berlin`; without one, the server's timezone is used.
+    #[serde(default)]
+    pub timezone: Option<Timezone>,
+}
+
+/// Retrieve the user's settings
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(get,
+    path = "/user/settings",
+    tag = "Settings",
+    responses(
+        (status = 200, body = UserSettings, description = "Returns the settings"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn get_user_settings(txn: DbTransaction) -> Result<Json<UserSettings>, ApiError> {
+    Ok(Json(UserSettings {
+        timezone: txn.user().timezone,
+    }))
+}
+
+/// Change the user's settings
+#[debug_handler(state = Arc<GlobalWebAppState>)]
+#[utoipa::path(put,
+    path = "/user/settings",
+    request_body = UserSettings,
+    tag = "Settings",
+    responses(
+        (status = 200, body = UserSettings, description = "Returns the new settings"),
+    ),
+)]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+async fn update_user_settings(
+    mut txn: DbTransaction<ReadWrite>,
+    Json(settings): Json<UserSettings>,
+) -> Result<Json<UserSettings>, ApiError> {
+    txn.set_user_timezone(settings.timezone).await?;
+    txn.commit().await?;
+    Ok(Json(settings))
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 struct PageMetadataQuery {
     url: Url,
@@ -180,14 +850,612 @@ struct PageMetadataQuery {
     tag = "HTTP",
     responses(
         (status = 200, body = inline(Metadata), description = "Returns page metadata"),
+        (status = 400, body = ErrorResponse, description = "The page could not be retrieved"),
+        (status = 403, body = ErrorResponse, description = "The server may not retrieve the URL"),
     ),
 )]
-#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(txn))]
+#[tracing::instrument(err(Debug, level = tracing::Level::WARN), skip(state, txn))]
 async fn fetch_page_metadata(
+    State(state): State<Arc<GlobalWebAppState>>,
     mut txn: DbTransaction<ReadWrite>,
     Query(PageMetadataQuery { url }): Query<PageMetadataQuery>,
 ) -> Result<Json<Metadata>, ApiError> {
     txn.ensure_url(&url).await?;
     txn.commit().await?;
-    Ok(Json(lookup_page_from_web(&url).await?))
+    Ok(Json(state.fetcher().lookup_page(&url).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_test::TestServer;
+    use serde_json::{json, Value};
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::testing::test_db;
+
+    async fn server() -> anyhow::Result<TestServer> {
+        let state = GlobalWebAppState::new(
+            test_db().await?,
+            "X-Lz-User".to_string(),
+            None,
+            crate::http::Fetcher::new(Default::default())?,
+            &Default::default(),
+        );
+        TestServer::new(router().with_state(Arc::new(state)))
+    }
+
+    #[tokio::test]
+    async fn archives_new_bookmarks() -> TestResult {
+        let app = Router::new().route(
+            "/page",
+            get(|| async {
+                axum::response::Html(
+                    "<html><body><article><p>Lorem ipsum</p><script>steal()</script></article></body></html>",
+                )
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let dir = tempfile::tempdir()?;
+        let state = GlobalWebAppState::new(
+            test_db().await?,
+            "X-Lz-User".to_string(),
+            Some("alice".to_string()),
+            crate::http::Fetcher::new(crate::http::FetcherArgs {
+                fetch_allowed_networks: vec!["127.0.0.1/32".parse()?],
+                ..Default::default()
+            })?,
+            &crate::archive::local::LocalArchiveArgs {
+                archive_pages: true,
+                archive_dir: Some(dir.path().to_owned()),
+            },
+        );
+        let server = TestServer::new(router().with_state(Arc::new(state)))?;
+
+        let created: Value = server
+            .post("/bookmark/create")
+            .json(&json!({
+                "bookmark": {
+                    "url": format!("http://{addr}/page"),
+                    "title": "Soon to rot",
+                    "created_at": "2024-01-01T00:00:00Z",
+                },
+            }))
+            .await
+            .json();
+        let path = format!("/bookmark/{}/archive", created["bookmark"]["id"]);
+
+        // Archiving happens in the background:
+        let mut archive = server.get(&path).await;
+        for _ in 0..50 {
+            if archive.status_code() == StatusCode::OK {
+                break;
+            }
+            archive.assert_status(StatusCode::NOT_FOUND);
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            archive = server.get(&path).await;
+        }
+        let archive: Value = archive.json();
+        assert_eq!(archive["text"], "Lorem ipsum");
+
+        let page = server.get(&format!("{path}/page")).await;
+        assert_eq!(page.header("content-security-policy"), "sandbox");
+        assert!(page.text().contains("<p>Lorem ipsum</p>"));
+
+        let found: Value = server
+            .post("/bookmarks")
+            .add_query_param("q", "lorem")
+            .json(&json!({}))
+            .await
+            .json();
+        assert_eq!(found["bookmarks"][0]["bookmark"]["title"], "Soon to rot");
+        assert_eq!(found["bookmarks"][0]["snippet"], "**Lorem** ipsum");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refuses_to_fetch_internal_pages() -> TestResult {
+        let server = server().await?;
+        let response = server
+            .get("/http/fetch_metadata")
+            .add_query_param("url", "http://169.254.169.254/latest/meta-data/")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await;
+        response.assert_status(axum::http::StatusCode::FORBIDDEN);
+        let body: Value = response.json();
+        assert!(body["error_message"]
+            .as_str()
+            .unwrap()
+            .contains("169.254.169.254"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bookmark_crud() -> TestResult {
+        let server = server().await?;
+        let created: Value = server
+            .post("/bookmark/create")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({
+                "bookmark": {
+                    "url": "https://example.com/",
+                    "title": "Example",
+                    "description": "An example",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "shared": true,
+                    "unread": false,
+                },
+                "tag_names": ["a", "b"],
+            }))
+            .await
+            .json();
+        let path = format!("/bookmark/{}", created["bookmark"]["id"]);
+
+        let patched: Value = server
+            .patch(&path)
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({
+                "title": "Patched",
+                "description": null,
+                "tag_names": ["c"],
+                "associations": [{"link": "https://example.org/", "context": "via"}],
+            }))
+            .await
+            .json();
+        assert_eq!(patched["bookmark"]["title"], "Patched");
+        assert_eq!(patched["bookmark"]["description"], Value::Null);
+        assert_eq!(patched["bookmark"]["shared"], true);
+        assert_eq!(patched["tags"][0]["name"], "c");
+        assert_eq!(patched["associations"][0]["context"], "via");
+
+        let replaced: Value = server
+            .put(&path)
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({
+                "bookmark": {
+                    "url": "https://example.com/other",
+                    "title": "Replaced",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "shared": false,
+                    "unread": true,
+                },
+            }))
+            .await
+            .json();
+        assert_eq!(replaced["bookmark"]["url"], "https://example.com/other");
+        assert_eq!(replaced["tags"], json!([]));
+        assert_eq!(replaced["associations"], json!([]));
+
+        let fetched: Value = server
+            .get(&path)
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        assert_eq!(fetched, replaced);
+
+        // Other users can't see or change the bookmark:
+        server
+            .post("/bookmark/create")
+            .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+            .json(&json!({
+                "bookmark": {
+                    "url": "https://example.com/",
+                    "title": "Mallory's",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "shared": true,
+                    "unread": false,
+                },
+            }))
+            .await;
+        for response in [
+            server
+                .patch(&path)
+                .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+                .json(&json!({"title": "pwned"}))
+                .expect_failure()
+                .await,
+            server
+                .get(&path)
+                .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+                .expect_failure()
+                .await,
+            server
+                .delete(&path)
+                .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+                .expect_failure()
+                .await,
+        ] {
+            assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+        }
+
+        let response = server
+            .delete(&path)
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+        let response = server
+            .get(&path)
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn merge_bookmarks() -> TestResult {
+        let server = server().await?;
+        let mut ids = vec![];
+        for (url, tag, created_at) in [
+            ("https://example.com/", "kept", "2024-02-01T00:00:00Z"),
+            (
+                "https://example.com/?ref=elsewhere",
+                "absorbed",
+                "2024-01-01T00:00:00Z",
+            ),
+        ] {
+            let created: Value = server
+                .post("/bookmark/create")
+                .add_header("X-Lz-User".parse()?, "alice".parse()?)
+                .json(&json!({
+                    "bookmark": {
+                        "url": url,
+                        "title": "Example",
+                        "notes": tag,
+                        "created_at": created_at,
+                        "shared": true,
+                        "unread": false,
+                    },
+                    "tag_names": [tag],
+                }))
+                .await
+                .json();
+            ids.push(created["bookmark"]["id"].clone());
+        }
+        let path = format!("/bookmark/{}/merge", ids[0]);
+
+        let response = server
+            .post(&path)
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"absorb": ids[0]}))
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        let response = server
+            .post(&path)
+            .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+            .json(&json!({"absorb": ids[1]}))
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+
+        let merged: Value = server
+            .post(&path)
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"absorb": ids[1]}))
+            .await
+            .json();
+        assert_eq!(merged["bookmark"]["id"], ids[0]);
+        assert_eq!(merged["bookmark"]["created_at"], "2024-01-01T00:00:00Z");
+        assert_eq!(merged["bookmark"]["notes"], "kept\n\nabsorbed");
+        assert_eq!(merged["tags"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            merged["associations"][0]["link"],
+            "https://example.com/?ref=elsewhere"
+        );
+        let response = server
+            .get(&format!("/bookmark/{}", ids[1]))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn manage_tags() -> TestResult {
+        let server = server().await?;
+        for (user, url, tags) in [
+            ("alice", "https://example.com/1", json!(["js", "rust"])),
+            ("alice", "https://example.com/2", json!(["javascript"])),
+            ("mallory", "https://example.com/3", json!(["js"])),
+        ] {
+            server
+                .post("/bookmark/create")
+                .add_header("X-Lz-User".parse()?, user.parse()?)
+                .json(&json!({
+                    "bookmark": {
+                        "url": url,
+                        "title": "Example",
+                        "created_at": "2024-01-01T00:00:00Z",
+                        "shared": true,
+                        "unread": false,
+                    },
+                    "tag_names": tags,
+                }))
+                .await;
+        }
+        let tags: Value = server
+            .get("/tags")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        let names: Vec<_> = tags
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| {
+                (
+                    t["tag"]["name"].as_str().unwrap(),
+                    t["count"].as_i64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(names, vec![("javascript", 1), ("js", 1), ("rust", 1)]);
+        let (javascript, js, rust) = (
+            &tags[0]["tag"]["id"],
+            &tags[1]["tag"]["id"],
+            &tags[2]["tag"]["id"],
+        );
+
+        let response = server
+            .post(&format!("/tag/{js}/merge"))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"into": javascript}))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+        let response = server
+            .post(&format!("/tag/{rust}/rename"))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"name": "not valid"}))
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        let renamed: Value = server
+            .post(&format!("/tag/{rust}/rename"))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"name": "Rust"}))
+            .await
+            .json();
+        assert_eq!(renamed["name"], "Rust");
+        let response = server
+            .delete(&format!("/tag/{javascript}"))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+        let tags: Value = server
+            .get("/tags")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        assert_eq!(tags.as_array().unwrap().len(), 1);
+        assert_eq!(tags[0]["tag"]["id"], *rust);
+        assert_eq!(tags[0]["tag"]["name"], "Rust");
+
+        // Mallory's bookmark keeps its tag, and Mallory can't change
+        // Alice's tags:
+        let tags: Value = server
+            .get("/tags")
+            .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+            .await
+            .json();
+        assert_eq!(tags[0]["tag"]["name"], "js");
+        let response = server
+            .delete(&format!("/tag/{rust}"))
+            .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tag_tree() -> TestResult {
+        let server = server().await?;
+        server
+            .post("/bookmark/create")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({
+                "bookmark": {
+                    "url": "https://example.com/",
+                    "title": "Example",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "shared": true,
+                    "unread": false,
+                },
+                "tag_names": ["lang/rust/async", "lang/python"],
+            }))
+            .await;
+        let tree: Value = server
+            .get("/tags/tree")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        assert_eq!(tree.as_array().unwrap().len(), 1);
+        assert_eq!(tree[0]["path"], "lang");
+        assert_eq!(tree[0]["tag_id"], Value::Null);
+        let children = &tree[0]["children"];
+        assert_eq!(children[0]["path"], "lang/python");
+        assert_eq!(children[0]["count"], 1);
+        assert_eq!(children[1]["name"], "rust");
+        assert_eq!(children[1]["children"][0]["path"], "lang/rust/async");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tag_aliases() -> TestResult {
+        let server = server().await?;
+        let created: Value = server
+            .post("/bookmark/create")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({
+                "bookmark": {
+                    "url": "https://example.com/",
+                    "title": "Example",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "shared": true,
+                    "unread": false,
+                },
+                "tag_names": ["k8s", "kubernetes"],
+            }))
+            .await
+            .json();
+        let tags: Value = server
+            .get("/tags")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        assert_eq!(tags[1]["tag"]["name"], "kubernetes");
+        let kubernetes = &tags[1]["tag"]["id"];
+
+        let response = server
+            .post(&format!("/tag/{kubernetes}/aliases"))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"name": "Kubernetes"}))
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        let alias: Value = server
+            .post(&format!("/tag/{kubernetes}/aliases"))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"name": "k8s", "retro_apply": true}))
+            .await
+            .json();
+        assert_eq!(alias["tag_name"], "kubernetes");
+        let bookmark: Value = server
+            .get(&format!("/bookmark/{}", created["bookmark"]["id"]))
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        assert_eq!(bookmark["tags"].as_array().unwrap().len(), 1);
+        assert_eq!(bookmark["tags"][0]["name"], "kubernetes");
+
+        let aliases: Value = server
+            .get("/tag/aliases")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        assert_eq!(aliases.as_array().unwrap().len(), 1);
+        let response = server
+            .delete("/tag/aliases/k8s")
+            .add_header("X-Lz-User".parse()?, "mallory".parse()?)
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+        let response = server
+            .delete("/tag/aliases/k8s")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_in_pages() -> TestResult {
+        let server = server().await?;
+        for title in ["banana", "Cherry", "apple"] {
+            server
+                .post("/bookmark/create")
+                .add_header("X-Lz-User".parse()?, "alice".parse()?)
+                .json(&json!({
+                    "bookmark": {
+                        "url": format!("https://example.com/{title}"),
+                        "title": title,
+                        "created_at": "2024-01-01T00:00:00Z",
+                        "shared": false,
+                        "unread": false,
+                    },
+                }))
+                .await;
+        }
+
+        let mut titles = vec![];
+        let mut cursor = Value::Null;
+        loop {
+            let page: Value = server
+                .post("/bookmarks")
+                .add_header("X-Lz-User".parse()?, "alice".parse()?)
+                .json(&json!({"order": "title", "per_page": 2, "cursor": cursor}))
+                .await
+                .json();
+            for bookmark in page["bookmarks"].as_array().unwrap() {
+                titles.push(bookmark["bookmark"]["title"].as_str().unwrap().to_string());
+            }
+            cursor = page["next_cursor"].clone();
+            if cursor.is_null() {
+                break;
+            }
+        }
+        assert_eq!(titles, vec!["apple", "banana", "Cherry"]);
+
+        let response = server
+            .post("/bookmarks")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"per_page": 2, "cursor": "not a cursor"}))
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dates_in_user_timezone() -> TestResult {
+        let server = server().await?;
+        server
+            .post("/bookmark/create")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({
+                "bookmark": {
+                    "url": "https://example.com/",
+                    "title": "Example",
+                    "created_at": "2024-01-01T23:30:00Z",
+                    "shared": false,
+                    "unread": false,
+                },
+            }))
+            .await;
+        let settings: Value = server
+            .put("/user/settings")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"timezone": "Pacific/Auckland"}))
+            .await
+            .json();
+        assert_eq!(settings["timezone"], "Pacific/Auckland");
+        let settings: Value = server
+            .get("/user/settings")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .await
+            .json();
+        assert_eq!(settings["timezone"], "Pacific/Auckland");
+        server
+            .put("/user/settings")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({"timezone": "Mars/Olympus_Mons"}))
+            .expect_failure()
+            .await;
+
+        // It's already January 2nd in Auckland, but not in UTC:
+        for (timezone, count) in [(Value::Null, 1), (json!("UTC"), 0)] {
+            let listed: Value = server
+                .post("/bookmarks")
+                .add_query_param("q", "on:2024-01-02")
+                .add_header("X-Lz-User".parse()?, "alice".parse()?)
+                .json(&json!({"timezone": timezone}))
+                .await
+                .json();
+            assert_eq!(listed["bookmarks"].as_array().unwrap().len(), count);
+        }
+        let response = server
+            .post("/bookmarks")
+            .add_query_param("q", "after:someday")
+            .add_header("X-Lz-User".parse()?, "alice".parse()?)
+            .json(&json!({}))
+            .expect_failure()
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        Ok(())
+    }
 }
@@ -5,6 +5,9 @@ use axum::Json;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use lz_db::{ListError, MergeError, QueryParseError, TagError};
+
+use crate::archive::ArchiveError;
 use crate::http::LookupError;
 
 /// An error that can occur in the API.
@@ -22,6 +25,25 @@ pub(crate) enum ApiError {
     #[schema()]
     #[serde(serialize_with = "serialize_lookup_error", skip_deserializing)]
     Lookup(LookupError),
+
+    #[schema()]
+    #[serde(serialize_with = "serialize_query_error", skip_deserializing)]
+    InvalidQuery(QueryParseError),
+
+    #[schema()]
+    #[serde(serialize_with = "serialize_archive_error", skip_deserializing)]
+    Archive(ArchiveError),
+
+    #[schema()]
+    #[serde(serialize_with = "serialize_merge_error", skip_deserializing)]
+    InvalidMerge(MergeError),
+
+    #[schema()]
+    #[serde(serialize_with = "serialize_tag_error", skip_deserializing)]
+    InvalidTagChange(TagError),
+
+    #[schema()]
+    InvalidCursor,
 }
 
 impl fmt::Display for ApiError {
@@ -30,43 +52,114 @@ impl fmt::Display for ApiError {
             ApiError::NotFound => write!(f, "not found"),
             ApiError::DatastoreError(_) => write!(f, "datastore error"),
             ApiError::Lookup(e) => write!(f, "HTTP error {e}"),
+            ApiError::InvalidQuery(e) => write!(f, "invalid search query: {e}"),
+            ApiError::Archive(e) => write!(f, "archive error: {e}"),
+            ApiError::InvalidMerge(e) => write!(f, "invalid merge: {e}"),
+            ApiError::InvalidTagChange(e) => write!(f, "invalid tag change: {e}"),
+            ApiError::InvalidCursor => write!(f, "invalid pagination cursor"),
         }
     }
 }
 
+/// The body of an API response that reports an error.
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub(crate) struct ErrorResponse {
+    /// A human-readable description of the error.
+    pub error_message: String,
+}
+
 impl axum::response::IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
-        #[derive(Serialize)]
-        struct ErrorResponse<'a> {
-            error_message: &'a str,
-        }
-
-        let error_as_text = self.to_string();
-        let (status, error_message) = match &self {
-            ApiError::NotFound => (StatusCode::NOT_FOUND, &error_as_text),
+        let error_message = self.to_string();
+        let status = match &self {
+            ApiError::NotFound => StatusCode::NOT_FOUND,
             ApiError::DatastoreError(inner) => {
                 tracing::error!(error=%inner, error_debug=?inner, "datastore error");
-                (StatusCode::INTERNAL_SERVER_ERROR, &error_as_text)
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            ApiError::Lookup(error @ LookupError::Forbidden(_)) => {
+                tracing::warn!(%error, "refused to retrieve a link");
+                StatusCode::FORBIDDEN
             }
             ApiError::Lookup(error) => {
                 tracing::warn!(error_debug=?error, %error, "HTTP error");
-                (StatusCode::BAD_REQUEST, &error_as_text)
+                StatusCode::BAD_REQUEST
+            }
+            ApiError::InvalidQuery(_) => StatusCode::BAD_REQUEST,
+            ApiError::Archive(error) => {
+                tracing::error!(%error, error_debug=?error, "archive error");
+                StatusCode::INTERNAL_SERVER_ERROR
             }
+            ApiError::InvalidMerge(_) => StatusCode::BAD_REQUEST,
+            ApiError::InvalidTagChange(_) => StatusCode::BAD_REQUEST,
+            ApiError::InvalidCursor => StatusCode::BAD_REQUEST,
         };
         (status, Json(ErrorResponse { error_message })).into_response()
     }
 }
 
+impl ApiError {
+    /// Reports the [`RowNotFound`](sqlx::Error::RowNotFound) that the
+    /// datastore returns for a record the user doesn't have as
+    /// [`ApiError::NotFound`].
+    ///
+    /// This is for handlers to use on looking up the record named in
+    /// their path; other missing rows are datastore errors. Lookups by
+    /// ID are scoped to the user, so this is also what happens for
+    /// other users' records.
+    pub(crate) fn record_not_found<E: Into<ApiError>>(error: E) -> Self {
+        match error.into() {
+            ApiError::DatastoreError(sqlx::Error::RowNotFound) => ApiError::NotFound,
+            other => other,
+        }
+    }
+}
+
 impl From<sqlx::Error> for ApiError {
     fn from(other: sqlx::Error) -> Self {
         ApiError::DatastoreError(other)
     }
 }
+impl From<ListError> for ApiError {
+    fn from(other: ListError) -> Self {
+        match other {
+            ListError::InvalidCursor => ApiError::InvalidCursor,
+            ListError::Sqlx(e) => e.into(),
+        }
+    }
+}
 impl From<LookupError> for ApiError {
     fn from(other: LookupError) -> Self {
         ApiError::Lookup(other)
     }
 }
+impl From<QueryParseError> for ApiError {
+    fn from(other: QueryParseError) -> Self {
+        ApiError::InvalidQuery(other)
+    }
+}
+
+impl From<ArchiveError> for ApiError {
+    fn from(other: ArchiveError) -> Self {
+        ApiError::Archive(other)
+    }
+}
+impl From<MergeError> for ApiError {
+    fn from(other: MergeError) -> Self {
+        match other {
+            MergeError::Sqlx(inner) => inner.into(),
+            other => ApiError::InvalidMerge(other),
+        }
+    }
+}
+impl From<TagError> for ApiError {
+    fn from(other: TagError) -> Self {
+        match other {
+            TagError::Sqlx(inner) => inner.into(),
+            other => ApiError::InvalidTagChange(other),
+        }
+    }
+}
 
 fn serialize_db_error<S>(_err: &sqlx::Error, s: S) -> Result<S::Ok, S::Error>
 where
@@ -81,3 +174,31 @@ where
 {
     s.serialize_str(&format!("lookup error: {}", err))
 }
+
+fn serialize_query_error<S>(err: &QueryParseError, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    s.serialize_str(&format!("invalid search query: {}", err))
+}
+
+fn serialize_archive_error<S>(err: &ArchiveError, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    s.serialize_str(&format!("archive error: {}", err))
+}
+
+fn serialize_merge_error<S>(err: &MergeError, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    s.serialize_str(&format!("invalid merge: {}", err))
+}
+
+fn serialize_tag_error<S>(err: &TagError, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    s.serialize_str(&format!("invalid tag change: {}", err))
+}
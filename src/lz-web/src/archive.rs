@@ -0,0 +1,256 @@
+//! Snapshots of bookmarked pages in web archives.
+//!
+//! An [`ArchiveProvider`] finds (or requests) a snapshot of a URL in
+//! some web archive; [`archive_bookmark`] records that snapshot as a
+//! link associated with the bookmark, in the provider's context.
+//!
+//! Pages can also be archived locally, see the [`local`] module.
+
+use async_trait::async_trait;
+use lz_db::{ExistingBookmark, Transaction};
+use reqwest::header::CONTENT_LOCATION;
+use serde::Deserialize;
+use url::Url;
+
+use crate::http::{Fetcher, LookupError};
+
+pub mod local;
+
+/// Errors that can occur when working with web archives
+#[derive(thiserror::Error, Debug)]
+pub enum ArchiveError {
+    #[error(transparent)]
+    Lookup(#[from] LookupError),
+
+    #[error("invalid archive URL")]
+    InvalidUrl(#[from] url::ParseError),
+
+    #[error("unexpected response from the archive: {0}")]
+    UnexpectedResponse(String),
+
+    #[error("could not record the snapshot")]
+    Datastore(#[from] sqlx::Error),
+
+    #[error("only HTML pages can be archived")]
+    NotHtml,
+
+    #[error("could not store the archived page")]
+    Storage(#[from] std::io::Error),
+}
+
+/// A web archive that keeps snapshots of pages.
+#[async_trait]
+pub trait ArchiveProvider: Send + Sync {
+    /// The association context that this archive's snapshots get recorded in.
+    fn context(&self) -> &'static str;
+
+    /// Find the most recent existing snapshot of `url`, if there is one.
+    async fn lookup_snapshot(&self, url: &Url) -> Result<Option<Url>, ArchiveError>;
+
+    /// Ask the archive to take a snapshot of `url` now, and return its URL.
+    async fn request_snapshot(&self, url: &Url) -> Result<Url, ArchiveError>;
+}
+
+/// Find a snapshot of the bookmark's URL and associate it with the
+/// bookmark. If no snapshot exists and `request_if_missing` is set,
+/// ask the archive to take one.
+///
+/// Returns the snapshot's URL, if one was found or taken.
+pub async fn archive_bookmark(
+    provider: &dyn ArchiveProvider,
+    txn: &mut Transaction,
+    bookmark: &ExistingBookmark,
+    request_if_missing: bool,
+) -> Result<Option<Url>, ArchiveError> {
+    let snapshot = match provider.lookup_snapshot(&bookmark.url).await? {
+        Some(snapshot) => snapshot,
+        None if request_if_missing => provider.request_snapshot(&bookmark.url).await?,
+        None => return Ok(None),
+    };
+    let url_id = txn.ensure_url(&snapshot).await?;
+    match txn
+        .associate_bookmark_link(&bookmark.id, &url_id, Some(provider.context()))
+        .await
+    {
+        // We already knew about this snapshot:
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {}
+        res => res?,
+    }
+    Ok(Some(snapshot))
+}
+
+/// The Internet Archive's [Wayback Machine](https://web.archive.org/).
+pub struct Wayback {
+    fetcher: Fetcher,
+    base_url: Url,
+}
+
+impl Wayback {
+    pub fn new(fetcher: Fetcher) -> Self {
+        Self::with_base_url(
+            fetcher,
+            Url::parse("https://web.archive.org/").expect("valid URL"),
+        )
+    }
+
+    /// Use a Wayback Machine (or a stand-in for it) at another URL.
+    pub fn with_base_url(fetcher: Fetcher, base_url: Url) -> Self {
+        Self { fetcher, base_url }
+    }
+}
+
+/// The response of the [availability API](https://archive.org/help/wayback_api.php).
+#[derive(Deserialize, Debug)]
+struct Availability {
+    archived_snapshots: ArchivedSnapshots,
+}
+
+#[derive(Deserialize, Debug)]
+struct ArchivedSnapshots {
+    closest: Option<Snapshot>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Snapshot {
+    available: bool,
+    url: Url,
+}
+
+#[async_trait]
+impl ArchiveProvider for Wayback {
+    fn context(&self) -> &'static str {
+        lz_db::WAYBACK_CONTEXT
+    }
+
+    #[tracing::instrument(skip(self), err(Debug, level = tracing::Level::DEBUG))]
+    async fn lookup_snapshot(&self, url: &Url) -> Result<Option<Url>, ArchiveError> {
+        let mut api_url = self.base_url.join("wayback/available")?;
+        api_url.query_pairs_mut().append_pair("url", url.as_str());
+        let response = self.fetcher.get(&api_url).await?;
+        let body = self.fetcher.read_body(response).await?;
+        let availability: Availability = serde_json::from_slice(&body)
+            .map_err(|e| ArchiveError::UnexpectedResponse(e.to_string()))?;
+        Ok(availability
+            .archived_snapshots
+            .closest
+            .filter(|snapshot| snapshot.available)
+            .map(|snapshot| snapshot.url))
+    }
+
+    #[tracing::instrument(skip(self), err(Debug, level = tracing::Level::DEBUG))]
+    async fn request_snapshot(&self, url: &Url) -> Result<Url, ArchiveError> {
+        let save_url = self.base_url.join(&format!("save/{url}"))?;
+        let response = self.fetcher.get(&save_url).await?;
+        // "Save Page Now" either tells us where the snapshot is, or
+        // redirects us there:
+        if let Some(location) = response
+            .headers()
+            .get(CONTENT_LOCATION)
+            .and_then(|location| location.to_str().ok())
+        {
+            return Ok(response.url().join(location)?);
+        }
+        if response.url().path().starts_with("/web/") {
+            return Ok(response.url().clone());
+        }
+        Err(ArchiveError::UnexpectedResponse(format!(
+            "no snapshot location in response from {}",
+            response.url()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use axum::extract::{Path, Query};
+    use axum::routing::get;
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::http::FetcherArgs;
+    use crate::testing::{test_bookmark, test_db};
+
+    /// A Wayback Machine that has a snapshot of example.com only.
+    async fn stand_in_wayback() -> anyhow::Result<SocketAddr> {
+        let app = Router::new()
+            .route(
+                "/wayback/available",
+                get(|Query(query): Query<Value>| async move {
+                    if query["url"] == "https://example.com/" {
+                        Json(json!({"url": query["url"], "archived_snapshots": {"closest": {
+                            "status": "200",
+                            "available": true,
+                            "url": "http://web.archive.org/web/20240101000000/https://example.com/",
+                            "timestamp": "20240101000000"
+                        }}}))
+                    } else {
+                        Json(json!({"url": query["url"], "archived_snapshots": {}}))
+                    }
+                }),
+            )
+            .route(
+                "/save/*url",
+                get(|Path(url): Path<String>| async move {
+                    [(
+                        axum::http::header::CONTENT_LOCATION,
+                        format!("/web/20240202000000/{url}"),
+                    )]
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        Ok(addr)
+    }
+
+    #[tokio::test]
+    async fn wayback_snapshots() -> TestResult {
+        let addr = stand_in_wayback().await?;
+        let fetcher = Fetcher::new(FetcherArgs {
+            fetch_allowed_networks: vec!["127.0.0.1/32".parse()?],
+            ..Default::default()
+        })?;
+        let wayback = Wayback::with_base_url(fetcher, Url::parse(&format!("http://{addr}/"))?);
+
+        let mut txn = test_db().await?.begin_for_user("alice").await?;
+        let archived = txn
+            .add_bookmark(test_bookmark(&Url::parse("https://example.com/")?))
+            .await?;
+        let unarchived = txn
+            .add_bookmark(test_bookmark(&Url::parse("https://example.org/a")?))
+            .await?;
+
+        let snapshot = archive_bookmark(&wayback, &mut txn, &archived, false).await?;
+        assert_eq!(
+            snapshot.as_ref().map(Url::as_str),
+            Some("http://web.archive.org/web/20240101000000/https://example.com/")
+        );
+        // Recording the same snapshot twice is fine:
+        archive_bookmark(&wayback, &mut txn, &archived, false).await?;
+
+        assert_eq!(
+            archive_bookmark(&wayback, &mut txn, &unarchived, false).await?,
+            None
+        );
+        let requested = archive_bookmark(&wayback, &mut txn, &unarchived, true).await?;
+        assert_eq!(
+            requested.map(String::from),
+            Some(format!(
+                "http://{addr}/web/20240202000000/https://example.org/a"
+            ))
+        );
+
+        let associations = txn
+            .associated_links_on_bookmarks([archived.id, unarchived.id])
+            .await?;
+        for id in [archived.id, unarchived.id] {
+            assert_eq!(associations[&id].len(), 1);
+            assert_eq!(associations[&id][0].context.as_deref(), Some("wayback"));
+        }
+        Ok(())
+    }
+}
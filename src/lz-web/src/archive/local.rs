@@ -0,0 +1,354 @@
+//! Local copies of bookmarked pages.
+//!
+//! The [`LocalArchiver`] retrieves a bookmarked page, extracts its
+//! readable text, and keeps both in an [`ArchiveStore`]: A directory
+//! of files named by the SHA-256 digest of their contents, which
+//! usually lives next to the database. The resulting
+//! [`Archive`](lz_db::Archive) gets recorded in the database.
+
+use std::cell::RefCell;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread_local;
+
+use chrono::Utc;
+use ego_tree::iter::Edge;
+use lz_db::{Archive, StoredUrlId};
+use scraper::{Html, Node, Selector};
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use super::ArchiveError;
+use crate::http::{make_selector, Fetcher};
+
+/// Where to keep local copies of bookmarked pages.
+#[derive(clap::Args, Clone, Eq, PartialEq, Debug, Default)]
+pub struct LocalArchiveArgs {
+    /// Keep a local copy of each newly bookmarked page
+    #[clap(long, action)]
+    pub archive_pages: bool,
+
+    /// Directory to keep local copies of pages in. Defaults to an
+    /// `archive` directory next to the database.
+    #[clap(long)]
+    pub archive_dir: Option<PathBuf>,
+}
+
+impl LocalArchiveArgs {
+    /// The store that the archived pages of the database at `db_path` go in.
+    pub fn store(&self, db_path: Option<&Path>) -> ArchiveStore {
+        let root = match (&self.archive_dir, db_path) {
+            (Some(dir), _) => dir.clone(),
+            (None, Some(db_path)) => db_path.with_file_name("archive"),
+            (None, None) => PathBuf::from("archive"),
+        };
+        ArchiveStore::new(root)
+    }
+}
+
+/// A directory of files that are named by their contents' SHA-256
+/// digest. Storing the same contents twice only keeps one file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveStore {
+    root: PathBuf,
+}
+
+/// Distinguishes the temporary files of concurrent writes.
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+impl ArchiveStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// The file that contents with the given digest are stored in.
+    pub fn path_for(&self, sha256: &str) -> PathBuf {
+        let prefix = sha256.get(..2).unwrap_or(sha256);
+        self.root.join(prefix).join(sha256)
+    }
+
+    /// Store `contents`, returning their digest in hex.
+    pub async fn put(&self, contents: &[u8]) -> io::Result<String> {
+        let sha256 = hex::encode(Sha256::digest(contents));
+        let path = self.path_for(&sha256);
+        if tokio::fs::try_exists(&path).await? {
+            return Ok(sha256);
+        }
+        let dir = path.parent().expect("stored files are in a subdirectory");
+        tokio::fs::create_dir_all(dir).await?;
+        // Write to a temporary file first, so that a file named by a
+        // digest always has all of its contents:
+        let temp = dir.join(format!(
+            ".{sha256}.{}.{}",
+            std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        tokio::fs::write(&temp, contents).await?;
+        tokio::fs::rename(&temp, &path).await?;
+        Ok(sha256)
+    }
+
+    /// Read the contents with the given digest.
+    pub async fn get(&self, sha256: &str) -> io::Result<Vec<u8>> {
+        if sha256.is_empty() || !sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("not a SHA-256 digest: {sha256:?}"),
+            ));
+        }
+        tokio::fs::read(self.path_for(sha256)).await
+    }
+}
+
+/// Keeps local copies of pages in an [`ArchiveStore`].
+#[derive(Clone, Debug)]
+pub struct LocalArchiver {
+    fetcher: Fetcher,
+    store: ArchiveStore,
+}
+
+impl LocalArchiver {
+    pub fn new(fetcher: Fetcher, store: ArchiveStore) -> Self {
+        Self { fetcher, store }
+    }
+
+    /// The store that archived pages are kept in.
+    pub fn store(&self) -> &ArchiveStore {
+        &self.store
+    }
+
+    /// Retrieve the page that a stored URL points to, and store its
+    /// HTML and readable text. The returned archive still needs to
+    /// be recorded in the database, with
+    /// [`set_archive`](lz_db::Transaction::set_archive).
+    #[tracing::instrument(skip(self), err(Debug, level = tracing::Level::DEBUG))]
+    pub async fn archive_page(
+        &self,
+        url_id: StoredUrlId,
+        url: &Url,
+    ) -> Result<Archive, ArchiveError> {
+        let (retrieved_from, html) = match self.fetcher.fetch_html(url).await? {
+            (retrieved_from, Some(html)) => (retrieved_from, html),
+            (_, None) => return Err(ArchiveError::NotHtml),
+        };
+        let text = readable_text(&html);
+        Ok(Archive {
+            url_id,
+            archived_at: Utc::now(),
+            retrieved_from,
+            html_sha256: self.store.put(html.as_bytes()).await?,
+            text_sha256: self.store.put(text.as_bytes()).await?,
+            text,
+        })
+    }
+}
+
+thread_local! {
+    static READABLE_ROOT: RefCell<Selector> = RefCell::new(make_selector(r#"article, main, [role="main"]"#));
+    static BODY: RefCell<Selector> = RefCell::new(make_selector("body"));
+}
+
+/// Elements whose text isn't part of what a reader would read.
+const UNREADABLE_ELEMENTS: &[&str] = &[
+    "aside", "button", "footer", "form", "iframe", "nav", "noscript", "script", "select", "style",
+    "svg", "template", "textarea",
+];
+
+/// Elements that start a new paragraph of text.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+/// The text that a reader of the page would read: That of its main
+/// article if it marks one up, or else of its body, without
+/// navigation, scripts, forms and the like. Paragraphs are separated
+/// by blank lines.
+pub fn readable_text(html: &str) -> String {
+    let doc = Html::parse_document(html);
+    let root = READABLE_ROOT
+        .with_borrow(|sel| doc.select(sel).next())
+        .or_else(|| BODY.with_borrow(|sel| doc.select(sel).next()))
+        .unwrap_or_else(|| doc.root_element());
+    // Outside of a main article, page headers are usually site
+    // navigation rather than content:
+    let skip_headers = root.value().name() == "body";
+
+    let mut paragraphs = vec![];
+    let mut paragraph = String::new();
+    let mut skipping = 0usize;
+    let mut end_paragraph = |paragraph: &mut String| {
+        let text = paragraph.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !text.is_empty() {
+            paragraphs.push(text);
+        }
+        paragraph.clear();
+    };
+    for edge in root.traverse() {
+        match edge {
+            Edge::Open(node) | Edge::Close(node) => match node.value() {
+                Node::Element(element) => {
+                    let name = element.name();
+                    if UNREADABLE_ELEMENTS.contains(&name) || (skip_headers && name == "header") {
+                        if matches!(edge, Edge::Open(_)) {
+                            skipping += 1;
+                        } else {
+                            skipping -= 1;
+                        }
+                    } else if skipping == 0 && BLOCK_ELEMENTS.contains(&name) {
+                        end_paragraph(&mut paragraph);
+                    }
+                }
+                Node::Text(text) if skipping == 0 && matches!(edge, Edge::Open(_)) => {
+                    paragraph.push_str(text);
+                }
+                _ => {}
+            },
+        }
+    }
+    end_paragraph(&mut paragraph);
+    paragraphs.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use axum::response::Html as HtmlResponse;
+    use axum::routing::get;
+    use axum::Router;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::http::FetcherArgs;
+
+    #[test]
+    fn extracts_readable_text() {
+        let html = r#"<!DOCTYPE html>
+            <html><head><title>Ignored</title><style>p { color: red }</style></head>
+            <body>
+              <header><a href="/">Home</a></header>
+              <nav><ul><li>Menu</li></ul></nav>
+              <article>
+                <header><h1>The   article</h1></header>
+                <p>First <em>paragraph</em>,
+                   over two lines.</p>
+                <script>alert("no")</script>
+                <p>Second paragraph &amp; more.</p>
+                <aside>Related links</aside>
+              </article>
+              <footer>Copyright</footer>
+            </body></html>"#;
+        assert_eq!(
+            readable_text(html),
+            "The article\n\nFirst paragraph, over two lines.\n\nSecond paragraph & more."
+        );
+
+        let no_article = r#"<html><body><header>Site name</header>
+            <div>Some text<br>on two lines</div><form><label>Search</label></form></body></html>"#;
+        assert_eq!(readable_text(no_article), "Some text\n\non two lines");
+    }
+
+    async fn stand_in_site() -> anyhow::Result<SocketAddr> {
+        let app = Router::new()
+            .route(
+                "/page",
+                get(|| async {
+                    HtmlResponse("<html><body><main><p>Remember this.</p></main></body></html>")
+                }),
+            )
+            .route("/data", get(|| async { axum::Json(vec![1, 2, 3]) }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        Ok(addr)
+    }
+
+    #[tokio::test]
+    async fn archives_pages() -> TestResult {
+        let addr = stand_in_site().await?;
+        let dir = tempfile::tempdir()?;
+        let store = LocalArchiveArgs {
+            archive_pages: true,
+            archive_dir: Some(dir.path().to_owned()),
+        }
+        .store(None);
+        let fetcher = Fetcher::new(FetcherArgs {
+            fetch_allowed_networks: vec!["127.0.0.1/32".parse()?],
+            ..Default::default()
+        })?;
+        let archiver = LocalArchiver::new(fetcher, store.clone());
+
+        let url = Url::parse(&format!("http://{addr}/page"))?;
+        let url_id = lz_db_url_id();
+        let archive = archiver.archive_page(url_id, &url).await?;
+        assert_eq!(archive.retrieved_from, url);
+        assert_eq!(archive.text, "Remember this.");
+        assert_eq!(
+            String::from_utf8(store.get(&archive.text_sha256).await?)?,
+            "Remember this."
+        );
+        assert!(String::from_utf8(store.get(&archive.html_sha256).await?)?
+            .contains("<main><p>Remember this.</p></main>"));
+        assert_eq!(
+            store.path_for(&archive.html_sha256),
+            dir.path()
+                .join(&archive.html_sha256[..2])
+                .join(&archive.html_sha256)
+        );
+
+        // Archiving again stores nothing new:
+        let again = archiver.archive_page(url_id, &url).await?;
+        assert_eq!(again.html_sha256, archive.html_sha256);
+        let mut files = 0;
+        for prefix in std::fs::read_dir(dir.path())? {
+            files += std::fs::read_dir(prefix?.path())?.count();
+        }
+        assert_eq!(files, 2);
+
+        assert!(matches!(
+            archiver
+                .archive_page(url_id, &Url::parse(&format!("http://{addr}/data"))?)
+                .await,
+            Err(ArchiveError::NotHtml)
+        ));
+        assert_eq!(
+            store.get("../../etc/passwd").await.unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        Ok(())
+    }
+
+    /// Stored URL IDs can only come from the database; the archiver
+    /// doesn't care which one it gets.
+    fn lz_db_url_id() -> StoredUrlId {
+        serde_json::from_value(serde_json::json!(1)).unwrap()
+    }
+}
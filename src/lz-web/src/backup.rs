@@ -0,0 +1,44 @@
+//! Backing up the database while the web server runs.
+//!
+//! [`back_up_periodically`] writes a timestamped backup into a
+//! directory every so often, with
+//! [`Connection::backup_to_directory`], and removes old ones.
+
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use lz_db::Connection;
+
+/// Settings for backing up the database in the background.
+#[derive(clap::Args, Clone, Eq, PartialEq, Debug, Default)]
+pub struct BackupArgs {
+    /// Back up the database every this many seconds, in the background
+    #[clap(long, requires = "backup_dir", value_parser = clap::value_parser!(u64).range(1..))]
+    pub backup_every: Option<u64>,
+
+    /// Directory to write background backups to
+    #[clap(long)]
+    pub backup_dir: Option<PathBuf>,
+
+    /// How many background backups to keep; older ones are removed
+    #[clap(long)]
+    pub backup_keep: Option<NonZeroUsize>,
+}
+
+/// Back up the database into `dir` every `every`, keeping the `keep`
+/// most recent backups. This never returns.
+pub async fn back_up_periodically(
+    conn: &Connection,
+    dir: PathBuf,
+    keep: Option<NonZeroUsize>,
+    every: Duration,
+) {
+    loop {
+        match conn.backup_to_directory(&dir, keep).await {
+            Ok(path) => tracing::info!(path = %path.display(), "backed up the database"),
+            Err(error) => tracing::warn!(?error, "could not back up the database"),
+        }
+        tokio::time::sleep(every).await;
+    }
+}
@@ -10,8 +10,13 @@ use axum::http::header::ToStrError;
 use axum::http::request::Parts;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
-use lz_db::{IdType, ReadWrite};
+use lz_db::{IdType, ReadWrite, StoredUrlId};
 use sentry::User;
+use url::Url;
+
+use crate::archive::local::{LocalArchiveArgs, LocalArchiver};
+use crate::archive::ArchiveError;
+use crate::http::Fetcher;
 
 pub(crate) mod queries;
 
@@ -23,6 +28,9 @@ pub struct GlobalWebAppState {
     pool: lz_db::Connection,
     authentication_header_name: String,
     default_user_name: Option<String>,
+    fetcher: Fetcher,
+    archiver: LocalArchiver,
+    archive_new_bookmarks: bool,
 }
 
 impl GlobalWebAppState {
@@ -30,12 +38,50 @@ impl GlobalWebAppState {
         pool: lz_db::Connection,
         authentication_header_name: String,
         default_user_name: Option<String>,
+        fetcher: Fetcher,
+        archive: &LocalArchiveArgs,
     ) -> Self {
+        let archiver = LocalArchiver::new(fetcher.clone(), archive.store(pool.path()));
         Self {
             pool,
             authentication_header_name,
             default_user_name,
+            fetcher,
+            archiver,
+            archive_new_bookmarks: archive.archive_pages,
+        }
+    }
+
+    /// The fetcher that all requests share for retrieving web pages.
+    pub fn fetcher(&self) -> &Fetcher {
+        &self.fetcher
+    }
+
+    /// The archiver that keeps local copies of bookmarked pages.
+    pub fn archiver(&self) -> &LocalArchiver {
+        &self.archiver
+    }
+
+    /// Keep a local copy of a newly bookmarked page, if the server is
+    /// configured to. This happens in the background, so the request
+    /// that created the bookmark doesn't have to wait for it.
+    pub fn archive_new_bookmark(self: &Arc<Self>, user: &str, url_id: StoredUrlId, url: &Url) {
+        if !self.archive_new_bookmarks {
+            return;
         }
+        let (state, user, url) = (self.clone(), user.to_string(), url.clone());
+        tokio::spawn(async move {
+            let archived = async {
+                let archive = state.archiver.archive_page(url_id, &url).await?;
+                let mut txn = state.pool.begin_for_user(&user).await?;
+                txn.set_archive(&archive).await?;
+                txn.commit().await?;
+                Ok::<_, ArchiveError>(())
+            };
+            if let Err(error) = archived.await {
+                tracing::warn!(%url, %error, error_debug=?error, "could not archive page");
+            }
+        });
     }
 }
 
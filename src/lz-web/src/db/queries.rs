@@ -1,5 +1,6 @@
 use lz_db::{
-    AssociatedLink, BookmarkId, BookmarkSearch, ExistingBookmark, ExistingTag, TransactionMode,
+    AssociatedLink, BookmarkCursor, BookmarkId, BookmarkOrder, BookmarkSearch, ExistingBookmark,
+    ExistingTag, FullTextQuery, IdType as _, ListError, TransactionMode,
 };
 use serde::{Deserialize, Serialize};
 use utoipa::{ToResponse, ToSchema};
@@ -12,15 +13,16 @@ use super::DbTransaction;
 /// Pagination in `lz` works by getting the next page based on what
 /// the previous page's last element was, aka "cursor-based
 /// pagination". To that end, use the previous call's `nextCursor`
-/// parameter into this call's `cursor` parameter.
+/// parameter into this call's `cursor` parameter, and keep the order
+/// the same.
 #[derive(
     Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq, Hash, Validate, ToSchema,
 )]
 #[schema(default)]
 pub struct Pagination {
-    /// The last batch's last (oldest) bookmark ID
+    /// The previous batch's `next_cursor`, where this batch starts
     #[schema(example = None)]
-    pub cursor: Option<BookmarkId>,
+    pub cursor: Option<BookmarkCursor>,
 
     /// How many items to return
     #[schema(example = 50)]
@@ -29,25 +31,47 @@ pub struct Pagination {
 }
 
 pub struct ListResult {
-    pub next_cursor: Option<BookmarkId>,
+    pub next_cursor: Option<BookmarkCursor>,
     pub batch: Vec<AnnotatedBookmark>,
 }
 
 pub async fn list_bookmarks<M: TransactionMode>(
     txn: &mut DbTransaction<M>,
     query: &[BookmarkSearch],
+    order: BookmarkOrder,
     pagination: &Pagination,
-) -> Result<ListResult, sqlx::Error> {
+) -> Result<ListResult, ListError> {
     let per_page = pagination.per_page.unwrap_or(20);
     let user_id = txn.user().id;
-    let bms = txn
-        .list_bookmarks_matching(
+    let mut listed = txn
+        .list_bookmarks_in_order(
             &[&[BookmarkSearch::User { id: user_id }], query].concat(),
+            order,
             per_page,
-            pagination.cursor,
+            pagination.cursor.as_ref(),
         )
         .await?;
-    let (batch, next_cursor) = annotate_bookmarks(txn, &bms, per_page).await?;
+    let next_cursor = listed.get(usize::from(per_page)).map(|l| l.cursor.clone());
+    listed.truncate(usize::from(per_page));
+    let bms: Vec<ExistingBookmark> = listed.into_iter().map(|l| l.bookmark).collect();
+    let (mut batch, _) = annotate_bookmarks(txn, &bms, per_page).await?;
+
+    // If we searched for text, show the user where it matched:
+    let full_text: Vec<&str> = query
+        .iter()
+        .filter_map(|criterium| match criterium {
+            BookmarkSearch::FullText { query } => Some(query.0.as_str()),
+            _ => None,
+        })
+        .collect();
+    if !full_text.is_empty() {
+        let full_text = FullTextQuery(full_text.join(" "));
+        let ids: Vec<BookmarkId> = batch.iter().map(|abm| abm.bookmark.id).collect();
+        let mut snippets = txn.full_text_snippets(&full_text, ids).await?;
+        for abm in batch.iter_mut() {
+            abm.snippet = snippets.remove(&abm.bookmark.id);
+        }
+    }
     Ok(ListResult { next_cursor, batch })
 }
 
@@ -62,6 +86,21 @@ pub struct AnnotatedBookmark {
 
     /// Links associated with the bookmark
     pub associations: Vec<AssociatedLink>,
+
+    /// An excerpt of the bookmark's text that matched the full-text
+    /// search, if the query contained one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+/// Retrieve a single one of the user's bookmarks, with its tags and associations.
+pub async fn get_annotated_bookmark<M: TransactionMode>(
+    txn: &mut DbTransaction<M>,
+    id: BookmarkId,
+) -> Result<AnnotatedBookmark, sqlx::Error> {
+    let bookmark = txn.get_bookmark_by_id(id.id()).await?;
+    let (mut batch, _) = annotate_bookmarks(txn, &[bookmark], 1).await?;
+    batch.pop().ok_or(sqlx::Error::RowNotFound)
 }
 
 pub async fn annotate_bookmarks<M: TransactionMode>(
@@ -86,6 +125,7 @@ pub async fn annotate_bookmarks<M: TransactionMode>(
                 bookmark: bm.clone(),
                 tags,
                 associations: associations.remove(&id).unwrap_or_else(std::vec::Vec::new),
+                snippet: None,
             });
         } else {
             tracing::warn!(
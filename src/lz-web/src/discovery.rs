@@ -0,0 +1,426 @@
+//! Discussions of bookmarked pages on other sites.
+//!
+//! A [`DiscussionSite`] searches a site like Hacker News, Reddit or
+//! Lobsters for submissions of a URL; [`discover`] asks all of them,
+//! and [`record_discussions`] associates what they found with the
+//! bookmark, in a context naming the site.
+
+use async_trait::async_trait;
+use lz_db::{BookmarkId, Transaction};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use url::Url;
+
+use crate::http::{Fetcher, LookupError};
+
+/// The association context for discussions on Hacker News.
+pub const HACKER_NEWS_CONTEXT: &str = "hackernews";
+
+/// The association context for discussions on Reddit.
+pub const REDDIT_CONTEXT: &str = "reddit";
+
+/// The association context for discussions on Lobsters.
+pub const LOBSTERS_CONTEXT: &str = "lobsters";
+
+/// Errors that can occur when searching a discussion site
+#[derive(thiserror::Error, Debug)]
+pub enum DiscoveryError {
+    #[error(transparent)]
+    Lookup(#[from] LookupError),
+
+    #[error("invalid discussion URL")]
+    InvalidUrl(#[from] url::ParseError),
+
+    #[error("unexpected response from the discussion site: {0}")]
+    UnexpectedResponse(String),
+}
+
+/// Where to search for discussions.
+#[derive(clap::Args, Clone, Eq, PartialEq, Debug)]
+pub struct DiscoveryArgs {
+    /// Base URL of the Hacker News search API
+    #[clap(long, default_value = "https://hn.algolia.com/api/v1/")]
+    pub hacker_news_endpoint: Url,
+
+    /// Base URL of the Reddit API
+    #[clap(long, default_value = "https://www.reddit.com/")]
+    pub reddit_endpoint: Url,
+
+    /// Base URL of the Lobsters site
+    #[clap(long, default_value = "https://lobste.rs/")]
+    pub lobsters_endpoint: Url,
+}
+
+impl Default for DiscoveryArgs {
+    fn default() -> Self {
+        Self {
+            hacker_news_endpoint: Url::parse("https://hn.algolia.com/api/v1/").expect("valid URL"),
+            reddit_endpoint: Url::parse("https://www.reddit.com/").expect("valid URL"),
+            lobsters_endpoint: Url::parse("https://lobste.rs/").expect("valid URL"),
+        }
+    }
+}
+
+/// A site where people discuss links.
+#[async_trait]
+pub trait DiscussionSite: Send + Sync {
+    /// The association context that this site's discussions get recorded in.
+    fn context(&self) -> &'static str;
+
+    /// Find the discussions of `url` on this site.
+    async fn discussions_of(&self, url: &Url) -> Result<Vec<Url>, DiscoveryError>;
+}
+
+/// All the discussion sites that lz knows, at the configured endpoints.
+pub fn sites(fetcher: &Fetcher, args: &DiscoveryArgs) -> Vec<Box<dyn DiscussionSite>> {
+    vec![
+        Box::new(HackerNews {
+            fetcher: fetcher.clone(),
+            endpoint: args.hacker_news_endpoint.clone(),
+        }),
+        Box::new(Reddit {
+            fetcher: fetcher.clone(),
+            endpoint: args.reddit_endpoint.clone(),
+        }),
+        Box::new(Lobsters {
+            fetcher: fetcher.clone(),
+            endpoint: args.lobsters_endpoint.clone(),
+        }),
+    ]
+}
+
+/// A discussion of a bookmarked page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Discussion {
+    pub context: &'static str,
+    pub url: Url,
+}
+
+/// What searching all discussion sites for a URL turned up.
+#[derive(Debug, Default)]
+pub struct Discoveries {
+    pub discussions: Vec<Discussion>,
+
+    /// The sites that couldn't be searched, and why.
+    pub failures: Vec<(&'static str, DiscoveryError)>,
+}
+
+/// Search all `sites` for discussions of `url`. A site that fails
+/// doesn't keep the others from being searched.
+pub async fn discover(sites: &[Box<dyn DiscussionSite>], url: &Url) -> Discoveries {
+    let mut discoveries = Discoveries::default();
+    for site in sites {
+        match site.discussions_of(url).await {
+            Ok(urls) => discoveries
+                .discussions
+                .extend(urls.into_iter().map(|url| Discussion {
+                    context: site.context(),
+                    url,
+                })),
+            Err(e) => discoveries.failures.push((site.context(), e)),
+        }
+    }
+    discoveries
+}
+
+/// Associate discussions with a bookmark, returning how many of them
+/// weren't associated already.
+pub async fn record_discussions(
+    txn: &mut Transaction,
+    bookmark_id: BookmarkId,
+    discussions: &[Discussion],
+) -> Result<usize, sqlx::Error> {
+    let mut recorded = 0;
+    for discussion in discussions {
+        let url_id = txn.ensure_url(&discussion.url).await?;
+        match txn
+            .associate_bookmark_link(&bookmark_id, &url_id, Some(discussion.context))
+            .await
+        {
+            Ok(_) => recorded += 1,
+            // We already knew about this discussion:
+            Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(recorded)
+}
+
+/// Retrieve and parse a JSON API response.
+async fn get_json<T: for<'de> Deserialize<'de>>(
+    fetcher: &Fetcher,
+    url: &Url,
+) -> Result<T, DiscoveryError> {
+    let response = fetcher.get(url).await?;
+    let body = fetcher.read_body(response).await?;
+    serde_json::from_slice(&body).map_err(|e| DiscoveryError::UnexpectedResponse(e.to_string()))
+}
+
+/// [Hacker News](https://news.ycombinator.com/), searched through
+/// [its Algolia API](https://hn.algolia.com/api).
+struct HackerNews {
+    fetcher: Fetcher,
+    endpoint: Url,
+}
+
+#[derive(Deserialize, Debug)]
+struct HackerNewsResults {
+    hits: Vec<HackerNewsHit>,
+}
+
+#[derive(Deserialize, Debug)]
+struct HackerNewsHit {
+    #[serde(rename = "objectID")]
+    object_id: String,
+    url: Option<String>,
+}
+
+#[async_trait]
+impl DiscussionSite for HackerNews {
+    fn context(&self) -> &'static str {
+        HACKER_NEWS_CONTEXT
+    }
+
+    #[tracing::instrument(skip(self), err(Debug, level = tracing::Level::DEBUG))]
+    async fn discussions_of(&self, url: &Url) -> Result<Vec<Url>, DiscoveryError> {
+        let mut api_url = self.endpoint.join("search")?;
+        api_url
+            .query_pairs_mut()
+            .append_pair("query", url.as_str())
+            .append_pair("restrictSearchableAttributes", "url")
+            .append_pair("tags", "story");
+        let results: HackerNewsResults = get_json(&self.fetcher, &api_url).await?;
+        // The search is fuzzy, so keep only the submissions of this exact URL:
+        results
+            .hits
+            .into_iter()
+            .filter(|hit| hit.url.as_deref().and_then(|u| Url::parse(u).ok()).as_ref() == Some(url))
+            .map(|hit| {
+                let mut item = Url::parse("https://news.ycombinator.com/item")?;
+                item.query_pairs_mut().append_pair("id", &hit.object_id);
+                Ok(item)
+            })
+            .collect()
+    }
+}
+
+/// [Reddit](https://www.reddit.com/), searched through its
+/// `api/info` endpoint.
+struct Reddit {
+    fetcher: Fetcher,
+    endpoint: Url,
+}
+
+#[derive(Deserialize, Debug)]
+struct RedditListing {
+    data: RedditListingData,
+}
+
+#[derive(Deserialize, Debug)]
+struct RedditListingData {
+    children: Vec<RedditThing>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RedditThing {
+    data: RedditPost,
+}
+
+#[derive(Deserialize, Debug)]
+struct RedditPost {
+    permalink: String,
+}
+
+#[async_trait]
+impl DiscussionSite for Reddit {
+    fn context(&self) -> &'static str {
+        REDDIT_CONTEXT
+    }
+
+    #[tracing::instrument(skip(self), err(Debug, level = tracing::Level::DEBUG))]
+    async fn discussions_of(&self, url: &Url) -> Result<Vec<Url>, DiscoveryError> {
+        let mut api_url = self.endpoint.join("api/info.json")?;
+        api_url.query_pairs_mut().append_pair("url", url.as_str());
+        let listing: RedditListing = get_json(&self.fetcher, &api_url).await?;
+        let reddit = Url::parse("https://www.reddit.com/")?;
+        listing
+            .data
+            .children
+            .into_iter()
+            .map(|thing| Ok(reddit.join(&thing.data.permalink)?))
+            .collect()
+    }
+}
+
+/// [Lobsters](https://lobste.rs/), searched through its stories-by-URL
+/// listing.
+struct Lobsters {
+    fetcher: Fetcher,
+    endpoint: Url,
+}
+
+#[derive(Deserialize, Debug)]
+struct LobstersStory {
+    comments_url: Url,
+}
+
+#[async_trait]
+impl DiscussionSite for Lobsters {
+    fn context(&self) -> &'static str {
+        LOBSTERS_CONTEXT
+    }
+
+    #[tracing::instrument(skip(self), err(Debug, level = tracing::Level::DEBUG))]
+    async fn discussions_of(&self, url: &Url) -> Result<Vec<Url>, DiscoveryError> {
+        let mut api_url = self.endpoint.join("stories/url/all.json")?;
+        api_url.query_pairs_mut().append_pair("url", url.as_str());
+        match get_json::<Vec<LobstersStory>>(&self.fetcher, &api_url).await {
+            Ok(stories) => Ok(stories.into_iter().map(|s| s.comments_url).collect()),
+            // Lobsters answers "not found" for URLs that nobody submitted:
+            Err(DiscoveryError::Lookup(LookupError::Status {
+                status: StatusCode::NOT_FOUND,
+            })) => Ok(vec![]),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use axum::extract::Query;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use axum::routing::get;
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::http::FetcherArgs;
+    use crate::testing::{test_bookmark, test_db};
+
+    const DISCUSSED: &str = "https://example.com/discussed";
+
+    /// Stand-ins for the discussion sites, where only [`DISCUSSED`]
+    /// was submitted.
+    async fn stand_in_sites() -> anyhow::Result<SocketAddr> {
+        let app = Router::new()
+            .route(
+                "/hn/search",
+                get(|Query(query): Query<Value>| async move {
+                    // Fuzzy matches come back, too:
+                    let hits = if query["query"] == DISCUSSED {
+                        json!([
+                            {"objectID": "1234", "url": DISCUSSED},
+                            {"objectID": "5678", "url": "https://example.com/discussed/more"},
+                        ])
+                    } else {
+                        json!([])
+                    };
+                    Json(json!({ "hits": hits }))
+                }),
+            )
+            .route(
+                "/reddit/api/info.json",
+                get(|Query(query): Query<Value>| async move {
+                    let children = if query["url"] == DISCUSSED {
+                        json!([{"kind": "t3", "data": {"permalink": "/r/rust/comments/abc/discussed/"}}])
+                    } else {
+                        json!([])
+                    };
+                    Json(json!({"kind": "Listing", "data": {"children": children}}))
+                }),
+            )
+            .route(
+                "/lobsters/stories/url/all.json",
+                get(|Query(query): Query<Value>| async move {
+                    if query["url"] == DISCUSSED {
+                        Json(json!([{"short_id": "xyz", "comments_url": "https://lobste.rs/s/xyz/discussed"}]))
+                            .into_response()
+                    } else {
+                        StatusCode::NOT_FOUND.into_response()
+                    }
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        Ok(addr)
+    }
+
+    #[tokio::test]
+    async fn discovers_discussions() -> TestResult {
+        let addr = stand_in_sites().await?;
+        let fetcher = Fetcher::new(FetcherArgs {
+            fetch_allowed_networks: vec!["127.0.0.1/32".parse()?],
+            ..Default::default()
+        })?;
+        let sites = sites(
+            &fetcher,
+            &DiscoveryArgs {
+                hacker_news_endpoint: Url::parse(&format!("http://{addr}/hn/"))?,
+                reddit_endpoint: Url::parse(&format!("http://{addr}/reddit/"))?,
+                lobsters_endpoint: Url::parse(&format!("http://{addr}/lobsters/"))?,
+            },
+        );
+
+        let found = discover(&sites, &Url::parse(DISCUSSED)?).await;
+        assert!(found.failures.is_empty(), "{:?}", found.failures);
+        assert_eq!(
+            found
+                .discussions
+                .iter()
+                .map(|d| (d.context, d.url.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("hackernews", "https://news.ycombinator.com/item?id=1234"),
+                (
+                    "reddit",
+                    "https://www.reddit.com/r/rust/comments/abc/discussed/"
+                ),
+                ("lobsters", "https://lobste.rs/s/xyz/discussed"),
+            ]
+        );
+
+        let undiscussed = discover(&sites, &Url::parse("https://example.com/")?).await;
+        assert!(undiscussed.failures.is_empty());
+        assert!(undiscussed.discussions.is_empty());
+
+        // A site that can't be reached doesn't hide the others' results:
+        let mut broken = sites;
+        broken.push(Box::new(Reddit {
+            fetcher,
+            endpoint: Url::parse(&format!("http://{addr}/nowhere/"))?,
+        }));
+        let partial = discover(&broken, &Url::parse(DISCUSSED)?).await;
+        assert_eq!(partial.discussions.len(), 3);
+        assert_eq!(partial.failures.len(), 1);
+
+        let mut txn = test_db().await?.begin_for_user("alice").await?;
+        let bookmark = txn
+            .add_bookmark(test_bookmark(&Url::parse(DISCUSSED)?))
+            .await?;
+        assert_eq!(
+            record_discussions(&mut txn, bookmark.id, &found.discussions).await?,
+            3
+        );
+        // Recording the same discussions twice is fine:
+        assert_eq!(
+            record_discussions(&mut txn, bookmark.id, &found.discussions).await?,
+            0
+        );
+        let associations = txn.associated_links_on_bookmarks([bookmark.id]).await?;
+        let mut contexts: Vec<_> = associations[&bookmark.id]
+            .iter()
+            .map(|link| link.context.as_deref())
+            .collect();
+        contexts.sort();
+        assert_eq!(
+            contexts,
+            vec![Some("hackernews"), Some("lobsters"), Some("reddit")]
+        );
+        Ok(())
+    }
+}
@@ -1,76 +1,465 @@
 //! Functions for interacting with websites that we want to bookmark.
 
+use std::error::Error as _;
+use std::sync::Arc;
+use std::thread::LocalKey;
+use std::time::Duration;
 use std::{cell::RefCell, thread_local};
 
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
+use encoding_rs::Encoding;
+use ipnet::IpNet;
 use lz_db::{Bookmark, NoId};
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
+use reqwest::StatusCode;
 use scraper::{Html, Selector};
 
 use serde::Serialize;
 use url::Url;
 use utoipa::ToSchema;
 
+mod policy;
+pub use policy::{FetchPolicy, PolicyViolation};
+
 /// Errors that can occur when retrieving content from the web
 #[derive(thiserror::Error, Debug)]
 pub enum LookupError {
     #[error("could not retrieve link")]
     HttpError(#[from] reqwest::Error),
+
+    #[error("timed out retrieving link")]
+    Timeout,
+
+    #[error("more than {limit} redirects")]
+    TooManyRedirects { limit: usize },
+
+    #[error("response body is larger than {limit} bytes")]
+    BodyTooLarge { limit: usize },
+
+    #[error("server responded with {status}")]
+    Status { status: StatusCode },
+
+    #[error("not allowed to retrieve link: {0}")]
+    Forbidden(#[from] PolicyViolation),
+}
+
+/// What the redirect policy reports when it runs out of redirects.
+#[derive(thiserror::Error, Debug)]
+#[error("too many redirects")]
+struct TooManyRedirects;
+
+impl LookupError {
+    fn from_reqwest(error: reqwest::Error, settings: &FetcherArgs) -> Self {
+        // Policy violations from the resolver or the redirect policy
+        // are buried in the error's sources:
+        let mut source = error.source();
+        while let Some(inner) = source {
+            if let Some(violation) = inner.downcast_ref::<PolicyViolation>() {
+                return LookupError::Forbidden(violation.clone());
+            }
+            source = inner.source();
+        }
+        if error.is_timeout() {
+            LookupError::Timeout
+        } else if error.is_redirect() {
+            LookupError::TooManyRedirects {
+                limit: settings.fetch_max_redirects,
+            }
+        } else {
+            LookupError::HttpError(error)
+        }
+    }
+}
+
+const DEFAULT_USER_AGENT: &str = concat!("lz/", env!("CARGO_PKG_VERSION"));
+
+/// Settings for retrieving web pages
+#[derive(Clone, Eq, PartialEq, Debug, clap::Args)]
+pub struct FetcherArgs {
+    /// Seconds to wait for a web page to be retrieved, before giving up.
+    #[clap(long, default_value_t = 10)]
+    pub fetch_timeout_secs: u64,
+
+    /// Largest web page (in bytes) to read.
+    #[clap(long, default_value_t = 5 * 1024 * 1024)]
+    pub fetch_max_body_size: usize,
+
+    /// Number of redirects to follow when retrieving a web page.
+    #[clap(long, default_value_t = 10)]
+    pub fetch_max_redirects: usize,
+
+    /// User-Agent header to send when retrieving web pages.
+    #[clap(long, default_value = DEFAULT_USER_AGENT)]
+    pub fetch_user_agent: String,
+
+    /// URL schemes that web pages may be retrieved with.
+    #[clap(long, value_delimiter = ',', default_value = "http,https")]
+    pub fetch_allowed_schemes: Vec<String>,
+
+    /// Ports that web pages may be retrieved from. Any port, if none are given.
+    #[clap(long, value_delimiter = ',')]
+    pub fetch_allowed_ports: Vec<u16>,
+
+    /// Allow retrieving web pages from loopback, private, link-local
+    /// and other non-public addresses.
+    ///
+    /// Commands that run on the user's own machine may; `lz web` only
+    /// does with its `--fetch-allow-private-networks` flag.
+    #[clap(skip = true)]
+    pub fetch_allow_private_networks: bool,
+
+    /// Networks (in CIDR notation) that web pages may always be
+    /// retrieved from, even if they are private or denied.
+    #[clap(long, value_delimiter = ',')]
+    pub fetch_allowed_networks: Vec<IpNet>,
+
+    /// Networks (in CIDR notation) that web pages may never be
+    /// retrieved from, in addition to the non-public ones.
+    #[clap(long, value_delimiter = ',')]
+    pub fetch_denied_networks: Vec<IpNet>,
+}
+
+impl Default for FetcherArgs {
+    fn default() -> Self {
+        Self {
+            fetch_timeout_secs: 10,
+            fetch_max_body_size: 5 * 1024 * 1024,
+            fetch_max_redirects: 10,
+            fetch_user_agent: DEFAULT_USER_AGENT.to_string(),
+            fetch_allowed_schemes: vec!["http".to_string(), "https".to_string()],
+            fetch_allowed_ports: vec![],
+            fetch_allow_private_networks: false,
+            fetch_allowed_networks: vec![],
+            fetch_denied_networks: vec![],
+        }
+    }
+}
+
+/// Retrieves web pages, with the limits given in its [`FetcherArgs`].
+///
+/// Every URL that the fetcher visits, and every address it connects
+/// to, must be allowed by its [`FetchPolicy`].
+///
+/// The fetcher is cheap to clone, and clones share their connection pool.
+#[derive(Clone, Debug)]
+pub struct Fetcher {
+    client: reqwest::Client,
+    /// A client that doesn't follow redirects, for [`Fetcher::get_once`].
+    single_hop_client: reqwest::Client,
+    settings: FetcherArgs,
+    policy: Arc<FetchPolicy>,
+}
+
+impl Fetcher {
+    pub fn new(settings: FetcherArgs) -> Result<Self, LookupError> {
+        let policy = Arc::new(FetchPolicy::new(&settings));
+        let redirect_policy = {
+            let policy = policy.clone();
+            let max_redirects = settings.fetch_max_redirects;
+            reqwest::redirect::Policy::custom(move |attempt| {
+                if attempt.previous().len() > max_redirects {
+                    attempt.error(TooManyRedirects)
+                } else if let Err(violation) = policy.check_url(attempt.url()) {
+                    attempt.error(violation)
+                } else {
+                    attempt.follow()
+                }
+            })
+        };
+        let builder = || {
+            reqwest::Client::builder()
+                .timeout(Duration::from_secs(settings.fetch_timeout_secs))
+                .dns_resolver(Arc::new(policy::PolicyResolver {
+                    policy: policy.clone(),
+                }))
+                // A proxy would resolve host names without consulting our policy:
+                .no_proxy()
+        };
+        let client = builder().redirect(redirect_policy).build()?;
+        let single_hop_client = builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+        Ok(Self {
+            client,
+            single_hop_client,
+            settings,
+            policy,
+        })
+    }
+
+    /// Send a GET request for `url` (following redirects) and return
+    /// the response, if it was successful. Its body is not read yet.
+    pub async fn get(&self, url: &Url) -> Result<reqwest::Response, LookupError> {
+        self.policy.check_url(url)?;
+        let response = self
+            .client
+            .get(url.clone())
+            .header(USER_AGENT, &self.settings.fetch_user_agent)
+            .send()
+            .await
+            .map_err(|e| LookupError::from_reqwest(e, &self.settings))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(LookupError::Status { status });
+        }
+        Ok(response)
+    }
+
+    /// Send a GET request for `url` without following redirects, and
+    /// return the response whatever its status. Its body is not read yet.
+    pub async fn get_once(&self, url: &Url) -> Result<reqwest::Response, LookupError> {
+        self.policy.check_url(url)?;
+        self.single_hop_client
+            .get(url.clone())
+            .header(USER_AGENT, &self.settings.fetch_user_agent)
+            .send()
+            .await
+            .map_err(|e| LookupError::from_reqwest(e, &self.settings))
+    }
+
+    /// The number of redirects that the fetcher follows.
+    pub fn max_redirects(&self) -> usize {
+        self.settings.fetch_max_redirects
+    }
+
+    /// Read the body of a response, up to the configured size limit.
+    pub async fn read_body(&self, mut response: reqwest::Response) -> Result<Vec<u8>, LookupError> {
+        let limit = self.settings.fetch_max_body_size;
+        if response
+            .content_length()
+            .is_some_and(|length| length > limit as u64)
+        {
+            return Err(LookupError::BodyTooLarge { limit });
+        }
+        let mut body = vec![];
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| LookupError::from_reqwest(e, &self.settings))?
+        {
+            if body.len() + chunk.len() > limit {
+                return Err(LookupError::BodyTooLarge { limit });
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+
+    /// Retrieve a web page and return its text along with the URL
+    /// that it ended up being retrieved from.
+    ///
+    /// Responses that aren't HTML (or plain text) have no text that
+    /// we could use, so their body is not read and `None` is returned.
+    #[tracing::instrument(skip(self), err(Debug, level = tracing::Level::DEBUG))]
+    pub async fn fetch_html(&self, url: &Url) -> Result<(Url, Option<String>), LookupError> {
+        let response = self.get(url).await?;
+        let final_url = response.url().clone();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|ct| ct.to_str().ok())
+            .map(str::to_ascii_lowercase);
+        if let Some(content_type) = &content_type {
+            if !content_type.contains("html") && !content_type.starts_with("text/") {
+                tracing::debug!(%content_type, "not reading body of a non-HTML response");
+                return Ok((final_url, None));
+            }
+        }
+        let body = self.read_body(response).await?;
+        Ok((final_url, Some(decode(&body, content_type.as_deref()))))
+    }
+
+    /// Retrieves metadata about a link on the web.
+    pub async fn lookup_page(&self, url: &Url) -> Result<Metadata, LookupError> {
+        tracing::trace!(?url, "retrieving page");
+        match self.fetch_html(url).await? {
+            (final_url, Some(body)) => Ok(Metadata::from_html(&final_url, &body)),
+            (_, None) => Ok(Metadata::default()),
+        }
+    }
+}
+
+/// Decode a document, in the encoding given by its byte order mark,
+/// the `charset` of its content type or its `<meta charset>`, in that
+/// order. Anything else is assumed to be UTF-8.
+fn decode(body: &[u8], content_type: Option<&str>) -> String {
+    let charset_label = |text: &str| {
+        text.find("charset=").map(|start| {
+            text[start + "charset=".len()..]
+                .trim_start_matches(['"', '\''])
+                .split(|c: char| !(c.is_ascii_alphanumeric() || "-_:.".contains(c)))
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        })
+    };
+    let from_header = content_type
+        .and_then(charset_label)
+        .and_then(|label| Encoding::for_label(label.as_bytes()));
+    let from_meta = || {
+        // The HTML spec only looks at the first 1024 bytes:
+        let head = String::from_utf8_lossy(&body[..body.len().min(1024)]).to_ascii_lowercase();
+        charset_label(&head).and_then(|label| Encoding::for_label(label.as_bytes()))
+    };
+    let encoding = Encoding::for_bom(body)
+        .map(|(encoding, _)| encoding)
+        .or(from_header)
+        .or_else(from_meta)
+        .unwrap_or(encoding_rs::UTF_8);
+    encoding.decode_with_bom_removal(body).0.into_owned()
 }
 
-fn make_selector(selector: &str) -> Selector {
+pub(crate) fn make_selector(selector: &str) -> Selector {
     Selector::parse(selector).unwrap()
 }
 
 thread_local! {
     static TITLE: RefCell<Selector> = RefCell::new(make_selector("title"));
     static DESCRIPTION: RefCell<Selector> = RefCell::new(make_selector(r#"meta[name="description"]"#));
+    static OG_TITLE: RefCell<Selector> = RefCell::new(make_selector(r#"meta[property="og:title"]"#));
+    static OG_DESCRIPTION: RefCell<Selector> = RefCell::new(make_selector(r#"meta[property="og:description"]"#));
+    static OG_IMAGE: RefCell<Selector> = RefCell::new(make_selector(r#"meta[property="og:image"]"#));
+    static TWITTER_TITLE: RefCell<Selector> = RefCell::new(make_selector(r#"meta[name="twitter:title"], meta[property="twitter:title"]"#));
+    static TWITTER_DESCRIPTION: RefCell<Selector> = RefCell::new(make_selector(r#"meta[name="twitter:description"], meta[property="twitter:description"]"#));
+    static TWITTER_IMAGE: RefCell<Selector> = RefCell::new(make_selector(r#"meta[name="twitter:image"], meta[property="twitter:image"]"#));
+    static CANONICAL: RefCell<Selector> = RefCell::new(make_selector(r#"link[rel="canonical"][href]"#));
+    static FAVICON: RefCell<Selector> = RefCell::new(make_selector(r#"link[rel~="icon"][href]"#));
+    static AUTHOR: RefCell<Selector> = RefCell::new(make_selector(r#"meta[name="author"], meta[property="article:author"]"#));
+    static PUBLISHED: RefCell<Selector> = RefCell::new(make_selector(r#"meta[property="article:published_time"], meta[name="date"]"#));
 }
 
 /// Metadata retrieved from a URL
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, ToSchema)]
+#[derive(PartialEq, Eq, Clone, Debug, Default, Serialize, ToSchema)]
 pub struct Metadata {
-    /// The title of the document retrieved
+    /// The title of the document retrieved, from its `<title>` or
+    /// else its OpenGraph or Twitter card title. Empty if the page
+    /// has none of these.
     pub title: String,
 
-    /// A description (probably from a meta tag on HTML)
+    /// A description (from a meta tag, OpenGraph or Twitter card)
     pub description: Option<String>,
+
+    /// A preview image of the page (OpenGraph or Twitter card)
+    pub image: Option<Url>,
+
+    /// The URL that the page declares as its canonical one
+    pub canonical_url: Option<Url>,
+
+    /// The page's icon
+    pub favicon: Option<Url>,
+
+    /// The language that the page declares in `<html lang>`
+    pub language: Option<String>,
+
+    /// The page's author
+    pub author: Option<String>,
+
+    /// When the page was published
+    pub published_at: Option<DateTime<Utc>>,
 }
 
-/// Retrieves metadata about a link on the web.
-pub async fn lookup_page_from_web(url: &Url) -> Result<Metadata, LookupError> {
-    tracing::trace!(?url, "retrieving page");
-    let client = reqwest::Client::new();
-    let response = client.get(url.clone()).send().await?;
-    response.error_for_status_ref()?;
-    let Ok(body) = response.text().await else {
-        return Ok(Metadata {
-            title: "Untitled".to_string(),
-            description: None,
-        });
-    };
-    let doc = Html::parse_document(&body);
-    let root_ref = doc.root_element();
-    let found_title = TITLE.with_borrow(|title| root_ref.select(title).next());
-    let title = match found_title {
-        Some(el) => el.inner_html(),
-        None => "Untitled".to_string(),
-    };
-    let found_description =
-        DESCRIPTION.with_borrow(|description| root_ref.select(description).next());
-    let description = match found_description {
-        Some(el) => el
-            .value()
-            .attr("content")
-            .map(|meta_val| meta_val.to_string()),
-        None => None,
-    };
-    Ok(Metadata { title, description })
+/// The first matching element's text, with whitespace collapsed.
+fn element_text(doc: &Html, selector: &'static LocalKey<RefCell<Selector>>) -> Option<String> {
+    selector
+        .with_borrow(|sel| doc.select(sel).next())
+        .map(|el| el.text().collect::<Vec<_>>().join(" "))
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|text| !text.is_empty())
+}
+
+/// The first non-empty value of an attribute on a matching element.
+fn attribute(
+    doc: &Html,
+    selector: &'static LocalKey<RefCell<Selector>>,
+    attr: &str,
+) -> Option<String> {
+    selector.with_borrow(|sel| {
+        doc.select(sel)
+            .filter_map(|el| el.value().attr(attr))
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+            .map(String::from)
+    })
+}
+
+fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(date)
+        .map(|date| date.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|date| date.and_utc())
+        })
+}
+
+impl Metadata {
+    /// Extract metadata from an HTML document retrieved from `url`.
+    ///
+    /// Relative links in the document are resolved against `url`.
+    pub fn from_html(url: &Url, body: &str) -> Self {
+        let doc = Html::parse_document(body);
+        let link =
+            |selector, attr| attribute(&doc, selector, attr).and_then(|href| url.join(&href).ok());
+        Metadata {
+            title: element_text(&doc, &TITLE)
+                .or_else(|| attribute(&doc, &OG_TITLE, "content"))
+                .or_else(|| attribute(&doc, &TWITTER_TITLE, "content"))
+                .unwrap_or_default(),
+            description: attribute(&doc, &DESCRIPTION, "content")
+                .or_else(|| attribute(&doc, &OG_DESCRIPTION, "content"))
+                .or_else(|| attribute(&doc, &TWITTER_DESCRIPTION, "content")),
+            image: link(&OG_IMAGE, "content").or_else(|| link(&TWITTER_IMAGE, "content")),
+            canonical_url: link(&CANONICAL, "href"),
+            favicon: link(&FAVICON, "href"),
+            language: doc
+                .root_element()
+                .value()
+                .attr("lang")
+                .map(str::trim)
+                .filter(|lang| !lang.is_empty())
+                .map(String::from),
+            author: attribute(&doc, &AUTHOR, "content"),
+            published_at: attribute(&doc, &PUBLISHED, "content").and_then(|d| parse_date(&d)),
+        }
+    }
+
+    /// Links that the page refers to and that are worth keeping
+    /// around with a bookmark for `url`, along with the association
+    /// context to store them under.
+    pub fn associated_links<'a>(&'a self, url: &'a Url) -> Vec<(&'static str, &'a Url)> {
+        let mut links: Vec<(&'static str, &Url)> = vec![];
+        let candidates = [
+            ("canonical", self.canonical_url.as_ref()),
+            ("image", self.image.as_ref()),
+            ("icon", self.favicon.as_ref()),
+        ];
+        for (context, link) in candidates {
+            if let Some(link) = link {
+                // Bookmarks can be associated with each link only once:
+                if link != url && links.iter().all(|(_, seen)| *seen != link) {
+                    links.push((context, link));
+                }
+            }
+        }
+        links
+    }
 }
 
 /// Retrieve metadata for a link and pre-fill a [Bookmark] structure
-pub async fn lookup_bookmark_from_web(url: &Url) -> Result<Bookmark<NoId, NoId>, LookupError> {
+///
+/// The metadata is returned alongside the bookmark, so that callers
+/// can store its [associated links][Metadata::associated_links].
+pub async fn lookup_bookmark_from_web(
+    fetcher: &Fetcher,
+    url: &Url,
+) -> Result<(Bookmark<NoId, NoId>, Metadata), LookupError> {
     let now = Utc::now();
-    let Metadata { title, description } = lookup_page_from_web(url).await?;
+    let metadata = fetcher.lookup_page(url).await?;
+    let Metadata {
+        title, description, ..
+    } = metadata.clone();
     let to_add = Bookmark {
         accessed_at: Some(now),
         created_at: now,
@@ -80,7 +469,11 @@ pub async fn lookup_bookmark_from_web(url: &Url) -> Result<Bookmark<NoId, NoId>,
         modified_at: None,
         notes: None,
         shared: true,
-        title: title.clone(),
+        title: if title.is_empty() {
+            url.to_string()
+        } else {
+            title.clone()
+        },
         unread: true,
         url: url.clone(),
         user_id: NoId,
@@ -91,5 +484,243 @@ pub async fn lookup_bookmark_from_web(url: &Url) -> Result<Bookmark<NoId, NoId>,
         },
         website_description: description.clone(),
     };
-    Ok(to_add)
+    Ok((to_add, metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use axum::http::{header, HeaderMap};
+    use axum::response::{IntoResponse, Redirect};
+    use axum::routing::get;
+    use axum::Router;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Serve a few pages that misbehave in various ways on a local port.
+    async fn stand_in_server() -> anyhow::Result<SocketAddr> {
+        let app = Router::new()
+            .route(
+                "/latin1",
+                get(|| async {
+                    let mut body = b"<html><head><meta charset=\"iso-8859-1\"><title>Caf".to_vec();
+                    body.extend_from_slice(b"\xe9</title></head></html>");
+                    ([(header::CONTENT_TYPE, "text/html")], body)
+                }),
+            )
+            .route(
+                "/user-agent",
+                get(|headers: HeaderMap| async move {
+                    let ua = headers[header::USER_AGENT].to_str().unwrap().to_string();
+                    axum::response::Html(format!("<title>{ua}</title>"))
+                }),
+            )
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    axum::response::Html("<title>Slow</title>")
+                }),
+            )
+            .route(
+                "/huge",
+                get(|| async { axum::response::Html("<title>x</title>".repeat(1000)) }),
+            )
+            .route("/loop", get(|| async { Redirect::temporary("/loop") }))
+            .route(
+                "/redirect",
+                get(|| async { Redirect::temporary("/latin1") }),
+            )
+            .route(
+                "/cloud-metadata",
+                get(|| async { Redirect::temporary("http://169.254.169.254/latest/meta-data/") }),
+            )
+            .route(
+                "/pdf",
+                get(|| async { ([(header::CONTENT_TYPE, "application/pdf")], "%PDF-1.4") }),
+            )
+            .route(
+                "/missing",
+                get(|| async { axum::http::StatusCode::NOT_FOUND.into_response() }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        Ok(addr)
+    }
+
+    fn fetcher() -> Fetcher {
+        Fetcher::new(FetcherArgs {
+            fetch_timeout_secs: 1,
+            fetch_max_body_size: 4096,
+            fetch_max_redirects: 3,
+            fetch_user_agent: "lz-test".to_string(),
+            fetch_allowed_networks: vec!["127.0.0.1/32".parse().unwrap()],
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetching() -> TestResult {
+        let addr = stand_in_server().await?;
+        let url = |path: &str| Url::parse(&format!("http://{addr}{path}")).unwrap();
+        let fetcher = fetcher();
+
+        assert_eq!(fetcher.lookup_page(&url("/latin1")).await?.title, "Café");
+        assert_eq!(
+            fetcher.lookup_page(&url("/user-agent")).await?.title,
+            "lz-test"
+        );
+        let (final_url, _) = fetcher.fetch_html(&url("/redirect")).await?;
+        assert_eq!(final_url, url("/latin1"));
+        assert_eq!(fetcher.fetch_html(&url("/pdf")).await?, (url("/pdf"), None));
+
+        let failure = |path: &'static str| {
+            let fetcher = fetcher.clone();
+            let url = url(path);
+            async move { fetcher.lookup_page(&url).await.unwrap_err() }
+        };
+        assert!(matches!(failure("/slow").await, LookupError::Timeout));
+        assert!(matches!(
+            failure("/huge").await,
+            LookupError::BodyTooLarge { limit: 4096 }
+        ));
+        assert!(matches!(
+            failure("/loop").await,
+            LookupError::TooManyRedirects { limit: 3 }
+        ));
+        assert!(matches!(
+            failure("/missing").await,
+            LookupError::Status {
+                status: StatusCode::NOT_FOUND
+            }
+        ));
+        assert!(matches!(
+            failure("/cloud-metadata").await,
+            LookupError::Forbidden(PolicyViolation::Address { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn default_policy_refuses_local_pages() -> TestResult {
+        let addr = stand_in_server().await?;
+        let fetcher = Fetcher::new(Default::default())?;
+        for url in [
+            format!("http://{addr}/latin1"),
+            format!("http://localhost:{}/latin1", addr.port()),
+        ] {
+            let result = fetcher.lookup_page(&Url::parse(&url)?).await;
+            assert!(
+                matches!(
+                    result,
+                    Err(LookupError::Forbidden(PolicyViolation::Address { .. }))
+                ),
+                "{url}: {result:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decoding() {
+        assert_eq!(
+            decode(b"caf\xe9", Some("text/html; charset=ISO-8859-1")),
+            "café"
+        );
+        assert_eq!(
+            decode(
+                b"\xef\xbb\xbfcaf\xc3\xa9",
+                Some("text/html; charset=latin1")
+            ),
+            "café"
+        );
+        assert_eq!(decode("café".as_bytes(), None), "café");
+    }
+
+    #[test]
+    fn from_html() {
+        let url = Url::parse("https://example.com/posts/1?utm_source=x").unwrap();
+        let metadata = Metadata::from_html(
+            &url,
+            r#"<!DOCTYPE html>
+            <html lang="en-GB">
+            <head>
+              <title>
+                Cats &amp; dogs
+              </title>
+              <meta name="description" content="All about pets">
+              <meta property="og:image" content="/img/cat.png">
+              <meta name="author" content="Jane &quot;JD&quot; Doe">
+              <meta property="article:published_time" content="2024-02-03T04:05:06+01:00">
+              <link rel="canonical" href="https://example.com/posts/1">
+              <link rel="shortcut icon" href="/favicon.ico">
+            </head>
+            <body></body>
+            </html>"#,
+        );
+        assert_eq!(
+            metadata,
+            Metadata {
+                title: "Cats & dogs".to_string(),
+                description: Some("All about pets".to_string()),
+                image: Some(Url::parse("https://example.com/img/cat.png").unwrap()),
+                canonical_url: Some(Url::parse("https://example.com/posts/1").unwrap()),
+                favicon: Some(Url::parse("https://example.com/favicon.ico").unwrap()),
+                language: Some("en-GB".to_string()),
+                author: Some(r#"Jane "JD" Doe"#.to_string()),
+                published_at: Some("2024-02-03T03:05:06Z".parse().unwrap()),
+            }
+        );
+        assert_eq!(
+            metadata
+                .associated_links(&url)
+                .into_iter()
+                .map(|(context, link)| (context, link.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("canonical", "https://example.com/posts/1"),
+                ("image", "https://example.com/img/cat.png"),
+                ("icon", "https://example.com/favicon.ico"),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_page_app_fallbacks() {
+        let url = Url::parse("https://app.example.com/").unwrap();
+        let metadata = Metadata::from_html(
+            &url,
+            r#"<html><head>
+              <title></title>
+              <meta property="og:title" content="The App">
+              <meta name="twitter:title" content="Not this one">
+              <meta name="twitter:description" content="Does things">
+              <meta name="twitter:image" content="https://cdn.example.com/card.png">
+              <meta name="date" content="2023-12-24">
+              <link rel="canonical" href="/">
+            </head><body><div id="root"></div></body></html>"#,
+        );
+        assert_eq!(metadata.title, "The App");
+        assert_eq!(metadata.description.as_deref(), Some("Does things"));
+        assert_eq!(
+            metadata.image.as_ref().map(Url::as_str),
+            Some("https://cdn.example.com/card.png")
+        );
+        assert_eq!(metadata.language, None);
+        assert_eq!(
+            metadata.published_at,
+            Some("2023-12-24T00:00:00Z".parse().unwrap())
+        );
+        // The canonical URL is the bookmarked one, so it's not worth keeping:
+        assert_eq!(
+            metadata.associated_links(&url),
+            vec![("image", metadata.image.as_ref().unwrap())]
+        );
+
+        assert_eq!(Metadata::from_html(&url, "<p>hi").title, "");
+    }
 }
@@ -0,0 +1,249 @@
+//! Restrictions on what the server may retrieve on behalf of users.
+//!
+//! Without these, anyone who can bookmark a link could make the lz
+//! server talk to services that are only reachable from it: The
+//! loopback interface, the private network it runs in, or a cloud
+//! provider's metadata endpoint. The [`FetchPolicy`] gets checked
+//! for every URL (including each redirect target), and for every
+//! address that a URL's host name resolves to.
+
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+
+use ipnet::IpNet;
+use lazy_static::lazy_static;
+use url::Url;
+
+use super::FetcherArgs;
+
+lazy_static! {
+    /// Networks that are not on the public internet: Loopback,
+    /// private, link-local, shared, multicast and reserved ranges.
+    static ref NON_PUBLIC_NETWORKS: Vec<IpNet> = [
+        "0.0.0.0/8",
+        "10.0.0.0/8",
+        "100.64.0.0/10",
+        "127.0.0.0/8",
+        "169.254.0.0/16",
+        "172.16.0.0/12",
+        "192.0.0.0/24",
+        "192.0.2.0/24",
+        "192.168.0.0/16",
+        "198.18.0.0/15",
+        "198.51.100.0/24",
+        "203.0.113.0/24",
+        "224.0.0.0/4",
+        "240.0.0.0/4",
+        "::/128",
+        "::1/128",
+        "64:ff9b::/96",
+        "100::/64",
+        "2001:db8::/32",
+        // 6to4, which embeds (possibly private) IPv4 addresses:
+        "2002::/16",
+        "fc00::/7",
+        "fe80::/10",
+        "ff00::/8",
+    ]
+    .iter()
+    .map(|net| net.parse().expect("valid network"))
+    .collect();
+}
+
+/// A reason for refusing to retrieve a URL.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    #[error("URL scheme {scheme:?} is not allowed")]
+    Scheme { scheme: String },
+
+    #[error("port {port} is not allowed")]
+    Port { port: u16 },
+
+    #[error("URL has no host")]
+    NoHost,
+
+    #[error("{host} is at {address}, which is not allowed")]
+    Address { host: String, address: IpAddr },
+}
+
+/// Which URLs and addresses the server may retrieve pages from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FetchPolicy {
+    schemes: Vec<String>,
+    ports: Vec<u16>,
+    allow_private_networks: bool,
+    allowed_networks: Vec<IpNet>,
+    denied_networks: Vec<IpNet>,
+}
+
+impl FetchPolicy {
+    pub fn new(settings: &FetcherArgs) -> Self {
+        Self {
+            schemes: settings
+                .fetch_allowed_schemes
+                .iter()
+                .map(|s| s.to_ascii_lowercase())
+                .collect(),
+            ports: settings.fetch_allowed_ports.clone(),
+            allow_private_networks: settings.fetch_allow_private_networks,
+            allowed_networks: settings.fetch_allowed_networks.clone(),
+            denied_networks: settings.fetch_denied_networks.clone(),
+        }
+    }
+
+    /// Check the parts of a URL that are known without resolving its
+    /// host: The scheme, the port and the host, if it is an IP address.
+    pub fn check_url(&self, url: &Url) -> Result<(), PolicyViolation> {
+        if !self.schemes.iter().any(|s| s == url.scheme()) {
+            return Err(PolicyViolation::Scheme {
+                scheme: url.scheme().to_string(),
+            });
+        }
+        if let Some(port) = url.port_or_known_default() {
+            if !self.ports.is_empty() && !self.ports.contains(&port) {
+                return Err(PolicyViolation::Port { port });
+            }
+        }
+        match url.host() {
+            None => Err(PolicyViolation::NoHost),
+            Some(url::Host::Domain(_)) => Ok(()),
+            Some(url::Host::Ipv4(ip)) => self.check_address(&ip.to_string(), ip.into()),
+            Some(url::Host::Ipv6(ip)) => self.check_address(&ip.to_string(), ip.into()),
+        }
+    }
+
+    /// Check an address that a host resolved to.
+    ///
+    /// Explicitly allowed networks take precedence over denied ones,
+    /// which take precedence over the non-public networks.
+    pub fn check_address(&self, host: &str, address: IpAddr) -> Result<(), PolicyViolation> {
+        // IPv4 addresses can hide in IPv6 ones:
+        let address = match address {
+            IpAddr::V6(v6) => v6
+                .to_ipv4_mapped()
+                .map(IpAddr::V4)
+                .unwrap_or(IpAddr::V6(v6)),
+            v4 => v4,
+        };
+        let in_any = |nets: &[IpNet]| nets.iter().any(|net| net.contains(&address));
+        let allowed = in_any(&self.allowed_networks)
+            || !(in_any(&self.denied_networks)
+                || (!self.allow_private_networks && in_any(&NON_PUBLIC_NETWORKS)));
+        if allowed {
+            Ok(())
+        } else {
+            Err(PolicyViolation::Address {
+                host: host.to_string(),
+                address,
+            })
+        }
+    }
+}
+
+/// Resolves host names for reqwest, leaving out the addresses that
+/// the [`FetchPolicy`] doesn't allow.
+pub(super) struct PolicyResolver {
+    pub(super) policy: Arc<FetchPolicy>,
+}
+
+impl reqwest::dns::Resolve for PolicyResolver {
+    fn resolve(&self, name: hyper::client::connect::dns::Name) -> reqwest::dns::Resolving {
+        let policy = self.policy.clone();
+        let host = name.as_str().to_string();
+        let resolving: Pin<Box<dyn Future<Output = _> + Send>> = Box::pin(async move {
+            let addresses: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            let mut violation = None;
+            let allowed: Vec<SocketAddr> = addresses
+                .into_iter()
+                .filter(|addr| match policy.check_address(&host, addr.ip()) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        tracing::debug!(%host, address=%addr.ip(), "skipping disallowed address");
+                        violation = Some(e);
+                        false
+                    }
+                })
+                .collect();
+            match (allowed.is_empty(), violation) {
+                (true, Some(violation)) => Err(violation.into()),
+                _ => Ok(Box::new(allowed.into_iter()) as reqwest::dns::Addrs),
+            }
+        });
+        resolving
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy() {
+        let policy = FetchPolicy::new(&FetcherArgs::default());
+        let check = |url: &str| policy.check_url(&Url::parse(url).unwrap());
+        assert_eq!(check("https://example.com/"), Ok(()));
+        assert_eq!(check("http://93.184.215.14:8080/"), Ok(()));
+        assert_eq!(
+            check("ftp://example.com/"),
+            Err(PolicyViolation::Scheme {
+                scheme: "ftp".to_string()
+            })
+        );
+        for denied in [
+            "http://127.0.0.1/",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://10.1.2.3/",
+            "http://[::1]/",
+            "http://[::ffff:192.168.0.1]/",
+            "http://[2002:c0a8:1::1]/",
+            "http://[fd00::1]/",
+            "http://0.0.0.0/",
+        ] {
+            assert!(
+                matches!(check(denied), Err(PolicyViolation::Address { .. })),
+                "{denied} should be denied"
+            );
+        }
+    }
+
+    #[test]
+    fn command_line_policy() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[clap(flatten)]
+            fetcher: FetcherArgs,
+        }
+        let policy = FetchPolicy::new(&<Cli as clap::Parser>::parse_from(["lz"]).fetcher);
+        let check = |url: &str| policy.check_url(&Url::parse(url).unwrap());
+        assert_eq!(check("http://localhost:8080/"), Ok(()));
+        assert_eq!(check("http://127.0.0.1/"), Ok(()));
+        assert_eq!(check("http://10.1.2.3/"), Ok(()));
+    }
+
+    #[test]
+    fn configured_policy() {
+        let policy = FetchPolicy::new(&FetcherArgs {
+            fetch_allowed_ports: vec![443],
+            fetch_allowed_networks: vec!["10.0.0.0/24".parse().unwrap()],
+            fetch_denied_networks: vec!["93.184.0.0/16".parse().unwrap()],
+            ..Default::default()
+        });
+        let check = |url: &str| policy.check_url(&Url::parse(url).unwrap());
+        assert_eq!(check("https://10.0.0.5/"), Ok(()));
+        assert!(matches!(
+            check("https://10.0.1.5/"),
+            Err(PolicyViolation::Address { .. })
+        ));
+        assert!(matches!(
+            check("https://93.184.215.14/"),
+            Err(PolicyViolation::Address { .. })
+        ));
+        assert_eq!(
+            check("http://example.com/"),
+            Err(PolicyViolation::Port { port: 80 })
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::io;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::http::header;
 use axum::http::Uri;
@@ -16,11 +17,18 @@ use utoipa_redoc::{Redoc, Servable as _};
 use utoipa_swagger_ui::SwaggerUi;
 
 pub mod api;
+pub mod archive;
+pub mod backup;
 pub mod db;
+pub mod discovery;
 pub mod http;
+pub mod link_check;
 
 pub mod export_openapi;
 
+#[cfg(test)]
+mod testing;
+
 /// The lz tagged bookmark manager web server
 #[derive(Clone, Eq, PartialEq, Debug, Parser)]
 pub struct Args {
@@ -35,15 +43,80 @@ pub struct Args {
     /// Address to listen on.
     #[clap(long, default_value = "0.0.0.0:8000")]
     listen_on: SocketAddr,
+
+    #[clap(flatten)]
+    fetcher: http::FetcherArgs,
+
+    /// Allow retrieving web pages from loopback, private, link-local
+    /// and other non-public addresses.
+    #[clap(long)]
+    fetch_allow_private_networks: bool,
+
+    #[clap(flatten)]
+    archive: archive::local::LocalArchiveArgs,
+
+    #[clap(flatten)]
+    link_check: link_check::LinkCheckArgs,
+
+    #[clap(flatten)]
+    backup: backup::BackupArgs,
+}
+
+impl Args {
+    /// Settings for the server's fetcher. Unlike on the command line,
+    /// it may only retrieve pages from non-public addresses when told to.
+    fn fetcher_args(&self) -> http::FetcherArgs {
+        http::FetcherArgs {
+            fetch_allow_private_networks: self.fetch_allow_private_networks,
+            ..self.fetcher.clone()
+        }
+    }
 }
 
 pub async fn run(pool: lz_db::Connection, args: &Args) -> anyhow::Result<()> {
     init_observability(args)?;
 
+    // Serving a schema that we don't know could corrupt it:
+    let status = pool.schema_status().await?;
+    status.check_not_newer()?;
+    if status.pending().next().is_some() {
+        tracing::warn!("the database has pending migrations; run `lz db migrate` to apply them");
+    }
+
+    if let Some(every) = args.link_check.check_links_every {
+        // The checks get their own connection, so they don't compete
+        // with requests for the pool:
+        let conn = match pool.path() {
+            Some(path) => lz_db::Connection::from_path_unmigrated(path).await?,
+            None => anyhow::bail!("checking links in the background needs a database file"),
+        };
+        let fetcher = http::Fetcher::new(args.fetcher_args())?;
+        let delay = Duration::from_millis(args.link_check.check_links_delay);
+        tokio::spawn(async move {
+            link_check::check_links_periodically(&conn, &fetcher, Duration::from_secs(every), delay)
+                .await
+        });
+    }
+
+    if let (Some(every), Some(dir)) = (args.backup.backup_every, &args.backup.backup_dir) {
+        // Backups read the whole database, so they get their own
+        // connection too:
+        let conn = match pool.path() {
+            Some(path) => lz_db::Connection::from_path_unmigrated(path).await?,
+            None => anyhow::bail!("backing up in the background needs a database file"),
+        };
+        let (dir, keep) = (dir.clone(), args.backup.backup_keep);
+        tokio::spawn(async move {
+            backup::back_up_periodically(&conn, dir, keep, Duration::from_secs(every)).await
+        });
+    }
+
     let db_conns = Arc::new(GlobalWebAppState::new(
         pool,
         args.authentication_header_name.to_owned(),
         args.default_user_name.to_owned(),
+        http::Fetcher::new(args.fetcher_args())?,
+        &args.archive,
     ));
     let api_router = api::router();
     let app = Router::new()
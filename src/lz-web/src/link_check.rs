@@ -0,0 +1,405 @@
+//! Checking whether bookmarked links still work.
+//!
+//! [`check_link`] retrieves a stored URL, following redirects one at
+//! a time so it can tell permanent redirects from temporary ones.
+//! [`check_all_links`] does that for every stored URL and records the
+//! results, and [`fix_redirects`] updates a user's bookmarks whose
+//! links moved for good.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use lz_db::{
+    BookmarkSearch, Connection, ExistingBookmark, LinkCheck, LinkStatus, StoredUrlId, Transaction,
+};
+use reqwest::header::LOCATION;
+use reqwest::StatusCode;
+use url::Url;
+
+use crate::http::{Fetcher, LookupError};
+
+/// Settings for checking links in the background.
+#[derive(clap::Args, Clone, Eq, PartialEq, Debug, Default)]
+pub struct LinkCheckArgs {
+    /// Check all stored links every this many seconds, in the background
+    #[clap(long)]
+    pub check_links_every: Option<u64>,
+
+    /// Milliseconds to wait between checking two links
+    #[clap(long, default_value_t = 1000)]
+    pub check_links_delay: u64,
+}
+
+/// Check whether a stored URL still works.
+///
+/// Redirects are followed (up to the fetcher's limit, and as far as
+/// its policy allows); if the URL itself permanently redirects, the
+/// check records where the redirects ended up.
+#[tracing::instrument(skip(fetcher))]
+pub async fn check_link(fetcher: &Fetcher, url_id: StoredUrlId, url: &Url) -> LinkCheck {
+    let mut check = LinkCheck {
+        url_id,
+        checked_at: Utc::now(),
+        status: LinkStatus::Failed,
+        http_status: None,
+        redirected_to: None,
+        error: None,
+    };
+    let mut current = url.clone();
+    let mut moved_permanently = false;
+    for hop in 0..=fetcher.max_redirects() {
+        let response = match fetcher.get_once(&current).await {
+            Ok(response) => response,
+            Err(error) => {
+                check.status = status_of_error(&error);
+                check.error = Some(error_message(&error));
+                return check;
+            }
+        };
+        let status = response.status();
+        check.http_status = Some(status.as_u16());
+        if status.is_redirection() {
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .and_then(|location| current.join(location).ok());
+            if let Some(location) = location {
+                // Only the URL's own redirect says whether it moved; the
+                // hops after that are the new location's business:
+                if hop == 0 {
+                    moved_permanently = matches!(
+                        status,
+                        StatusCode::MOVED_PERMANENTLY | StatusCode::PERMANENT_REDIRECT
+                    );
+                }
+                current = location;
+                continue;
+            }
+        }
+        if moved_permanently && current != *url {
+            check.redirected_to = Some(current);
+        }
+        check.status = match status {
+            StatusCode::NOT_FOUND | StatusCode::GONE => LinkStatus::NotFound,
+            status if !status.is_success() => {
+                check.error = Some(format!("server responded with {status}"));
+                LinkStatus::Failed
+            }
+            _ if check.redirected_to.is_some() => LinkStatus::Redirected,
+            _ => LinkStatus::Ok,
+        };
+        return check;
+    }
+    check.error = Some(error_message(&LookupError::TooManyRedirects {
+        limit: fetcher.max_redirects(),
+    }));
+    check
+}
+
+fn status_of_error(error: &LookupError) -> LinkStatus {
+    match error {
+        LookupError::Timeout => LinkStatus::Unreachable,
+        // Name resolution failures are connection errors, too:
+        LookupError::HttpError(error) if error.is_connect() => LinkStatus::Unreachable,
+        _ => LinkStatus::Failed,
+    }
+}
+
+/// The error's message, along with those of its sources (which is
+/// where reqwest keeps the interesting parts).
+fn error_message(error: &LookupError) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(inner) = source {
+        message.push_str(": ");
+        message.push_str(&inner.to_string());
+        source = inner.source();
+    }
+    message
+}
+
+/// What checking all links found.
+#[derive(Debug, Default)]
+pub struct LinkCheckReport {
+    /// The number of links checked
+    pub checked: usize,
+
+    /// The links that didn't check out as [`LinkStatus::Ok`], and
+    /// what was found
+    pub problems: Vec<(Url, LinkCheck)>,
+}
+
+/// Check every stored URL and record the results, waiting `delay`
+/// between two checks.
+///
+/// The database isn't kept busy while links are being retrieved:
+/// URLs are listed in batches, and each result is recorded by itself.
+pub async fn check_all_links(
+    conn: &Connection,
+    fetcher: &Fetcher,
+    delay: Duration,
+) -> Result<LinkCheckReport, sqlx::Error> {
+    let mut report = LinkCheckReport::default();
+    let mut last_seen = None;
+    loop {
+        let urls = conn.list_urls(last_seen, 100).await?;
+        let Some(last) = urls.last() else {
+            return Ok(report);
+        };
+        last_seen = Some(last.id);
+        for url in urls {
+            if report.checked > 0 {
+                tokio::time::sleep(delay).await;
+            }
+            let check = check_link(fetcher, url.id, &url.link).await;
+            conn.record_link_check(&check).await?;
+            report.checked += 1;
+            if check.status != LinkStatus::Ok {
+                report.problems.push((url.link, check));
+            }
+        }
+    }
+}
+
+/// Check all links every `every`, for as long as the server runs.
+pub async fn check_links_periodically(
+    conn: &Connection,
+    fetcher: &Fetcher,
+    every: Duration,
+    delay: Duration,
+) {
+    loop {
+        match check_all_links(conn, fetcher, delay).await {
+            Ok(report) => tracing::info!(
+                checked = report.checked,
+                problems = report.problems.len(),
+                "checked links"
+            ),
+            Err(error) => tracing::warn!(?error, "could not check links"),
+        }
+        tokio::time::sleep(every).await;
+    }
+}
+
+/// What happened to a bookmark whose link permanently redirects.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RedirectFix {
+    /// The bookmark now points to the new location; its old URL is
+    /// associated with it in the
+    /// [`REDIRECTED_FROM_CONTEXT`](lz_db::REDIRECTED_FROM_CONTEXT).
+    Updated { from: Url, to: Url },
+
+    /// The user already has a bookmark of the new location, so the
+    /// bookmark was left alone.
+    AlreadyBookmarked { from: Url, to: Url },
+}
+
+/// Update all of the user's bookmarks whose links were found to
+/// permanently redirect, so they point to where the redirects lead.
+pub async fn fix_redirects(txn: &mut Transaction) -> Result<Vec<RedirectFix>, sqlx::Error> {
+    let criteria = [
+        BookmarkSearch::User { id: txn.user().id },
+        BookmarkSearch::LinkStatus {
+            link_status: LinkStatus::Redirected,
+        },
+    ];
+    let mut redirected: Vec<ExistingBookmark> = vec![];
+    let page_size = 1000;
+    let mut last_seen = None;
+    loop {
+        let mut page = txn
+            .list_bookmarks_matching(&criteria, page_size, last_seen)
+            .await?;
+        let next = if page.len() > usize::from(page_size) {
+            page.pop().map(|bm| bm.id)
+        } else {
+            None
+        };
+        redirected.extend(page);
+        match next {
+            Some(next) => last_seen = Some(next),
+            None => break,
+        }
+    }
+
+    let mut fixes = vec![];
+    for mut bookmark in redirected {
+        let old_url_id = txn.ensure_url(&bookmark.url).await?;
+        let Some(to) = txn
+            .link_check_for_url(old_url_id)
+            .await?
+            .and_then(|check| check.redirected_to)
+        else {
+            continue;
+        };
+        let from = std::mem::replace(&mut bookmark.url, to.clone());
+        match txn.find_bookmark_with_url(&to).await? {
+            // The new location has the same canonical URL (e.g. it only
+            // moved to https), so it's this bookmark that stands for it:
+            Some(existing) if existing.id == bookmark.id => {}
+            Some(_) => {
+                fixes.push(RedirectFix::AlreadyBookmarked { from, to });
+                continue;
+            }
+            None => {}
+        }
+        txn.update_bookmark(&bookmark).await?;
+        match txn
+            .associate_bookmark_link(
+                &bookmark.id,
+                &old_url_id,
+                Some(lz_db::REDIRECTED_FROM_CONTEXT),
+            )
+            .await
+        {
+            // The bookmark was moved from there once before:
+            Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {}
+            res => res?,
+        }
+        fixes.push(RedirectFix::Updated { from, to });
+    }
+    Ok(fixes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use axum::http::StatusCode as AxumStatusCode;
+    use axum::response::{IntoResponse, Redirect};
+    use axum::routing::get;
+    use axum::Router;
+    use lz_db::IdType as _;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::http::FetcherArgs;
+    use crate::testing::{test_bookmark, test_db};
+
+    async fn stand_in_site() -> anyhow::Result<SocketAddr> {
+        let app = Router::new()
+            .route("/ok", get(|| async { "fine" }))
+            .route(
+                "/gone",
+                get(|| async { AxumStatusCode::NOT_FOUND.into_response() }),
+            )
+            .route(
+                "/broken",
+                get(|| async { AxumStatusCode::INTERNAL_SERVER_ERROR.into_response() }),
+            )
+            .route("/moved", get(|| async { Redirect::permanent("/hop") }))
+            .route("/hop", get(|| async { Redirect::temporary("/ok") }))
+            .route("/elsewhere", get(|| async { Redirect::temporary("/ok") }))
+            .route("/dir", get(|| async { Redirect::permanent("/dir/") }))
+            .route("/dir/", get(|| async { "fine" }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        Ok(addr)
+    }
+
+    #[tokio::test]
+    async fn checks_and_fixes_links() -> TestResult {
+        let addr = stand_in_site().await?;
+        let url = |path: &str| Url::parse(&format!("http://{addr}{path}")).unwrap();
+        let fetcher = Fetcher::new(FetcherArgs {
+            fetch_timeout_secs: 2,
+            fetch_allowed_networks: vec!["127.0.0.1/32".parse()?],
+            ..Default::default()
+        })?;
+
+        let conn = test_db().await?;
+        let mut txn = conn.begin_for_user("alice").await?;
+        let links = [
+            url("/ok"),
+            url("/gone"),
+            url("/broken"),
+            url("/moved"),
+            url("/elsewhere"),
+            url("/dir"),
+            Url::parse("http://nonexistent.invalid/")?,
+        ];
+        for link in &links {
+            txn.add_bookmark(test_bookmark(link)).await?;
+        }
+        txn.commit().await?;
+        // Links are only checked once, however many users bookmarked them:
+        let mut txn = conn.begin_for_user("bob").await?;
+        txn.add_bookmark(test_bookmark(&url("/moved"))).await?;
+        txn.add_bookmark(test_bookmark(&url("/ok"))).await?;
+        txn.commit().await?;
+
+        let report = check_all_links(&conn, &fetcher, Duration::ZERO).await?;
+        assert_eq!(report.checked, links.len());
+        let found: Vec<_> = report
+            .problems
+            .iter()
+            .map(|(link, check)| (link.path(), check.status, check.http_status))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("/gone", LinkStatus::NotFound, Some(404)),
+                ("/broken", LinkStatus::Failed, Some(500)),
+                ("/moved", LinkStatus::Redirected, Some(200)),
+                ("/dir", LinkStatus::Redirected, Some(200)),
+                ("/", LinkStatus::Unreachable, None),
+            ]
+        );
+        assert_eq!(report.problems[2].1.redirected_to, Some(url("/ok")));
+
+        let mut txn = conn.begin_for_user("alice").await?;
+        let broken = txn
+            .list_bookmarks_matching(&lz_db::parse_query("link:broken")?, 10, None)
+            .await?;
+        let mut broken: Vec<_> = broken.into_iter().map(|bm| bm.url).collect();
+        broken.sort();
+        assert_eq!(
+            broken,
+            vec![url("/gone"), Url::parse("http://nonexistent.invalid/")?]
+        );
+
+        // Alice has bookmarked the new location already, too; the
+        // bookmark of /dir stands for /dir/ as well, so it moves:
+        assert_eq!(
+            fix_redirects(&mut txn).await?,
+            vec![
+                RedirectFix::Updated {
+                    from: url("/dir"),
+                    to: url("/dir/")
+                },
+                RedirectFix::AlreadyBookmarked {
+                    from: url("/moved"),
+                    to: url("/ok")
+                },
+            ]
+        );
+        let moved = txn.find_bookmark_with_url(&url("/moved")).await?.unwrap();
+        let new_location = txn.find_bookmark_with_url(&url("/ok")).await?.unwrap();
+        txn.delete_bookmark(new_location.id).await?;
+        assert_eq!(
+            fix_redirects(&mut txn).await?,
+            vec![RedirectFix::Updated {
+                from: url("/moved"),
+                to: url("/ok")
+            }]
+        );
+        let fixed = txn.get_bookmark_by_id(moved.id.id()).await?;
+        assert_eq!(fixed.url, url("/ok"));
+        let associations = txn.associated_links_on_bookmarks([moved.id]).await?;
+        assert_eq!(
+            associations[&moved.id]
+                .iter()
+                .map(|assoc| (assoc.context.as_deref(), assoc.link.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(Some(lz_db::REDIRECTED_FROM_CONTEXT), url("/moved").as_str())]
+        );
+        txn.commit().await?;
+
+        // Bob's bookmark is his business:
+        let mut txn = conn.begin_for_user("bob").await?;
+        assert!(txn.find_bookmark_with_url(&url("/moved")).await?.is_some());
+        Ok(())
+    }
+}
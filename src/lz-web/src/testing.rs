@@ -0,0 +1,34 @@
+//! Helpers shared by the web server's tests.
+
+use sqlx::sqlite::SqlitePoolOptions;
+use url::Url;
+
+/// A connection to a new in-memory database with all migrations applied.
+pub(crate) async fn test_db() -> anyhow::Result<lz_db::Connection> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+    sqlx::migrate!("../lz-db/migrations").run(&pool).await?;
+    Ok(lz_db::Connection::from_pool(pool))
+}
+
+/// A bookmark of `url`, with nothing but a title set otherwise.
+pub(crate) fn test_bookmark(url: &Url) -> lz_db::Bookmark<lz_db::NoId, lz_db::NoId> {
+    lz_db::Bookmark {
+        id: lz_db::NoId,
+        user_id: lz_db::NoId,
+        url: url.clone(),
+        created_at: Default::default(),
+        modified_at: None,
+        accessed_at: None,
+        title: "A bookmark".to_string(),
+        description: None,
+        website_title: None,
+        website_description: None,
+        notes: None,
+        unread: false,
+        shared: false,
+        import_properties: None,
+    }
+}